@@ -12,7 +12,9 @@ use tokio::process::Command;
 
 use crate::api::error::ApiError;
 use crate::models::ConnectionTestResponse;
-use crate::services::connection_service::ConnectionService;
+use crate::services::connection_service::{
+    AssumeRoleOptions, AwsEndpointOptions, ConnectionService,
+};
 
 pub fn router() -> Router {
     Router::new()
@@ -43,6 +45,40 @@ struct AwsConnectionRequest {
     assume_role_arn: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     assume_role_session_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assume_role_external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assume_role_duration_seconds: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mfa_serial: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mfa_token_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    endpoint_url: Option<String>,
+}
+
+impl AwsConnectionRequest {
+    /// リクエストからAssumeRole設定を作成（`assume_role_arn`未指定の場合はNone）
+    fn assume_role_options(&self) -> Option<AssumeRoleOptions> {
+        AssumeRoleOptions::from_parts(
+            self.assume_role_arn.as_deref(),
+            self.assume_role_session_name.as_deref(),
+            self.assume_role_external_id.as_deref(),
+            self.assume_role_duration_seconds,
+            self.mfa_serial.as_deref(),
+            self.mfa_token_code.as_deref(),
+        )
+    }
+
+    /// リクエストからリージョン・エンドポイント設定を作成（空文字列は未指定として扱う）
+    fn endpoint_options(&self) -> AwsEndpointOptions {
+        AwsEndpointOptions {
+            region: self.region.clone().filter(|region| !region.is_empty()),
+            endpoint_url: self.endpoint_url.clone().filter(|url| !url.is_empty()),
+        }
+    }
 }
 
 async fn aws_login(
@@ -137,8 +173,8 @@ async fn test_aws_connection(
 ) -> Result<Json<ConnectionTestResponse>, ApiError> {
     ConnectionService::test_aws_connection(
         request.profile.clone(),
-        request.assume_role_arn.clone(),
-        request.assume_role_session_name.clone(),
+        request.assume_role_options(),
+        request.endpoint_options(),
     )
     .await
     .map(Json)
@@ -175,16 +211,16 @@ struct AzureSubscriptionsQuery {
 async fn list_azure_subscriptions(
     Query(params): Query<AzureSubscriptionsQuery>,
 ) -> Result<Json<Value>, ApiError> {
-    let service_principal_config = if params.auth_method.as_deref() == Some("service_principal")
-        && params.client_id.is_some()
-        && params.client_secret.is_some()
-    {
-        let mut config = HashMap::new();
-        config.insert("client_id".to_string(), params.client_id.unwrap());
-        config.insert("client_secret".to_string(), params.client_secret.unwrap());
-        Some(config)
-    } else {
-        None
+    let service_principal_config = match (params.client_id, params.client_secret) {
+        (Some(client_id), Some(client_secret))
+            if params.auth_method.as_deref() == Some("service_principal") =>
+        {
+            let mut config = HashMap::new();
+            config.insert("client_id".to_string(), client_id);
+            config.insert("client_secret".to_string(), client_secret);
+            Some(config)
+        }
+        _ => None,
     };
 
     ConnectionService::list_azure_subscriptions(
@@ -212,16 +248,16 @@ struct AzureResourceGroupsQuery {
 async fn list_azure_resource_groups(
     Query(params): Query<AzureResourceGroupsQuery>,
 ) -> Result<Json<Value>, ApiError> {
-    let service_principal_config = if params.auth_method.as_deref() == Some("service_principal")
-        && params.client_id.is_some()
-        && params.client_secret.is_some()
-    {
-        let mut config = HashMap::new();
-        config.insert("client_id".to_string(), params.client_id.unwrap());
-        config.insert("client_secret".to_string(), params.client_secret.unwrap());
-        Some(config)
-    } else {
-        None
+    let service_principal_config = match (params.client_id, params.client_secret) {
+        (Some(client_id), Some(client_secret))
+            if params.auth_method.as_deref() == Some("service_principal") =>
+        {
+            let mut config = HashMap::new();
+            config.insert("client_id".to_string(), client_id);
+            config.insert("client_secret".to_string(), client_secret);
+            Some(config)
+        }
+        _ => None,
     };
 
     ConnectionService::list_azure_resource_groups(
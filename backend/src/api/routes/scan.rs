@@ -68,6 +68,7 @@ async fn scan_azure(Json(request): Json<ScanRequest>) -> Result<Json<Value>, Api
 /// イベントタイプ:
 /// - `progress`: スキャン進捗の更新
 /// - `resource`: リソーススキャン完了（件数付き）
+/// - `warning`: 一部のリソースの取得失敗（スキャンは継続）
 /// - `completed`: スキャン完了（全データ付き）
 /// - `error`: エラー発生
 async fn scan_aws_stream(
@@ -130,6 +131,12 @@ async fn get_scan_status(Path(scan_id): Path<String>) -> Result<Json<Value>, Api
             if let Some(summary) = result.summary {
                 response["summary"] = json!(summary);
             }
+            if let Some(progress_detail) = result.progress_detail {
+                response["progress_detail"] = json!(progress_detail);
+            }
+            if !result.warnings.is_empty() {
+                response["warnings"] = json!(result.warnings);
+            }
             Ok(Json(response))
         }
         None => Err(ApiError::NotFound(format!(
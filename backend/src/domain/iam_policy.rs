@@ -7,10 +7,29 @@ pub struct IamPolicyDocument {
     #[serde(rename = "Version")]
     pub version: Option<String>,
 
-    #[serde(rename = "Statement")]
+    /// 単一のStatementオブジェクトも1要素の配列として読み込む
+    #[serde(rename = "Statement", deserialize_with = "deserialize_statements")]
     pub statements: Vec<PolicyStatement>,
 }
 
+/// `Statement`はオブジェクトまたは配列
+fn deserialize_statements<'de, D>(deserializer: D) -> Result<Vec<PolicyStatement>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(Box<PolicyStatement>),
+        Many(Vec<PolicyStatement>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(statement) => vec![*statement],
+        OneOrMany::Many(statements) => statements,
+    })
+}
+
 /// IAMポリシーのStatementブロック
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolicyStatement {
@@ -79,7 +98,6 @@ impl ResourceList {
 
 impl IamPolicyDocument {
     /// JSON文字列からパース
-    #[allow(dead_code)]
     pub fn from_json_str(json_str: &str) -> Result<Self, serde_json::Error> {
         serde_json::from_str(json_str)
     }
@@ -126,6 +144,22 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_single_statement_object() {
+        let json = r#"{
+            "Version": "2012-10-17",
+            "Statement": {
+                "Effect": "Deny",
+                "Action": "organizations:LeaveOrganization",
+                "Resource": "*"
+            }
+        }"#;
+
+        let doc = IamPolicyDocument::from_json_str(json).unwrap();
+        assert_eq!(doc.statements.len(), 1);
+        assert_eq!(doc.statements[0].effect, "Deny");
+    }
+
     #[test]
     fn test_action_list_as_vec() {
         let single = ActionList::Single("s3:GetObject".to_string());
@@ -0,0 +1,998 @@
+//! GetAccountAuthorizationDetailsによる一括取得の`IamClientOps`実装
+//!
+//! 通常の実装（`RealIamClient`）はエンティティごとにAPIを呼び出すため、
+//! 大規模なアカウントでは数千回のリクエストが必要になります。
+//! この実装はスキャン開始時にGetAccountAuthorizationDetailsで
+//! ユーザー・グループ・ロール・カスタマー管理ポリシーをまとめて取得し、
+//! 各トレイトメソッドには取得済みのデータから応答します。
+//! スキャナーからは同じトレイトとして扱えるため、スキャン結果のJSON形式は変わりません。
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use aws_sdk_iam::error::DisplayErrorContext;
+use aws_sdk_iam::types::{
+    AttachedPermissionsBoundary, AttachedPolicy, EntityType, PolicyDetail, Tag,
+};
+use aws_sdk_iam::Client as IamClient;
+use std::collections::HashMap;
+use tracing::info;
+
+use super::iam_client_trait::{
+    IamAccessKeyInfo, IamClientOps, IamEntityDetail, IamGroupInfo, IamInstanceProfileInfo,
+    IamLoginProfileInfo, IamMfaDeviceInfo, IamOidcProviderInfo, IamPasswordPolicyInfo,
+    IamPolicyInfo, IamRoleInfo, IamSamlProviderInfo, IamServiceLastAccessedInfo, IamUserInfo,
+    PolicyAttachment, PolicyDocument,
+};
+use super::real_iam_client::RealIamClient;
+use crate::models::ScanConfig;
+
+/// インラインポリシー（名前とURLエンコードされたドキュメント）
+#[derive(Debug, Clone)]
+pub struct InlinePolicyDetail {
+    pub policy_name: String,
+    pub document: String,
+}
+
+/// ユーザーの認可情報
+#[derive(Debug, Clone)]
+pub struct UserAuthorizationDetail {
+    pub user: IamUserInfo,
+    pub inline_policies: Vec<InlinePolicyDetail>,
+    pub attached_policies: Vec<PolicyAttachment>,
+    pub group_names: Vec<String>,
+}
+
+/// グループの認可情報
+#[derive(Debug, Clone)]
+pub struct GroupAuthorizationDetail {
+    pub group: IamGroupInfo,
+    pub inline_policies: Vec<InlinePolicyDetail>,
+    pub attached_policies: Vec<PolicyAttachment>,
+}
+
+/// ロールの認可情報
+#[derive(Debug, Clone)]
+pub struct RoleAuthorizationDetail {
+    pub role: IamRoleInfo,
+    pub inline_policies: Vec<InlinePolicyDetail>,
+    pub attached_policies: Vec<PolicyAttachment>,
+}
+
+/// カスタマー管理ポリシーとバージョンごとのドキュメント
+#[derive(Debug, Clone)]
+pub struct PolicyAuthorizationDetail {
+    pub policy: IamPolicyInfo,
+    /// (バージョンID, URLエンコードされたドキュメント)
+    pub versions: Vec<(String, String)>,
+}
+
+/// GetAccountAuthorizationDetailsの取得結果
+#[derive(Debug, Clone, Default)]
+pub struct AccountAuthorizationDetails {
+    pub users: Vec<UserAuthorizationDetail>,
+    pub groups: Vec<GroupAuthorizationDetail>,
+    pub roles: Vec<RoleAuthorizationDetail>,
+    pub policies: Vec<PolicyAuthorizationDetail>,
+    /// インスタンスプロファイル（GetAccountAuthorizationDetailsはロールに関連付けられた
+    /// ものしか返さないため、ListInstanceProfilesで別途取得する）
+    pub instance_profiles: Vec<IamInstanceProfileInfo>,
+    /// OIDC/SAMLプロバイダー（GetAccountAuthorizationDetailsの対象外のため別途取得する）
+    pub oidc_providers: Vec<IamOidcProviderInfo>,
+    pub saml_providers: Vec<IamSamlProviderInfo>,
+}
+
+/// GetAccountAuthorizationDetailsの取得結果から応答する`IamClientOps`実装
+pub struct AuthorizationDetailsIamClient {
+    details: AccountAuthorizationDetails,
+    user_index: HashMap<String, usize>,
+    group_index: HashMap<String, usize>,
+    role_index: HashMap<String, usize>,
+    policy_index: HashMap<String, usize>,
+    /// GetAccountAuthorizationDetailsに含まれない情報の取得に使用するクライアント
+    ///
+    /// 認証情報（アクセスキー・ログインプロファイル・MFAデバイス）とアカウント設定
+    /// （パスワードポリシー・エイリアス）は個別のAPIで取得します。
+    /// 未設定の場合は空として応答します。
+    per_resource_client: Option<RealIamClient>,
+}
+
+impl AuthorizationDetailsIamClient {
+    /// 取得済みの認可情報からクライアントを作成
+    pub fn new(details: AccountAuthorizationDetails) -> Self {
+        let user_index = Self::index(details.users.iter().map(|u| &u.user.user_name));
+        let group_index = Self::index(details.groups.iter().map(|g| &g.group.group_name));
+        let role_index = Self::index(details.roles.iter().map(|r| &r.role.role_name));
+        let policy_index = Self::index(details.policies.iter().map(|p| &p.policy.arn));
+        Self {
+            details,
+            user_index,
+            group_index,
+            role_index,
+            policy_index,
+            per_resource_client: None,
+        }
+    }
+
+    /// GetAccountAuthorizationDetailsを全ページ取得してクライアントを作成
+    ///
+    /// インスタンスプロファイル・OIDC/SAMLプロバイダーは、対応するスキャン対象が
+    /// 有効な場合のみ個別のAPIで取得します。
+    pub async fn fetch(client: &IamClient, config: &ScanConfig) -> Result<Self> {
+        let mut details = AccountAuthorizationDetails::default();
+        let mut paginator = client
+            .get_account_authorization_details()
+            .filter(EntityType::User)
+            .filter(EntityType::Group)
+            .filter(EntityType::Role)
+            .filter(EntityType::LocalManagedPolicy)
+            .into_paginator()
+            .send();
+
+        let mut pages = 0;
+        while let Some(page_result) = paginator.next().await {
+            let page = page_result.map_err(|e| {
+                anyhow!(
+                    "Failed to get account authorization details: {}",
+                    DisplayErrorContext(&e)
+                )
+            })?;
+            pages += 1;
+
+            for user in page.user_detail_list() {
+                details.users.push(UserAuthorizationDetail {
+                    user: IamUserInfo {
+                        user_name: user.user_name().unwrap_or_default().to_string(),
+                        user_id: user.user_id().unwrap_or_default().to_string(),
+                        arn: user.arn().unwrap_or_default().to_string(),
+                        create_date: user.create_date().map(|dt| dt.secs()).unwrap_or(0),
+                        path: user.path().unwrap_or("/").to_string(),
+                        tags: convert_tags(user.tags()),
+                        permissions_boundary: permissions_boundary_arn(user.permissions_boundary()),
+                        // UserDetailにはパスワードの最終使用日時が含まれないため、
+                        // ListUsersの結果で後から補完する
+                        password_last_used: None,
+                    },
+                    inline_policies: convert_inline_policies(user.user_policy_list()),
+                    attached_policies: convert_attached_policies(user.attached_managed_policies()),
+                    group_names: user.group_list().to_vec(),
+                });
+            }
+
+            for group in page.group_detail_list() {
+                details.groups.push(GroupAuthorizationDetail {
+                    group: IamGroupInfo {
+                        group_name: group.group_name().unwrap_or_default().to_string(),
+                        group_id: group.group_id().unwrap_or_default().to_string(),
+                        arn: group.arn().unwrap_or_default().to_string(),
+                        create_date: group.create_date().map(|dt| dt.secs()).unwrap_or(0),
+                        path: group.path().unwrap_or("/").to_string(),
+                    },
+                    inline_policies: convert_inline_policies(group.group_policy_list()),
+                    attached_policies: convert_attached_policies(group.attached_managed_policies()),
+                });
+            }
+
+            for role in page.role_detail_list() {
+                details.roles.push(RoleAuthorizationDetail {
+                    role: IamRoleInfo {
+                        role_name: role.role_name().unwrap_or_default().to_string(),
+                        role_id: role.role_id().unwrap_or_default().to_string(),
+                        arn: role.arn().unwrap_or_default().to_string(),
+                        create_date: role.create_date().map(|dt| dt.secs()).unwrap_or(0),
+                        path: role.path().unwrap_or("/").to_string(),
+                        assume_role_policy_document: role
+                            .assume_role_policy_document()
+                            .map(|s| s.to_string()),
+                        tags: convert_tags(role.tags()),
+                        // RoleDetailには説明と最大セッション時間が含まれないため、
+                        // ListRolesの結果で後から補完する
+                        description: None,
+                        max_session_duration: None,
+                        permissions_boundary: permissions_boundary_arn(role.permissions_boundary()),
+                        last_used_date: role
+                            .role_last_used()
+                            .and_then(|u| u.last_used_date())
+                            .map(|dt| dt.secs()),
+                        last_used_region: role
+                            .role_last_used()
+                            .and_then(|u| u.region())
+                            .map(|s| s.to_string()),
+                    },
+                    inline_policies: convert_inline_policies(role.role_policy_list()),
+                    attached_policies: convert_attached_policies(role.attached_managed_policies()),
+                });
+            }
+
+            for policy in page.policies() {
+                details.policies.push(PolicyAuthorizationDetail {
+                    policy: IamPolicyInfo {
+                        policy_name: policy.policy_name().unwrap_or("").to_string(),
+                        policy_id: policy.policy_id().unwrap_or("").to_string(),
+                        arn: policy.arn().unwrap_or("").to_string(),
+                        path: policy.path().unwrap_or("/").to_string(),
+                        default_version_id: policy.default_version_id().unwrap_or("").to_string(),
+                        attachment_count: policy.attachment_count().unwrap_or(0),
+                        create_date: policy.create_date().map(|dt| dt.secs()).unwrap_or(0),
+                        update_date: policy.update_date().map(|dt| dt.secs()).unwrap_or(0),
+                        description: policy.description().unwrap_or("").to_string(),
+                    },
+                    versions: policy
+                        .policy_version_list()
+                        .iter()
+                        .filter_map(|version| {
+                            Some((
+                                version.version_id()?.to_string(),
+                                version.document()?.to_string(),
+                            ))
+                        })
+                        .collect(),
+                });
+            }
+        }
+
+        Self::fill_password_last_used(client, &mut details.users).await?;
+        Self::fill_role_settings(client, &mut details.roles).await?;
+
+        let real_client = RealIamClient::new(client.clone());
+        let scan_targets = &config.scan_targets;
+        if scan_targets
+            .get("instance_profiles")
+            .copied()
+            .unwrap_or(false)
+        {
+            details.instance_profiles = real_client.list_instance_profiles().await?;
+        }
+        if scan_targets.get("oidc_providers").copied().unwrap_or(false) {
+            details.oidc_providers = real_client.list_open_id_connect_providers().await?;
+        }
+        if scan_targets.get("saml_providers").copied().unwrap_or(false) {
+            details.saml_providers = real_client.list_saml_providers().await?;
+        }
+
+        info!(
+            pages,
+            users = details.users.len(),
+            groups = details.groups.len(),
+            roles = details.roles.len(),
+            policies = details.policies.len(),
+            instance_profiles = details.instance_profiles.len(),
+            oidc_providers = details.oidc_providers.len(),
+            saml_providers = details.saml_providers.len(),
+            "GetAccountAuthorizationDetailsの取得完了"
+        );
+
+        Ok(Self {
+            per_resource_client: Some(real_client),
+            ..Self::new(details)
+        })
+    }
+
+    /// ListUsersで取得できるパスワードの最終使用日時をユーザーに補完
+    ///
+    /// 補完しない場合、コンソールのみを使用するユーザーが未使用と判定されるため、
+    /// 取得に失敗した場合はエラーとします。
+    async fn fill_password_last_used(
+        client: &IamClient,
+        users: &mut [UserAuthorizationDetail],
+    ) -> Result<()> {
+        let mut paginator = client.list_users().into_paginator().page_size(100).send();
+        let mut listed = Vec::new();
+
+        while let Some(page_result) = paginator.next().await {
+            let page = page_result
+                .map_err(|e| anyhow!("Failed to list users: {}", DisplayErrorContext(&e)))?;
+            listed.extend(page.users().iter().map(|user| {
+                (
+                    user.user_name().to_string(),
+                    user.password_last_used().map(|dt| dt.secs()),
+                )
+            }));
+        }
+
+        Self::apply_password_last_used(users, listed);
+        Ok(())
+    }
+
+    /// ユーザー名ごとのパスワード最終使用日時をユーザーに設定
+    fn apply_password_last_used(
+        users: &mut [UserAuthorizationDetail],
+        listed: impl IntoIterator<Item = (String, Option<i64>)>,
+    ) {
+        let user_index = Self::index(users.iter().map(|u| &u.user.user_name));
+        for (user_name, password_last_used) in listed {
+            if let Some(&i) = user_index.get(&user_name) {
+                users[i].user.password_last_used = password_last_used;
+            }
+        }
+    }
+
+    /// ListRolesで取得できる説明と最大セッション時間をロールに補完
+    async fn fill_role_settings(
+        client: &IamClient,
+        roles: &mut [RoleAuthorizationDetail],
+    ) -> Result<()> {
+        let role_index = Self::index(roles.iter().map(|r| &r.role.role_name));
+        let mut paginator = client.list_roles().into_paginator().page_size(100).send();
+
+        while let Some(page_result) = paginator.next().await {
+            let page = page_result
+                .map_err(|e| anyhow!("Failed to list roles: {}", DisplayErrorContext(&e)))?;
+            for role in page.roles() {
+                if let Some(&i) = role_index.get(role.role_name()) {
+                    roles[i].role.description = role.description().map(|s| s.to_string());
+                    roles[i].role.max_session_duration = role.max_session_duration();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn index<'a>(keys: impl Iterator<Item = &'a String>) -> HashMap<String, usize> {
+        keys.enumerate().map(|(i, key)| (key.clone(), i)).collect()
+    }
+
+    fn user(&self, user_name: &str) -> Option<&UserAuthorizationDetail> {
+        self.user_index
+            .get(user_name)
+            .map(|&i| &self.details.users[i])
+    }
+
+    fn group(&self, group_name: &str) -> Option<&GroupAuthorizationDetail> {
+        self.group_index
+            .get(group_name)
+            .map(|&i| &self.details.groups[i])
+    }
+
+    fn role(&self, role_name: &str) -> Option<&RoleAuthorizationDetail> {
+        self.role_index
+            .get(role_name)
+            .map(|&i| &self.details.roles[i])
+    }
+
+    fn inline_policy_names(policies: Option<&[InlinePolicyDetail]>) -> Vec<String> {
+        policies
+            .unwrap_or_default()
+            .iter()
+            .map(|p| p.policy_name.clone())
+            .collect()
+    }
+
+    fn inline_policy_document(
+        policies: Option<&[InlinePolicyDetail]>,
+        policy_name: &str,
+    ) -> Option<PolicyDocument> {
+        policies
+            .unwrap_or_default()
+            .iter()
+            .find(|p| p.policy_name == policy_name)
+            .map(|p| PolicyDocument {
+                document: p.document.clone(),
+            })
+    }
+}
+
+fn permissions_boundary_arn(boundary: Option<&AttachedPermissionsBoundary>) -> Option<String> {
+    boundary
+        .and_then(|b| b.permissions_boundary_arn())
+        .map(|arn| arn.to_string())
+}
+
+fn convert_tags(tags: &[Tag]) -> HashMap<String, String> {
+    tags.iter()
+        .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+        .collect()
+}
+
+fn convert_inline_policies(policies: &[PolicyDetail]) -> Vec<InlinePolicyDetail> {
+    policies
+        .iter()
+        .filter_map(|policy| {
+            Some(InlinePolicyDetail {
+                policy_name: policy.policy_name()?.to_string(),
+                document: policy.policy_document().unwrap_or_default().to_string(),
+            })
+        })
+        .collect()
+}
+
+fn convert_attached_policies(policies: &[AttachedPolicy]) -> Vec<PolicyAttachment> {
+    policies
+        .iter()
+        .filter_map(|policy| {
+            Some(PolicyAttachment {
+                policy_arn: policy.policy_arn()?.to_string(),
+                policy_name: policy.policy_name().map(|s| s.to_string()),
+            })
+        })
+        .collect()
+}
+
+#[async_trait]
+impl IamClientOps for AuthorizationDetailsIamClient {
+    async fn list_users(&self) -> Result<Vec<IamUserInfo>> {
+        self.list_users_with_options(true).await
+    }
+
+    async fn list_users_with_options(&self, include_tags: bool) -> Result<Vec<IamUserInfo>> {
+        Ok(self
+            .details
+            .users
+            .iter()
+            .map(|detail| {
+                let mut user = detail.user.clone();
+                if !include_tags {
+                    user.tags.clear();
+                }
+                user
+            })
+            .collect())
+    }
+
+    async fn list_groups(&self) -> Result<Vec<IamGroupInfo>> {
+        Ok(self
+            .details
+            .groups
+            .iter()
+            .map(|detail| detail.group.clone())
+            .collect())
+    }
+
+    async fn list_roles(&self) -> Result<Vec<IamRoleInfo>> {
+        self.list_roles_with_options(true).await
+    }
+
+    async fn list_roles_with_options(&self, include_tags: bool) -> Result<Vec<IamRoleInfo>> {
+        Ok(self
+            .details
+            .roles
+            .iter()
+            .map(|detail| {
+                let mut role = detail.role.clone();
+                if !include_tags {
+                    role.tags.clear();
+                }
+                role
+            })
+            .collect())
+    }
+
+    // 境界・タグ・最終使用日時はGetAccountAuthorizationDetailsの結果に含まれる
+    async fn get_user_detail(
+        &self,
+        _user_name: &str,
+        _include_tags: bool,
+    ) -> Result<Option<IamEntityDetail>> {
+        Ok(None)
+    }
+
+    async fn get_role_detail(
+        &self,
+        _role_name: &str,
+        _include_tags: bool,
+    ) -> Result<Option<IamEntityDetail>> {
+        Ok(None)
+    }
+
+    async fn list_policies(&self) -> Result<Vec<IamPolicyInfo>> {
+        Ok(self
+            .details
+            .policies
+            .iter()
+            .map(|detail| detail.policy.clone())
+            .collect())
+    }
+
+    async fn list_instance_profiles(&self) -> Result<Vec<IamInstanceProfileInfo>> {
+        Ok(self.details.instance_profiles.clone())
+    }
+
+    async fn list_open_id_connect_providers(&self) -> Result<Vec<IamOidcProviderInfo>> {
+        Ok(self.details.oidc_providers.clone())
+    }
+
+    async fn list_saml_providers(&self) -> Result<Vec<IamSamlProviderInfo>> {
+        Ok(self.details.saml_providers.clone())
+    }
+
+    async fn list_access_keys(&self, user_name: &str) -> Result<Vec<IamAccessKeyInfo>> {
+        match &self.per_resource_client {
+            Some(client) => client.list_access_keys(user_name).await,
+            None => Ok(Vec::new()),
+        }
+    }
+
+    async fn get_login_profile(&self, user_name: &str) -> Result<Option<IamLoginProfileInfo>> {
+        match &self.per_resource_client {
+            Some(client) => client.get_login_profile(user_name).await,
+            None => Ok(None),
+        }
+    }
+
+    async fn list_mfa_devices(&self, user_name: &str) -> Result<Vec<IamMfaDeviceInfo>> {
+        match &self.per_resource_client {
+            Some(client) => client.list_mfa_devices(user_name).await,
+            None => Ok(Vec::new()),
+        }
+    }
+
+    async fn get_account_password_policy(&self) -> Result<Option<IamPasswordPolicyInfo>> {
+        match &self.per_resource_client {
+            Some(client) => client.get_account_password_policy().await,
+            None => Ok(None),
+        }
+    }
+
+    async fn list_account_aliases(&self) -> Result<Vec<String>> {
+        match &self.per_resource_client {
+            Some(client) => client.list_account_aliases().await,
+            None => Ok(Vec::new()),
+        }
+    }
+
+    async fn get_service_last_accessed(
+        &self,
+        arn: &str,
+    ) -> Result<Vec<IamServiceLastAccessedInfo>> {
+        match &self.per_resource_client {
+            Some(client) => client.get_service_last_accessed(arn).await,
+            None => Ok(Vec::new()),
+        }
+    }
+
+    async fn list_user_policies(&self, user_name: &str) -> Result<Vec<String>> {
+        Ok(Self::inline_policy_names(
+            self.user(user_name).map(|u| u.inline_policies.as_slice()),
+        ))
+    }
+
+    async fn list_attached_user_policies(&self, user_name: &str) -> Result<Vec<PolicyAttachment>> {
+        Ok(self
+            .user(user_name)
+            .map(|u| u.attached_policies.clone())
+            .unwrap_or_default())
+    }
+
+    async fn list_group_policies(&self, group_name: &str) -> Result<Vec<String>> {
+        Ok(Self::inline_policy_names(
+            self.group(group_name).map(|g| g.inline_policies.as_slice()),
+        ))
+    }
+
+    async fn list_attached_group_policies(
+        &self,
+        group_name: &str,
+    ) -> Result<Vec<PolicyAttachment>> {
+        Ok(self
+            .group(group_name)
+            .map(|g| g.attached_policies.clone())
+            .unwrap_or_default())
+    }
+
+    async fn list_role_policies(&self, role_name: &str) -> Result<Vec<String>> {
+        Ok(Self::inline_policy_names(
+            self.role(role_name).map(|r| r.inline_policies.as_slice()),
+        ))
+    }
+
+    async fn list_attached_role_policies(&self, role_name: &str) -> Result<Vec<PolicyAttachment>> {
+        Ok(self
+            .role(role_name)
+            .map(|r| r.attached_policies.clone())
+            .unwrap_or_default())
+    }
+
+    async fn list_groups_for_user(&self, user_name: &str) -> Result<Vec<String>> {
+        Ok(self
+            .user(user_name)
+            .map(|u| u.group_names.clone())
+            .unwrap_or_default())
+    }
+
+    async fn get_policy_version(
+        &self,
+        policy_arn: &str,
+        version_id: &str,
+    ) -> Result<Option<PolicyDocument>> {
+        Ok(self.policy_index.get(policy_arn).and_then(|&i| {
+            self.details.policies[i]
+                .versions
+                .iter()
+                .find(|(id, _)| id == version_id)
+                .map(|(_, document)| PolicyDocument {
+                    document: document.clone(),
+                })
+        }))
+    }
+
+    async fn get_user_policy(
+        &self,
+        user_name: &str,
+        policy_name: &str,
+    ) -> Result<Option<PolicyDocument>> {
+        Ok(Self::inline_policy_document(
+            self.user(user_name).map(|u| u.inline_policies.as_slice()),
+            policy_name,
+        ))
+    }
+
+    async fn get_group_policy(
+        &self,
+        group_name: &str,
+        policy_name: &str,
+    ) -> Result<Option<PolicyDocument>> {
+        Ok(Self::inline_policy_document(
+            self.group(group_name).map(|g| g.inline_policies.as_slice()),
+            policy_name,
+        ))
+    }
+
+    async fn get_role_policy(
+        &self,
+        role_name: &str,
+        policy_name: &str,
+    ) -> Result<Option<PolicyDocument>> {
+        Ok(Self::inline_policy_document(
+            self.role(role_name).map(|r| r.inline_policies.as_slice()),
+            policy_name,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::aws::iam_client_trait::mock::MockIamClient;
+    use crate::infra::aws::scanner::AwsIamScanner;
+    use crate::models::ScanConfig;
+
+    const ADMIN_DOC: &str =
+        "%7B%22Version%22%3A%222012-10-17%22%2C%22Statement%22%3A%5B%7B%22Effect%22%3A%22Allow%22%2C%22Action%22%3A%22%2A%22%2C%22Resource%22%3A%22%2A%22%7D%5D%7D";
+    const READ_DOC: &str = r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow","Action":"s3:GetObject","Resource":"*"}]}"#;
+    const TRUST_DOC: &str = r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow","Principal":{"Service":"ec2.amazonaws.com"},"Action":"sts:AssumeRole"}]}"#;
+    const CUSTOM_POLICY_ARN: &str = "arn:aws:iam::123456789012:policy/custom";
+
+    fn inline(policy_name: &str, document: &str) -> InlinePolicyDetail {
+        InlinePolicyDetail {
+            policy_name: policy_name.to_string(),
+            document: document.to_string(),
+        }
+    }
+
+    fn attached(policy_arn: &str) -> PolicyAttachment {
+        PolicyAttachment {
+            policy_arn: policy_arn.to_string(),
+            policy_name: None,
+        }
+    }
+
+    fn sample_details() -> AccountAuthorizationDetails {
+        AccountAuthorizationDetails {
+            users: vec![UserAuthorizationDetail {
+                user: IamUserInfo {
+                    user_name: "alice".to_string(),
+                    user_id: "AIDAALICE".to_string(),
+                    arn: "arn:aws:iam::123456789012:user/alice".to_string(),
+                    create_date: 1_700_000_000,
+                    path: "/".to_string(),
+                    tags: HashMap::from([("team".to_string(), "platform".to_string())]),
+                    permissions_boundary: None,
+                    password_last_used: None,
+                },
+                inline_policies: vec![inline("alice-admin", ADMIN_DOC)],
+                attached_policies: vec![attached(CUSTOM_POLICY_ARN)],
+                group_names: vec!["developers".to_string()],
+            }],
+            groups: vec![GroupAuthorizationDetail {
+                group: IamGroupInfo {
+                    group_name: "developers".to_string(),
+                    group_id: "AGPADEV".to_string(),
+                    arn: "arn:aws:iam::123456789012:group/developers".to_string(),
+                    create_date: 1_700_000_000,
+                    path: "/".to_string(),
+                },
+                inline_policies: vec![inline("dev-read", READ_DOC)],
+                attached_policies: vec![attached("arn:aws:iam::aws:policy/ReadOnlyAccess")],
+            }],
+            roles: vec![RoleAuthorizationDetail {
+                role: IamRoleInfo {
+                    role_name: "app".to_string(),
+                    role_id: "AROAAPP".to_string(),
+                    arn: "arn:aws:iam::123456789012:role/app".to_string(),
+                    create_date: 1_700_000_000,
+                    path: "/service/".to_string(),
+                    assume_role_policy_document: Some(TRUST_DOC.to_string()),
+                    tags: HashMap::new(),
+                    description: Some("application role".to_string()),
+                    max_session_duration: Some(7200),
+                    permissions_boundary: Some(
+                        "arn:aws:iam::123456789012:policy/boundary".to_string(),
+                    ),
+                    last_used_date: None,
+                    last_used_region: None,
+                },
+                inline_policies: vec![inline("app-read", READ_DOC)],
+                attached_policies: vec![attached(CUSTOM_POLICY_ARN)],
+            }],
+            policies: vec![PolicyAuthorizationDetail {
+                policy: IamPolicyInfo {
+                    policy_name: "custom".to_string(),
+                    policy_id: "ANPACUSTOM".to_string(),
+                    arn: CUSTOM_POLICY_ARN.to_string(),
+                    path: "/".to_string(),
+                    default_version_id: "v2".to_string(),
+                    attachment_count: 2,
+                    create_date: 1_700_000_000,
+                    update_date: 1_700_000_100,
+                    description: "custom policy".to_string(),
+                },
+                versions: vec![
+                    ("v1".to_string(), ADMIN_DOC.to_string()),
+                    ("v2".to_string(), READ_DOC.to_string()),
+                ],
+            }],
+            instance_profiles: vec![IamInstanceProfileInfo {
+                instance_profile_name: "app-profile".to_string(),
+                instance_profile_id: "AIPAAPP".to_string(),
+                arn: "arn:aws:iam::123456789012:instance-profile/app-profile".to_string(),
+                create_date: 1_700_000_000,
+                path: "/".to_string(),
+                role_names: vec!["app".to_string()],
+            }],
+            oidc_providers: vec![IamOidcProviderInfo {
+                arn: "arn:aws:iam::123456789012:oidc-provider/token.actions.githubusercontent.com"
+                    .to_string(),
+                url: "token.actions.githubusercontent.com".to_string(),
+                client_id_list: vec!["sts.amazonaws.com".to_string()],
+                thumbprint_list: vec!["6938fd4d98bab03faadb97b34396831e3780aea1".to_string()],
+                create_date: 1_700_000_000,
+                tags: HashMap::new(),
+            }],
+            saml_providers: Vec::new(),
+        }
+    }
+
+    /// `sample_details`と同じ内容をエンティティ単位のAPIで返すモック
+    fn per_resource_mock(details: AccountAuthorizationDetails) -> MockIamClient {
+        let d = details;
+        let mut mock = MockIamClient::new();
+
+        let users: Vec<IamUserInfo> = d.users.iter().map(|u| u.user.clone()).collect();
+        mock.expect_get_user_detail().returning(|_, _| Ok(None));
+        mock.expect_list_users_with_options()
+            .returning(move |_| Ok(users.clone()));
+        mock.expect_list_access_keys().returning(|_| Ok(Vec::new()));
+        mock.expect_get_login_profile().returning(|_| Ok(None));
+        mock.expect_list_mfa_devices().returning(|_| Ok(Vec::new()));
+        mock.expect_get_account_password_policy()
+            .returning(|| Ok(None));
+        mock.expect_list_account_aliases()
+            .returning(|| Ok(Vec::new()));
+        let instance_profiles = d.instance_profiles.clone();
+        mock.expect_list_instance_profiles()
+            .returning(move || Ok(instance_profiles.clone()));
+        let oidc_providers = d.oidc_providers.clone();
+        mock.expect_list_open_id_connect_providers()
+            .returning(move || Ok(oidc_providers.clone()));
+        let saml_providers = d.saml_providers.clone();
+        mock.expect_list_saml_providers()
+            .returning(move || Ok(saml_providers.clone()));
+        let groups: Vec<IamGroupInfo> = d.groups.iter().map(|g| g.group.clone()).collect();
+        mock.expect_list_groups()
+            .returning(move || Ok(groups.clone()));
+        let roles: Vec<IamRoleInfo> = d.roles.iter().map(|r| r.role.clone()).collect();
+        mock.expect_get_role_detail().returning(|_, _| Ok(None));
+        mock.expect_list_roles_with_options()
+            .returning(move |_| Ok(roles.clone()));
+        let policies: Vec<IamPolicyInfo> = d.policies.iter().map(|p| p.policy.clone()).collect();
+        mock.expect_list_policies()
+            .returning(move || Ok(policies.clone()));
+
+        let user = d.users[0].clone();
+        let u = user.clone();
+        mock.expect_list_user_policies().returning(move |_| {
+            Ok(u.inline_policies
+                .iter()
+                .map(|p| p.policy_name.clone())
+                .collect())
+        });
+        let u = user.clone();
+        mock.expect_get_user_policy().returning(move |_, _| {
+            Ok(Some(PolicyDocument {
+                document: u.inline_policies[0].document.clone(),
+            }))
+        });
+        let u = user.clone();
+        mock.expect_list_attached_user_policies()
+            .returning(move |_| Ok(u.attached_policies.clone()));
+        mock.expect_list_groups_for_user()
+            .returning(move |_| Ok(user.group_names.clone()));
+
+        let group = d.groups[0].clone();
+        let g = group.clone();
+        mock.expect_list_group_policies().returning(move |_| {
+            Ok(g.inline_policies
+                .iter()
+                .map(|p| p.policy_name.clone())
+                .collect())
+        });
+        let g = group.clone();
+        mock.expect_get_group_policy().returning(move |_, _| {
+            Ok(Some(PolicyDocument {
+                document: g.inline_policies[0].document.clone(),
+            }))
+        });
+        mock.expect_list_attached_group_policies()
+            .returning(move |_| Ok(group.attached_policies.clone()));
+
+        let role = d.roles[0].clone();
+        let r = role.clone();
+        mock.expect_list_role_policies().returning(move |_| {
+            Ok(r.inline_policies
+                .iter()
+                .map(|p| p.policy_name.clone())
+                .collect())
+        });
+        let r = role.clone();
+        mock.expect_get_role_policy().returning(move |_, _| {
+            Ok(Some(PolicyDocument {
+                document: r.inline_policies[0].document.clone(),
+            }))
+        });
+        mock.expect_list_attached_role_policies()
+            .returning(move |_| Ok(role.attached_policies.clone()));
+
+        mock.expect_get_policy_version().returning(|_, _| {
+            Ok(Some(PolicyDocument {
+                document: READ_DOC.to_string(),
+            }))
+        });
+
+        mock
+    }
+
+    fn create_test_config() -> ScanConfig {
+        let scan_targets = [
+            "users",
+            "groups",
+            "roles",
+            "instance_profiles",
+            "oidc_providers",
+            "saml_providers",
+            "account_settings",
+            "policies",
+        ]
+        .iter()
+        .map(|t| (t.to_string(), true))
+        .collect();
+        ScanConfig {
+            provider: "aws".to_string(),
+            account_id: None,
+            profile: None,
+            assume_role_arn: None,
+            assume_role_session_name: None,
+            assume_role_external_id: None,
+            assume_role_duration_seconds: None,
+            mfa_serial: None,
+            mfa_token_code: None,
+            account_ids: Vec::new(),
+            discover_accounts: false,
+            member_role_name: None,
+            region: None,
+            endpoint_url: None,
+            subscription_id: None,
+            tenant_id: None,
+            auth_method: None,
+            service_principal_config: None,
+            scope_type: None,
+            scope_value: None,
+            scan_targets,
+            filters: HashMap::new(),
+            include_tags: true,
+            max_concurrency: None,
+            scan_strategy: Some("authorization_details".to_string()),
+            include_service_last_accessed: false,
+            unused_threshold_days: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scan_result_matches_per_resource_strategy() {
+        let bulk_scanner = AwsIamScanner::new_with_client(
+            create_test_config(),
+            AuthorizationDetailsIamClient::new(sample_details()),
+        );
+        let per_resource_scanner = AwsIamScanner::new_with_client(
+            create_test_config(),
+            per_resource_mock(sample_details()),
+        );
+
+        let bulk = bulk_scanner.scan(Box::new(|_| {})).await.unwrap();
+        let per_resource = per_resource_scanner.scan(Box::new(|_| {})).await.unwrap();
+
+        assert_eq!(bulk, per_resource);
+        assert_eq!(
+            bulk["attachments"]["user_groups"][0]["group_name"],
+            "developers"
+        );
+        assert_eq!(bulk["instance_profiles"][0]["role_name"], "app");
+        assert_eq!(
+            bulk["oidc_providers"][0]["url"],
+            "https://token.actions.githubusercontent.com"
+        );
+        assert_eq!(
+            bulk["policies"][0]["policy_document"]["Statement"][0]["Action"],
+            "s3:GetObject"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_console_only_user_is_not_reported_unused() {
+        let now = chrono::Utc::now().timestamp();
+        let mut details = sample_details();
+        details.users[0].user.create_date = now - 400 * 86_400;
+        // ListUsersで取得したパスワードの最終使用日時を補完（アクセスキーは無い）
+        AuthorizationDetailsIamClient::apply_password_last_used(
+            &mut details.users,
+            vec![
+                ("alice".to_string(), Some(now - 86_400)),
+                ("nobody".to_string(), Some(now)),
+            ],
+        );
+        assert_eq!(details.users[0].user.password_last_used, Some(now - 86_400));
+
+        let mut config = create_test_config();
+        config.scan_targets.insert("cleanup".to_string(), true);
+        let scanner =
+            AwsIamScanner::new_with_client(config, AuthorizationDetailsIamClient::new(details));
+        let result = scanner.scan(Box::new(|_| {})).await.unwrap();
+
+        let alice = &result["users"][0];
+        assert_eq!(alice["last_used_date"], now - 86_400);
+        assert_eq!(alice["unused"], false);
+        assert!(result["unused_identities"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|identity| identity["name"] != "alice"));
+    }
+
+    #[tokio::test]
+    async fn test_lookups_for_unknown_entities_are_empty() {
+        let client = AuthorizationDetailsIamClient::new(sample_details());
+
+        assert!(client
+            .list_user_policies("nobody")
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(client
+            .list_groups_for_user("nobody")
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(client
+            .get_role_policy("app", "missing")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(client
+            .get_policy_version(CUSTOM_POLICY_ARN, "v3")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_list_users_without_tags() {
+        let client = AuthorizationDetailsIamClient::new(sample_details());
+
+        let users = client.list_users_with_options(false).await.unwrap();
+        assert!(users[0].tags.is_empty());
+        let users = client.list_users_with_options(true).await.unwrap();
+        assert_eq!(
+            users[0].tags.get("team").map(String::as_str),
+            Some("platform")
+        );
+    }
+}
@@ -0,0 +1,345 @@
+//! AWS CLI実行ヘルパー
+//!
+//! Rust SDKクレートを導入していないサービス（Organizations等）は、
+//! AWS CLIをサブプロセスとして実行してJSON出力を取得します。
+//! 認証情報は`SdkConfig`の認証情報プロバイダーからコマンドの実行ごとに解決し
+//! （有効期限が近づくまではキャッシュを使用）、子プロセスの環境変数としてのみ渡すため、
+//! プロセス全体の環境変数は変更しません。AssumeRoleの一時認証情報もSDKクライアントと
+//! 同様に更新されます。親プロセスの`AWS_*`環境変数は子プロセスに引き継がないため、
+//! スキャン対象アカウント以外の認証情報が混入することはありません。
+//!
+//! 実行環境にAWS CLI v2（`AWS_ENDPOINT_URL`に対応したバージョン）が必要です。
+
+use anyhow::{anyhow, Context, Result};
+use aws_config::SdkConfig;
+use aws_sdk_sts::config::{Credentials, ProvideCredentials, SharedCredentialsProvider};
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::process::Command;
+use tokio::sync::Mutex;
+
+/// 有効期限までの残り時間がこれ未満の認証情報は再取得する
+const CREDENTIALS_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// 子プロセスに必ず引き継がない認証情報関連の環境変数
+///
+/// 親プロセスに設定されている`AWS_*`環境変数もすべて取り除きますが、
+/// 認証情報の変数はスナップショットに含まれない場合も確実に取り除くため明示します。
+const CREDENTIAL_ENV_VARS: &[&str] = &[
+    "AWS_PROFILE",
+    "AWS_DEFAULT_PROFILE",
+    "AWS_ACCESS_KEY_ID",
+    "AWS_SECRET_ACCESS_KEY",
+    "AWS_SESSION_TOKEN",
+    "AWS_SECURITY_TOKEN",
+    "AWS_ROLE_ARN",
+    "AWS_ROLE_SESSION_NAME",
+    "AWS_WEB_IDENTITY_TOKEN_FILE",
+    "AWS_CONTAINER_CREDENTIALS_RELATIVE_URI",
+    "AWS_CONTAINER_CREDENTIALS_FULL_URI",
+    "AWS_CONTAINER_AUTHORIZATION_TOKEN",
+];
+
+/// AWS CLIをサブプロセスとして実行するランナー
+///
+/// クローンしたランナーは認証情報のキャッシュを共有します。
+#[derive(Debug, Clone, Default)]
+pub struct AwsCliRunner {
+    /// 認証情報の取得元（SDK設定の認証情報プロバイダー）
+    credentials_provider: Option<SharedCredentialsProvider>,
+    /// 最後に取得した認証情報
+    cached_credentials: Arc<Mutex<Option<Credentials>>>,
+    /// 子プロセスにのみ設定する環境変数（リージョン・エンドポイント）
+    env: Vec<(String, String)>,
+}
+
+impl AwsCliRunner {
+    /// SDK設定の認証情報プロバイダー・リージョン・エンドポイントを引き継いだランナーを作成
+    pub fn new(config: &SdkConfig) -> Self {
+        let mut env = Vec::new();
+        if let Some(region) = config.region() {
+            env.push(("AWS_REGION".to_string(), region.to_string()));
+            env.push(("AWS_DEFAULT_REGION".to_string(), region.to_string()));
+        }
+        // AWS CLI v2は`AWS_ENDPOINT_URL`を全サービス共通のエンドポイントとして使用する
+        if let Some(endpoint_url) = config.endpoint_url() {
+            env.push(("AWS_ENDPOINT_URL".to_string(), endpoint_url.to_string()));
+        }
+
+        Self {
+            credentials_provider: config.credentials_provider(),
+            cached_credentials: Arc::default(),
+            env,
+        }
+    }
+
+    /// コマンドの実行に使用する認証情報を取得
+    ///
+    /// キャッシュした認証情報の有効期限が近い場合はプロバイダーから再取得します。
+    async fn credentials(&self) -> Result<Credentials> {
+        let provider = self
+            .credentials_provider
+            .as_ref()
+            .ok_or_else(|| anyhow!("No AWS credentials provider is configured"))?;
+
+        let mut cached = self.cached_credentials.lock().await;
+        if let Some(credentials) = cached
+            .as_ref()
+            .filter(|c| !needs_refresh(c, SystemTime::now()))
+        {
+            return Ok(credentials.clone());
+        }
+        let credentials = provider
+            .provide_credentials()
+            .await
+            .map_err(|e| anyhow!("Failed to resolve AWS credentials: {}", e))?;
+        *cached = Some(credentials.clone());
+        Ok(credentials)
+    }
+
+    /// SDK設定から引き継いだリージョン
+    pub fn region(&self) -> Option<&str> {
+        self.env
+            .iter()
+            .find(|(key, _)| key == "AWS_REGION")
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// AWS CLIコマンドを実行し、JSON出力をパースして返す
+    ///
+    /// `--output json`は自動的に付与されます。
+    pub async fn run_json(&self, args: &[&str]) -> Result<Value> {
+        let credentials = self.credentials().await?;
+        let output = self
+            .command(args, &credentials)
+            .output()
+            .await
+            .with_context(|| format!("Failed to execute aws {}", args.join(" ")))?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "aws {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if stdout.trim().is_empty() {
+            return Ok(Value::Null);
+        }
+        serde_json::from_str(&stdout)
+            .with_context(|| format!("Failed to parse output of aws {}", args.join(" ")))
+    }
+
+    /// AWS CLIの子プロセスを構築
+    ///
+    /// 親プロセスの`AWS_*`環境変数を取り除いた上で、認証情報とこのランナーの
+    /// 環境変数のみを設定します。
+    fn command(&self, args: &[&str], credentials: &Credentials) -> Command {
+        let mut command = Command::new("aws");
+        command.args(args).args(["--output", "json"]);
+        for key in CREDENTIAL_ENV_VARS {
+            command.env_remove(key);
+        }
+        for (key, _) in std::env::vars_os() {
+            if key.to_string_lossy().starts_with("AWS_") {
+                command.env_remove(key);
+            }
+        }
+        command
+            .env("AWS_ACCESS_KEY_ID", credentials.access_key_id())
+            .env("AWS_SECRET_ACCESS_KEY", credentials.secret_access_key());
+        if let Some(session_token) = credentials.session_token() {
+            command.env("AWS_SESSION_TOKEN", session_token);
+        }
+        command.envs(self.env.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+        command
+    }
+}
+
+/// 認証情報を再取得する必要があるかどうか（有効期限のない認証情報は再取得しない）
+fn needs_refresh(credentials: &Credentials, now: SystemTime) -> bool {
+    credentials
+        .expiry()
+        .is_some_and(|expiry| expiry <= now + CREDENTIALS_REFRESH_MARGIN)
+}
+
+/// オブジェクトの文字列フィールドを取得（存在しない場合は空文字列）
+pub(crate) fn field(item: &Value, key: &str) -> String {
+    item.get(key)
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string()
+}
+
+/// オブジェクトの任意の文字列フィールドを取得（空文字列はNone）
+pub(crate) fn optional_field(item: &Value, key: &str) -> Option<String> {
+    item.get(key)
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+}
+
+/// 文字列配列のフィールドを取得
+pub(crate) fn string_array(output: &Value, key: &str) -> Vec<String> {
+    output
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str())
+                .map(|item| item.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_config::{BehaviorVersion, Region};
+    use aws_credential_types::credential_fn::provide_credentials_fn;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn sdk_config(credentials: Credentials) -> SdkConfig {
+        SdkConfig::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new("us-east-1"))
+            .credentials_provider(SharedCredentialsProvider::new(credentials))
+            .build()
+    }
+
+    /// 子プロセスに明示的に設定・削除される環境変数（削除はNone）
+    async fn command_envs(runner: &AwsCliRunner) -> HashMap<String, Option<String>> {
+        let credentials = runner.credentials().await.unwrap();
+        runner
+            .command(&["sts", "get-caller-identity"], &credentials)
+            .as_std()
+            .get_envs()
+            .map(|(key, value)| {
+                (
+                    key.to_string_lossy().to_string(),
+                    value.map(|v| v.to_string_lossy().to_string()),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_runners_do_not_share_credentials() {
+        let hub = AwsCliRunner::new(&sdk_config(Credentials::new(
+            "AKIAHUB",
+            "hub-secret",
+            Some("hub-token".to_string()),
+            None,
+            "test",
+        )));
+        let member = AwsCliRunner::new(&sdk_config(Credentials::new(
+            "AKIAMEMBER",
+            "member-secret",
+            None,
+            None,
+            "test",
+        )));
+
+        let hub_envs = command_envs(&hub).await;
+        let member_envs = command_envs(&member).await;
+
+        assert_eq!(hub_envs["AWS_ACCESS_KEY_ID"].as_deref(), Some("AKIAHUB"));
+        assert_eq!(hub_envs["AWS_SESSION_TOKEN"].as_deref(), Some("hub-token"));
+        assert_eq!(
+            member_envs["AWS_ACCESS_KEY_ID"].as_deref(),
+            Some("AKIAMEMBER")
+        );
+        assert_eq!(
+            member_envs["AWS_SECRET_ACCESS_KEY"].as_deref(),
+            Some("member-secret")
+        );
+        // セッショントークンを持たない認証情報では、親プロセスのトークンも引き継がない
+        assert_eq!(member_envs["AWS_SESSION_TOKEN"], None);
+        assert_eq!(member_envs["AWS_PROFILE"], None);
+        assert_eq!(member_envs["AWS_WEB_IDENTITY_TOKEN_FILE"], None);
+    }
+
+    #[test]
+    fn test_needs_refresh() {
+        let now = SystemTime::now();
+        let expiring = |expiry: Option<SystemTime>| {
+            Credentials::new("AKIA", "secret", Some("token".to_string()), expiry, "test")
+        };
+
+        assert!(!needs_refresh(&expiring(None), now));
+        assert!(!needs_refresh(
+            &expiring(Some(now + Duration::from_secs(3600))),
+            now
+        ));
+        // 有効期限が近い・切れている認証情報は再取得する
+        assert!(needs_refresh(
+            &expiring(Some(now + Duration::from_secs(60))),
+            now
+        ));
+        assert!(needs_refresh(
+            &expiring(Some(now - Duration::from_secs(60))),
+            now
+        ));
+    }
+
+    /// 取得のたびに異なるアクセスキーを返し、呼び出し回数を数える認証情報プロバイダー
+    fn counting_config(lifetime: Duration) -> (SdkConfig, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let provider = provide_credentials_fn(move || {
+            let number = counter.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                Ok(Credentials::new(
+                    format!("ASIA{}", number),
+                    "secret",
+                    Some("token".to_string()),
+                    Some(SystemTime::now() + lifetime),
+                    "test",
+                ))
+            }
+        });
+        let config = SdkConfig::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .credentials_provider(SharedCredentialsProvider::new(provider))
+            .build();
+        (config, calls)
+    }
+
+    #[tokio::test]
+    async fn test_expiring_credentials_are_resolved_per_command() {
+        let (config, calls) = counting_config(Duration::from_secs(60));
+        let runner = AwsCliRunner::new(&config);
+
+        // 期限切れ間近の認証情報は再利用せず、実行のたびにプロバイダーから取得する
+        assert_eq!(runner.credentials().await.unwrap().access_key_id(), "ASIA1");
+        assert_eq!(runner.credentials().await.unwrap().access_key_id(), "ASIA2");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_valid_credentials_are_cached() {
+        let (config, calls) = counting_config(Duration::from_secs(3600));
+        let runner = AwsCliRunner::new(&config);
+        let cloned = runner.clone();
+
+        assert_eq!(runner.credentials().await.unwrap().access_key_id(), "ASIA1");
+        assert_eq!(cloned.credentials().await.unwrap().access_key_id(), "ASIA1");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_credentials_require_provider() {
+        let runner = AwsCliRunner::new(
+            &SdkConfig::builder()
+                .behavior_version(BehaviorVersion::latest())
+                .build(),
+        );
+        assert!(runner.credentials().await.is_err());
+    }
+}
@@ -1,93 +1,321 @@
-use anyhow::{Context, Result};
-use aws_config::SdkConfig;
-use aws_sdk_iam::Client as IamClient;
+use anyhow::{anyhow, Context, Result};
+use aws_config::sts::AssumeRoleProvider;
+use aws_config::{Region, SdkConfig};
+use aws_sdk_sts::config::{Credentials, ProvideCredentials, SharedCredentialsProvider};
+use aws_sdk_sts::error::DisplayErrorContext;
 use aws_sdk_sts::Client as StsClient;
+use std::time::{Duration, SystemTime};
 
-use crate::models::ConnectionTestResponse;
+use crate::models::{ConnectionTestResponse, ScanConfig};
+
+/// AssumeRoleのセッション名が指定されていない場合のデフォルト値
+const DEFAULT_ASSUME_ROLE_SESSION_NAME: &str = "tfkosmos-session";
+
+/// STS AssumeRoleの設定
+#[derive(Debug, Clone, Default)]
+pub struct AssumeRoleOptions {
+    /// 引き受けるロールのARN
+    pub role_arn: String,
+    /// セッション名（未指定の場合は`tfkosmos-session`）
+    pub session_name: Option<String>,
+    /// 信頼ポリシーで要求される外部ID
+    pub external_id: Option<String>,
+    /// セッションの有効期間（秒）
+    pub duration_seconds: Option<i32>,
+    /// MFAデバイスのシリアル番号（ARN）
+    pub mfa_serial: Option<String>,
+    /// MFAデバイスのワンタイムコード
+    pub mfa_token_code: Option<String>,
+}
+
+impl AssumeRoleOptions {
+    /// 各項目からAssumeRole設定を作成（`role_arn`が未指定・空文字列の場合はNone）
+    ///
+    /// スキャン設定と接続テストのリクエストはどちらもこの関数で変換します。
+    pub fn from_parts(
+        role_arn: Option<&str>,
+        session_name: Option<&str>,
+        external_id: Option<&str>,
+        duration_seconds: Option<i32>,
+        mfa_serial: Option<&str>,
+        mfa_token_code: Option<&str>,
+    ) -> Option<Self> {
+        let role_arn = role_arn.filter(|arn| !arn.is_empty())?;
+        Some(Self {
+            role_arn: role_arn.to_string(),
+            session_name: session_name.map(str::to_string),
+            external_id: external_id.map(str::to_string),
+            duration_seconds,
+            mfa_serial: mfa_serial.map(str::to_string),
+            mfa_token_code: mfa_token_code.map(str::to_string),
+        })
+    }
+
+    /// スキャン設定からAssumeRole設定を作成（`assume_role_arn`未指定の場合はNone）
+    pub fn from_scan_config(config: &ScanConfig) -> Option<Self> {
+        Self::from_parts(
+            config.assume_role_arn.as_deref(),
+            config.assume_role_session_name.as_deref(),
+            config.assume_role_external_id.as_deref(),
+            config.assume_role_duration_seconds,
+            config.mfa_serial.as_deref(),
+            config.mfa_token_code.as_deref(),
+        )
+    }
+
+    /// 実際に使用するセッション名
+    pub fn session_name(&self) -> &str {
+        self.session_name
+            .as_deref()
+            .filter(|name| !name.is_empty())
+            .unwrap_or(DEFAULT_ASSUME_ROLE_SESSION_NAME)
+    }
+
+    /// STSを呼び出す前に設定の整合性を検証
+    pub fn validate(&self) -> Result<()> {
+        if self.role_arn.is_empty() {
+            return Err(anyhow!("assume_role_arn must not be empty"));
+        }
+        if let Some(duration) = self.duration_seconds {
+            // STSのDurationSecondsは900秒（15分）〜43200秒（12時間）
+            if !(900..=43200).contains(&duration) {
+                return Err(anyhow!(
+                    "assume_role_duration_seconds must be between 900 and 43200, got {}",
+                    duration
+                ));
+            }
+        }
+        if self.mfa_serial.is_some() && self.mfa_token_code.is_none() {
+            return Err(anyhow!(
+                "mfa_token_code is required when mfa_serial is specified"
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// リージョン・エンドポイントの上書き設定
+///
+/// LocalStackやmotoなどのローカル環境に対してスキャンする場合や、
+/// リージョナルサービスのスキャン対象リージョンを明示する場合に使用します。
+#[derive(Debug, Clone, Default)]
+pub struct AwsEndpointOptions {
+    /// 使用するリージョン（未指定の場合はプロファイル・環境変数の設定）
+    pub region: Option<String>,
+    /// 全サービス共通のエンドポイントURL（例: `http://localhost:4566`）
+    pub endpoint_url: Option<String>,
+}
+
+impl AwsEndpointOptions {
+    /// スキャン設定からリージョン・エンドポイント設定を作成（空文字列は未指定として扱う）
+    pub fn from_scan_config(config: &ScanConfig) -> Self {
+        Self {
+            region: config.region.clone().filter(|region| !region.is_empty()),
+            endpoint_url: config.endpoint_url.clone().filter(|url| !url.is_empty()),
+        }
+    }
+
+    /// SDK設定を読み込む前に設定の整合性を検証
+    pub fn validate(&self) -> Result<()> {
+        if let Some(endpoint_url) = &self.endpoint_url {
+            if !endpoint_url.starts_with("http://") && !endpoint_url.starts_with("https://") {
+                return Err(anyhow!(
+                    "endpoint_url must start with http:// or https://, got {}",
+                    endpoint_url
+                ));
+            }
+        }
+        Ok(())
+    }
+}
 
 pub struct AwsClientFactory;
 
 impl AwsClientFactory {
     pub async fn create_config(
         profile: Option<String>,
-        assume_role_arn: Option<String>,
-        _assume_role_session_name: Option<String>,
+        assume_role: Option<&AssumeRoleOptions>,
+        endpoint: &AwsEndpointOptions,
     ) -> Result<SdkConfig> {
+        endpoint.validate()?;
+        let mut config_loader = aws_config::defaults(aws_config::BehaviorVersion::latest());
+
+        if let Some(profile_name) = &profile {
+            config_loader = config_loader.profile_name(profile_name);
+        }
+        // 指定されたリージョン・エンドポイントはIAM・STSを含む全クライアントで使用される
+        if let Some(region) = &endpoint.region {
+            config_loader = config_loader.region(Region::new(region.clone()));
+        }
+        if let Some(endpoint_url) = &endpoint.endpoint_url {
+            config_loader = config_loader.endpoint_url(endpoint_url);
+        }
+
         // aws loginで設定された認証情報を使用する場合、AWS CLIコマンド経由で認証情報を取得
         // これは、aws-configがlogin_sessionを直接サポートしていないため
-        let profile_name = profile.as_deref().unwrap_or("default");
-
-        // AWS CLIコマンドで認証情報を取得（aws loginで設定された認証情報を使用）
-        // 環境変数が設定されていない場合のみ実行
-        if std::env::var("AWS_ACCESS_KEY_ID").is_err()
+        // 取得した認証情報はこの設定専用のプロバイダーとして使用し、
+        // プロセスの環境変数には書き込まない（同時実行中の他スキャンと共有しないため）
+        if profile.is_some()
+            || std::env::var("AWS_ACCESS_KEY_ID").is_err()
             || std::env::var("AWS_SECRET_ACCESS_KEY").is_err()
         {
-            let credentials_output = tokio::process::Command::new("aws")
-                .args([
-                    "configure",
-                    "export-credentials",
-                    "--profile",
-                    profile_name,
-                    "--format",
-                    "env",
-                ])
-                .output()
-                .await;
-
-            // 環境変数を設定（成功した場合）
-            if let Ok(output) = credentials_output {
-                if output.status.success() {
-                    let env_vars = String::from_utf8_lossy(&output.stdout);
-                    for line in env_vars.lines() {
-                        if let Some((key, value)) = line.split_once('=') {
-                            let key = key.trim().trim_start_matches("export ");
-                            let value = value.trim().trim_matches('"');
-                            if !key.is_empty() && !value.is_empty() {
-                                std::env::set_var(key, value);
-                            }
-                        }
-                    }
-                }
+            let profile_name = profile.as_deref().unwrap_or("default");
+            if let Some(credentials) = Self::export_credentials(profile_name).await {
+                config_loader =
+                    config_loader.credentials_provider(SharedCredentialsProvider::new(credentials));
             }
         }
 
-        let mut config_loader = aws_config::defaults(aws_config::BehaviorVersion::latest());
+        let config = config_loader.load().await;
 
-        if let Some(profile_name) = &profile {
-            config_loader = config_loader.profile_name(profile_name);
+        // AssumeRoleが指定されている場合は、ベースの認証情報でロールを引き受ける
+        match assume_role {
+            Some(options) => Self::assume_role(&config, options).await,
+            None => Ok(config),
         }
+    }
 
-        let config = config_loader.load().await;
+    /// `aws configure export-credentials`でプロファイルの認証情報を取得
+    ///
+    /// コマンドが失敗した場合は`None`を返し、SDKの標準の認証情報チェーンに任せます。
+    async fn export_credentials(profile_name: &str) -> Option<Credentials> {
+        let output = tokio::process::Command::new("aws")
+            .args([
+                "configure",
+                "export-credentials",
+                "--profile",
+                profile_name,
+                "--format",
+                "env",
+            ])
+            .output()
+            .await
+            .ok()?;
 
-        // Handle assume role if provided
-        if let Some(_role_arn) = assume_role_arn {
-            // In a real implementation, use STS to assume the role
-            // For now, return the base config
-            // TODO: Implement assume role logic
+        if !output.status.success() {
+            return None;
         }
+        parse_exported_credentials(&String::from_utf8_lossy(&output.stdout))
+    }
 
-        Ok(config)
+    /// STS AssumeRoleで引き受けたロールの認証情報を使用する設定を作成
+    ///
+    /// `base_config`が引き受け済みロールの設定の場合は、そのロールからの連鎖となります。
+    /// 一時認証情報の有効期限が近づくと、SDKが自動的に再度AssumeRoleします。
+    /// ただしMFAのワンタイムコードは再利用できないため、MFA指定時は最初に取得した
+    /// 一時認証情報（有効期間は`duration_seconds`）をそのまま使用します。
+    pub async fn assume_role(
+        base_config: &SdkConfig,
+        options: &AssumeRoleOptions,
+    ) -> Result<SdkConfig> {
+        options.validate()?;
+
+        let credentials_provider = if options.mfa_serial.is_some() {
+            SharedCredentialsProvider::new(Self::assume_role_with_mfa(base_config, options).await?)
+        } else {
+            let mut builder = AssumeRoleProvider::builder(&options.role_arn)
+                .session_name(options.session_name())
+                .configure(base_config);
+            if let Some(external_id) = &options.external_id {
+                builder = builder.external_id(external_id);
+            }
+            if let Some(duration_seconds) = options.duration_seconds {
+                builder = builder.session_length(Duration::from_secs(duration_seconds as u64));
+            }
+            let provider = builder.build().await;
+
+            // 引き受けられないロールはスキャン開始前にエラーとするため、一度取得しておく
+            provider.provide_credentials().await.map_err(|e| {
+                anyhow!(
+                    "Failed to assume role {}: {}",
+                    options.role_arn,
+                    DisplayErrorContext(&e)
+                )
+            })?;
+            SharedCredentialsProvider::new(provider)
+        };
+
+        Ok(base_config
+            .to_builder()
+            .credentials_provider(credentials_provider)
+            .build())
+    }
+
+    /// MFAのワンタイムコードを指定してAssumeRoleし、一時認証情報を取得
+    async fn assume_role_with_mfa(
+        base_config: &SdkConfig,
+        options: &AssumeRoleOptions,
+    ) -> Result<Credentials> {
+        let sts_client = StsClient::new(base_config);
+        let mut request = sts_client
+            .assume_role()
+            .role_arn(&options.role_arn)
+            .role_session_name(options.session_name())
+            .set_serial_number(options.mfa_serial.clone())
+            .set_token_code(options.mfa_token_code.clone());
+
+        if let Some(external_id) = &options.external_id {
+            request = request.external_id(external_id);
+        }
+        if let Some(duration_seconds) = options.duration_seconds {
+            request = request.duration_seconds(duration_seconds);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to assume role {}: {}", options.role_arn, e))?;
+        let credentials = response.credentials().ok_or_else(|| {
+            anyhow!(
+                "AssumeRole response for {} has no credentials",
+                options.role_arn
+            )
+        })?;
+
+        Ok(Credentials::new(
+            credentials.access_key_id(),
+            credentials.secret_access_key(),
+            Some(credentials.session_token().to_string()),
+            SystemTime::try_from(*credentials.expiration()).ok(),
+            "AssumeRole",
+        ))
+    }
+
+    /// 認証情報のパーティション（`aws`、`aws-cn`、`aws-us-gov`等）を取得
+    ///
+    /// STS GetCallerIdentityで得たARNから判定します。
+    pub async fn caller_partition(config: &SdkConfig) -> Result<String> {
+        let response = StsClient::new(config)
+            .get_caller_identity()
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to get caller identity: {}", e))?;
+        response
+            .arn()
+            .and_then(partition_from_arn)
+            .map(|partition| partition.to_string())
+            .ok_or_else(|| anyhow!("Caller identity response has no valid ARN"))
     }
 
-    pub async fn create_iam_client(
+    /// スキャン用の設定を作成し、STS GetCallerIdentityで認証情報を検証
+    ///
+    /// 作成した設定はIAMと各サブスキャナーのクライアントで共有します。
+    pub async fn create_verified_config(
         profile: Option<String>,
-        assume_role_arn: Option<String>,
-        assume_role_session_name: Option<String>,
-    ) -> Result<IamClient> {
-        let config = Self::create_config(
-            profile.clone(),
-            assume_role_arn.clone(),
-            assume_role_session_name.clone(),
-        )
-        .await
-        .with_context(|| {
-            format!(
-                "Failed to load AWS configuration. Profile: {:?}, Assume Role ARN: {:?}. \
+        assume_role: Option<AssumeRoleOptions>,
+        endpoint: &AwsEndpointOptions,
+    ) -> Result<SdkConfig> {
+        let assume_role_arn = assume_role.as_ref().map(|o| o.role_arn.clone());
+        let config = Self::create_config(profile.clone(), assume_role.as_ref(), endpoint)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to load AWS configuration. Profile: {:?}, Assume Role ARN: {:?}. \
                     Please ensure AWS credentials are configured. \
                     If using 'aws login', make sure the authentication is complete.",
-                profile, assume_role_arn
-            )
-        })?;
+                    profile, assume_role_arn
+                )
+            })?;
 
         // Verify credentials by testing with STS
         let sts_client = StsClient::new(&config);
@@ -101,16 +329,30 @@ impl AwsClientFactory {
             ));
         }
 
-        Ok(IamClient::new(&config))
+        Ok(config)
     }
 
     pub async fn test_connection(
         profile: Option<String>,
-        assume_role_arn: Option<String>,
-        assume_role_session_name: Option<String>,
+        assume_role: Option<AssumeRoleOptions>,
+        endpoint: &AwsEndpointOptions,
     ) -> Result<ConnectionTestResponse> {
-        let sts_config =
-            Self::create_config(profile, assume_role_arn, assume_role_session_name).await?;
+        let assumed_role_arn = assume_role.as_ref().map(|o| o.role_arn.clone());
+        let sts_config = match Self::create_config(profile, assume_role.as_ref(), endpoint).await {
+            Ok(config) => config,
+            // AssumeRoleの失敗は接続テストの結果として返す
+            Err(e) if assumed_role_arn.is_some() => {
+                return Ok(ConnectionTestResponse {
+                    success: false,
+                    message: Some(format!("Connection failed: {}", e)),
+                    account_id: None,
+                    user_arn: None,
+                    assumed_role_arn,
+                    subscription_name: None,
+                });
+            }
+            Err(e) => return Err(e),
+        };
         let sts_client = StsClient::new(&sts_config);
 
         match sts_client.get_caller_identity().send().await {
@@ -119,7 +361,9 @@ impl AwsClientFactory {
                     success: true,
                     message: Some("Connection successful".to_string()),
                     account_id: response.account().map(|s| s.to_string()),
+                    // AssumeRole時は引き受けたロールのセッションARN（assumed-role/...）となる
                     user_arn: response.arn().map(|s| s.to_string()),
+                    assumed_role_arn,
                     subscription_name: None, // AWSでは使用しない
                 })
             }
@@ -128,8 +372,289 @@ impl AwsClientFactory {
                 message: Some(format!("Connection failed: {}", e)),
                 account_id: None,
                 user_arn: None,
+                assumed_role_arn,
                 subscription_name: None,
             }),
         }
     }
 }
+
+/// `aws configure export-credentials --format env`の出力から認証情報を作成
+fn parse_exported_credentials(output: &str) -> Option<Credentials> {
+    let mut access_key_id = None;
+    let mut secret_access_key = None;
+    let mut session_token = None;
+    let mut expiration = None;
+
+    for line in output.lines() {
+        if let Some((key, value)) = line.split_once('=') {
+            let key = key.trim().trim_start_matches("export ");
+            let value = value.trim().trim_matches('"');
+            if value.is_empty() {
+                continue;
+            }
+            match key {
+                "AWS_ACCESS_KEY_ID" => access_key_id = Some(value.to_string()),
+                "AWS_SECRET_ACCESS_KEY" => secret_access_key = Some(value.to_string()),
+                "AWS_SESSION_TOKEN" => session_token = Some(value.to_string()),
+                "AWS_CREDENTIAL_EXPIRATION" => {
+                    expiration = chrono::DateTime::parse_from_rfc3339(value)
+                        .ok()
+                        .map(SystemTime::from)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Some(Credentials::new(
+        access_key_id?,
+        secret_access_key?,
+        session_token,
+        expiration,
+        "ExportCredentials",
+    ))
+}
+
+/// ARN（`arn:<partition>:...`）からパーティションを取得
+pub fn partition_from_arn(arn: &str) -> Option<&str> {
+    let mut parts = arn.split(':');
+    match (parts.next(), parts.next()) {
+        (Some("arn"), Some(partition)) if !partition.is_empty() => Some(partition),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn create_test_config(assume_role_arn: Option<&str>) -> ScanConfig {
+        ScanConfig {
+            provider: "aws".to_string(),
+            account_id: None,
+            profile: Some("tooling".to_string()),
+            assume_role_arn: assume_role_arn.map(|s| s.to_string()),
+            assume_role_session_name: Some("scan-session".to_string()),
+            assume_role_external_id: Some("external-123".to_string()),
+            assume_role_duration_seconds: Some(3600),
+            mfa_serial: None,
+            mfa_token_code: None,
+            account_ids: Vec::new(),
+            discover_accounts: false,
+            member_role_name: None,
+            region: None,
+            endpoint_url: None,
+            subscription_id: None,
+            tenant_id: None,
+            auth_method: None,
+            service_principal_config: None,
+            scope_type: None,
+            scope_value: None,
+            scan_targets: HashMap::new(),
+            filters: HashMap::new(),
+            include_tags: true,
+            max_concurrency: None,
+            scan_strategy: None,
+            include_service_last_accessed: false,
+            unused_threshold_days: None,
+        }
+    }
+
+    #[test]
+    fn test_assume_role_options_from_scan_config() {
+        let config = create_test_config(Some("arn:aws:iam::123456789012:role/Audit"));
+        let options = AssumeRoleOptions::from_scan_config(&config).unwrap();
+
+        assert_eq!(options.role_arn, "arn:aws:iam::123456789012:role/Audit");
+        assert_eq!(options.session_name(), "scan-session");
+        assert_eq!(options.external_id.as_deref(), Some("external-123"));
+        assert_eq!(options.duration_seconds, Some(3600));
+        assert!(options.validate().is_ok());
+    }
+
+    #[test]
+    fn test_assume_role_options_from_scan_config_without_role() {
+        assert!(AssumeRoleOptions::from_scan_config(&create_test_config(None)).is_none());
+        assert!(AssumeRoleOptions::from_scan_config(&create_test_config(Some(""))).is_none());
+    }
+
+    #[test]
+    fn test_assume_role_options_default_session_name() {
+        let options = AssumeRoleOptions {
+            role_arn: "arn:aws:iam::123456789012:role/Audit".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(options.session_name(), "tfkosmos-session");
+    }
+
+    #[test]
+    fn test_assume_role_options_validate_mfa_requires_token_code() {
+        let mut options = AssumeRoleOptions {
+            role_arn: "arn:aws:iam::123456789012:role/Audit".to_string(),
+            ..Default::default()
+        };
+        options.mfa_serial = Some("arn:aws:iam::111111111111:mfa/alice".to_string());
+        assert!(options.validate().is_err());
+
+        options.mfa_token_code = Some("123456".to_string());
+        assert!(options.validate().is_ok());
+    }
+
+    #[test]
+    fn test_assume_role_options_validate_duration_range() {
+        let mut options = AssumeRoleOptions {
+            role_arn: "arn:aws:iam::123456789012:role/Audit".to_string(),
+            ..Default::default()
+        };
+        options.duration_seconds = Some(60);
+        assert!(options.validate().is_err());
+
+        options.duration_seconds = Some(43200);
+        assert!(options.validate().is_ok());
+    }
+
+    #[test]
+    fn test_endpoint_options_from_scan_config() {
+        let mut config = create_test_config(None);
+        assert!(AwsEndpointOptions::from_scan_config(&config)
+            .region
+            .is_none());
+
+        config.region = Some("eu-west-1".to_string());
+        config.endpoint_url = Some(String::new());
+        let options = AwsEndpointOptions::from_scan_config(&config);
+        assert_eq!(options.region.as_deref(), Some("eu-west-1"));
+        assert!(options.endpoint_url.is_none());
+    }
+
+    #[test]
+    fn test_endpoint_options_validate_endpoint_url() {
+        let mut options = AwsEndpointOptions {
+            endpoint_url: Some("localhost:4566".to_string()),
+            ..Default::default()
+        };
+        assert!(options.validate().is_err());
+
+        options.endpoint_url = Some("http://localhost:4566".to_string());
+        assert!(options.validate().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_config_applies_region_and_endpoint() {
+        let options = AwsEndpointOptions {
+            region: Some("eu-west-1".to_string()),
+            endpoint_url: Some("http://localhost:4566".to_string()),
+        };
+
+        let config = AwsClientFactory::create_config(None, None, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(config.region().map(|r| r.as_ref()), Some("eu-west-1"));
+        assert_eq!(config.endpoint_url(), Some("http://localhost:4566"));
+    }
+
+    /// AssumeRoleに応答し、受け付けたリクエスト数を数えるSTSエンドポイントを起動
+    async fn spawn_sts_endpoint() -> (String, Arc<AtomicUsize>) {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        let app = axum::Router::new().route(
+            "/",
+            axum::routing::post(move || {
+                let number = counter.fetch_add(1, Ordering::SeqCst) + 1;
+                async move {
+                    format!(
+                        r#"<AssumeRoleResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleResult>
+    <Credentials>
+      <AccessKeyId>ASIA{number}</AccessKeyId>
+      <SecretAccessKey>secret</SecretAccessKey>
+      <SessionToken>token</SessionToken>
+      <Expiration>2099-01-01T00:00:00Z</Expiration>
+    </Credentials>
+    <AssumedRoleUser>
+      <AssumedRoleId>AROAEXAMPLE:scan-session</AssumedRoleId>
+      <Arn>arn:aws:sts::123456789012:assumed-role/Audit/scan-session</Arn>
+    </AssumedRoleUser>
+  </AssumeRoleResult>
+  <ResponseMetadata><RequestId>request-{number}</RequestId></ResponseMetadata>
+</AssumeRoleResponse>"#
+                    )
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        (endpoint, requests)
+    }
+
+    #[tokio::test]
+    async fn test_assume_role_credentials_are_refreshable() {
+        let (endpoint, requests) = spawn_sts_endpoint().await;
+        let base_config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+            .region(Region::new("us-east-1"))
+            .endpoint_url(endpoint)
+            .credentials_provider(Credentials::for_tests())
+            .load()
+            .await;
+        let options = AssumeRoleOptions {
+            role_arn: "arn:aws:iam::123456789012:role/Audit".to_string(),
+            ..Default::default()
+        };
+
+        let config = AwsClientFactory::assume_role(&base_config, &options)
+            .await
+            .unwrap();
+        // 作成時に一度AssumeRoleし、ロールを引き受けられることを確認する
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // 固定の認証情報ではなく、取得のたびにAssumeRoleするプロバイダーが設定される
+        let provider = config.credentials_provider().unwrap();
+        let credentials = provider.provide_credentials().await.unwrap();
+        assert_eq!(credentials.access_key_id(), "ASIA2");
+        assert_eq!(credentials.session_token(), Some("token"));
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_parse_exported_credentials() {
+        let output = "export AWS_ACCESS_KEY_ID=AKIAEXAMPLE\n\
+            export AWS_SECRET_ACCESS_KEY=\"secret/key\"\n\
+            export AWS_SESSION_TOKEN=token\n\
+            export AWS_CREDENTIAL_EXPIRATION=2030-01-01T00:00:00+00:00\n";
+
+        let credentials = parse_exported_credentials(output).unwrap();
+        assert_eq!(credentials.access_key_id(), "AKIAEXAMPLE");
+        assert_eq!(credentials.secret_access_key(), "secret/key");
+        assert_eq!(credentials.session_token(), Some("token"));
+        assert!(credentials.expiry().is_some());
+    }
+
+    #[test]
+    fn test_parse_exported_credentials_requires_secret_key() {
+        assert!(parse_exported_credentials("export AWS_ACCESS_KEY_ID=AKIAEXAMPLE\n").is_none());
+        assert!(parse_exported_credentials("").is_none());
+    }
+
+    #[test]
+    fn test_partition_from_arn() {
+        assert_eq!(
+            partition_from_arn("arn:aws:sts::111111111111:assumed-role/Hub/session"),
+            Some("aws")
+        );
+        assert_eq!(
+            partition_from_arn("arn:aws-cn:iam::111111111111:user/alice"),
+            Some("aws-cn")
+        );
+        assert_eq!(
+            partition_from_arn("arn:aws-us-gov:iam::111111111111:root"),
+            Some("aws-us-gov")
+        );
+        assert_eq!(partition_from_arn("not-an-arn"), None);
+    }
+}
@@ -0,0 +1,124 @@
+//! EC2クライアント操作の抽象化トレイト
+//!
+//! このモジュールは、VPC・サブネット・セキュリティグループの取得操作を抽象化し、
+//! テスト時にモック実装を注入できるようにします。
+
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// VPC情報
+#[derive(Debug, Clone)]
+pub struct VpcInfo {
+    pub vpc_id: String,
+    pub cidr_block: String,
+    pub instance_tenancy: String,
+    pub is_default: bool,
+    pub tags: HashMap<String, String>,
+}
+
+/// VPCのDNS属性
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VpcDnsAttribute {
+    EnableDnsSupport,
+    EnableDnsHostnames,
+}
+
+impl VpcDnsAttribute {
+    /// `describe-vpc-attribute`の`--attribute`に指定する属性名
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::EnableDnsSupport => "enableDnsSupport",
+            Self::EnableDnsHostnames => "enableDnsHostnames",
+        }
+    }
+}
+
+/// サブネット情報
+#[derive(Debug, Clone)]
+pub struct SubnetInfo {
+    pub subnet_id: String,
+    pub vpc_id: String,
+    pub cidr_block: String,
+    pub availability_zone: String,
+    pub map_public_ip_on_launch: bool,
+    pub tags: HashMap<String, String>,
+}
+
+/// セキュリティグループ情報
+#[derive(Debug, Clone)]
+pub struct SecurityGroupInfo {
+    pub group_id: String,
+    pub group_name: String,
+    pub description: String,
+    /// EC2-Classicのセキュリティグループの場合はNone
+    pub vpc_id: Option<String>,
+    pub tags: HashMap<String, String>,
+}
+
+/// セキュリティグループルール情報
+///
+/// 1つのルールは送信元/送信先（CIDR・プレフィックスリスト・参照先グループ）を
+/// 1つだけ持ちます。
+#[derive(Debug, Clone)]
+pub struct SecurityGroupRuleInfo {
+    pub rule_id: String,
+    pub group_id: String,
+    pub is_egress: bool,
+    /// プロトコル（全プロトコルの場合は"-1"）
+    pub ip_protocol: String,
+    pub from_port: Option<i64>,
+    pub to_port: Option<i64>,
+    pub cidr_ipv4: Option<String>,
+    pub cidr_ipv6: Option<String>,
+    pub prefix_list_id: Option<String>,
+    pub referenced_group_id: Option<String>,
+    pub description: Option<String>,
+    pub tags: HashMap<String, String>,
+}
+
+/// EC2クライアント操作を抽象化するトレイト
+#[async_trait]
+pub trait Ec2ClientOps: Send + Sync {
+    /// VPCの一覧を取得
+    async fn describe_vpcs(&self) -> Result<Vec<VpcInfo>>;
+
+    /// VPCのDNS属性を取得
+    async fn describe_vpc_attribute(
+        &self,
+        vpc_id: &str,
+        attribute: VpcDnsAttribute,
+    ) -> Result<bool>;
+
+    /// サブネットの一覧を取得
+    async fn describe_subnets(&self) -> Result<Vec<SubnetInfo>>;
+
+    /// セキュリティグループの一覧を取得
+    async fn describe_security_groups(&self) -> Result<Vec<SecurityGroupInfo>>;
+
+    /// セキュリティグループルールの一覧を取得
+    async fn describe_security_group_rules(&self) -> Result<Vec<SecurityGroupRuleInfo>>;
+}
+
+#[cfg(test)]
+pub mod mock {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub Ec2Client {}
+
+        #[async_trait]
+        impl Ec2ClientOps for Ec2Client {
+            async fn describe_vpcs(&self) -> Result<Vec<VpcInfo>>;
+            async fn describe_vpc_attribute(
+                &self,
+                vpc_id: &str,
+                attribute: VpcDnsAttribute,
+            ) -> Result<bool>;
+            async fn describe_subnets(&self) -> Result<Vec<SubnetInfo>>;
+            async fn describe_security_groups(&self) -> Result<Vec<SecurityGroupInfo>>;
+            async fn describe_security_group_rules(&self) -> Result<Vec<SecurityGroupRuleInfo>>;
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod ec2_client_trait;
+pub mod real_ec2_client;
+pub mod scanner;
@@ -0,0 +1,305 @@
+//! EC2クライアントの本番実装
+//!
+//! このモジュールは、`Ec2ClientOps`トレイトの本番実装を提供します。
+//! EC2用のSDKクレートは導入していないため、AWS CLIを利用します。
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use super::ec2_client_trait::{
+    Ec2ClientOps, SecurityGroupInfo, SecurityGroupRuleInfo, SubnetInfo, VpcDnsAttribute, VpcInfo,
+};
+use crate::infra::aws::cli::{field, optional_field, AwsCliRunner};
+
+/// AWS CLIをラップしたEC2クライアントの本番実装
+pub struct RealEc2Client {
+    cli: AwsCliRunner,
+}
+
+impl RealEc2Client {
+    pub fn new(cli: AwsCliRunner) -> Self {
+        Self { cli }
+    }
+}
+
+#[async_trait]
+impl Ec2ClientOps for RealEc2Client {
+    async fn describe_vpcs(&self) -> Result<Vec<VpcInfo>> {
+        let output = self
+            .cli
+            .run_json(&["ec2", "describe-vpcs"])
+            .await
+            .map_err(|e| anyhow!("Failed to describe VPCs: {}", e))?;
+
+        Ok(parse_vpcs(&output))
+    }
+
+    async fn describe_vpc_attribute(
+        &self,
+        vpc_id: &str,
+        attribute: VpcDnsAttribute,
+    ) -> Result<bool> {
+        let output = self
+            .cli
+            .run_json(&[
+                "ec2",
+                "describe-vpc-attribute",
+                "--vpc-id",
+                vpc_id,
+                "--attribute",
+                attribute.as_str(),
+            ])
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to describe attribute {} for VPC {}: {}",
+                    attribute.as_str(),
+                    vpc_id,
+                    e
+                )
+            })?;
+
+        Ok(parse_vpc_attribute(&output, attribute))
+    }
+
+    async fn describe_subnets(&self) -> Result<Vec<SubnetInfo>> {
+        let output = self
+            .cli
+            .run_json(&["ec2", "describe-subnets"])
+            .await
+            .map_err(|e| anyhow!("Failed to describe subnets: {}", e))?;
+
+        Ok(parse_subnets(&output))
+    }
+
+    async fn describe_security_groups(&self) -> Result<Vec<SecurityGroupInfo>> {
+        let output = self
+            .cli
+            .run_json(&["ec2", "describe-security-groups"])
+            .await
+            .map_err(|e| anyhow!("Failed to describe security groups: {}", e))?;
+
+        Ok(parse_security_groups(&output))
+    }
+
+    async fn describe_security_group_rules(&self) -> Result<Vec<SecurityGroupRuleInfo>> {
+        let output = self
+            .cli
+            .run_json(&["ec2", "describe-security-group-rules"])
+            .await
+            .map_err(|e| anyhow!("Failed to describe security group rules: {}", e))?;
+
+        Ok(parse_security_group_rules(&output))
+    }
+}
+
+/// オブジェクト配列のフィールドを取得
+fn items<'a>(output: &'a Value, key: &str) -> &'a [Value] {
+    output
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|items| items.as_slice())
+        .unwrap_or_default()
+}
+
+/// `Tags`（`Key`/`Value`の配列）をマップに変換
+fn parse_tags(item: &Value) -> HashMap<String, String> {
+    items(item, "Tags")
+        .iter()
+        .map(|tag| (field(tag, "Key"), field(tag, "Value")))
+        .filter(|(key, _)| !key.is_empty())
+        .collect()
+}
+
+/// `describe-vpcs`の出力をVPC情報に変換
+fn parse_vpcs(output: &Value) -> Vec<VpcInfo> {
+    items(output, "Vpcs")
+        .iter()
+        .map(|vpc| VpcInfo {
+            vpc_id: field(vpc, "VpcId"),
+            cidr_block: field(vpc, "CidrBlock"),
+            instance_tenancy: field(vpc, "InstanceTenancy"),
+            is_default: vpc
+                .get("IsDefault")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            tags: parse_tags(vpc),
+        })
+        .collect()
+}
+
+/// `describe-vpc-attribute`の出力から属性値を取得
+fn parse_vpc_attribute(output: &Value, attribute: VpcDnsAttribute) -> bool {
+    let key = match attribute {
+        VpcDnsAttribute::EnableDnsSupport => "EnableDnsSupport",
+        VpcDnsAttribute::EnableDnsHostnames => "EnableDnsHostnames",
+    };
+    output
+        .get(key)
+        .and_then(|v| v.get("Value"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// `describe-subnets`の出力をサブネット情報に変換
+fn parse_subnets(output: &Value) -> Vec<SubnetInfo> {
+    items(output, "Subnets")
+        .iter()
+        .map(|subnet| SubnetInfo {
+            subnet_id: field(subnet, "SubnetId"),
+            vpc_id: field(subnet, "VpcId"),
+            cidr_block: field(subnet, "CidrBlock"),
+            availability_zone: field(subnet, "AvailabilityZone"),
+            map_public_ip_on_launch: subnet
+                .get("MapPublicIpOnLaunch")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            tags: parse_tags(subnet),
+        })
+        .collect()
+}
+
+/// `describe-security-groups`の出力をセキュリティグループ情報に変換
+fn parse_security_groups(output: &Value) -> Vec<SecurityGroupInfo> {
+    items(output, "SecurityGroups")
+        .iter()
+        .map(|group| SecurityGroupInfo {
+            group_id: field(group, "GroupId"),
+            group_name: field(group, "GroupName"),
+            description: field(group, "Description"),
+            vpc_id: optional_field(group, "VpcId"),
+            tags: parse_tags(group),
+        })
+        .collect()
+}
+
+/// `describe-security-group-rules`の出力をルール情報に変換
+fn parse_security_group_rules(output: &Value) -> Vec<SecurityGroupRuleInfo> {
+    items(output, "SecurityGroupRules")
+        .iter()
+        .map(|rule| SecurityGroupRuleInfo {
+            rule_id: field(rule, "SecurityGroupRuleId"),
+            group_id: field(rule, "GroupId"),
+            is_egress: rule
+                .get("IsEgress")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            ip_protocol: field(rule, "IpProtocol"),
+            from_port: rule.get("FromPort").and_then(|v| v.as_i64()),
+            to_port: rule.get("ToPort").and_then(|v| v.as_i64()),
+            cidr_ipv4: optional_field(rule, "CidrIpv4"),
+            cidr_ipv6: optional_field(rule, "CidrIpv6"),
+            prefix_list_id: optional_field(rule, "PrefixListId"),
+            referenced_group_id: rule
+                .get("ReferencedGroupInfo")
+                .and_then(|info| optional_field(info, "GroupId")),
+            description: optional_field(rule, "Description"),
+            tags: parse_tags(rule),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_vpcs_and_attribute() {
+        let output = json!({
+            "Vpcs": [{
+                "VpcId": "vpc-0a1b2c3d",
+                "CidrBlock": "10.0.0.0/16",
+                "InstanceTenancy": "default",
+                "IsDefault": false,
+                "Tags": [{"Key": "Name", "Value": "main"}]
+            }]
+        });
+
+        let vpcs = parse_vpcs(&output);
+        assert_eq!(vpcs.len(), 1);
+        assert_eq!(vpcs[0].vpc_id, "vpc-0a1b2c3d");
+        assert_eq!(vpcs[0].cidr_block, "10.0.0.0/16");
+        assert!(!vpcs[0].is_default);
+        assert_eq!(vpcs[0].tags.get("Name"), Some(&"main".to_string()));
+
+        let attribute = json!({
+            "VpcId": "vpc-0a1b2c3d",
+            "EnableDnsHostnames": {"Value": true}
+        });
+        assert!(parse_vpc_attribute(
+            &attribute,
+            VpcDnsAttribute::EnableDnsHostnames
+        ));
+        assert!(!parse_vpc_attribute(
+            &attribute,
+            VpcDnsAttribute::EnableDnsSupport
+        ));
+    }
+
+    #[test]
+    fn test_parse_subnets_and_security_groups() {
+        let subnets = parse_subnets(&json!({
+            "Subnets": [{
+                "SubnetId": "subnet-0aaa",
+                "VpcId": "vpc-0a1b2c3d",
+                "CidrBlock": "10.0.1.0/24",
+                "AvailabilityZone": "ap-northeast-1a",
+                "MapPublicIpOnLaunch": true
+            }]
+        }));
+        assert_eq!(subnets[0].subnet_id, "subnet-0aaa");
+        assert_eq!(subnets[0].availability_zone, "ap-northeast-1a");
+        assert!(subnets[0].map_public_ip_on_launch);
+        assert!(subnets[0].tags.is_empty());
+
+        let groups = parse_security_groups(&json!({
+            "SecurityGroups": [{
+                "GroupId": "sg-0web",
+                "GroupName": "web",
+                "Description": "Web servers",
+                "VpcId": "vpc-0a1b2c3d"
+            }]
+        }));
+        assert_eq!(groups[0].group_name, "web");
+        assert_eq!(groups[0].vpc_id.as_deref(), Some("vpc-0a1b2c3d"));
+        assert!(parse_security_groups(&Value::Null).is_empty());
+    }
+
+    #[test]
+    fn test_parse_security_group_rules() {
+        let rules = parse_security_group_rules(&json!({
+            "SecurityGroupRules": [{
+                "SecurityGroupRuleId": "sgr-0ingress",
+                "GroupId": "sg-0db",
+                "IsEgress": false,
+                "IpProtocol": "tcp",
+                "FromPort": 5432,
+                "ToPort": 5432,
+                "ReferencedGroupInfo": {"GroupId": "sg-0web", "UserId": "111111111111"},
+                "Description": "From web"
+            }, {
+                "SecurityGroupRuleId": "sgr-0egress",
+                "GroupId": "sg-0db",
+                "IsEgress": true,
+                "IpProtocol": "-1",
+                "FromPort": -1,
+                "ToPort": -1,
+                "CidrIpv4": "0.0.0.0/0"
+            }]
+        }));
+
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].rule_id, "sgr-0ingress");
+        assert!(!rules[0].is_egress);
+        assert_eq!(rules[0].from_port, Some(5432));
+        assert_eq!(rules[0].referenced_group_id.as_deref(), Some("sg-0web"));
+        assert_eq!(rules[0].description.as_deref(), Some("From web"));
+        assert!(rules[1].is_egress);
+        assert_eq!(rules[1].ip_protocol, "-1");
+        assert_eq!(rules[1].cidr_ipv4.as_deref(), Some("0.0.0.0/0"));
+        assert_eq!(rules[1].referenced_group_id, None);
+    }
+}
@@ -0,0 +1,419 @@
+//! AWSネットワーキングスキャナー
+//!
+//! VPC、サブネット、セキュリティグループ（ルールを含む）をスキャンし、
+//! Terraform生成用のデータ構造に変換します。
+//!
+//! EC2の権限はIAMスキャン用のロールに付与されていない場合があるため、
+//! API呼び出しの失敗はスキャン全体を失敗させず、スキャン警告として記録します。
+
+use anyhow::Result;
+use aws_config::SdkConfig;
+use futures::stream::{self, StreamExt};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+use super::ec2_client_trait::{Ec2ClientOps, SecurityGroupRuleInfo, VpcDnsAttribute, VpcInfo};
+use super::real_ec2_client::RealEc2Client;
+use crate::infra::aws::cli::AwsCliRunner;
+use crate::infra::aws::retry::with_throttling_retry;
+use crate::infra::aws::scanner::scan_warning;
+use crate::models::{ScanConfig, ScanWarning};
+
+/// リソース名として使用するタグのキー
+const NAME_TAG: &str = "Name";
+
+/// セキュリティグループのスキャン結果
+#[derive(Debug, Default)]
+pub struct SecurityGroupResources {
+    pub security_groups: Vec<Value>,
+    pub ingress_rules: Vec<Value>,
+    pub egress_rules: Vec<Value>,
+}
+
+impl SecurityGroupResources {
+    /// 全リソースの件数
+    pub fn count(&self) -> usize {
+        self.security_groups.len() + self.ingress_rules.len() + self.egress_rules.len()
+    }
+}
+
+/// VPC1件分のスキャン結果
+struct VpcResult {
+    vpc: Value,
+    warnings: Vec<ScanWarning>,
+}
+
+/// AWSネットワーキングスキャナー
+///
+/// EC2クライアントを抽象化することで、テスト時にモックを注入可能にしています。
+pub struct AwsEc2Scanner<E: Ec2ClientOps + ?Sized> {
+    client: Arc<E>,
+    /// VPC単位の同時取得数
+    concurrency: usize,
+}
+
+impl AwsEc2Scanner<dyn Ec2ClientOps> {
+    /// 本番用のスキャナーを作成（AWS CLI経由でEC2 APIを呼び出す）
+    pub async fn from_sdk_config(sdk_config: &SdkConfig, config: &ScanConfig) -> Result<Self> {
+        let cli = AwsCliRunner::new(sdk_config);
+        Ok(Self::new_with_client(
+            Arc::new(RealEc2Client::new(cli)),
+            config.max_concurrency(),
+        ))
+    }
+}
+
+impl<E: Ec2ClientOps + ?Sized> AwsEc2Scanner<E> {
+    pub fn new_with_client(client: Arc<E>, concurrency: usize) -> Self {
+        Self {
+            client,
+            concurrency: concurrency.max(1),
+        }
+    }
+
+    /// VPCをDNS属性を含めてスキャン
+    ///
+    /// DNS属性の取得に失敗したVPCは、属性を含めずに出力して`warnings`に記録します。
+    pub async fn scan_vpcs(&self, warnings: &mut Vec<ScanWarning>) -> Vec<Value> {
+        let vpcs = match with_throttling_retry("DescribeVpcs", || self.client.describe_vpcs()).await
+        {
+            Ok(vpcs) => vpcs,
+            Err(e) => {
+                warn!(error = %e, "VPC一覧の取得に失敗");
+                warnings.push(scan_warning("vpc", "", "DescribeVpcs", &e));
+                return Vec::new();
+            }
+        };
+        debug!(count = vpcs.len(), "VPCのスキャンを開始");
+
+        // 同時実行数を制限して並列に取得（結果は列挙順で返る）
+        let mut results = stream::iter(vpcs)
+            .map(|vpc| self.scan_vpc(vpc))
+            .buffered(self.concurrency);
+
+        let mut resources = Vec::new();
+        while let Some(result) = results.next().await {
+            resources.push(result.vpc);
+            warnings.extend(result.warnings);
+        }
+
+        resources
+    }
+
+    /// VPC1件のDNS属性を取得
+    async fn scan_vpc(&self, vpc: VpcInfo) -> VpcResult {
+        let vpc_id = vpc.vpc_id.as_str();
+        let mut vpc_json = json!({
+            "vpc_id": vpc_id,
+            "cidr_block": vpc.cidr_block,
+            "instance_tenancy": vpc.instance_tenancy,
+            "is_default": vpc.is_default,
+        });
+        insert_tags(&mut vpc_json, &vpc.tags);
+
+        let mut warnings = Vec::new();
+        for (attribute, key) in [
+            (VpcDnsAttribute::EnableDnsSupport, "enable_dns_support"),
+            (VpcDnsAttribute::EnableDnsHostnames, "enable_dns_hostnames"),
+        ] {
+            match with_throttling_retry("DescribeVpcAttribute", || {
+                self.client.describe_vpc_attribute(vpc_id, attribute)
+            })
+            .await
+            {
+                Ok(value) => {
+                    vpc_json[key] = json!(value);
+                }
+                Err(e) => {
+                    warn!(vpc_id, attribute = attribute.as_str(), error = %e, "VPC属性の取得に失敗");
+                    warnings.push(scan_warning("vpc", vpc_id, "DescribeVpcAttribute", &e));
+                }
+            }
+        }
+
+        VpcResult {
+            vpc: vpc_json,
+            warnings,
+        }
+    }
+
+    /// サブネットをスキャン
+    pub async fn scan_subnets(&self, warnings: &mut Vec<ScanWarning>) -> Vec<Value> {
+        let subnets =
+            match with_throttling_retry("DescribeSubnets", || self.client.describe_subnets()).await
+            {
+                Ok(subnets) => subnets,
+                Err(e) => {
+                    warn!(error = %e, "サブネット一覧の取得に失敗");
+                    warnings.push(scan_warning("subnet", "", "DescribeSubnets", &e));
+                    return Vec::new();
+                }
+            };
+        debug!(count = subnets.len(), "サブネットのスキャン完了");
+
+        subnets
+            .into_iter()
+            .map(|subnet| {
+                let mut subnet_json = json!({
+                    "subnet_id": subnet.subnet_id,
+                    "vpc_id": subnet.vpc_id,
+                    "cidr_block": subnet.cidr_block,
+                    "availability_zone": subnet.availability_zone,
+                    "map_public_ip_on_launch": subnet.map_public_ip_on_launch,
+                });
+                insert_tags(&mut subnet_json, &subnet.tags);
+                subnet_json
+            })
+            .collect()
+    }
+
+    /// セキュリティグループとそのルールをスキャン
+    ///
+    /// ルールは受信（ingress）と送信（egress）に分けて返します。
+    pub async fn scan_security_groups(
+        &self,
+        warnings: &mut Vec<ScanWarning>,
+    ) -> SecurityGroupResources {
+        let groups = match with_throttling_retry("DescribeSecurityGroups", || {
+            self.client.describe_security_groups()
+        })
+        .await
+        {
+            Ok(groups) => groups,
+            Err(e) => {
+                warn!(error = %e, "セキュリティグループ一覧の取得に失敗");
+                warnings.push(scan_warning(
+                    "security_group",
+                    "",
+                    "DescribeSecurityGroups",
+                    &e,
+                ));
+                return SecurityGroupResources::default();
+            }
+        };
+        debug!(count = groups.len(), "セキュリティグループのスキャンを開始");
+
+        let group_names: HashMap<String, String> = groups
+            .iter()
+            .map(|group| (group.group_id.clone(), group.group_name.clone()))
+            .collect();
+        let mut resources = SecurityGroupResources {
+            security_groups: groups
+                .into_iter()
+                .map(|group| {
+                    let mut group_json = json!({
+                        "group_id": group.group_id,
+                        "group_name": group.group_name,
+                        "description": group.description,
+                    });
+                    if let Some(vpc_id) = group.vpc_id {
+                        group_json["vpc_id"] = json!(vpc_id);
+                    }
+                    insert_tags(&mut group_json, &group.tags);
+                    group_json
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        let rules = match with_throttling_retry("DescribeSecurityGroupRules", || {
+            self.client.describe_security_group_rules()
+        })
+        .await
+        {
+            Ok(rules) => rules,
+            Err(e) => {
+                warn!(error = %e, "セキュリティグループルールの取得に失敗");
+                warnings.push(scan_warning(
+                    "security_group",
+                    "",
+                    "DescribeSecurityGroupRules",
+                    &e,
+                ));
+                return resources;
+            }
+        };
+
+        for rule in rules {
+            let is_egress = rule.is_egress;
+            let rule_json = rule_to_json(rule, &group_names);
+            if is_egress {
+                resources.egress_rules.push(rule_json);
+            } else {
+                resources.ingress_rules.push(rule_json);
+            }
+        }
+
+        resources
+    }
+}
+
+/// タグと、`Name`タグがある場合は`name`をリソースに追加
+fn insert_tags(resource: &mut Value, tags: &HashMap<String, String>) {
+    if let Some(name) = tags.get(NAME_TAG) {
+        resource["name"] = json!(name);
+    }
+    resource["tags"] = json!(tags);
+}
+
+/// セキュリティグループルールをJSONに変換
+///
+/// 任意項目は値がある場合のみ出力します。
+fn rule_to_json(rule: SecurityGroupRuleInfo, group_names: &HashMap<String, String>) -> Value {
+    let mut rule_json = json!({
+        "rule_id": rule.rule_id,
+        "group_id": rule.group_id,
+        "ip_protocol": rule.ip_protocol,
+    });
+    if let Some(group_name) = group_names.get(&rule.group_id) {
+        rule_json["group_name"] = json!(group_name);
+    }
+    for (key, value) in [
+        ("from_port", rule.from_port.map(|port| json!(port))),
+        ("to_port", rule.to_port.map(|port| json!(port))),
+        ("cidr_ipv4", rule.cidr_ipv4.map(Value::String)),
+        ("cidr_ipv6", rule.cidr_ipv6.map(Value::String)),
+        ("prefix_list_id", rule.prefix_list_id.map(Value::String)),
+        (
+            "referenced_group_id",
+            rule.referenced_group_id.map(Value::String),
+        ),
+        ("description", rule.description.map(Value::String)),
+    ] {
+        if let Some(value) = value {
+            rule_json[key] = value;
+        }
+    }
+    insert_tags(&mut rule_json, &rule.tags);
+    rule_json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::aws::ec2::ec2_client_trait::mock::MockEc2Client;
+    use crate::infra::aws::ec2::ec2_client_trait::SecurityGroupInfo;
+    use anyhow::anyhow;
+
+    fn rule(rule_id: &str, group_id: &str, is_egress: bool) -> SecurityGroupRuleInfo {
+        SecurityGroupRuleInfo {
+            rule_id: rule_id.to_string(),
+            group_id: group_id.to_string(),
+            is_egress,
+            ip_protocol: "tcp".to_string(),
+            from_port: Some(443),
+            to_port: Some(443),
+            cidr_ipv4: None,
+            cidr_ipv6: None,
+            prefix_list_id: None,
+            referenced_group_id: None,
+            description: None,
+            tags: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scan_vpcs_records_attribute_failure() {
+        let mut mock = MockEc2Client::new();
+        mock.expect_describe_vpcs().returning(|| {
+            Ok(vec![VpcInfo {
+                vpc_id: "vpc-0main".to_string(),
+                cidr_block: "10.0.0.0/16".to_string(),
+                instance_tenancy: "default".to_string(),
+                is_default: false,
+                tags: HashMap::from([("Name".to_string(), "main".to_string())]),
+            }])
+        });
+        mock.expect_describe_vpc_attribute()
+            .returning(|_, attribute| match attribute {
+                VpcDnsAttribute::EnableDnsSupport => Ok(true),
+                VpcDnsAttribute::EnableDnsHostnames => {
+                    Err(anyhow!("UnauthorizedOperation: not authorized"))
+                }
+            });
+        let scanner = AwsEc2Scanner::new_with_client(Arc::new(mock), 2);
+        let mut warnings = Vec::new();
+
+        let vpcs = scanner.scan_vpcs(&mut warnings).await;
+
+        assert_eq!(vpcs.len(), 1);
+        assert_eq!(vpcs[0]["vpc_id"], "vpc-0main");
+        assert_eq!(vpcs[0]["name"], "main");
+        assert_eq!(vpcs[0]["tags"]["Name"], "main");
+        assert_eq!(vpcs[0]["enable_dns_support"], true);
+        assert!(vpcs[0].get("enable_dns_hostnames").is_none());
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].resource_name, "vpc-0main");
+        assert_eq!(warnings[0].operation, "DescribeVpcAttribute");
+    }
+
+    #[tokio::test]
+    async fn test_scan_security_groups_splits_rules() {
+        let mut mock = MockEc2Client::new();
+        mock.expect_describe_security_groups().returning(|| {
+            Ok(vec![
+                SecurityGroupInfo {
+                    group_id: "sg-0web".to_string(),
+                    group_name: "web".to_string(),
+                    description: "Web servers".to_string(),
+                    vpc_id: Some("vpc-0main".to_string()),
+                    tags: HashMap::new(),
+                },
+                SecurityGroupInfo {
+                    group_id: "sg-0db".to_string(),
+                    group_name: "db".to_string(),
+                    description: "Databases".to_string(),
+                    vpc_id: Some("vpc-0main".to_string()),
+                    tags: HashMap::new(),
+                },
+            ])
+        });
+        mock.expect_describe_security_group_rules().returning(|| {
+            let mut from_web = rule("sgr-0ingress", "sg-0db", false);
+            from_web.referenced_group_id = Some("sg-0web".to_string());
+            let mut all_out = rule("sgr-0egress", "sg-0db", true);
+            all_out.ip_protocol = "-1".to_string();
+            all_out.from_port = Some(-1);
+            all_out.to_port = Some(-1);
+            all_out.cidr_ipv4 = Some("0.0.0.0/0".to_string());
+            Ok(vec![from_web, all_out])
+        });
+        let scanner = AwsEc2Scanner::new_with_client(Arc::new(mock), 1);
+        let mut warnings = Vec::new();
+
+        let resources = scanner.scan_security_groups(&mut warnings).await;
+
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(resources.security_groups.len(), 2);
+        assert_eq!(resources.security_groups[0]["vpc_id"], "vpc-0main");
+        assert_eq!(resources.ingress_rules.len(), 1);
+        assert_eq!(resources.ingress_rules[0]["group_name"], "db");
+        assert_eq!(resources.ingress_rules[0]["referenced_group_id"], "sg-0web");
+        assert!(resources.ingress_rules[0].get("cidr_ipv4").is_none());
+        assert_eq!(resources.egress_rules.len(), 1);
+        assert_eq!(resources.egress_rules[0]["cidr_ipv4"], "0.0.0.0/0");
+        assert_eq!(resources.count(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_scan_records_warning_without_ec2_permissions() {
+        let mut mock = MockEc2Client::new();
+        mock.expect_describe_subnets()
+            .returning(|| Err(anyhow!("UnauthorizedOperation: not authorized")));
+        mock.expect_describe_security_groups()
+            .returning(|| Err(anyhow!("UnauthorizedOperation: not authorized")));
+        let scanner = AwsEc2Scanner::new_with_client(Arc::new(mock), 1);
+        let mut warnings = Vec::new();
+
+        let subnets = scanner.scan_subnets(&mut warnings).await;
+        let groups = scanner.scan_security_groups(&mut warnings).await;
+
+        assert!(subnets.is_empty());
+        assert_eq!(groups.count(), 0);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].resource_type, "subnet");
+        assert_eq!(warnings[1].operation, "DescribeSecurityGroups");
+    }
+}
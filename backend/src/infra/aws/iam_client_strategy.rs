@@ -0,0 +1,194 @@
+//! スキャン方式に応じたIAMクライアントの切り替え
+//!
+//! `ScanConfig::scan_strategy`に応じて、エンティティごとにAPIを呼び出す
+//! `RealIamClient`と、GetAccountAuthorizationDetailsで一括取得する
+//! `AuthorizationDetailsIamClient`のどちらかを使用します。
+
+use anyhow::Result;
+use async_trait::async_trait;
+use aws_sdk_iam::Client as IamClient;
+
+use super::authorization_details_client::AuthorizationDetailsIamClient;
+use super::iam_client_trait::{
+    IamAccessKeyInfo, IamClientOps, IamEntityDetail, IamGroupInfo, IamInstanceProfileInfo,
+    IamLoginProfileInfo, IamMfaDeviceInfo, IamOidcProviderInfo, IamPasswordPolicyInfo,
+    IamPolicyInfo, IamRoleInfo, IamSamlProviderInfo, IamServiceLastAccessedInfo, IamUserInfo,
+    PolicyAttachment, PolicyDocument,
+};
+use super::real_iam_client::RealIamClient;
+use crate::models::ScanConfig;
+
+/// スキャン方式ごとのIAMクライアント
+pub enum StrategyIamClient {
+    /// エンティティごとにAPIを呼び出す（デフォルト）
+    PerResource(RealIamClient),
+    /// GetAccountAuthorizationDetailsで一括取得する
+    AuthorizationDetails(Box<AuthorizationDetailsIamClient>),
+}
+
+impl StrategyIamClient {
+    /// スキャン設定に応じたクライアントを作成
+    pub async fn from_config(client: IamClient, config: &ScanConfig) -> Result<Self> {
+        if config.uses_authorization_details() {
+            Ok(Self::AuthorizationDetails(Box::new(
+                AuthorizationDetailsIamClient::fetch(&client, config).await?,
+            )))
+        } else {
+            Ok(Self::PerResource(RealIamClient::new(client)))
+        }
+    }
+}
+
+macro_rules! dispatch {
+    ($self:ident.$method:ident($($arg:expr),*)) => {
+        match $self {
+            Self::PerResource(client) => client.$method($($arg),*).await,
+            Self::AuthorizationDetails(client) => client.$method($($arg),*).await,
+        }
+    };
+}
+
+#[async_trait]
+impl IamClientOps for StrategyIamClient {
+    async fn list_users(&self) -> Result<Vec<IamUserInfo>> {
+        dispatch!(self.list_users())
+    }
+
+    async fn list_users_with_options(&self, include_tags: bool) -> Result<Vec<IamUserInfo>> {
+        dispatch!(self.list_users_with_options(include_tags))
+    }
+
+    async fn list_groups(&self) -> Result<Vec<IamGroupInfo>> {
+        dispatch!(self.list_groups())
+    }
+
+    async fn list_roles(&self) -> Result<Vec<IamRoleInfo>> {
+        dispatch!(self.list_roles())
+    }
+
+    async fn list_roles_with_options(&self, include_tags: bool) -> Result<Vec<IamRoleInfo>> {
+        dispatch!(self.list_roles_with_options(include_tags))
+    }
+
+    async fn get_user_detail(
+        &self,
+        user_name: &str,
+        include_tags: bool,
+    ) -> Result<Option<IamEntityDetail>> {
+        dispatch!(self.get_user_detail(user_name, include_tags))
+    }
+
+    async fn get_role_detail(
+        &self,
+        role_name: &str,
+        include_tags: bool,
+    ) -> Result<Option<IamEntityDetail>> {
+        dispatch!(self.get_role_detail(role_name, include_tags))
+    }
+
+    async fn list_policies(&self) -> Result<Vec<IamPolicyInfo>> {
+        dispatch!(self.list_policies())
+    }
+
+    async fn list_instance_profiles(&self) -> Result<Vec<IamInstanceProfileInfo>> {
+        dispatch!(self.list_instance_profiles())
+    }
+
+    async fn list_open_id_connect_providers(&self) -> Result<Vec<IamOidcProviderInfo>> {
+        dispatch!(self.list_open_id_connect_providers())
+    }
+
+    async fn list_saml_providers(&self) -> Result<Vec<IamSamlProviderInfo>> {
+        dispatch!(self.list_saml_providers())
+    }
+
+    async fn list_access_keys(&self, user_name: &str) -> Result<Vec<IamAccessKeyInfo>> {
+        dispatch!(self.list_access_keys(user_name))
+    }
+
+    async fn get_login_profile(&self, user_name: &str) -> Result<Option<IamLoginProfileInfo>> {
+        dispatch!(self.get_login_profile(user_name))
+    }
+
+    async fn list_mfa_devices(&self, user_name: &str) -> Result<Vec<IamMfaDeviceInfo>> {
+        dispatch!(self.list_mfa_devices(user_name))
+    }
+
+    async fn get_account_password_policy(&self) -> Result<Option<IamPasswordPolicyInfo>> {
+        dispatch!(self.get_account_password_policy())
+    }
+
+    async fn list_account_aliases(&self) -> Result<Vec<String>> {
+        dispatch!(self.list_account_aliases())
+    }
+
+    async fn get_service_last_accessed(
+        &self,
+        arn: &str,
+    ) -> Result<Vec<IamServiceLastAccessedInfo>> {
+        dispatch!(self.get_service_last_accessed(arn))
+    }
+
+    async fn list_user_policies(&self, user_name: &str) -> Result<Vec<String>> {
+        dispatch!(self.list_user_policies(user_name))
+    }
+
+    async fn list_attached_user_policies(&self, user_name: &str) -> Result<Vec<PolicyAttachment>> {
+        dispatch!(self.list_attached_user_policies(user_name))
+    }
+
+    async fn list_group_policies(&self, group_name: &str) -> Result<Vec<String>> {
+        dispatch!(self.list_group_policies(group_name))
+    }
+
+    async fn list_attached_group_policies(
+        &self,
+        group_name: &str,
+    ) -> Result<Vec<PolicyAttachment>> {
+        dispatch!(self.list_attached_group_policies(group_name))
+    }
+
+    async fn list_role_policies(&self, role_name: &str) -> Result<Vec<String>> {
+        dispatch!(self.list_role_policies(role_name))
+    }
+
+    async fn list_attached_role_policies(&self, role_name: &str) -> Result<Vec<PolicyAttachment>> {
+        dispatch!(self.list_attached_role_policies(role_name))
+    }
+
+    async fn list_groups_for_user(&self, user_name: &str) -> Result<Vec<String>> {
+        dispatch!(self.list_groups_for_user(user_name))
+    }
+
+    async fn get_policy_version(
+        &self,
+        policy_arn: &str,
+        version_id: &str,
+    ) -> Result<Option<PolicyDocument>> {
+        dispatch!(self.get_policy_version(policy_arn, version_id))
+    }
+
+    async fn get_user_policy(
+        &self,
+        user_name: &str,
+        policy_name: &str,
+    ) -> Result<Option<PolicyDocument>> {
+        dispatch!(self.get_user_policy(user_name, policy_name))
+    }
+
+    async fn get_group_policy(
+        &self,
+        group_name: &str,
+        policy_name: &str,
+    ) -> Result<Option<PolicyDocument>> {
+        dispatch!(self.get_group_policy(group_name, policy_name))
+    }
+
+    async fn get_role_policy(
+        &self,
+        role_name: &str,
+        policy_name: &str,
+    ) -> Result<Option<PolicyDocument>> {
+        dispatch!(self.get_role_policy(role_name, policy_name))
+    }
+}
@@ -16,6 +16,10 @@ pub struct IamUserInfo {
     pub create_date: i64,
     pub path: String,
     pub tags: HashMap<String, String>,
+    /// アクセス許可の境界として設定されたポリシーのARN
+    pub permissions_boundary: Option<String>,
+    /// コンソールパスワードの最終使用日時（一度も使用されていない場合はNone）
+    pub password_last_used: Option<i64>,
 }
 
 /// IAMグループ情報
@@ -38,6 +42,27 @@ pub struct IamRoleInfo {
     pub path: String,
     pub assume_role_policy_document: Option<String>,
     pub tags: HashMap<String, String>,
+    pub description: Option<String>,
+    /// 最大セッション時間（秒）
+    pub max_session_duration: Option<i32>,
+    /// アクセス許可の境界として設定されたポリシーのARN
+    pub permissions_boundary: Option<String>,
+    /// ロールの最終使用日時（追跡期間内に使用されていない場合はNone）
+    pub last_used_date: Option<i64>,
+    /// ロールが最後に使用されたリージョン
+    pub last_used_region: Option<String>,
+}
+
+/// ListUsers/ListRolesの結果に含まれない、ユーザー/ロールの詳細情報
+#[derive(Debug, Clone, Default)]
+pub struct IamEntityDetail {
+    /// アクセス許可の境界として設定されたポリシーのARN
+    pub permissions_boundary: Option<String>,
+    pub tags: HashMap<String, String>,
+    /// ロールの最終使用日時（ユーザーの場合は常にNone）
+    pub last_used_date: Option<i64>,
+    /// ロールが最後に使用されたリージョン
+    pub last_used_region: Option<String>,
 }
 
 /// IAMポリシー情報
@@ -54,6 +79,97 @@ pub struct IamPolicyInfo {
     pub description: String,
 }
 
+/// インスタンスプロファイル情報
+#[derive(Debug, Clone)]
+pub struct IamInstanceProfileInfo {
+    pub instance_profile_name: String,
+    pub instance_profile_id: String,
+    pub arn: String,
+    pub create_date: i64,
+    pub path: String,
+    /// 関連付けられたロール名（インスタンスプロファイルに関連付けられるロールは最大1つ）
+    pub role_names: Vec<String>,
+}
+
+/// OIDCプロバイダー情報
+#[derive(Debug, Clone)]
+pub struct IamOidcProviderInfo {
+    pub arn: String,
+    /// 発行者URL（`https://`を除いた形式でIAM APIから返される）
+    pub url: String,
+    pub client_id_list: Vec<String>,
+    pub thumbprint_list: Vec<String>,
+    pub create_date: i64,
+    pub tags: HashMap<String, String>,
+}
+
+/// SAMLプロバイダー情報
+#[derive(Debug, Clone)]
+pub struct IamSamlProviderInfo {
+    pub arn: String,
+    pub name: String,
+    pub saml_metadata_document: String,
+    pub create_date: i64,
+    pub valid_until: i64,
+    pub tags: HashMap<String, String>,
+}
+
+/// アクセスキーのメタデータ（シークレットアクセスキーは含まない）
+#[derive(Debug, Clone)]
+pub struct IamAccessKeyInfo {
+    pub user_name: String,
+    pub access_key_id: String,
+    /// "Active" または "Inactive"
+    pub status: String,
+    pub create_date: i64,
+    /// 最終使用日時（一度も使用されていない場合はNone）
+    pub last_used_date: Option<i64>,
+}
+
+/// コンソールログインプロファイル情報（パスワードは含まない）
+#[derive(Debug, Clone)]
+pub struct IamLoginProfileInfo {
+    pub user_name: String,
+    pub create_date: i64,
+    pub password_reset_required: bool,
+}
+
+/// ユーザーに関連付けられたMFAデバイス情報
+#[derive(Debug, Clone)]
+pub struct IamMfaDeviceInfo {
+    pub user_name: String,
+    /// 仮想MFAデバイスの場合はARN、ハードウェアMFAデバイスの場合はシリアル番号
+    pub serial_number: String,
+    pub enable_date: i64,
+}
+
+/// アカウントのパスワードポリシー情報
+#[derive(Debug, Clone)]
+pub struct IamPasswordPolicyInfo {
+    pub minimum_password_length: Option<i32>,
+    pub require_symbols: bool,
+    pub require_numbers: bool,
+    pub require_uppercase_characters: bool,
+    pub require_lowercase_characters: bool,
+    pub allow_users_to_change_password: bool,
+    /// パスワードの有効期間（日）。未設定の場合は無期限
+    pub max_password_age: Option<i32>,
+    pub password_reuse_prevention: Option<i32>,
+    pub hard_expiry: Option<bool>,
+}
+
+/// サービス最終アクセス情報（GetServiceLastAccessedDetailsの結果）
+#[derive(Debug, Clone)]
+pub struct IamServiceLastAccessedInfo {
+    pub service_name: String,
+    pub service_namespace: String,
+    /// 最終アクセス日時（追跡期間内にアクセスがない場合はNone）
+    pub last_authenticated: Option<i64>,
+    /// 最後にアクセスしたエンティティのARN
+    pub last_authenticated_entity: Option<String>,
+    pub last_authenticated_region: Option<String>,
+}
+
 /// ポリシーアタッチメント情報
 #[derive(Debug, Clone)]
 pub struct PolicyAttachment {
@@ -103,9 +219,57 @@ pub trait IamClientOps: Send + Sync {
     /// * `include_tags` - タグ情報を取得するかどうか。falseの場合、tagsは空のHashMapになる
     async fn list_roles_with_options(&self, include_tags: bool) -> Result<Vec<IamRoleInfo>>;
 
+    /// ユーザーの詳細情報（アクセス許可の境界・タグ）を取得
+    ///
+    /// 一覧の取得時点で詳細情報を含む実装（一括取得等）はNoneを返します。
+    async fn get_user_detail(
+        &self,
+        user_name: &str,
+        include_tags: bool,
+    ) -> Result<Option<IamEntityDetail>>;
+
+    /// ロールの詳細情報（アクセス許可の境界・タグ・最終使用日時）を取得
+    ///
+    /// 一覧の取得時点で詳細情報を含む実装（一括取得等）はNoneを返します。
+    async fn get_role_detail(
+        &self,
+        role_name: &str,
+        include_tags: bool,
+    ) -> Result<Option<IamEntityDetail>>;
+
     /// IAMポリシー一覧を取得（ローカルスコープのみ）
     async fn list_policies(&self) -> Result<Vec<IamPolicyInfo>>;
 
+    /// インスタンスプロファイル一覧を取得
+    async fn list_instance_profiles(&self) -> Result<Vec<IamInstanceProfileInfo>>;
+
+    /// OIDCプロバイダー一覧を取得
+    async fn list_open_id_connect_providers(&self) -> Result<Vec<IamOidcProviderInfo>>;
+
+    /// SAMLプロバイダー一覧を取得
+    async fn list_saml_providers(&self) -> Result<Vec<IamSamlProviderInfo>>;
+
+    /// ユーザーのアクセスキー一覧を取得（最終使用日時を含む）
+    async fn list_access_keys(&self, user_name: &str) -> Result<Vec<IamAccessKeyInfo>>;
+
+    /// ユーザーのログインプロファイルを取得（存在しない場合はNone）
+    async fn get_login_profile(&self, user_name: &str) -> Result<Option<IamLoginProfileInfo>>;
+
+    /// ユーザーのMFAデバイス一覧を取得
+    async fn list_mfa_devices(&self, user_name: &str) -> Result<Vec<IamMfaDeviceInfo>>;
+
+    /// アカウントのパスワードポリシーを取得（設定されていない場合はNone）
+    async fn get_account_password_policy(&self) -> Result<Option<IamPasswordPolicyInfo>>;
+
+    /// アカウントエイリアス一覧を取得（設定できるエイリアスは最大1つ）
+    async fn list_account_aliases(&self) -> Result<Vec<String>>;
+
+    /// ユーザー/ロールのサービス最終アクセス情報を取得
+    ///
+    /// レポート生成ジョブを開始し、完了するまでポーリングします。
+    async fn get_service_last_accessed(&self, arn: &str)
+        -> Result<Vec<IamServiceLastAccessedInfo>>;
+
     /// ユーザーのインラインポリシー名一覧を取得
     async fn list_user_policies(&self, user_name: &str) -> Result<Vec<String>>;
 
@@ -134,6 +298,27 @@ pub trait IamClientOps: Send + Sync {
         policy_arn: &str,
         version_id: &str,
     ) -> Result<Option<PolicyDocument>>;
+
+    /// ユーザーのインラインポリシードキュメントを取得
+    async fn get_user_policy(
+        &self,
+        user_name: &str,
+        policy_name: &str,
+    ) -> Result<Option<PolicyDocument>>;
+
+    /// グループのインラインポリシードキュメントを取得
+    async fn get_group_policy(
+        &self,
+        group_name: &str,
+        policy_name: &str,
+    ) -> Result<Option<PolicyDocument>>;
+
+    /// ロールのインラインポリシードキュメントを取得
+    async fn get_role_policy(
+        &self,
+        role_name: &str,
+        policy_name: &str,
+    ) -> Result<Option<PolicyDocument>>;
 }
 
 #[cfg(test)]
@@ -151,7 +336,18 @@ pub mod mock {
             async fn list_groups(&self) -> Result<Vec<IamGroupInfo>>;
             async fn list_roles(&self) -> Result<Vec<IamRoleInfo>>;
             async fn list_roles_with_options(&self, include_tags: bool) -> Result<Vec<IamRoleInfo>>;
+            async fn get_user_detail(&self, user_name: &str, include_tags: bool) -> Result<Option<IamEntityDetail>>;
+            async fn get_role_detail(&self, role_name: &str, include_tags: bool) -> Result<Option<IamEntityDetail>>;
             async fn list_policies(&self) -> Result<Vec<IamPolicyInfo>>;
+            async fn list_instance_profiles(&self) -> Result<Vec<IamInstanceProfileInfo>>;
+            async fn list_open_id_connect_providers(&self) -> Result<Vec<IamOidcProviderInfo>>;
+            async fn list_saml_providers(&self) -> Result<Vec<IamSamlProviderInfo>>;
+            async fn list_access_keys(&self, user_name: &str) -> Result<Vec<IamAccessKeyInfo>>;
+            async fn get_login_profile(&self, user_name: &str) -> Result<Option<IamLoginProfileInfo>>;
+            async fn list_mfa_devices(&self, user_name: &str) -> Result<Vec<IamMfaDeviceInfo>>;
+            async fn get_account_password_policy(&self) -> Result<Option<IamPasswordPolicyInfo>>;
+            async fn list_account_aliases(&self) -> Result<Vec<String>>;
+            async fn get_service_last_accessed(&self, arn: &str) -> Result<Vec<IamServiceLastAccessedInfo>>;
             async fn list_user_policies(&self, user_name: &str) -> Result<Vec<String>>;
             async fn list_attached_user_policies(&self, user_name: &str) -> Result<Vec<PolicyAttachment>>;
             async fn list_group_policies(&self, group_name: &str) -> Result<Vec<String>>;
@@ -160,6 +356,9 @@ pub mod mock {
             async fn list_attached_role_policies(&self, role_name: &str) -> Result<Vec<PolicyAttachment>>;
             async fn list_groups_for_user(&self, user_name: &str) -> Result<Vec<String>>;
             async fn get_policy_version(&self, policy_arn: &str, version_id: &str) -> Result<Option<PolicyDocument>>;
+            async fn get_user_policy(&self, user_name: &str, policy_name: &str) -> Result<Option<PolicyDocument>>;
+            async fn get_group_policy(&self, group_name: &str, policy_name: &str) -> Result<Option<PolicyDocument>>;
+            async fn get_role_policy(&self, role_name: &str, policy_name: &str) -> Result<Option<PolicyDocument>>;
         }
     }
 }
@@ -0,0 +1,49 @@
+//! KMSクライアント操作の抽象化トレイト
+//!
+//! このモジュールは、KMSの操作を抽象化し、
+//! テスト時にモック実装を注入できるようにします。
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+/// KMSキー情報
+#[derive(Debug, Clone)]
+pub struct KmsKeyInfo {
+    pub key_id: String,
+    pub key_arn: String,
+    pub description: Option<String>,
+    /// キーの管理者（`CUSTOMER` / `AWS`）
+    pub key_manager: String,
+    /// キーの状態（`Enabled` / `Disabled` / `PendingDeletion`など）
+    pub key_state: String,
+}
+
+/// KMSクライアント操作を抽象化するトレイト
+#[async_trait]
+pub trait KmsClientOps: Send + Sync {
+    /// キーID一覧を取得
+    async fn list_keys(&self) -> Result<Vec<String>>;
+
+    /// キーの詳細を取得
+    async fn describe_key(&self, key_id: &str) -> Result<KmsKeyInfo>;
+
+    /// キーポリシー（`default`）を取得
+    async fn get_key_policy(&self, key_id: &str) -> Result<String>;
+}
+
+#[cfg(test)]
+pub mod mock {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub KmsClient {}
+
+        #[async_trait]
+        impl KmsClientOps for KmsClient {
+            async fn list_keys(&self) -> Result<Vec<String>>;
+            async fn describe_key(&self, key_id: &str) -> Result<KmsKeyInfo>;
+            async fn get_key_policy(&self, key_id: &str) -> Result<String>;
+        }
+    }
+}
@@ -1,4 +1,26 @@
+pub mod authorization_details_client;
+pub mod cli;
 pub mod client_factory;
+pub mod ec2;
+pub mod iam_client_strategy;
 pub mod iam_client_trait;
+pub mod kms_client_trait;
+pub mod multi_account;
+pub mod organizations_client_trait;
+pub mod organizations_scanner;
 pub mod real_iam_client;
+pub mod real_kms_client;
+pub mod real_organizations_client;
+pub mod real_s3_client;
+pub mod real_sns_client;
+pub mod real_sqs_client;
+pub mod real_sso_admin_client;
+pub mod resource_policy_scanner;
+pub mod retry;
+pub mod s3_client_trait;
+pub mod s3_scanner;
 pub mod scanner;
+pub mod sns_client_trait;
+pub mod sqs_client_trait;
+pub mod sso_admin_client_trait;
+pub mod sso_scanner;
@@ -0,0 +1,712 @@
+//! AWSマルチアカウントスキャナー
+//!
+//! 複数のAWSアカウント（明示的なリストまたはOrganizationsから検出）に対して
+//! 指定ロールを引き受けながらIAMスキャンを実行し、結果をアカウント単位で統合します。
+//!
+//! メンバーアカウントのロールは、ハブの認証情報（プロファイル、`assume_role_arn`が
+//! 指定されている場合はそのロール）から連鎖して引き受けます。外部ID・MFA・
+//! セッション時間は最初に引き受けるロールに適用します。つまり、ハブロールが
+//! 指定されている場合はハブロールのみ、指定されていない場合は各メンバーロールに
+//! 適用します（MFAのワンタイムコードはメンバーロールごとに再利用できないため、
+//! ハブロールなしでのMFA指定はエラーとします）。
+//!
+//! 統合結果では、各リソースに`account_id`が付与され、
+//! `accounts`キーにアカウントごとのスキャン状態が格納されます。
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use std::future::Future;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+use crate::infra::aws::cli::AwsCliRunner;
+use crate::infra::aws::client_factory::{AssumeRoleOptions, AwsClientFactory, AwsEndpointOptions};
+use crate::infra::aws::iam_client_trait::IamClientOps;
+use crate::infra::aws::organizations_client_trait::OrganizationsClientOps;
+use crate::infra::aws::real_organizations_client::RealOrganizationsClient;
+use crate::infra::aws::scanner::AwsIamScanner;
+use crate::models::{ProgressCallback, ScanConfig, ScanPhase, ScanProgress};
+
+/// メンバーアカウントで引き受けるロール名のデフォルト値
+pub const DEFAULT_MEMBER_ROLE_NAME: &str = "OrganizationAccountAccessRole";
+
+/// ARNのパーティションのデフォルト値（呼び出し元の識別情報を取得する前）
+const DEFAULT_PARTITION: &str = "aws";
+
+/// AWSマルチアカウントスキャナー
+pub struct AwsMultiAccountScanner {
+    config: ScanConfig,
+    /// メンバーロールのARNに使用するパーティション
+    partition: String,
+}
+
+impl AwsMultiAccountScanner {
+    pub fn new(config: ScanConfig) -> Self {
+        Self {
+            config,
+            partition: DEFAULT_PARTITION.to_string(),
+        }
+    }
+
+    /// メンバーロールのARNに使用するパーティションを指定
+    pub fn with_partition(mut self, partition: impl Into<String>) -> Self {
+        self.partition = partition.into();
+        self
+    }
+
+    /// 全対象アカウントをスキャン（本番用）
+    ///
+    /// ハブの認証情報は一度だけ解決し、パーティションの判定、Organizationsの参照、
+    /// 各メンバーロールの引き受けに共有します。
+    /// `discover_accounts`が有効な場合は、ハブの認証情報でOrganizationsから
+    /// アカウント一覧を取得します。
+    pub async fn scan(self, progress_callback: ProgressCallback) -> Result<Value> {
+        self.validate()?;
+        let hub_config = AwsClientFactory::create_config(
+            self.config.profile.clone(),
+            AssumeRoleOptions::from_scan_config(&self.config).as_ref(),
+            &AwsEndpointOptions::from_scan_config(&self.config),
+        )
+        .await?;
+        let partition = AwsClientFactory::caller_partition(&hub_config).await?;
+        let scanner = self.with_partition(partition);
+
+        let account_ids = if scanner.config.discover_accounts {
+            progress_callback(ScanProgress::new(
+                ScanPhase::Started,
+                0,
+                "AWS Organizationsからアカウントを検出しています...",
+            ));
+            let cli = AwsCliRunner::new(&hub_config);
+            let organizations = RealOrganizationsClient::new(cli);
+            scanner.resolve_account_ids(Some(&organizations)).await?
+        } else {
+            scanner
+                .resolve_account_ids::<RealOrganizationsClient>(None)
+                .await?
+        };
+
+        let create_scanner = |config: ScanConfig| {
+            let hub_config = hub_config.clone();
+            async move {
+                let member_role = AssumeRoleOptions::from_scan_config(&config)
+                    .ok_or_else(|| anyhow!("Member role ARN is not set"))?;
+                let member_config =
+                    AwsClientFactory::assume_role(&hub_config, &member_role).await?;
+                AwsIamScanner::from_sdk_config(config, &member_config).await
+            }
+        };
+        scanner
+            .scan_accounts(&account_ids, create_scanner, progress_callback)
+            .await
+    }
+
+    /// スキャン対象のアカウントIDを解決
+    ///
+    /// 明示的に指定されたアカウントと、Organizationsから検出したアクティブな
+    /// アカウントを重複なく結合します（指定順 → 検出順）。
+    pub async fn resolve_account_ids<O: OrganizationsClientOps>(
+        &self,
+        organizations: Option<&O>,
+    ) -> Result<Vec<String>> {
+        let mut account_ids: Vec<String> = Vec::new();
+        let mut push_unique = |id: &str| {
+            let id = id.trim();
+            if !id.is_empty() && !account_ids.iter().any(|existing| existing == id) {
+                account_ids.push(id.to_string());
+            }
+        };
+
+        for account_id in &self.config.account_ids {
+            push_unique(account_id);
+        }
+
+        if self.config.discover_accounts {
+            let organizations = organizations
+                .ok_or_else(|| anyhow!("Organizations client is required to discover accounts"))?;
+            let accounts = organizations.list_accounts().await?;
+            for account in accounts.iter().filter(|a| a.is_active()) {
+                debug!(account_id = %account.account_id, name = %account.name, "アカウントを検出");
+                push_unique(&account.account_id);
+            }
+        }
+
+        if account_ids.is_empty() {
+            return Err(anyhow!("No AWS accounts to scan"));
+        }
+        Ok(account_ids)
+    }
+
+    /// ハブロールが指定されているかどうか
+    fn has_hub_role(&self) -> bool {
+        self.config
+            .assume_role_arn
+            .as_deref()
+            .is_some_and(|arn| !arn.is_empty())
+    }
+
+    /// ロールの引き受けに関する設定の整合性を検証
+    ///
+    /// ハブロールなしでMFAを指定すると、同じワンタイムコードで各メンバーロールを
+    /// 引き受けることになり2アカウント目以降が失敗するため、スキャン前にエラーとします。
+    pub fn validate(&self) -> Result<()> {
+        if !self.has_hub_role() && self.config.mfa_serial.is_some() {
+            return Err(anyhow!(
+                "mfa_serial requires assume_role_arn in multi-account scans: \
+                the MFA code is used to assume the hub role and cannot be reused for each member role"
+            ));
+        }
+        Ok(())
+    }
+
+    /// メンバーアカウントで引き受けるロールのARN
+    pub fn member_role_arn(&self, account_id: &str) -> String {
+        let role_name = self
+            .config
+            .member_role_name
+            .as_deref()
+            .filter(|name| !name.is_empty())
+            .unwrap_or(DEFAULT_MEMBER_ROLE_NAME);
+        format!(
+            "arn:{}:iam::{}:role/{}",
+            self.partition, account_id, role_name
+        )
+    }
+
+    /// アカウント単位のスキャン設定を作成
+    ///
+    /// `assume_role_arn`はメンバーロールとなります。ハブロールが指定されている場合、
+    /// 外部ID・セッション時間はハブロール用のため引き継ぎません（ロール連鎖のセッションは
+    /// 最長1時間のため、セッション時間はSTSの既定値を使用します）。ハブロールが
+    /// 指定されていない場合は、外部ID・セッション時間をメンバーロールに引き継ぎます。
+    /// MFAはハブロールにのみ使用します（`validate`を参照）。
+    pub fn account_config(&self, account_id: &str) -> ScanConfig {
+        let mut config = self.config.clone();
+        config.account_id = Some(account_id.to_string());
+        config.assume_role_arn = Some(self.member_role_arn(account_id));
+        if self.has_hub_role() {
+            config.assume_role_external_id = None;
+            config.assume_role_duration_seconds = None;
+        }
+        config.mfa_serial = None;
+        config.mfa_token_code = None;
+        config.account_ids = Vec::new();
+        config.discover_accounts = false;
+        config
+    }
+
+    /// 指定アカウントを順にスキャンし、結果を統合
+    ///
+    /// 一部のアカウントでスキャンに失敗しても残りのアカウントは継続し、
+    /// 失敗内容は`accounts`に記録します。全アカウントが失敗した場合のみエラーを返します。
+    pub async fn scan_accounts<C, F, Fut>(
+        &self,
+        account_ids: &[String],
+        create_scanner: F,
+        progress_callback: ProgressCallback,
+    ) -> Result<Value>
+    where
+        C: IamClientOps,
+        F: Fn(ScanConfig) -> Fut,
+        Fut: Future<Output = Result<AwsIamScanner<C>>>,
+    {
+        let progress_callback: Arc<dyn Fn(ScanProgress) + Send + Sync> =
+            Arc::from(progress_callback);
+        let total = account_ids.len().max(1) as u32;
+
+        let mut merged = serde_json::Map::new();
+        merged.insert("provider".to_string(), Value::String("aws".to_string()));
+        let mut accounts = serde_json::Map::new();
+        let mut errors = Vec::new();
+
+        for (index, account_id) in account_ids.iter().enumerate() {
+            let index = index as u32;
+            let role_arn = self.member_role_arn(account_id);
+            info!(account_id = %account_id, "アカウントのスキャンを開始");
+
+            let callback = progress_callback.clone();
+            let prefix = account_id.clone();
+            let account_callback: ProgressCallback = Box::new(move |mut event| {
+                event.progress = (index * 100 + event.progress.min(100)) / total;
+                event.message = format!("[{}] {}", prefix, event.message);
+                // アカウント単位の完了は全体の完了ではないため、リソース単位の進捗として扱う
+                if event.phase == ScanPhase::Completed {
+                    event.phase = ScanPhase::Scanning;
+                }
+                event.account_id = Some(prefix.clone());
+                callback(event);
+            });
+
+            let result = match create_scanner(self.account_config(account_id)).await {
+                Ok(scanner) => scanner.scan(account_callback).await,
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(data) => {
+                    let summary = summarize(&data);
+                    merge_account_results(&mut merged, account_id, data);
+                    accounts.insert(
+                        account_id.clone(),
+                        json!({
+                            "account_id": account_id,
+                            "role_arn": role_arn,
+                            "status": "completed",
+                            "summary": summary,
+                        }),
+                    );
+                }
+                Err(e) => {
+                    warn!(account_id = %account_id, error = %e, "アカウントのスキャンに失敗");
+                    errors.push(format!("{}: {:#}", account_id, e));
+                    accounts.insert(
+                        account_id.clone(),
+                        json!({
+                            "account_id": account_id,
+                            "role_arn": role_arn,
+                            "status": "failed",
+                            "error": format!("{:#}", e),
+                        }),
+                    );
+                }
+            }
+        }
+
+        if !account_ids.is_empty() && errors.len() == account_ids.len() {
+            return Err(anyhow!("All account scans failed: {}", errors.join("; ")));
+        }
+
+        merged.insert("accounts".to_string(), Value::Object(accounts));
+        progress_callback(ScanProgress::new(
+            ScanPhase::Completed,
+            100,
+            "マルチアカウントスキャンが完了しました",
+        ));
+        Ok(Value::Object(merged))
+    }
+}
+
+/// 各リソースに`account_id`を付与
+fn tag_with_account(items: Vec<Value>, account_id: &str) -> Vec<Value> {
+    items
+        .into_iter()
+        .map(|mut item| {
+            if let Some(obj) = item.as_object_mut() {
+                obj.insert(
+                    "account_id".to_string(),
+                    Value::String(account_id.to_string()),
+                );
+            }
+            item
+        })
+        .collect()
+}
+
+/// 単一アカウントのスキャン結果を統合結果にマージ
+///
+/// 配列はアカウントIDを付与して連結し、`attachments`のような
+/// 配列を値に持つオブジェクトはキー単位で連結します。
+fn merge_account_results(
+    merged: &mut serde_json::Map<String, Value>,
+    account_id: &str,
+    data: Value,
+) {
+    let Value::Object(data) = data else {
+        return;
+    };
+
+    for (key, value) in data {
+        match value {
+            Value::Array(items) => {
+                let target = merged
+                    .entry(key)
+                    .or_insert_with(|| Value::Array(Vec::new()));
+                if let Some(target) = target.as_array_mut() {
+                    target.extend(tag_with_account(items, account_id));
+                }
+            }
+            Value::Object(groups) => {
+                let target = merged
+                    .entry(key)
+                    .or_insert_with(|| Value::Object(serde_json::Map::new()));
+                let Some(target) = target.as_object_mut() else {
+                    continue;
+                };
+                for (sub_key, sub_value) in groups {
+                    if let Value::Array(items) = sub_value {
+                        let sub_target = target
+                            .entry(sub_key)
+                            .or_insert_with(|| Value::Array(Vec::new()));
+                        if let Some(sub_target) = sub_target.as_array_mut() {
+                            sub_target.extend(tag_with_account(items, account_id));
+                        }
+                    }
+                }
+            }
+            // provider等のスカラー値は統合結果側の値を維持
+            _ => {}
+        }
+    }
+}
+
+/// アカウント単位のリソース件数サマリー
+fn summarize(data: &Value) -> Value {
+    let mut summary = serde_json::Map::new();
+    if let Some(obj) = data.as_object() {
+        for (key, value) in obj {
+            if let Some(items) = value.as_array() {
+                summary.insert(key.clone(), json!(items.len()));
+            }
+        }
+    }
+    Value::Object(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::aws::iam_client_trait::mock::MockIamClient;
+    use crate::infra::aws::iam_client_trait::IamUserInfo;
+    use crate::infra::aws::organizations_client_trait::mock::MockOrganizationsClient;
+    use crate::infra::aws::organizations_client_trait::OrganizationAccountInfo;
+    use std::collections::HashMap;
+
+    fn create_test_config(account_ids: &[&str], discover_accounts: bool) -> ScanConfig {
+        let mut scan_targets = HashMap::new();
+        scan_targets.insert("users".to_string(), true);
+        ScanConfig {
+            provider: "aws".to_string(),
+            account_id: None,
+            profile: None,
+            assume_role_arn: None,
+            assume_role_session_name: None,
+            assume_role_external_id: None,
+            assume_role_duration_seconds: None,
+            mfa_serial: None,
+            mfa_token_code: None,
+            account_ids: account_ids.iter().map(|s| s.to_string()).collect(),
+            discover_accounts,
+            member_role_name: None,
+            region: None,
+            endpoint_url: None,
+            subscription_id: None,
+            tenant_id: None,
+            auth_method: None,
+            service_principal_config: None,
+            scope_type: None,
+            scope_value: None,
+            scan_targets,
+            filters: HashMap::new(),
+            include_tags: true,
+            max_concurrency: None,
+            scan_strategy: None,
+            include_service_last_accessed: false,
+            unused_threshold_days: None,
+        }
+    }
+
+    fn org_account(account_id: &str, status: &str) -> OrganizationAccountInfo {
+        OrganizationAccountInfo {
+            account_id: account_id.to_string(),
+            name: format!("account-{}", account_id),
+            email: format!("{}@example.com", account_id),
+            status: status.to_string(),
+        }
+    }
+
+    /// 指定ユーザーを1件返すIAMモックを作成
+    fn mock_iam_with_user(user_name: &str) -> MockIamClient {
+        let user_name = user_name.to_string();
+        let mut mock_client = MockIamClient::new();
+        mock_client
+            .expect_get_user_detail()
+            .returning(|_, _| Ok(None));
+        mock_client
+            .expect_list_users_with_options()
+            .returning(move |_| {
+                Ok(vec![IamUserInfo {
+                    user_name: user_name.clone(),
+                    user_id: "id".to_string(),
+                    arn: format!("arn:aws:iam::000000000000:user/{}", user_name),
+                    create_date: 0,
+                    path: "/".to_string(),
+                    tags: HashMap::new(),
+                    permissions_boundary: None,
+                    password_last_used: None,
+                }])
+            });
+        mock_client
+            .expect_list_user_policies()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_list_attached_user_policies()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_list_groups_for_user()
+            .returning(|_| Ok(vec!["developers".to_string()]));
+        mock_client
+            .expect_list_access_keys()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_get_login_profile()
+            .returning(|_| Ok(None));
+        mock_client
+            .expect_list_mfa_devices()
+            .returning(|_| Ok(vec![]));
+        mock_client
+    }
+
+    fn noop_callback() -> ProgressCallback {
+        Box::new(|_| {})
+    }
+
+    // ========================================
+    // アカウント解決のテスト
+    // ========================================
+
+    #[tokio::test]
+    async fn test_resolve_account_ids_merges_explicit_and_discovered() {
+        let mut organizations = MockOrganizationsClient::new();
+        organizations.expect_list_accounts().times(1).returning(|| {
+            Ok(vec![
+                org_account("111111111111", "ACTIVE"),
+                org_account("222222222222", "ACTIVE"),
+                org_account("333333333333", "SUSPENDED"),
+            ])
+        });
+
+        let scanner = AwsMultiAccountScanner::new(create_test_config(&["222222222222"], true));
+        let account_ids = scanner
+            .resolve_account_ids(Some(&organizations))
+            .await
+            .unwrap();
+
+        assert_eq!(account_ids, vec!["222222222222", "111111111111"]);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_account_ids_without_accounts_fails() {
+        let scanner = AwsMultiAccountScanner::new(create_test_config(&[], false));
+        let result = scanner
+            .resolve_account_ids::<MockOrganizationsClient>(None)
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_account_config_assumes_member_role() {
+        let mut config = create_test_config(&["111111111111"], false);
+        config.member_role_name = Some("AuditRole".to_string());
+        let scanner = AwsMultiAccountScanner::new(config);
+
+        let account_config = scanner.account_config("111111111111");
+        assert_eq!(account_config.account_id.as_deref(), Some("111111111111"));
+        assert_eq!(
+            account_config.assume_role_arn.as_deref(),
+            Some("arn:aws:iam::111111111111:role/AuditRole")
+        );
+        assert!(!account_config.is_multi_account());
+
+        let default_scanner =
+            AwsMultiAccountScanner::new(create_test_config(&["111111111111"], false));
+        assert_eq!(
+            default_scanner.member_role_arn("111111111111"),
+            "arn:aws:iam::111111111111:role/OrganizationAccountAccessRole"
+        );
+    }
+
+    #[test]
+    fn test_account_config_drops_hub_role_options() {
+        let mut config = create_test_config(&["111111111111"], false);
+        config.assume_role_arn = Some("arn:aws:iam::999999999999:role/Hub".to_string());
+        config.assume_role_session_name = Some("audit".to_string());
+        config.assume_role_external_id = Some("hub-external-id".to_string());
+        config.assume_role_duration_seconds = Some(7200);
+        config.mfa_serial = Some("arn:aws:iam::999999999999:mfa/alice".to_string());
+        config.mfa_token_code = Some("123456".to_string());
+        let scanner = AwsMultiAccountScanner::new(config);
+
+        let account_config = scanner.account_config("111111111111");
+        assert_eq!(
+            account_config.assume_role_arn.as_deref(),
+            Some("arn:aws:iam::111111111111:role/OrganizationAccountAccessRole")
+        );
+        assert_eq!(
+            account_config.assume_role_session_name.as_deref(),
+            Some("audit")
+        );
+        assert!(account_config.assume_role_external_id.is_none());
+        assert!(account_config.assume_role_duration_seconds.is_none());
+        assert!(account_config.mfa_serial.is_none());
+        assert!(account_config.mfa_token_code.is_none());
+    }
+
+    #[test]
+    fn test_account_config_passes_options_to_member_role_without_hub() {
+        let mut config = create_test_config(&["111111111111"], false);
+        config.assume_role_external_id = Some("member-external-id".to_string());
+        config.assume_role_duration_seconds = Some(7200);
+        let scanner = AwsMultiAccountScanner::new(config);
+        assert!(scanner.validate().is_ok());
+
+        let account_config = scanner.account_config("111111111111");
+        assert_eq!(
+            account_config.assume_role_external_id.as_deref(),
+            Some("member-external-id")
+        );
+        assert_eq!(account_config.assume_role_duration_seconds, Some(7200));
+    }
+
+    #[test]
+    fn test_validate_rejects_mfa_without_hub_role() {
+        let mut config = create_test_config(&["111111111111", "222222222222"], false);
+        config.mfa_serial = Some("arn:aws:iam::999999999999:mfa/alice".to_string());
+        config.mfa_token_code = Some("123456".to_string());
+        let scanner = AwsMultiAccountScanner::new(config.clone());
+
+        let error = scanner.validate().unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("mfa_serial requires assume_role_arn"));
+
+        // ハブロールの引き受けに使用する場合は有効
+        config.assume_role_arn = Some("arn:aws:iam::999999999999:role/Hub".to_string());
+        assert!(AwsMultiAccountScanner::new(config).validate().is_ok());
+    }
+
+    #[test]
+    fn test_member_role_arn_uses_partition() {
+        let scanner = AwsMultiAccountScanner::new(create_test_config(&["111111111111"], false))
+            .with_partition("aws-cn");
+
+        assert_eq!(
+            scanner.member_role_arn("111111111111"),
+            "arn:aws-cn:iam::111111111111:role/OrganizationAccountAccessRole"
+        );
+    }
+
+    // ========================================
+    // スキャン統合のテスト
+    // ========================================
+
+    #[tokio::test]
+    async fn test_scan_accounts_merges_results_per_account() {
+        let mut organizations = MockOrganizationsClient::new();
+        organizations
+            .expect_list_accounts()
+            .returning(|| Ok(vec![org_account("222222222222", "ACTIVE")]));
+
+        let scanner = AwsMultiAccountScanner::new(create_test_config(&["111111111111"], true));
+        let account_ids = scanner
+            .resolve_account_ids(Some(&organizations))
+            .await
+            .unwrap();
+
+        let progress_values = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let progress_clone = progress_values.clone();
+        let callback: ProgressCallback = Box::new(move |event| {
+            progress_clone.lock().unwrap().push(event);
+        });
+
+        let result = scanner
+            .scan_accounts(
+                &account_ids,
+                |config: ScanConfig| async move {
+                    let account_id = config.account_id.clone().unwrap();
+                    let client = mock_iam_with_user(&format!("user-{}", account_id));
+                    Ok(AwsIamScanner::new_with_client(config, client))
+                },
+                callback,
+            )
+            .await
+            .unwrap();
+
+        let users = result["users"].as_array().unwrap();
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0]["user_name"], "user-111111111111");
+        assert_eq!(users[0]["account_id"], "111111111111");
+        assert_eq!(users[1]["account_id"], "222222222222");
+
+        let memberships = result["attachments"]["user_groups"].as_array().unwrap();
+        assert_eq!(memberships.len(), 2);
+        assert_eq!(memberships[1]["account_id"], "222222222222");
+
+        let accounts = result["accounts"].as_object().unwrap();
+        assert_eq!(accounts["111111111111"]["status"], "completed");
+        assert_eq!(accounts["111111111111"]["summary"]["users"], 1);
+        assert_eq!(
+            accounts["222222222222"]["role_arn"],
+            "arn:aws:iam::222222222222:role/OrganizationAccountAccessRole"
+        );
+
+        let values = progress_values.lock().unwrap();
+        assert!(values.iter().any(|event| {
+            event.account_id.as_deref() == Some("222222222222")
+                && event.message.starts_with("[222222222222] ")
+        }));
+        assert!(values.windows(2).all(|w| w[0].progress <= w[1].progress));
+        // 全体の完了はマルチアカウントスキャンの最後の1回のみ
+        let completed: Vec<_> = values
+            .iter()
+            .filter(|event| event.phase == ScanPhase::Completed)
+            .collect();
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].progress, 100);
+        assert!(completed[0].account_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_scan_accounts_records_failed_account() {
+        let scanner = AwsMultiAccountScanner::new(create_test_config(
+            &["111111111111", "222222222222"],
+            false,
+        ));
+        let account_ids = scanner
+            .resolve_account_ids::<MockOrganizationsClient>(None)
+            .await
+            .unwrap();
+
+        let result = scanner
+            .scan_accounts(
+                &account_ids,
+                |config: ScanConfig| async move {
+                    if config.account_id.as_deref() == Some("222222222222") {
+                        return Err(anyhow!("AccessDenied: sts:AssumeRole"));
+                    }
+                    Ok(AwsIamScanner::new_with_client(
+                        config,
+                        mock_iam_with_user("alice"),
+                    ))
+                },
+                noop_callback(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result["users"].as_array().unwrap().len(), 1);
+        assert_eq!(result["accounts"]["111111111111"]["status"], "completed");
+        assert_eq!(result["accounts"]["222222222222"]["status"], "failed");
+        assert!(result["accounts"]["222222222222"]["error"]
+            .as_str()
+            .unwrap()
+            .contains("AccessDenied"));
+    }
+
+    #[tokio::test]
+    async fn test_scan_accounts_fails_when_all_accounts_fail() {
+        let scanner = AwsMultiAccountScanner::new(create_test_config(&["111111111111"], false));
+
+        let result = scanner
+            .scan_accounts(
+                &["111111111111".to_string()],
+                |_config: ScanConfig| async move {
+                    Err::<AwsIamScanner<MockIamClient>, _>(anyhow!("AccessDenied"))
+                },
+                noop_callback(),
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+}
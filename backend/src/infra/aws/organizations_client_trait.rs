@@ -0,0 +1,107 @@
+//! AWS Organizationsクライアント操作の抽象化トレイト
+//!
+//! このモジュールは、AWS Organizationsの操作を抽象化し、
+//! テスト時にモック実装を注入できるようにします。
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+/// Organizations配下のアカウント情報
+#[derive(Debug, Clone)]
+pub struct OrganizationAccountInfo {
+    pub account_id: String,
+    pub name: String,
+    #[allow(dead_code)]
+    pub email: String,
+    /// アカウントの状態（`ACTIVE` / `SUSPENDED` / `PENDING_CLOSURE`）
+    pub status: String,
+}
+
+impl OrganizationAccountInfo {
+    /// スキャン対象となるアクティブなアカウントかどうか
+    pub fn is_active(&self) -> bool {
+        self.status.eq_ignore_ascii_case("ACTIVE")
+    }
+}
+
+/// ルート・組織単位（OU）の情報
+#[derive(Debug, Clone)]
+pub struct OrganizationalUnitInfo {
+    pub id: String,
+    pub name: String,
+    pub arn: String,
+}
+
+/// Organizationsポリシーの概要
+#[derive(Debug, Clone)]
+pub struct OrganizationPolicySummary {
+    pub policy_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub arn: String,
+    /// AWS管理ポリシー（`FullAWSAccess`等）かどうか
+    pub aws_managed: bool,
+}
+
+/// ポリシーのアタッチ先
+#[derive(Debug, Clone)]
+pub struct OrganizationPolicyTargetInfo {
+    pub target_id: String,
+    pub name: String,
+    /// アタッチ先の種類（`ROOT` / `ORGANIZATIONAL_UNIT` / `ACCOUNT`）
+    pub target_type: String,
+}
+
+/// Organizationsクライアント操作を抽象化するトレイト
+#[async_trait]
+pub trait OrganizationsClientOps: Send + Sync {
+    /// Organizations配下の全アカウントを取得（ページネーション済み）
+    async fn list_accounts(&self) -> Result<Vec<OrganizationAccountInfo>>;
+
+    /// 組織のルート一覧を取得
+    async fn list_roots(&self) -> Result<Vec<OrganizationalUnitInfo>>;
+
+    /// 親（ルートまたはOU）直下の組織単位を取得
+    async fn list_organizational_units_for_parent(
+        &self,
+        parent_id: &str,
+    ) -> Result<Vec<OrganizationalUnitInfo>>;
+
+    /// 指定種別（`SERVICE_CONTROL_POLICY`等）のポリシー一覧を取得
+    async fn list_policies(&self, policy_type: &str) -> Result<Vec<OrganizationPolicySummary>>;
+
+    /// ポリシーの内容（JSONドキュメント）を取得
+    async fn describe_policy(&self, policy_id: &str) -> Result<String>;
+
+    /// ポリシーのアタッチ先一覧を取得
+    async fn list_targets_for_policy(
+        &self,
+        policy_id: &str,
+    ) -> Result<Vec<OrganizationPolicyTargetInfo>>;
+}
+
+#[cfg(test)]
+pub mod mock {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub OrganizationsClient {}
+
+        #[async_trait]
+        impl OrganizationsClientOps for OrganizationsClient {
+            async fn list_accounts(&self) -> Result<Vec<OrganizationAccountInfo>>;
+            async fn list_roots(&self) -> Result<Vec<OrganizationalUnitInfo>>;
+            async fn list_organizational_units_for_parent(
+                &self,
+                parent_id: &str,
+            ) -> Result<Vec<OrganizationalUnitInfo>>;
+            async fn list_policies(&self, policy_type: &str) -> Result<Vec<OrganizationPolicySummary>>;
+            async fn describe_policy(&self, policy_id: &str) -> Result<String>;
+            async fn list_targets_for_policy(
+                &self,
+                policy_id: &str,
+            ) -> Result<Vec<OrganizationPolicyTargetInfo>>;
+        }
+    }
+}
@@ -0,0 +1,371 @@
+//! AWS Organizationsスキャナー
+//!
+//! 組織単位（OU）のツリー、サービスコントロールポリシー（SCP）とそのアタッチ先を
+//! スキャンし、Terraform生成用のデータ構造に変換します。
+//!
+//! SCPは管理アカウント（または委任管理者アカウント）でのみ参照できるため、
+//! API呼び出しの失敗はスキャン全体を失敗させず、スキャン警告として記録します。
+
+use anyhow::{anyhow, Result};
+use aws_config::SdkConfig;
+use futures::stream::{self, StreamExt};
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+use crate::domain::iam_policy::IamPolicyDocument;
+use crate::infra::aws::cli::AwsCliRunner;
+use crate::infra::aws::organizations_client_trait::{
+    OrganizationPolicySummary, OrganizationsClientOps,
+};
+use crate::infra::aws::real_organizations_client::RealOrganizationsClient;
+use crate::infra::aws::retry::with_throttling_retry;
+use crate::infra::aws::scanner::scan_warning;
+use crate::models::{ScanConfig, ScanWarning};
+
+/// サービスコントロールポリシーのポリシー種別
+const SERVICE_CONTROL_POLICY: &str = "SERVICE_CONTROL_POLICY";
+
+/// AWS Organizationsのスキャン結果
+#[derive(Debug, Default)]
+pub struct OrganizationsResources {
+    pub organizational_units: Vec<Value>,
+    pub service_control_policies: Vec<Value>,
+    pub service_control_policy_attachments: Vec<Value>,
+}
+
+impl OrganizationsResources {
+    /// 全リソースの件数
+    pub fn count(&self) -> usize {
+        self.organizational_units.len()
+            + self.service_control_policies.len()
+            + self.service_control_policy_attachments.len()
+    }
+}
+
+/// SCP1件分のスキャン結果
+#[derive(Default)]
+struct PolicyResources {
+    policy: Option<Value>,
+    attachments: Vec<Value>,
+    warnings: Vec<ScanWarning>,
+}
+
+/// AWS Organizationsスキャナー
+///
+/// Organizationsクライアントを抽象化することで、テスト時にモックを注入可能にしています。
+pub struct AwsOrganizationsScanner<O: OrganizationsClientOps + ?Sized> {
+    client: Arc<O>,
+    /// ポリシー単位の同時取得数
+    concurrency: usize,
+}
+
+impl AwsOrganizationsScanner<dyn OrganizationsClientOps> {
+    /// 本番用のスキャナーを作成（AWS CLI経由でOrganizations APIを呼び出す）
+    pub async fn from_sdk_config(sdk_config: &SdkConfig, config: &ScanConfig) -> Result<Self> {
+        let cli = AwsCliRunner::new(sdk_config);
+        Ok(Self::new_with_client(
+            Arc::new(RealOrganizationsClient::new(cli)),
+            config.max_concurrency(),
+        ))
+    }
+}
+
+impl<O: OrganizationsClientOps + ?Sized> AwsOrganizationsScanner<O> {
+    pub fn new_with_client(client: Arc<O>, concurrency: usize) -> Self {
+        Self {
+            client,
+            concurrency: concurrency.max(1),
+        }
+    }
+
+    /// OUツリーとSCP（ドキュメント・アタッチ先を含む）をスキャン
+    ///
+    /// 取得に失敗したOU・ポリシーは`warnings`に記録してスキップします。
+    pub async fn scan(&self, warnings: &mut Vec<ScanWarning>) -> OrganizationsResources {
+        let mut resources = OrganizationsResources {
+            organizational_units: self.scan_organizational_units(warnings).await,
+            ..Default::default()
+        };
+
+        let policies = match with_throttling_retry("ListPolicies", || {
+            self.client.list_policies(SERVICE_CONTROL_POLICY)
+        })
+        .await
+        {
+            Ok(policies) => policies,
+            Err(e) => {
+                warn!(error = %e, "サービスコントロールポリシーの取得に失敗");
+                warnings.push(scan_warning("organizations", "", "ListPolicies", &e));
+                return resources;
+            }
+        };
+        debug!(count = policies.len(), "SCPのスキャンを開始");
+
+        // 同時実行数を制限して並列に取得（結果は列挙順で返る）
+        let mut results = stream::iter(policies)
+            .map(|policy| self.scan_policy(policy))
+            .buffered(self.concurrency);
+
+        while let Some(result) = results.next().await {
+            resources.service_control_policies.extend(result.policy);
+            resources
+                .service_control_policy_attachments
+                .extend(result.attachments);
+            warnings.extend(result.warnings);
+        }
+
+        resources
+    }
+
+    /// ルートから幅優先でOUツリーをたどり、各OUに親IDとパスを付与する
+    async fn scan_organizational_units(&self, warnings: &mut Vec<ScanWarning>) -> Vec<Value> {
+        let roots = match with_throttling_retry("ListRoots", || self.client.list_roots()).await {
+            Ok(roots) => roots,
+            Err(e) => {
+                warn!(error = %e, "組織ルートの取得に失敗");
+                warnings.push(scan_warning("organizations", "", "ListRoots", &e));
+                return Vec::new();
+            }
+        };
+
+        let mut units = Vec::new();
+        let mut queue: VecDeque<(String, String)> =
+            roots.into_iter().map(|root| (root.id, root.name)).collect();
+        while let Some((parent_id, parent_path)) = queue.pop_front() {
+            let children = match with_throttling_retry("ListOrganizationalUnitsForParent", || {
+                self.client.list_organizational_units_for_parent(&parent_id)
+            })
+            .await
+            {
+                Ok(children) => children,
+                Err(e) => {
+                    warn!(parent_id = %parent_id, error = %e, "組織単位の取得に失敗");
+                    warnings.push(scan_warning(
+                        "organizational_unit",
+                        &parent_id,
+                        "ListOrganizationalUnitsForParent",
+                        &e,
+                    ));
+                    continue;
+                }
+            };
+
+            for unit in children {
+                let path = format!("{}/{}", parent_path, unit.name);
+                units.push(json!({
+                    "ou_id": unit.id,
+                    "name": unit.name,
+                    "arn": unit.arn,
+                    "parent_id": parent_id,
+                    "path": path,
+                }));
+                queue.push_back((unit.id, path));
+            }
+        }
+
+        units
+    }
+
+    /// SCP1件のドキュメントとアタッチ先を取得
+    async fn scan_policy(&self, policy: OrganizationPolicySummary) -> PolicyResources {
+        let policy_id = policy.policy_id.as_str();
+        let name = policy.name.as_str();
+        let mut result = PolicyResources::default();
+
+        // ドキュメントはIAMポリシーと同じ構造で扱う
+        let policy_document = match with_throttling_retry("DescribePolicy", || {
+            self.client.describe_policy(policy_id)
+        })
+        .await
+        .and_then(|content| {
+            IamPolicyDocument::from_json_str(&content)
+                .map_err(|e| anyhow!("Failed to parse policy content: {}", e))
+        }) {
+            Ok(document) => document,
+            Err(e) => {
+                warn!(policy_id, error = %e, "SCPのドキュメントの取得に失敗");
+                result.warnings.push(scan_warning(
+                    "service_control_policy",
+                    name,
+                    "DescribePolicy",
+                    &e,
+                ));
+                return result;
+            }
+        };
+
+        let mut policy_json = json!({
+            "policy_id": policy_id,
+            "name": name,
+            "arn": policy.arn,
+            "aws_managed": policy.aws_managed,
+            "policy_document": policy_document,
+        });
+        if let Some(description) = &policy.description {
+            policy_json["description"] = json!(description);
+        }
+        result.policy = Some(policy_json);
+
+        match with_throttling_retry("ListTargetsForPolicy", || {
+            self.client.list_targets_for_policy(policy_id)
+        })
+        .await
+        {
+            Ok(targets) => {
+                result.attachments = targets
+                    .into_iter()
+                    .map(|target| {
+                        json!({
+                            "policy_id": policy_id,
+                            "policy_name": name,
+                            "aws_managed": policy.aws_managed,
+                            "target_id": target.target_id,
+                            "target_name": target.name,
+                            "target_type": target.target_type,
+                        })
+                    })
+                    .collect();
+            }
+            Err(e) => {
+                warn!(policy_id, error = %e, "SCPのアタッチ先の取得に失敗");
+                result.warnings.push(scan_warning(
+                    "service_control_policy",
+                    name,
+                    "ListTargetsForPolicy",
+                    &e,
+                ));
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::aws::organizations_client_trait::mock::MockOrganizationsClient;
+    use crate::infra::aws::organizations_client_trait::{
+        OrganizationPolicyTargetInfo, OrganizationalUnitInfo,
+    };
+
+    fn unit(id: &str, name: &str) -> OrganizationalUnitInfo {
+        OrganizationalUnitInfo {
+            id: id.to_string(),
+            name: name.to_string(),
+            arn: format!("arn:aws:organizations::111111111111:ou/o-example/{}", id),
+        }
+    }
+
+    fn policy(policy_id: &str, name: &str, aws_managed: bool) -> OrganizationPolicySummary {
+        OrganizationPolicySummary {
+            policy_id: policy_id.to_string(),
+            name: name.to_string(),
+            description: None,
+            arn: format!(
+                "arn:aws:organizations::aws:policy/service_control_policy/{}",
+                policy_id
+            ),
+            aws_managed,
+        }
+    }
+
+    fn mock_organization() -> MockOrganizationsClient {
+        let mut mock = MockOrganizationsClient::new();
+        mock.expect_list_roots()
+            .returning(|| Ok(vec![unit("r-abcd", "Root")]));
+        mock.expect_list_organizational_units_for_parent()
+            .returning(|parent_id| match parent_id {
+                "r-abcd" => Ok(vec![unit("ou-abcd-workloads", "Workloads")]),
+                "ou-abcd-workloads" => Ok(vec![unit("ou-abcd-prod", "Prod")]),
+                _ => Ok(Vec::new()),
+            });
+        mock.expect_list_policies()
+            .withf(|policy_type| policy_type == SERVICE_CONTROL_POLICY)
+            .returning(|_| {
+                Ok(vec![
+                    policy("p-FullAWSAccess", "FullAWSAccess", true),
+                    policy("p-denyleave", "DenyLeaveOrg", false),
+                ])
+            });
+        mock.expect_describe_policy().returning(|policy_id| match policy_id {
+            "p-denyleave" => Ok(r#"{"Version":"2012-10-17","Statement":{"Effect":"Deny","Action":"organizations:LeaveOrganization","Resource":"*"}}"#.to_string()),
+            _ => Ok(r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow","Action":"*","Resource":"*"}]}"#.to_string()),
+        });
+        mock.expect_list_targets_for_policy()
+            .returning(|policy_id| match policy_id {
+                "p-denyleave" => Ok(vec![OrganizationPolicyTargetInfo {
+                    target_id: "ou-abcd-workloads".to_string(),
+                    name: "Workloads".to_string(),
+                    target_type: "ORGANIZATIONAL_UNIT".to_string(),
+                }]),
+                _ => Ok(vec![OrganizationPolicyTargetInfo {
+                    target_id: "r-abcd".to_string(),
+                    name: "Root".to_string(),
+                    target_type: "ROOT".to_string(),
+                }]),
+            });
+        mock
+    }
+
+    #[tokio::test]
+    async fn test_scan_organizational_units_and_policies() {
+        let scanner = AwsOrganizationsScanner::new_with_client(Arc::new(mock_organization()), 2);
+        let mut warnings = Vec::new();
+
+        let resources = scanner.scan(&mut warnings).await;
+
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(resources.organizational_units.len(), 2);
+        assert_eq!(resources.organizational_units[1]["ou_id"], "ou-abcd-prod");
+        assert_eq!(
+            resources.organizational_units[1]["parent_id"],
+            "ou-abcd-workloads"
+        );
+        assert_eq!(
+            resources.organizational_units[1]["path"],
+            "Root/Workloads/Prod"
+        );
+
+        assert_eq!(resources.service_control_policies.len(), 2);
+        let deny_leave = &resources.service_control_policies[1];
+        assert_eq!(deny_leave["name"], "DenyLeaveOrg");
+        assert_eq!(deny_leave["aws_managed"], false);
+        // 単一のStatementもIamPolicyDocumentとして正規化される
+        assert_eq!(
+            deny_leave["policy_document"]["Statement"][0]["Action"],
+            "organizations:LeaveOrganization"
+        );
+
+        assert_eq!(resources.service_control_policy_attachments.len(), 2);
+        let attachment = &resources.service_control_policy_attachments[1];
+        assert_eq!(attachment["policy_id"], "p-denyleave");
+        assert_eq!(attachment["target_id"], "ou-abcd-workloads");
+        assert_eq!(attachment["target_type"], "ORGANIZATIONAL_UNIT");
+        assert_eq!(resources.count(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_scan_records_warning_outside_management_account() {
+        let mut mock = MockOrganizationsClient::new();
+        mock.expect_list_roots().returning(|| {
+            Err(anyhow!(
+                "AccessDeniedException: You don't have permissions to access this resource"
+            ))
+        });
+        mock.expect_list_policies()
+            .returning(|_| Err(anyhow!("AccessDeniedException: not authorized")));
+        let scanner = AwsOrganizationsScanner::new_with_client(Arc::new(mock), 1);
+        let mut warnings = Vec::new();
+
+        let resources = scanner.scan(&mut warnings).await;
+
+        assert_eq!(resources.count(), 0);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].operation, "ListRoots");
+        assert_eq!(warnings[1].operation, "ListPolicies");
+        assert_eq!(warnings[1].error_kind, "access_denied");
+    }
+}
@@ -4,14 +4,24 @@
 
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
+use aws_sdk_iam::error::DisplayErrorContext;
+use aws_sdk_iam::types::{JobStatusType, Tag};
 use aws_sdk_iam::Client as IamClient;
 use std::collections::HashMap;
+use std::time::Duration;
 
 use super::iam_client_trait::{
-    IamClientOps, IamGroupInfo, IamPolicyInfo, IamRoleInfo, IamUserInfo, PolicyAttachment,
-    PolicyDocument,
+    IamAccessKeyInfo, IamClientOps, IamEntityDetail, IamGroupInfo, IamInstanceProfileInfo,
+    IamLoginProfileInfo, IamMfaDeviceInfo, IamOidcProviderInfo, IamPasswordPolicyInfo,
+    IamPolicyInfo, IamRoleInfo, IamSamlProviderInfo, IamServiceLastAccessedInfo, IamUserInfo,
+    PolicyAttachment, PolicyDocument,
 };
 
+/// サービス最終アクセスレポートのポーリング間隔
+const SERVICE_LAST_ACCESSED_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// サービス最終アクセスレポートのポーリング上限回数
+const SERVICE_LAST_ACCESSED_MAX_POLLS: usize = 60;
+
 /// AWS SDK IAMクライアントをラップした本番実装
 pub struct RealIamClient {
     client: IamClient,
@@ -23,6 +33,12 @@ impl RealIamClient {
     }
 }
 
+fn convert_tags(tags: &[Tag]) -> HashMap<String, String> {
+    tags.iter()
+        .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+        .collect()
+}
+
 #[async_trait]
 impl IamClientOps for RealIamClient {
     async fn list_users(&self) -> Result<Vec<IamUserInfo>> {
@@ -30,7 +46,8 @@ impl IamClientOps for RealIamClient {
         self.list_users_with_options(true).await
     }
 
-    async fn list_users_with_options(&self, include_tags: bool) -> Result<Vec<IamUserInfo>> {
+    // タグは`get_user_detail`で取得するため、`include_tags`は使用しない
+    async fn list_users_with_options(&self, _include_tags: bool) -> Result<Vec<IamUserInfo>> {
         let mut users = Vec::new();
         let mut paginator = self
             .client
@@ -40,38 +57,21 @@ impl IamClientOps for RealIamClient {
             .send();
 
         while let Some(page_result) = paginator.next().await {
-            let page = page_result.map_err(|e| anyhow!("Failed to list users: {}", e))?;
+            let page = page_result
+                .map_err(|e| anyhow!("Failed to list users: {}", DisplayErrorContext(&e)))?;
 
             for user in page.users() {
-                let user_name = user.user_name().to_string();
-
-                // タグを取得（include_tagsがtrueの場合のみ）
-                let tags = if include_tags {
-                    match self
-                        .client
-                        .list_user_tags()
-                        .user_name(&user_name)
-                        .send()
-                        .await
-                    {
-                        Ok(tags_result) => tags_result
-                            .tags()
-                            .iter()
-                            .map(|tag| (tag.key().to_string(), tag.value().to_string()))
-                            .collect(),
-                        Err(_) => HashMap::new(),
-                    }
-                } else {
-                    HashMap::new()
-                };
-
+                // ListUsersはアクセス許可の境界とタグを返さないため、
+                // スキャナーが`get_user_detail`で個別に補完する
                 users.push(IamUserInfo {
-                    user_name,
+                    user_name: user.user_name().to_string(),
                     user_id: user.user_id().to_string(),
                     arn: user.arn().to_string(),
                     create_date: user.create_date().secs(),
                     path: user.path().to_string(),
-                    tags,
+                    tags: HashMap::new(),
+                    permissions_boundary: None,
+                    password_last_used: user.password_last_used().map(|dt| dt.secs()),
                 });
             }
         }
@@ -89,7 +89,8 @@ impl IamClientOps for RealIamClient {
             .send();
 
         while let Some(page_result) = paginator.next().await {
-            let page = page_result.map_err(|e| anyhow!("Failed to list groups: {}", e))?;
+            let page = page_result
+                .map_err(|e| anyhow!("Failed to list groups: {}", DisplayErrorContext(&e)))?;
 
             for group in page.groups() {
                 groups.push(IamGroupInfo {
@@ -110,7 +111,8 @@ impl IamClientOps for RealIamClient {
         self.list_roles_with_options(true).await
     }
 
-    async fn list_roles_with_options(&self, include_tags: bool) -> Result<Vec<IamRoleInfo>> {
+    // タグは`get_role_detail`で取得するため、`include_tags`は使用しない
+    async fn list_roles_with_options(&self, _include_tags: bool) -> Result<Vec<IamRoleInfo>> {
         let mut roles = Vec::new();
         let mut paginator = self
             .client
@@ -120,33 +122,14 @@ impl IamClientOps for RealIamClient {
             .send();
 
         while let Some(page_result) = paginator.next().await {
-            let page = page_result.map_err(|e| anyhow!("Failed to list roles: {}", e))?;
+            let page = page_result
+                .map_err(|e| anyhow!("Failed to list roles: {}", DisplayErrorContext(&e)))?;
 
             for role in page.roles() {
-                let role_name = role.role_name().to_string();
-
-                // タグを取得（include_tagsがtrueの場合のみ）
-                let tags = if include_tags {
-                    match self
-                        .client
-                        .list_role_tags()
-                        .role_name(&role_name)
-                        .send()
-                        .await
-                    {
-                        Ok(tags_result) => tags_result
-                            .tags()
-                            .iter()
-                            .map(|tag| (tag.key().to_string(), tag.value().to_string()))
-                            .collect(),
-                        Err(_) => HashMap::new(),
-                    }
-                } else {
-                    HashMap::new()
-                };
-
+                // ListRolesはアクセス許可の境界・タグ・最終使用日時を返さないため、
+                // スキャナーが`get_role_detail`で個別に補完する
                 roles.push(IamRoleInfo {
-                    role_name,
+                    role_name: role.role_name().to_string(),
                     role_id: role.role_id().to_string(),
                     arn: role.arn().to_string(),
                     create_date: role.create_date().secs(),
@@ -154,7 +137,12 @@ impl IamClientOps for RealIamClient {
                     assume_role_policy_document: role
                         .assume_role_policy_document()
                         .map(|s| s.to_string()),
-                    tags,
+                    tags: HashMap::new(),
+                    description: role.description().map(|s| s.to_string()),
+                    max_session_duration: role.max_session_duration(),
+                    permissions_boundary: None,
+                    last_used_date: None,
+                    last_used_region: None,
                 });
             }
         }
@@ -162,6 +150,85 @@ impl IamClientOps for RealIamClient {
         Ok(roles)
     }
 
+    async fn get_user_detail(
+        &self,
+        user_name: &str,
+        include_tags: bool,
+    ) -> Result<Option<IamEntityDetail>> {
+        let output = self
+            .client
+            .get_user()
+            .user_name(user_name)
+            .send()
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to get user {}: {}",
+                    user_name,
+                    DisplayErrorContext(&e)
+                )
+            })?;
+        let user = output
+            .user()
+            .ok_or_else(|| anyhow!("GetUser response for {} has no user", user_name))?;
+
+        Ok(Some(IamEntityDetail {
+            permissions_boundary: user
+                .permissions_boundary()
+                .and_then(|b| b.permissions_boundary_arn())
+                .map(|arn| arn.to_string()),
+            tags: if include_tags {
+                convert_tags(user.tags())
+            } else {
+                HashMap::new()
+            },
+            last_used_date: None,
+            last_used_region: None,
+        }))
+    }
+
+    async fn get_role_detail(
+        &self,
+        role_name: &str,
+        include_tags: bool,
+    ) -> Result<Option<IamEntityDetail>> {
+        let output = self
+            .client
+            .get_role()
+            .role_name(role_name)
+            .send()
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to get role {}: {}",
+                    role_name,
+                    DisplayErrorContext(&e)
+                )
+            })?;
+        let role = output
+            .role()
+            .ok_or_else(|| anyhow!("GetRole response for {} has no role", role_name))?;
+        let role_last_used = role.role_last_used();
+
+        Ok(Some(IamEntityDetail {
+            permissions_boundary: role
+                .permissions_boundary()
+                .and_then(|b| b.permissions_boundary_arn())
+                .map(|arn| arn.to_string()),
+            tags: if include_tags {
+                convert_tags(role.tags())
+            } else {
+                HashMap::new()
+            },
+            last_used_date: role_last_used
+                .and_then(|u| u.last_used_date())
+                .map(|dt| dt.secs()),
+            last_used_region: role_last_used
+                .and_then(|u| u.region())
+                .map(|s| s.to_string()),
+        }))
+    }
+
     async fn list_policies(&self) -> Result<Vec<IamPolicyInfo>> {
         let mut policies = Vec::new();
         let mut paginator = self
@@ -173,7 +240,8 @@ impl IamClientOps for RealIamClient {
             .send();
 
         while let Some(page_result) = paginator.next().await {
-            let page = page_result.map_err(|e| anyhow!("Failed to list policies: {}", e))?;
+            let page = page_result
+                .map_err(|e| anyhow!("Failed to list policies: {}", DisplayErrorContext(&e)))?;
 
             for policy in page.policies() {
                 policies.push(IamPolicyInfo {
@@ -193,6 +261,373 @@ impl IamClientOps for RealIamClient {
         Ok(policies)
     }
 
+    async fn list_instance_profiles(&self) -> Result<Vec<IamInstanceProfileInfo>> {
+        let mut instance_profiles = Vec::new();
+        let mut paginator = self
+            .client
+            .list_instance_profiles()
+            .into_paginator()
+            .page_size(100)
+            .send();
+
+        while let Some(page_result) = paginator.next().await {
+            let page = page_result.map_err(|e| {
+                anyhow!(
+                    "Failed to list instance profiles: {}",
+                    DisplayErrorContext(&e)
+                )
+            })?;
+
+            for profile in page.instance_profiles() {
+                instance_profiles.push(IamInstanceProfileInfo {
+                    instance_profile_name: profile.instance_profile_name().to_string(),
+                    instance_profile_id: profile.instance_profile_id().to_string(),
+                    arn: profile.arn().to_string(),
+                    create_date: profile.create_date().secs(),
+                    path: profile.path().to_string(),
+                    role_names: profile
+                        .roles()
+                        .iter()
+                        .map(|role| role.role_name().to_string())
+                        .collect(),
+                });
+            }
+        }
+
+        Ok(instance_profiles)
+    }
+
+    async fn list_open_id_connect_providers(&self) -> Result<Vec<IamOidcProviderInfo>> {
+        let result = self
+            .client
+            .list_open_id_connect_providers()
+            .send()
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to list OpenID Connect providers: {}",
+                    DisplayErrorContext(&e)
+                )
+            })?;
+
+        // 一覧APIはARNのみを返すため、プロバイダーごとに詳細を取得
+        let mut providers = Vec::new();
+        for entry in result.open_id_connect_provider_list() {
+            let Some(arn) = entry.arn() else {
+                continue;
+            };
+            let provider = self
+                .client
+                .get_open_id_connect_provider()
+                .open_id_connect_provider_arn(arn)
+                .send()
+                .await
+                .map_err(|e| {
+                    anyhow!(
+                        "Failed to get OpenID Connect provider {}: {}",
+                        arn,
+                        DisplayErrorContext(&e)
+                    )
+                })?;
+
+            providers.push(IamOidcProviderInfo {
+                arn: arn.to_string(),
+                url: provider.url().unwrap_or_default().to_string(),
+                client_id_list: provider.client_id_list().to_vec(),
+                thumbprint_list: provider.thumbprint_list().to_vec(),
+                create_date: provider.create_date().map(|dt| dt.secs()).unwrap_or(0),
+                tags: convert_tags(provider.tags()),
+            });
+        }
+
+        Ok(providers)
+    }
+
+    async fn list_saml_providers(&self) -> Result<Vec<IamSamlProviderInfo>> {
+        let result = self
+            .client
+            .list_saml_providers()
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to list SAML providers: {}", DisplayErrorContext(&e)))?;
+
+        // メタデータドキュメントは一覧APIに含まれないため、プロバイダーごとに取得
+        let mut providers = Vec::new();
+        for entry in result.saml_provider_list() {
+            let Some(arn) = entry.arn() else {
+                continue;
+            };
+            let provider = self
+                .client
+                .get_saml_provider()
+                .saml_provider_arn(arn)
+                .send()
+                .await
+                .map_err(|e| {
+                    anyhow!(
+                        "Failed to get SAML provider {}: {}",
+                        arn,
+                        DisplayErrorContext(&e)
+                    )
+                })?;
+
+            providers.push(IamSamlProviderInfo {
+                arn: arn.to_string(),
+                name: arn.rsplit('/').next().unwrap_or(arn).to_string(),
+                saml_metadata_document: provider
+                    .saml_metadata_document()
+                    .unwrap_or_default()
+                    .to_string(),
+                create_date: provider.create_date().map(|dt| dt.secs()).unwrap_or(0),
+                valid_until: provider.valid_until().map(|dt| dt.secs()).unwrap_or(0),
+                tags: convert_tags(provider.tags()),
+            });
+        }
+
+        Ok(providers)
+    }
+
+    async fn list_access_keys(&self, user_name: &str) -> Result<Vec<IamAccessKeyInfo>> {
+        let mut access_keys = Vec::new();
+        let mut paginator = self
+            .client
+            .list_access_keys()
+            .user_name(user_name)
+            .into_paginator()
+            .send();
+
+        while let Some(page_result) = paginator.next().await {
+            let page = page_result
+                .map_err(|e| anyhow!("Failed to list access keys: {}", DisplayErrorContext(&e)))?;
+
+            for key in page.access_key_metadata() {
+                let Some(access_key_id) = key.access_key_id() else {
+                    continue;
+                };
+                let last_used = self
+                    .client
+                    .get_access_key_last_used()
+                    .access_key_id(access_key_id)
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        anyhow!(
+                            "Failed to get access key last used: {}",
+                            DisplayErrorContext(&e)
+                        )
+                    })?;
+
+                access_keys.push(IamAccessKeyInfo {
+                    user_name: user_name.to_string(),
+                    access_key_id: access_key_id.to_string(),
+                    status: key
+                        .status()
+                        .map(|s| s.as_str().to_string())
+                        .unwrap_or_default(),
+                    create_date: key.create_date().map(|dt| dt.secs()).unwrap_or(0),
+                    last_used_date: last_used
+                        .access_key_last_used()
+                        .and_then(|u| u.last_used_date())
+                        .map(|dt| dt.secs()),
+                });
+            }
+        }
+
+        Ok(access_keys)
+    }
+
+    async fn get_login_profile(&self, user_name: &str) -> Result<Option<IamLoginProfileInfo>> {
+        match self
+            .client
+            .get_login_profile()
+            .user_name(user_name)
+            .send()
+            .await
+        {
+            Ok(result) => Ok(result.login_profile().map(|profile| IamLoginProfileInfo {
+                user_name: profile.user_name().to_string(),
+                create_date: profile.create_date().secs(),
+                password_reset_required: profile.password_reset_required(),
+            })),
+            // ログインプロファイルが無いユーザーはNoSuchEntityが返る
+            Err(e)
+                if e.as_service_error()
+                    .is_some_and(|se| se.is_no_such_entity_exception()) =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(anyhow!(
+                "Failed to get login profile: {}",
+                DisplayErrorContext(&e)
+            )),
+        }
+    }
+
+    async fn list_mfa_devices(&self, user_name: &str) -> Result<Vec<IamMfaDeviceInfo>> {
+        let mut devices = Vec::new();
+        let mut paginator = self
+            .client
+            .list_mfa_devices()
+            .user_name(user_name)
+            .into_paginator()
+            .send();
+
+        while let Some(page_result) = paginator.next().await {
+            let page = page_result
+                .map_err(|e| anyhow!("Failed to list MFA devices: {}", DisplayErrorContext(&e)))?;
+
+            for device in page.mfa_devices() {
+                devices.push(IamMfaDeviceInfo {
+                    user_name: device.user_name().to_string(),
+                    serial_number: device.serial_number().to_string(),
+                    enable_date: device.enable_date().secs(),
+                });
+            }
+        }
+
+        Ok(devices)
+    }
+
+    async fn get_account_password_policy(&self) -> Result<Option<IamPasswordPolicyInfo>> {
+        match self.client.get_account_password_policy().send().await {
+            Ok(result) => Ok(result
+                .password_policy()
+                .map(|policy| IamPasswordPolicyInfo {
+                    minimum_password_length: policy.minimum_password_length(),
+                    require_symbols: policy.require_symbols(),
+                    require_numbers: policy.require_numbers(),
+                    require_uppercase_characters: policy.require_uppercase_characters(),
+                    require_lowercase_characters: policy.require_lowercase_characters(),
+                    allow_users_to_change_password: policy.allow_users_to_change_password(),
+                    max_password_age: policy.max_password_age(),
+                    password_reuse_prevention: policy.password_reuse_prevention(),
+                    hard_expiry: policy.hard_expiry(),
+                })),
+            // パスワードポリシーが未設定のアカウントはNoSuchEntityが返る
+            Err(e)
+                if e.as_service_error()
+                    .is_some_and(|se| se.is_no_such_entity_exception()) =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(anyhow!(
+                "Failed to get account password policy: {}",
+                DisplayErrorContext(&e)
+            )),
+        }
+    }
+
+    async fn list_account_aliases(&self) -> Result<Vec<String>> {
+        let mut aliases = Vec::new();
+        let mut paginator = self.client.list_account_aliases().into_paginator().send();
+
+        while let Some(result) = paginator.next().await {
+            let output = result.map_err(|e| {
+                anyhow!(
+                    "Failed to list account aliases: {}",
+                    DisplayErrorContext(&e)
+                )
+            })?;
+            aliases.extend(output.account_aliases().iter().cloned());
+        }
+
+        Ok(aliases)
+    }
+
+    async fn get_service_last_accessed(
+        &self,
+        arn: &str,
+    ) -> Result<Vec<IamServiceLastAccessedInfo>> {
+        let job = self
+            .client
+            .generate_service_last_accessed_details()
+            .arn(arn)
+            .send()
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to generate service last accessed details for {}: {}",
+                    arn,
+                    DisplayErrorContext(&e)
+                )
+            })?;
+        let job_id = job
+            .job_id()
+            .ok_or_else(|| anyhow!("No job ID returned for {}", arn))?
+            .to_string();
+
+        let mut services = Vec::new();
+        let mut marker: Option<String> = None;
+        let mut polls = 0;
+
+        loop {
+            let output = self
+                .client
+                .get_service_last_accessed_details()
+                .job_id(&job_id)
+                .set_marker(marker.clone())
+                .send()
+                .await
+                .map_err(|e| {
+                    anyhow!(
+                        "Failed to get service last accessed details for {}: {}",
+                        arn,
+                        DisplayErrorContext(&e)
+                    )
+                })?;
+
+            match output.job_status() {
+                JobStatusType::Completed => {}
+                JobStatusType::Failed => {
+                    return Err(anyhow!(
+                        "Service last accessed job failed for {}: {}",
+                        arn,
+                        output
+                            .error()
+                            .map(|e| e.message())
+                            .unwrap_or("unknown error")
+                    ));
+                }
+                _ => {
+                    // ジョブ完了待ち（IN_PROGRESS）
+                    polls += 1;
+                    if polls >= SERVICE_LAST_ACCESSED_MAX_POLLS {
+                        return Err(anyhow!(
+                            "Timed out waiting for service last accessed job for {}",
+                            arn
+                        ));
+                    }
+                    tokio::time::sleep(SERVICE_LAST_ACCESSED_POLL_INTERVAL).await;
+                    continue;
+                }
+            }
+
+            for service in output.services_last_accessed() {
+                services.push(IamServiceLastAccessedInfo {
+                    service_name: service.service_name().to_string(),
+                    service_namespace: service.service_namespace().to_string(),
+                    last_authenticated: service.last_authenticated().map(|dt| dt.secs()),
+                    last_authenticated_entity: service
+                        .last_authenticated_entity()
+                        .map(|s| s.to_string()),
+                    last_authenticated_region: service
+                        .last_authenticated_region()
+                        .map(|s| s.to_string()),
+                });
+            }
+
+            if !output.is_truncated() {
+                break;
+            }
+            marker = output.marker().map(|s| s.to_string());
+            if marker.is_none() {
+                break;
+            }
+        }
+
+        Ok(services)
+    }
+
     async fn list_user_policies(&self, user_name: &str) -> Result<Vec<String>> {
         let result = self
             .client
@@ -200,7 +635,7 @@ impl IamClientOps for RealIamClient {
             .user_name(user_name)
             .send()
             .await
-            .map_err(|e| anyhow!("Failed to list user policies: {}", e))?;
+            .map_err(|e| anyhow!("Failed to list user policies: {}", DisplayErrorContext(&e)))?;
 
         Ok(result.policy_names().to_vec())
     }
@@ -212,7 +647,12 @@ impl IamClientOps for RealIamClient {
             .user_name(user_name)
             .send()
             .await
-            .map_err(|e| anyhow!("Failed to list attached user policies: {}", e))?;
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to list attached user policies: {}",
+                    DisplayErrorContext(&e)
+                )
+            })?;
 
         Ok(result
             .attached_policies()
@@ -231,7 +671,7 @@ impl IamClientOps for RealIamClient {
             .group_name(group_name)
             .send()
             .await
-            .map_err(|e| anyhow!("Failed to list group policies: {}", e))?;
+            .map_err(|e| anyhow!("Failed to list group policies: {}", DisplayErrorContext(&e)))?;
 
         Ok(result.policy_names().to_vec())
     }
@@ -246,7 +686,12 @@ impl IamClientOps for RealIamClient {
             .group_name(group_name)
             .send()
             .await
-            .map_err(|e| anyhow!("Failed to list attached group policies: {}", e))?;
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to list attached group policies: {}",
+                    DisplayErrorContext(&e)
+                )
+            })?;
 
         Ok(result
             .attached_policies()
@@ -265,7 +710,7 @@ impl IamClientOps for RealIamClient {
             .role_name(role_name)
             .send()
             .await
-            .map_err(|e| anyhow!("Failed to list role policies: {}", e))?;
+            .map_err(|e| anyhow!("Failed to list role policies: {}", DisplayErrorContext(&e)))?;
 
         Ok(result.policy_names().to_vec())
     }
@@ -277,7 +722,12 @@ impl IamClientOps for RealIamClient {
             .role_name(role_name)
             .send()
             .await
-            .map_err(|e| anyhow!("Failed to list attached role policies: {}", e))?;
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to list attached role policies: {}",
+                    DisplayErrorContext(&e)
+                )
+            })?;
 
         Ok(result
             .attached_policies()
@@ -296,7 +746,12 @@ impl IamClientOps for RealIamClient {
             .user_name(user_name)
             .send()
             .await
-            .map_err(|e| anyhow!("Failed to list groups for user: {}", e))?;
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to list groups for user: {}",
+                    DisplayErrorContext(&e)
+                )
+            })?;
 
         Ok(result
             .groups()
@@ -317,7 +772,7 @@ impl IamClientOps for RealIamClient {
             .version_id(version_id)
             .send()
             .await
-            .map_err(|e| anyhow!("Failed to get policy version: {}", e))?;
+            .map_err(|e| anyhow!("Failed to get policy version: {}", DisplayErrorContext(&e)))?;
 
         Ok(result.policy_version().and_then(|pv| {
             pv.document().map(|doc| PolicyDocument {
@@ -325,4 +780,61 @@ impl IamClientOps for RealIamClient {
             })
         }))
     }
+
+    async fn get_user_policy(
+        &self,
+        user_name: &str,
+        policy_name: &str,
+    ) -> Result<Option<PolicyDocument>> {
+        let result = self
+            .client
+            .get_user_policy()
+            .user_name(user_name)
+            .policy_name(policy_name)
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to get user policy: {}", DisplayErrorContext(&e)))?;
+
+        Ok(Some(PolicyDocument {
+            document: result.policy_document().to_string(),
+        }))
+    }
+
+    async fn get_group_policy(
+        &self,
+        group_name: &str,
+        policy_name: &str,
+    ) -> Result<Option<PolicyDocument>> {
+        let result = self
+            .client
+            .get_group_policy()
+            .group_name(group_name)
+            .policy_name(policy_name)
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to get group policy: {}", DisplayErrorContext(&e)))?;
+
+        Ok(Some(PolicyDocument {
+            document: result.policy_document().to_string(),
+        }))
+    }
+
+    async fn get_role_policy(
+        &self,
+        role_name: &str,
+        policy_name: &str,
+    ) -> Result<Option<PolicyDocument>> {
+        let result = self
+            .client
+            .get_role_policy()
+            .role_name(role_name)
+            .policy_name(policy_name)
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to get role policy: {}", DisplayErrorContext(&e)))?;
+
+        Ok(Some(PolicyDocument {
+            document: result.policy_document().to_string(),
+        }))
+    }
 }
@@ -0,0 +1,122 @@
+//! KMSクライアントの本番実装
+//!
+//! このモジュールは、`KmsClientOps`トレイトの本番実装を提供します。
+//! KMS用のSDKクレートは導入していないため、AWS CLIを利用します。
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use serde_json::Value;
+
+use super::cli::{field, optional_field, AwsCliRunner};
+use super::kms_client_trait::{KmsClientOps, KmsKeyInfo};
+
+/// キーポリシー名（KMSがサポートするのは`default`のみ）
+const DEFAULT_POLICY_NAME: &str = "default";
+
+/// AWS CLIをラップしたKMSクライアントの本番実装
+pub struct RealKmsClient {
+    cli: AwsCliRunner,
+}
+
+impl RealKmsClient {
+    pub fn new(cli: AwsCliRunner) -> Self {
+        Self { cli }
+    }
+}
+
+#[async_trait]
+impl KmsClientOps for RealKmsClient {
+    async fn list_keys(&self) -> Result<Vec<String>> {
+        // AWS CLIはJSON出力時に全ページを自動で取得する
+        let output = self
+            .cli
+            .run_json(&["kms", "list-keys"])
+            .await
+            .map_err(|e| anyhow!("Failed to list KMS keys: {}", e))?;
+
+        Ok(output
+            .get("Keys")
+            .and_then(|v| v.as_array())
+            .map(|keys| {
+                keys.iter()
+                    .map(|key| field(key, "KeyId"))
+                    .filter(|key_id| !key_id.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn describe_key(&self, key_id: &str) -> Result<KmsKeyInfo> {
+        let output = self
+            .cli
+            .run_json(&["kms", "describe-key", "--key-id", key_id])
+            .await
+            .map_err(|e| anyhow!("Failed to describe KMS key {}: {}", key_id, e))?;
+
+        parse_key_metadata(&output)
+            .ok_or_else(|| anyhow!("DescribeKey response for {} has no key metadata", key_id))
+    }
+
+    async fn get_key_policy(&self, key_id: &str) -> Result<String> {
+        let output = self
+            .cli
+            .run_json(&[
+                "kms",
+                "get-key-policy",
+                "--key-id",
+                key_id,
+                "--policy-name",
+                DEFAULT_POLICY_NAME,
+            ])
+            .await
+            .map_err(|e| anyhow!("Failed to get key policy for {}: {}", key_id, e))?;
+
+        output
+            .get("Policy")
+            .and_then(|v| v.as_str())
+            .map(|policy| policy.to_string())
+            .ok_or_else(|| anyhow!("GetKeyPolicy response for {} has no policy", key_id))
+    }
+}
+
+/// `describe-key`の出力をキー情報に変換
+fn parse_key_metadata(output: &Value) -> Option<KmsKeyInfo> {
+    let metadata = output.get("KeyMetadata")?;
+    let key_id = field(metadata, "KeyId");
+    if key_id.is_empty() {
+        return None;
+    }
+    Some(KmsKeyInfo {
+        key_id,
+        key_arn: field(metadata, "Arn"),
+        description: optional_field(metadata, "Description"),
+        key_manager: field(metadata, "KeyManager"),
+        key_state: field(metadata, "KeyState"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_key_metadata() {
+        let output = json!({
+            "KeyMetadata": {
+                "KeyId": "1234abcd-12ab-34cd-56ef-1234567890ab",
+                "Arn": "arn:aws:kms:us-east-1:111111111111:key/1234abcd-12ab-34cd-56ef-1234567890ab",
+                "Description": "",
+                "KeyManager": "CUSTOMER",
+                "KeyState": "Enabled"
+            }
+        });
+
+        let key = parse_key_metadata(&output).unwrap();
+        assert_eq!(key.key_id, "1234abcd-12ab-34cd-56ef-1234567890ab");
+        assert_eq!(key.description, None);
+        assert_eq!(key.key_manager, "CUSTOMER");
+        assert_eq!(key.key_state, "Enabled");
+        assert!(parse_key_metadata(&Value::Null).is_none());
+    }
+}
@@ -0,0 +1,284 @@
+//! AWS Organizationsクライアントの本番実装
+//!
+//! このモジュールは、`OrganizationsClientOps`トレイトの本番実装を提供します。
+//! Organizations用のSDKクレートは導入していないため、AWS CLIを利用します。
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use serde_json::Value;
+
+use super::cli::{field, optional_field, AwsCliRunner};
+use super::organizations_client_trait::{
+    OrganizationAccountInfo, OrganizationPolicySummary, OrganizationPolicyTargetInfo,
+    OrganizationalUnitInfo, OrganizationsClientOps,
+};
+
+/// AWS CLIをラップしたOrganizationsクライアントの本番実装
+pub struct RealOrganizationsClient {
+    cli: AwsCliRunner,
+}
+
+impl RealOrganizationsClient {
+    pub fn new(cli: AwsCliRunner) -> Self {
+        Self { cli }
+    }
+}
+
+#[async_trait]
+impl OrganizationsClientOps for RealOrganizationsClient {
+    async fn list_accounts(&self) -> Result<Vec<OrganizationAccountInfo>> {
+        // AWS CLIはJSON出力時に全ページを自動で取得する
+        let output = self
+            .cli
+            .run_json(&["organizations", "list-accounts"])
+            .await
+            .map_err(|e| anyhow!("Failed to list organization accounts: {}", e))?;
+
+        Ok(parse_accounts(&output))
+    }
+
+    async fn list_roots(&self) -> Result<Vec<OrganizationalUnitInfo>> {
+        let output = self
+            .cli
+            .run_json(&["organizations", "list-roots"])
+            .await
+            .map_err(|e| anyhow!("Failed to list organization roots: {}", e))?;
+
+        Ok(parse_organizational_units(&output, "Roots"))
+    }
+
+    async fn list_organizational_units_for_parent(
+        &self,
+        parent_id: &str,
+    ) -> Result<Vec<OrganizationalUnitInfo>> {
+        let output = self
+            .cli
+            .run_json(&[
+                "organizations",
+                "list-organizational-units-for-parent",
+                "--parent-id",
+                parent_id,
+            ])
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to list organizational units for {}: {}",
+                    parent_id,
+                    e
+                )
+            })?;
+
+        Ok(parse_organizational_units(&output, "OrganizationalUnits"))
+    }
+
+    async fn list_policies(&self, policy_type: &str) -> Result<Vec<OrganizationPolicySummary>> {
+        let output = self
+            .cli
+            .run_json(&["organizations", "list-policies", "--filter", policy_type])
+            .await
+            .map_err(|e| anyhow!("Failed to list organization policies: {}", e))?;
+
+        Ok(parse_policies(&output))
+    }
+
+    async fn describe_policy(&self, policy_id: &str) -> Result<String> {
+        let output = self
+            .cli
+            .run_json(&["organizations", "describe-policy", "--policy-id", policy_id])
+            .await
+            .map_err(|e| anyhow!("Failed to describe policy {}: {}", policy_id, e))?;
+
+        output
+            .get("Policy")
+            .and_then(|policy| policy.get("Content"))
+            .and_then(|v| v.as_str())
+            .map(|content| content.to_string())
+            .ok_or_else(|| anyhow!("DescribePolicy response for {} has no content", policy_id))
+    }
+
+    async fn list_targets_for_policy(
+        &self,
+        policy_id: &str,
+    ) -> Result<Vec<OrganizationPolicyTargetInfo>> {
+        let output = self
+            .cli
+            .run_json(&[
+                "organizations",
+                "list-targets-for-policy",
+                "--policy-id",
+                policy_id,
+            ])
+            .await
+            .map_err(|e| anyhow!("Failed to list targets for policy {}: {}", policy_id, e))?;
+
+        Ok(parse_policy_targets(&output))
+    }
+}
+
+/// `list-accounts`の出力をアカウント情報に変換
+fn parse_accounts(output: &Value) -> Vec<OrganizationAccountInfo> {
+    output
+        .get("Accounts")
+        .and_then(|v| v.as_array())
+        .map(|accounts| {
+            accounts
+                .iter()
+                .filter_map(|account| {
+                    let account_id = field(account, "Id");
+                    if account_id.is_empty() {
+                        return None;
+                    }
+                    Some(OrganizationAccountInfo {
+                        account_id,
+                        name: field(account, "Name"),
+                        email: field(account, "Email"),
+                        status: field(account, "Status"),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `list-roots` / `list-organizational-units-for-parent`の出力を組織単位の情報に変換
+fn parse_organizational_units(output: &Value, key: &str) -> Vec<OrganizationalUnitInfo> {
+    output
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|units| {
+            units
+                .iter()
+                .filter_map(|unit| {
+                    let id = field(unit, "Id");
+                    if id.is_empty() {
+                        return None;
+                    }
+                    Some(OrganizationalUnitInfo {
+                        id,
+                        name: field(unit, "Name"),
+                        arn: field(unit, "Arn"),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `list-policies`の出力をポリシーの概要に変換
+fn parse_policies(output: &Value) -> Vec<OrganizationPolicySummary> {
+    output
+        .get("Policies")
+        .and_then(|v| v.as_array())
+        .map(|policies| {
+            policies
+                .iter()
+                .filter_map(|policy| {
+                    let policy_id = field(policy, "Id");
+                    if policy_id.is_empty() {
+                        return None;
+                    }
+                    Some(OrganizationPolicySummary {
+                        policy_id,
+                        name: field(policy, "Name"),
+                        description: optional_field(policy, "Description"),
+                        arn: field(policy, "Arn"),
+                        aws_managed: policy
+                            .get("AwsManaged")
+                            .and_then(|v| v.as_bool())
+                            .unwrap_or(false),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `list-targets-for-policy`の出力をアタッチ先の情報に変換
+fn parse_policy_targets(output: &Value) -> Vec<OrganizationPolicyTargetInfo> {
+    output
+        .get("Targets")
+        .and_then(|v| v.as_array())
+        .map(|targets| {
+            targets
+                .iter()
+                .filter_map(|target| {
+                    let target_id = field(target, "TargetId");
+                    if target_id.is_empty() {
+                        return None;
+                    }
+                    Some(OrganizationPolicyTargetInfo {
+                        target_id,
+                        name: field(target, "Name"),
+                        target_type: field(target, "Type"),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_accounts() {
+        let output = json!({
+            "Accounts": [
+                {"Id": "111111111111", "Name": "prod", "Email": "prod@example.com", "Status": "ACTIVE"},
+                {"Id": "222222222222", "Name": "old", "Email": "old@example.com", "Status": "SUSPENDED"},
+                {"Name": "broken"}
+            ]
+        });
+
+        let accounts = parse_accounts(&output);
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].account_id, "111111111111");
+        assert!(accounts[0].is_active());
+        assert!(!accounts[1].is_active());
+    }
+
+    #[test]
+    fn test_parse_accounts_empty_output() {
+        assert!(parse_accounts(&Value::Null).is_empty());
+    }
+
+    #[test]
+    fn test_parse_organizational_units() {
+        let output = json!({
+            "OrganizationalUnits": [
+                {"Id": "ou-abcd-11111111", "Name": "Workloads", "Arn": "arn:aws:organizations::111111111111:ou/o-example/ou-abcd-11111111"},
+                {"Name": "broken"}
+            ]
+        });
+
+        let units = parse_organizational_units(&output, "OrganizationalUnits");
+        assert_eq!(units.len(), 1);
+        assert_eq!(units[0].id, "ou-abcd-11111111");
+        assert_eq!(units[0].name, "Workloads");
+        assert!(parse_organizational_units(&output, "Roots").is_empty());
+    }
+
+    #[test]
+    fn test_parse_policies_and_targets() {
+        let policies = parse_policies(&json!({
+            "Policies": [
+                {"Id": "p-FullAWSAccess", "Name": "FullAWSAccess", "Arn": "arn:aws:organizations::aws:policy/service_control_policy/p-FullAWSAccess", "Description": "Allows access to every operation", "Type": "SERVICE_CONTROL_POLICY", "AwsManaged": true},
+                {"Id": "p-12345678", "Name": "DenyLeaveOrg", "Arn": "arn:aws:organizations::111111111111:policy/o-example/service_control_policy/p-12345678", "Description": "", "Type": "SERVICE_CONTROL_POLICY", "AwsManaged": false}
+            ]
+        }));
+        assert_eq!(policies.len(), 2);
+        assert!(policies[0].aws_managed);
+        assert_eq!(policies[1].name, "DenyLeaveOrg");
+        assert_eq!(policies[1].description, None);
+
+        let targets = parse_policy_targets(&json!({
+            "Targets": [
+                {"TargetId": "ou-abcd-11111111", "Arn": "arn:aws:organizations::111111111111:ou/o-example/ou-abcd-11111111", "Name": "Workloads", "Type": "ORGANIZATIONAL_UNIT"}
+            ]
+        }));
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].target_type, "ORGANIZATIONAL_UNIT");
+    }
+}
@@ -0,0 +1,391 @@
+//! S3クライアントの本番実装
+//!
+//! このモジュールは、`S3ClientOps`トレイトの本番実装を提供します。
+//! S3用のSDKクレートは導入していないため、AWS CLIを利用します。
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+use super::cli::{field, optional_field, AwsCliRunner};
+use super::s3_client_trait::{BucketEncryptionInfo, PublicAccessBlockInfo, S3ClientOps};
+
+/// バケットポリシー未設定時のエラーコード
+const NO_SUCH_BUCKET_POLICY: &str = "NoSuchBucketPolicy";
+/// タグ未設定時のエラーコード
+const NO_SUCH_TAG_SET: &str = "NoSuchTagSet";
+/// デフォルト暗号化未設定時のエラーコード
+const ENCRYPTION_NOT_FOUND: &str = "ServerSideEncryptionConfigurationNotFoundError";
+/// ライフサイクル未設定時のエラーコード
+const NO_SUCH_LIFECYCLE_CONFIGURATION: &str = "NoSuchLifecycleConfiguration";
+/// パブリックアクセスブロック未設定時のエラーコード
+const NO_SUCH_PUBLIC_ACCESS_BLOCK: &str = "NoSuchPublicAccessBlockConfiguration";
+/// オブジェクト所有者の設定が未設定時のエラーコード
+const OWNERSHIP_CONTROLS_NOT_FOUND: &str = "OwnershipControlsNotFoundError";
+
+/// `LocationConstraint`が空の場合のリージョン
+const DEFAULT_BUCKET_REGION: &str = "us-east-1";
+
+/// AWS CLIをラップしたS3クライアントの本番実装
+pub struct RealS3Client {
+    cli: AwsCliRunner,
+}
+
+impl RealS3Client {
+    pub fn new(cli: AwsCliRunner) -> Self {
+        Self { cli }
+    }
+
+    /// バケット単位の`s3api`コマンドを実行
+    ///
+    /// `not_found_code`のエラーは設定が存在しないことを示すため、Noneとして返します。
+    async fn run_bucket_command(
+        &self,
+        command: &str,
+        bucket: &str,
+        not_found_code: &str,
+    ) -> Result<Option<Value>> {
+        match self
+            .cli
+            .run_json(&["s3api", command, "--bucket", bucket])
+            .await
+        {
+            Ok(output) => Ok(Some(output)),
+            Err(e) if e.to_string().contains(not_found_code) => Ok(None),
+            Err(e) => Err(anyhow!("Failed to {} for {}: {}", command, bucket, e)),
+        }
+    }
+}
+
+#[async_trait]
+impl S3ClientOps for RealS3Client {
+    async fn list_buckets(&self) -> Result<Vec<String>> {
+        let output = self
+            .cli
+            .run_json(&["s3api", "list-buckets"])
+            .await
+            .map_err(|e| anyhow!("Failed to list S3 buckets: {}", e))?;
+
+        Ok(parse_bucket_names(&output))
+    }
+
+    async fn get_bucket_policy(&self, bucket: &str) -> Result<Option<String>> {
+        match self
+            .cli
+            .run_json(&["s3api", "get-bucket-policy", "--bucket", bucket])
+            .await
+        {
+            Ok(output) => Ok(output
+                .get("Policy")
+                .and_then(|v| v.as_str())
+                .filter(|policy| !policy.trim().is_empty())
+                .map(|policy| policy.to_string())),
+            // ポリシー未設定はエラーではなく「ポリシーなし」として扱う
+            Err(e) if e.to_string().contains(NO_SUCH_BUCKET_POLICY) => Ok(None),
+            Err(e) => Err(anyhow!("Failed to get bucket policy for {}: {}", bucket, e)),
+        }
+    }
+
+    async fn get_bucket_location(&self, bucket: &str) -> Result<String> {
+        let output = self
+            .cli
+            .run_json(&["s3api", "get-bucket-location", "--bucket", bucket])
+            .await
+            .map_err(|e| anyhow!("Failed to get bucket location for {}: {}", bucket, e))?;
+
+        Ok(parse_bucket_location(&output))
+    }
+
+    async fn get_bucket_tagging(&self, bucket: &str) -> Result<HashMap<String, String>> {
+        Ok(self
+            .run_bucket_command("get-bucket-tagging", bucket, NO_SUCH_TAG_SET)
+            .await?
+            .map(|output| parse_tag_set(&output))
+            .unwrap_or_default())
+    }
+
+    async fn get_bucket_versioning(&self, bucket: &str) -> Result<Option<String>> {
+        // 一度もバージョニングを有効化していないバケットは`Status`を持たない
+        let output = self
+            .cli
+            .run_json(&["s3api", "get-bucket-versioning", "--bucket", bucket])
+            .await
+            .map_err(|e| anyhow!("Failed to get bucket versioning for {}: {}", bucket, e))?;
+
+        Ok(optional_field(&output, "Status"))
+    }
+
+    async fn get_bucket_encryption(&self, bucket: &str) -> Result<Option<BucketEncryptionInfo>> {
+        Ok(self
+            .run_bucket_command("get-bucket-encryption", bucket, ENCRYPTION_NOT_FOUND)
+            .await?
+            .and_then(|output| parse_bucket_encryption(&output)))
+    }
+
+    async fn get_bucket_lifecycle_configuration(&self, bucket: &str) -> Result<Option<Vec<Value>>> {
+        Ok(self
+            .run_bucket_command(
+                "get-bucket-lifecycle-configuration",
+                bucket,
+                NO_SUCH_LIFECYCLE_CONFIGURATION,
+            )
+            .await?
+            .map(|output| parse_lifecycle_rules(&output)))
+    }
+
+    async fn get_public_access_block(&self, bucket: &str) -> Result<Option<PublicAccessBlockInfo>> {
+        Ok(self
+            .run_bucket_command(
+                "get-public-access-block",
+                bucket,
+                NO_SUCH_PUBLIC_ACCESS_BLOCK,
+            )
+            .await?
+            .map(|output| parse_public_access_block(&output)))
+    }
+
+    async fn get_bucket_ownership_controls(&self, bucket: &str) -> Result<Option<String>> {
+        Ok(self
+            .run_bucket_command(
+                "get-bucket-ownership-controls",
+                bucket,
+                OWNERSHIP_CONTROLS_NOT_FOUND,
+            )
+            .await?
+            .and_then(|output| parse_object_ownership(&output)))
+    }
+}
+
+/// `list-buckets`の出力をバケット名の一覧に変換
+fn parse_bucket_names(output: &Value) -> Vec<String> {
+    output
+        .get("Buckets")
+        .and_then(|v| v.as_array())
+        .map(|buckets| {
+            buckets
+                .iter()
+                .map(|bucket| field(bucket, "Name"))
+                .filter(|name| !name.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `get-bucket-location`の出力をリージョンに変換
+///
+/// us-east-1のバケットは`LocationConstraint`がnull、旧来のeu-west-1のバケットは"EU"を返します。
+fn parse_bucket_location(output: &Value) -> String {
+    match optional_field(output, "LocationConstraint").as_deref() {
+        None => DEFAULT_BUCKET_REGION.to_string(),
+        Some("EU") => "eu-west-1".to_string(),
+        Some(region) => region.to_string(),
+    }
+}
+
+/// `get-bucket-tagging`の出力をタグのマップに変換
+fn parse_tag_set(output: &Value) -> HashMap<String, String> {
+    output
+        .get("TagSet")
+        .and_then(|v| v.as_array())
+        .map(|tags| {
+            tags.iter()
+                .map(|tag| (field(tag, "Key"), field(tag, "Value")))
+                .filter(|(key, _)| !key.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `get-bucket-encryption`の出力から最初のルールを取得
+fn parse_bucket_encryption(output: &Value) -> Option<BucketEncryptionInfo> {
+    let rule = output
+        .get("ServerSideEncryptionConfiguration")?
+        .get("Rules")?
+        .as_array()?
+        .first()?;
+    let default = rule.get("ApplyServerSideEncryptionByDefault")?;
+    Some(BucketEncryptionInfo {
+        sse_algorithm: optional_field(default, "SSEAlgorithm")?,
+        kms_master_key_id: optional_field(default, "KMSMasterKeyID"),
+        bucket_key_enabled: rule
+            .get("BucketKeyEnabled")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+    })
+}
+
+/// `get-bucket-lifecycle-configuration`の出力をスネークケースのルールに変換
+fn parse_lifecycle_rules(output: &Value) -> Vec<Value> {
+    output
+        .get("Rules")
+        .and_then(|v| v.as_array())
+        .map(|rules| rules.iter().map(to_snake_case_keys).collect())
+        .unwrap_or_default()
+}
+
+/// JSONオブジェクトのキーを再帰的にスネークケースへ変換（例: `NoncurrentDays` → `noncurrent_days`）
+fn to_snake_case_keys(value: &Value) -> Value {
+    match value {
+        Value::Object(object) => Value::Object(
+            object
+                .iter()
+                .map(|(key, value)| (snake_case_key(key), to_snake_case_keys(value)))
+                .collect::<Map<String, Value>>(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(to_snake_case_keys).collect()),
+        _ => value.clone(),
+    }
+}
+
+/// パスカルケースのキーをスネークケースに変換（連続する大文字は1語として扱う）
+fn snake_case_key(key: &str) -> String {
+    let mut snake = String::with_capacity(key.len() + 4);
+    let mut prev_lower = false;
+    for c in key.chars() {
+        if c.is_ascii_uppercase() {
+            if prev_lower {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+            prev_lower = false;
+        } else {
+            snake.push(c);
+            prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        }
+    }
+    snake
+}
+
+/// `get-public-access-block`の出力を設定に変換
+fn parse_public_access_block(output: &Value) -> PublicAccessBlockInfo {
+    let config = output
+        .get("PublicAccessBlockConfiguration")
+        .unwrap_or(&Value::Null);
+    let flag = |key: &str| config.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+    PublicAccessBlockInfo {
+        block_public_acls: flag("BlockPublicAcls"),
+        block_public_policy: flag("BlockPublicPolicy"),
+        ignore_public_acls: flag("IgnorePublicAcls"),
+        restrict_public_buckets: flag("RestrictPublicBuckets"),
+    }
+}
+
+/// `get-bucket-ownership-controls`の出力からオブジェクト所有者の設定を取得
+fn parse_object_ownership(output: &Value) -> Option<String> {
+    let rule = output
+        .get("OwnershipControls")?
+        .get("Rules")?
+        .as_array()?
+        .first()?;
+    optional_field(rule, "ObjectOwnership")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_bucket_names() {
+        let output = json!({
+            "Buckets": [
+                {"Name": "app-logs", "CreationDate": "2024-01-01T00:00:00+00:00"},
+                {"CreationDate": "2024-01-01T00:00:00+00:00"}
+            ],
+            "Owner": {"ID": "abc"}
+        });
+
+        assert_eq!(parse_bucket_names(&output), vec!["app-logs".to_string()]);
+        assert!(parse_bucket_names(&Value::Null).is_empty());
+    }
+
+    #[test]
+    fn test_parse_bucket_location() {
+        assert_eq!(
+            parse_bucket_location(&json!({"LocationConstraint": null})),
+            "us-east-1"
+        );
+        assert_eq!(
+            parse_bucket_location(&json!({"LocationConstraint": "EU"})),
+            "eu-west-1"
+        );
+        assert_eq!(
+            parse_bucket_location(&json!({"LocationConstraint": "ap-northeast-1"})),
+            "ap-northeast-1"
+        );
+    }
+
+    #[test]
+    fn test_parse_bucket_configuration() {
+        let encryption = parse_bucket_encryption(&json!({
+            "ServerSideEncryptionConfiguration": {
+                "Rules": [{
+                    "ApplyServerSideEncryptionByDefault": {
+                        "SSEAlgorithm": "aws:kms",
+                        "KMSMasterKeyID": "arn:aws:kms:us-east-1:111111111111:key/abcd"
+                    },
+                    "BucketKeyEnabled": true
+                }]
+            }
+        }))
+        .unwrap();
+        assert_eq!(encryption.sse_algorithm, "aws:kms");
+        assert_eq!(
+            encryption.kms_master_key_id.as_deref(),
+            Some("arn:aws:kms:us-east-1:111111111111:key/abcd")
+        );
+        assert!(encryption.bucket_key_enabled);
+
+        let block = parse_public_access_block(&json!({
+            "PublicAccessBlockConfiguration": {
+                "BlockPublicAcls": true,
+                "IgnorePublicAcls": true,
+                "BlockPublicPolicy": false,
+                "RestrictPublicBuckets": true
+            }
+        }));
+        assert!(block.block_public_acls);
+        assert!(!block.block_public_policy);
+
+        assert_eq!(
+            parse_object_ownership(&json!({
+                "OwnershipControls": {"Rules": [{"ObjectOwnership": "BucketOwnerEnforced"}]}
+            }))
+            .as_deref(),
+            Some("BucketOwnerEnforced")
+        );
+        assert_eq!(
+            parse_tag_set(&json!({"TagSet": [{"Key": "env", "Value": "prod"}]})).get("env"),
+            Some(&"prod".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_lifecycle_rules_converts_keys() {
+        let rules = parse_lifecycle_rules(&json!({
+            "Rules": [{
+                "ID": "archive-logs",
+                "Status": "Enabled",
+                "Filter": {"And": {"Prefix": "logs/", "Tags": [{"Key": "tier", "Value": "cold"}]}},
+                "Transitions": [{"Days": 30, "StorageClass": "GLACIER"}],
+                "NoncurrentVersionExpiration": {"NoncurrentDays": 90},
+                "AbortIncompleteMultipartUpload": {"DaysAfterInitiation": 7}
+            }]
+        }));
+
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0]["id"], "archive-logs");
+        assert_eq!(rules[0]["filter"]["and"]["prefix"], "logs/");
+        assert_eq!(rules[0]["filter"]["and"]["tags"][0]["key"], "tier");
+        assert_eq!(rules[0]["transitions"][0]["storage_class"], "GLACIER");
+        assert_eq!(
+            rules[0]["noncurrent_version_expiration"]["noncurrent_days"],
+            90
+        );
+        assert_eq!(
+            rules[0]["abort_incomplete_multipart_upload"]["days_after_initiation"],
+            7
+        );
+    }
+}
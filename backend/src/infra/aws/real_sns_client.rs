@@ -0,0 +1,84 @@
+//! SNSクライアントの本番実装
+//!
+//! このモジュールは、`SnsClientOps`トレイトの本番実装を提供します。
+//! SNS用のSDKクレートは導入していないため、AWS CLIを利用します。
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use serde_json::Value;
+
+use super::cli::{field, optional_field, AwsCliRunner};
+use super::sns_client_trait::SnsClientOps;
+
+/// AWS CLIをラップしたSNSクライアントの本番実装
+pub struct RealSnsClient {
+    cli: AwsCliRunner,
+}
+
+impl RealSnsClient {
+    pub fn new(cli: AwsCliRunner) -> Self {
+        Self { cli }
+    }
+}
+
+#[async_trait]
+impl SnsClientOps for RealSnsClient {
+    async fn list_topics(&self) -> Result<Vec<String>> {
+        // AWS CLIはJSON出力時に全ページを自動で取得する
+        let output = self
+            .cli
+            .run_json(&["sns", "list-topics"])
+            .await
+            .map_err(|e| anyhow!("Failed to list SNS topics: {}", e))?;
+
+        Ok(parse_topic_arns(&output))
+    }
+
+    async fn get_topic_policy(&self, topic_arn: &str) -> Result<Option<String>> {
+        let output = self
+            .cli
+            .run_json(&["sns", "get-topic-attributes", "--topic-arn", topic_arn])
+            .await
+            .map_err(|e| anyhow!("Failed to get attributes for topic {}: {}", topic_arn, e))?;
+
+        Ok(output
+            .get("Attributes")
+            .and_then(|attributes| optional_field(attributes, "Policy")))
+    }
+}
+
+/// `list-topics`の出力をトピックARNの一覧に変換
+fn parse_topic_arns(output: &Value) -> Vec<String> {
+    output
+        .get("Topics")
+        .and_then(|v| v.as_array())
+        .map(|topics| {
+            topics
+                .iter()
+                .map(|topic| field(topic, "TopicArn"))
+                .filter(|arn| !arn.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_topic_arns() {
+        let output = json!({
+            "Topics": [
+                {"TopicArn": "arn:aws:sns:us-east-1:111111111111:alerts"},
+                {}
+            ]
+        });
+
+        assert_eq!(
+            parse_topic_arns(&output),
+            vec!["arn:aws:sns:us-east-1:111111111111:alerts".to_string()]
+        );
+    }
+}
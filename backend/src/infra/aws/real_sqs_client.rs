@@ -0,0 +1,89 @@
+//! SQSクライアントの本番実装
+//!
+//! このモジュールは、`SqsClientOps`トレイトの本番実装を提供します。
+//! SQS用のSDKクレートは導入していないため、AWS CLIを利用します。
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use serde_json::Value;
+
+use super::cli::{optional_field, string_array, AwsCliRunner};
+use super::sqs_client_trait::{SqsClientOps, SqsQueueInfo};
+
+/// AWS CLIをラップしたSQSクライアントの本番実装
+pub struct RealSqsClient {
+    cli: AwsCliRunner,
+}
+
+impl RealSqsClient {
+    pub fn new(cli: AwsCliRunner) -> Self {
+        Self { cli }
+    }
+}
+
+#[async_trait]
+impl SqsClientOps for RealSqsClient {
+    async fn list_queues(&self) -> Result<Vec<String>> {
+        // キューが存在しない場合は出力が空になる
+        let output = self
+            .cli
+            .run_json(&["sqs", "list-queues"])
+            .await
+            .map_err(|e| anyhow!("Failed to list SQS queues: {}", e))?;
+
+        Ok(string_array(&output, "QueueUrls"))
+    }
+
+    async fn get_queue_attributes(&self, queue_url: &str) -> Result<SqsQueueInfo> {
+        let output = self
+            .cli
+            .run_json(&[
+                "sqs",
+                "get-queue-attributes",
+                "--queue-url",
+                queue_url,
+                "--attribute-names",
+                "QueueArn",
+                "Policy",
+            ])
+            .await
+            .map_err(|e| anyhow!("Failed to get attributes for queue {}: {}", queue_url, e))?;
+
+        Ok(parse_queue_attributes(queue_url, &output))
+    }
+}
+
+/// `get-queue-attributes`の出力をキュー情報に変換
+fn parse_queue_attributes(queue_url: &str, output: &Value) -> SqsQueueInfo {
+    let attributes = output.get("Attributes").unwrap_or(&Value::Null);
+    SqsQueueInfo {
+        queue_url: queue_url.to_string(),
+        queue_arn: optional_field(attributes, "QueueArn").unwrap_or_default(),
+        policy: optional_field(attributes, "Policy"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_queue_attributes() {
+        let url = "https://sqs.us-east-1.amazonaws.com/111111111111/jobs";
+        let output = json!({
+            "Attributes": {
+                "QueueArn": "arn:aws:sqs:us-east-1:111111111111:jobs",
+                "Policy": "{\"Version\":\"2012-10-17\",\"Statement\":[]}"
+            }
+        });
+
+        let queue = parse_queue_attributes(url, &output);
+        assert_eq!(queue.queue_url, url);
+        assert_eq!(queue.queue_arn, "arn:aws:sqs:us-east-1:111111111111:jobs");
+        assert!(queue.policy.is_some());
+
+        let queue = parse_queue_attributes(url, &json!({"Attributes": {}}));
+        assert_eq!(queue.policy, None);
+    }
+}
@@ -0,0 +1,404 @@
+//! IAM Identity Center（SSO Admin）クライアントの本番実装
+//!
+//! このモジュールは、`SsoAdminClientOps`トレイトの本番実装を提供します。
+//! SSO Admin用のSDKクレートは導入していないため、AWS CLIを利用します。
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use super::cli::{field, optional_field, string_array, AwsCliRunner};
+use super::sso_admin_client_trait::{
+    SsoAccountAssignmentInfo, SsoAdminClientOps, SsoInstanceInfo, SsoManagedPolicyInfo,
+    SsoPermissionSetInfo,
+};
+
+/// AWS CLIをラップしたIAM Identity Centerクライアントの本番実装
+pub struct RealSsoAdminClient {
+    cli: AwsCliRunner,
+}
+
+impl RealSsoAdminClient {
+    pub fn new(cli: AwsCliRunner) -> Self {
+        Self { cli }
+    }
+}
+
+#[async_trait]
+impl SsoAdminClientOps for RealSsoAdminClient {
+    async fn list_instances(&self) -> Result<Vec<SsoInstanceInfo>> {
+        // AWS CLIはJSON出力時に全ページを自動で取得する
+        let output = self
+            .cli
+            .run_json(&["sso-admin", "list-instances"])
+            .await
+            .map_err(|e| anyhow!("Failed to list SSO instances: {}", e))?;
+
+        Ok(parse_instances(&output))
+    }
+
+    async fn list_permission_sets(&self, instance_arn: &str) -> Result<Vec<String>> {
+        let output = self
+            .cli
+            .run_json(&[
+                "sso-admin",
+                "list-permission-sets",
+                "--instance-arn",
+                instance_arn,
+            ])
+            .await
+            .map_err(|e| anyhow!("Failed to list permission sets: {}", e))?;
+
+        Ok(string_array(&output, "PermissionSets"))
+    }
+
+    async fn describe_permission_set(
+        &self,
+        instance_arn: &str,
+        permission_set_arn: &str,
+    ) -> Result<SsoPermissionSetInfo> {
+        let output = self
+            .cli
+            .run_json(&[
+                "sso-admin",
+                "describe-permission-set",
+                "--instance-arn",
+                instance_arn,
+                "--permission-set-arn",
+                permission_set_arn,
+            ])
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to describe permission set {}: {}",
+                    permission_set_arn,
+                    e
+                )
+            })?;
+        let mut permission_set = parse_permission_set(&output).ok_or_else(|| {
+            anyhow!(
+                "DescribePermissionSet response for {} has no permission set",
+                permission_set_arn
+            )
+        })?;
+
+        let tags = self
+            .cli
+            .run_json(&[
+                "sso-admin",
+                "list-tags-for-resource",
+                "--instance-arn",
+                instance_arn,
+                "--resource-arn",
+                permission_set_arn,
+            ])
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to list tags for permission set {}: {}",
+                    permission_set_arn,
+                    e
+                )
+            })?;
+        permission_set.tags = parse_tags(&tags);
+
+        Ok(permission_set)
+    }
+
+    async fn get_inline_policy_for_permission_set(
+        &self,
+        instance_arn: &str,
+        permission_set_arn: &str,
+    ) -> Result<Option<String>> {
+        let output = self
+            .cli
+            .run_json(&[
+                "sso-admin",
+                "get-inline-policy-for-permission-set",
+                "--instance-arn",
+                instance_arn,
+                "--permission-set-arn",
+                permission_set_arn,
+            ])
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to get inline policy for permission set {}: {}",
+                    permission_set_arn,
+                    e
+                )
+            })?;
+
+        // インラインポリシーが未設定の場合は空文字列が返る
+        Ok(output
+            .get("InlinePolicy")
+            .and_then(|v| v.as_str())
+            .filter(|policy| !policy.trim().is_empty())
+            .map(|policy| policy.to_string()))
+    }
+
+    async fn list_managed_policies_in_permission_set(
+        &self,
+        instance_arn: &str,
+        permission_set_arn: &str,
+    ) -> Result<Vec<SsoManagedPolicyInfo>> {
+        let output = self
+            .cli
+            .run_json(&[
+                "sso-admin",
+                "list-managed-policies-in-permission-set",
+                "--instance-arn",
+                instance_arn,
+                "--permission-set-arn",
+                permission_set_arn,
+            ])
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to list managed policies for permission set {}: {}",
+                    permission_set_arn,
+                    e
+                )
+            })?;
+
+        Ok(parse_managed_policies(&output))
+    }
+
+    async fn list_accounts_for_provisioned_permission_set(
+        &self,
+        instance_arn: &str,
+        permission_set_arn: &str,
+    ) -> Result<Vec<String>> {
+        let output = self
+            .cli
+            .run_json(&[
+                "sso-admin",
+                "list-accounts-for-provisioned-permission-set",
+                "--instance-arn",
+                instance_arn,
+                "--permission-set-arn",
+                permission_set_arn,
+            ])
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to list accounts for permission set {}: {}",
+                    permission_set_arn,
+                    e
+                )
+            })?;
+
+        Ok(string_array(&output, "AccountIds"))
+    }
+
+    async fn list_account_assignments(
+        &self,
+        instance_arn: &str,
+        account_id: &str,
+        permission_set_arn: &str,
+    ) -> Result<Vec<SsoAccountAssignmentInfo>> {
+        let output = self
+            .cli
+            .run_json(&[
+                "sso-admin",
+                "list-account-assignments",
+                "--instance-arn",
+                instance_arn,
+                "--account-id",
+                account_id,
+                "--permission-set-arn",
+                permission_set_arn,
+            ])
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to list account assignments for {} in {}: {}",
+                    permission_set_arn,
+                    account_id,
+                    e
+                )
+            })?;
+
+        Ok(parse_account_assignments(&output))
+    }
+}
+
+/// `list-instances`の出力をインスタンス情報に変換
+fn parse_instances(output: &Value) -> Vec<SsoInstanceInfo> {
+    output
+        .get("Instances")
+        .and_then(|v| v.as_array())
+        .map(|instances| {
+            instances
+                .iter()
+                .filter_map(|instance| {
+                    let instance_arn = field(instance, "InstanceArn");
+                    if instance_arn.is_empty() {
+                        return None;
+                    }
+                    Some(SsoInstanceInfo {
+                        instance_arn,
+                        identity_store_id: field(instance, "IdentityStoreId"),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `describe-permission-set`の出力を許可セット情報に変換（タグは別途取得）
+fn parse_permission_set(output: &Value) -> Option<SsoPermissionSetInfo> {
+    let permission_set = output.get("PermissionSet")?;
+    let permission_set_arn = field(permission_set, "PermissionSetArn");
+    if permission_set_arn.is_empty() {
+        return None;
+    }
+    Some(SsoPermissionSetInfo {
+        permission_set_arn,
+        name: field(permission_set, "Name"),
+        description: optional_field(permission_set, "Description"),
+        session_duration: optional_field(permission_set, "SessionDuration"),
+        relay_state: optional_field(permission_set, "RelayState"),
+        tags: HashMap::new(),
+    })
+}
+
+/// `list-tags-for-resource`の出力をタグのマップに変換
+fn parse_tags(output: &Value) -> HashMap<String, String> {
+    output
+        .get("Tags")
+        .and_then(|v| v.as_array())
+        .map(|tags| {
+            tags.iter()
+                .map(|tag| (field(tag, "Key"), field(tag, "Value")))
+                .filter(|(key, _)| !key.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `list-managed-policies-in-permission-set`の出力をポリシー情報に変換
+fn parse_managed_policies(output: &Value) -> Vec<SsoManagedPolicyInfo> {
+    output
+        .get("AttachedManagedPolicies")
+        .and_then(|v| v.as_array())
+        .map(|policies| {
+            policies
+                .iter()
+                .filter_map(|policy| {
+                    let arn = field(policy, "Arn");
+                    if arn.is_empty() {
+                        return None;
+                    }
+                    Some(SsoManagedPolicyInfo {
+                        name: field(policy, "Name"),
+                        arn,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `list-account-assignments`の出力をアカウント割り当て情報に変換
+fn parse_account_assignments(output: &Value) -> Vec<SsoAccountAssignmentInfo> {
+    output
+        .get("AccountAssignments")
+        .and_then(|v| v.as_array())
+        .map(|assignments| {
+            assignments
+                .iter()
+                .filter_map(|assignment| {
+                    let principal_id = field(assignment, "PrincipalId");
+                    if principal_id.is_empty() {
+                        return None;
+                    }
+                    Some(SsoAccountAssignmentInfo {
+                        account_id: field(assignment, "AccountId"),
+                        permission_set_arn: field(assignment, "PermissionSetArn"),
+                        principal_type: field(assignment, "PrincipalType"),
+                        principal_id,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_instances() {
+        let output = json!({
+            "Instances": [
+                {"InstanceArn": "arn:aws:sso:::instance/ssoins-1111", "IdentityStoreId": "d-1111"},
+                {"IdentityStoreId": "d-broken"}
+            ]
+        });
+
+        let instances = parse_instances(&output);
+        assert_eq!(instances.len(), 1);
+        assert_eq!(
+            instances[0].instance_arn,
+            "arn:aws:sso:::instance/ssoins-1111"
+        );
+        assert_eq!(instances[0].identity_store_id, "d-1111");
+    }
+
+    #[test]
+    fn test_parse_permission_set() {
+        let output = json!({
+            "PermissionSet": {
+                "Name": "AdministratorAccess",
+                "PermissionSetArn": "arn:aws:sso:::permissionSet/ssoins-1111/ps-aaaa",
+                "Description": "",
+                "SessionDuration": "PT4H"
+            }
+        });
+
+        let permission_set = parse_permission_set(&output).unwrap();
+        assert_eq!(permission_set.name, "AdministratorAccess");
+        assert_eq!(permission_set.description, None);
+        assert_eq!(permission_set.session_duration.as_deref(), Some("PT4H"));
+        assert_eq!(permission_set.relay_state, None);
+        assert!(parse_permission_set(&Value::Null).is_none());
+    }
+
+    #[test]
+    fn test_parse_tags_and_managed_policies() {
+        let tags = parse_tags(&json!({"Tags": [{"Key": "team", "Value": "platform"}]}));
+        assert_eq!(tags.get("team").map(String::as_str), Some("platform"));
+
+        let policies = parse_managed_policies(&json!({
+            "AttachedManagedPolicies": [
+                {"Name": "ReadOnlyAccess", "Arn": "arn:aws:iam::aws:policy/ReadOnlyAccess"}
+            ]
+        }));
+        assert_eq!(policies.len(), 1);
+        assert_eq!(policies[0].name, "ReadOnlyAccess");
+    }
+
+    #[test]
+    fn test_parse_account_assignments() {
+        let output = json!({
+            "AccountAssignments": [
+                {
+                    "AccountId": "111111111111",
+                    "PermissionSetArn": "arn:aws:sso:::permissionSet/ssoins-1111/ps-aaaa",
+                    "PrincipalType": "GROUP",
+                    "PrincipalId": "90676a1f-aaaa-bbbb-cccc-000000000000"
+                }
+            ]
+        });
+
+        let assignments = parse_account_assignments(&output);
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(assignments[0].account_id, "111111111111");
+        assert_eq!(assignments[0].principal_type, "GROUP");
+        assert!(parse_account_assignments(&Value::Null).is_empty());
+    }
+}
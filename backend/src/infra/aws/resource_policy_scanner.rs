@@ -0,0 +1,485 @@
+//! AWSリソースベースポリシースキャナー
+//!
+//! S3バケットポリシー、KMSキーポリシー、SQSキューポリシー、SNSトピックポリシーを
+//! スキャンし、Terraform生成用のデータ構造に変換します。
+//!
+//! サービスごとに必要な権限が異なるため、API呼び出しの失敗はスキャン全体を
+//! 失敗させず、スキャン警告として記録します。
+
+use anyhow::{anyhow, Result};
+use aws_config::SdkConfig;
+use futures::stream::{self, StreamExt};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+use crate::infra::aws::cli::AwsCliRunner;
+use crate::infra::aws::kms_client_trait::KmsClientOps;
+use crate::infra::aws::real_kms_client::RealKmsClient;
+use crate::infra::aws::real_s3_client::RealS3Client;
+use crate::infra::aws::real_sns_client::RealSnsClient;
+use crate::infra::aws::real_sqs_client::RealSqsClient;
+use crate::infra::aws::retry::with_throttling_retry;
+use crate::infra::aws::s3_client_trait::S3ClientOps;
+use crate::infra::aws::scanner::scan_warning;
+use crate::infra::aws::sns_client_trait::SnsClientOps;
+use crate::infra::aws::sqs_client_trait::SqsClientOps;
+use crate::models::{ScanConfig, ScanWarning};
+
+/// AWS管理キーの`KeyManager`（キーポリシーを変更できないため生成対象外）
+const KEY_MANAGER_AWS: &str = "AWS";
+
+/// 削除待ちキーの`KeyState`
+const KEY_STATE_PENDING_DELETION: &str = "PendingDeletion";
+
+/// リソースベースポリシーのスキャン結果
+#[derive(Debug, Default)]
+pub struct ResourcePolicyResources {
+    pub s3_bucket_policies: Vec<Value>,
+    pub kms_key_policies: Vec<Value>,
+    pub sqs_queue_policies: Vec<Value>,
+    pub sns_topic_policies: Vec<Value>,
+}
+
+impl ResourcePolicyResources {
+    /// 全リソースの件数
+    pub fn count(&self) -> usize {
+        self.s3_bucket_policies.len()
+            + self.kms_key_policies.len()
+            + self.sqs_queue_policies.len()
+            + self.sns_topic_policies.len()
+    }
+}
+
+/// リソース1件分のスキャン結果
+#[derive(Default)]
+struct PolicyResult {
+    resource: Option<Value>,
+    warning: Option<ScanWarning>,
+}
+
+impl PolicyResult {
+    fn failed(resource_type: &str, name: &str, operation: &str, error: &anyhow::Error) -> Self {
+        Self {
+            resource: None,
+            warning: Some(scan_warning(resource_type, name, operation, error)),
+        }
+    }
+}
+
+/// AWSリソースベースポリシースキャナー
+///
+/// 各サービスのクライアントを抽象化することで、テスト時にモックを注入可能にしています。
+pub struct AwsResourcePolicyScanner {
+    s3: Arc<dyn S3ClientOps>,
+    kms: Arc<dyn KmsClientOps>,
+    sqs: Arc<dyn SqsClientOps>,
+    sns: Arc<dyn SnsClientOps>,
+    /// リソース単位の同時取得数
+    concurrency: usize,
+}
+
+impl AwsResourcePolicyScanner {
+    /// 本番用のスキャナーを作成（AWS CLI経由で各サービスのAPIを呼び出す）
+    pub async fn from_sdk_config(sdk_config: &SdkConfig, config: &ScanConfig) -> Result<Self> {
+        let cli = AwsCliRunner::new(sdk_config);
+        Ok(Self::new_with_clients(
+            Arc::new(RealS3Client::new(cli.clone())),
+            Arc::new(RealKmsClient::new(cli.clone())),
+            Arc::new(RealSqsClient::new(cli.clone())),
+            Arc::new(RealSnsClient::new(cli)),
+            config.max_concurrency(),
+        ))
+    }
+
+    pub fn new_with_clients(
+        s3: Arc<dyn S3ClientOps>,
+        kms: Arc<dyn KmsClientOps>,
+        sqs: Arc<dyn SqsClientOps>,
+        sns: Arc<dyn SnsClientOps>,
+        concurrency: usize,
+    ) -> Self {
+        Self {
+            s3,
+            kms,
+            sqs,
+            sns,
+            concurrency: concurrency.max(1),
+        }
+    }
+
+    /// 全サービスのリソースベースポリシーをスキャン
+    ///
+    /// 取得に失敗したサービス・リソースは`warnings`に記録してスキップします。
+    pub async fn scan(&self, warnings: &mut Vec<ScanWarning>) -> ResourcePolicyResources {
+        ResourcePolicyResources {
+            s3_bucket_policies: self.scan_s3_bucket_policies(warnings).await,
+            kms_key_policies: self.scan_kms_key_policies(warnings).await,
+            sqs_queue_policies: self.scan_sqs_queue_policies(warnings).await,
+            sns_topic_policies: self.scan_sns_topic_policies(warnings).await,
+        }
+    }
+
+    async fn scan_s3_bucket_policies(&self, warnings: &mut Vec<ScanWarning>) -> Vec<Value> {
+        let buckets = match with_throttling_retry("ListBuckets", || self.s3.list_buckets()).await {
+            Ok(buckets) => buckets,
+            Err(e) => {
+                warn!(error = %e, "S3バケット一覧の取得に失敗");
+                warnings.push(scan_warning("s3_bucket", "", "ListBuckets", &e));
+                return Vec::new();
+            }
+        };
+        debug!(count = buckets.len(), "S3バケットポリシーのスキャンを開始");
+
+        let results = stream::iter(buckets)
+            .map(|bucket| self.scan_bucket_policy(bucket))
+            .buffered(self.concurrency);
+        Self::collect(results, warnings).await
+    }
+
+    async fn scan_bucket_policy(&self, bucket: String) -> PolicyResult {
+        let operation = "GetBucketPolicy";
+        match with_throttling_retry(operation, || self.s3.get_bucket_policy(&bucket)).await {
+            Ok(Some(document)) => match parse_policy(&document) {
+                Ok(policy_document) => PolicyResult {
+                    resource: Some(json!({
+                        "bucket": bucket,
+                        "policy_document": policy_document,
+                    })),
+                    warning: None,
+                },
+                Err(e) => PolicyResult::failed("s3_bucket", &bucket, operation, &e),
+            },
+            Ok(None) => PolicyResult::default(),
+            Err(e) => {
+                warn!(bucket = %bucket, error = %e, "バケットポリシーの取得に失敗");
+                PolicyResult::failed("s3_bucket", &bucket, operation, &e)
+            }
+        }
+    }
+
+    async fn scan_kms_key_policies(&self, warnings: &mut Vec<ScanWarning>) -> Vec<Value> {
+        let key_ids = match with_throttling_retry("ListKeys", || self.kms.list_keys()).await {
+            Ok(key_ids) => key_ids,
+            Err(e) => {
+                warn!(error = %e, "KMSキー一覧の取得に失敗");
+                warnings.push(scan_warning("kms_key", "", "ListKeys", &e));
+                return Vec::new();
+            }
+        };
+        debug!(count = key_ids.len(), "KMSキーポリシーのスキャンを開始");
+
+        let results = stream::iter(key_ids)
+            .map(|key_id| self.scan_key_policy(key_id))
+            .buffered(self.concurrency);
+        Self::collect(results, warnings).await
+    }
+
+    async fn scan_key_policy(&self, key_id: String) -> PolicyResult {
+        let key =
+            match with_throttling_retry("DescribeKey", || self.kms.describe_key(&key_id)).await {
+                Ok(key) => key,
+                Err(e) => {
+                    warn!(key_id = %key_id, error = %e, "KMSキーの詳細の取得に失敗");
+                    return PolicyResult::failed("kms_key", &key_id, "DescribeKey", &e);
+                }
+            };
+        // AWS管理キー・削除待ちキーのポリシーはTerraformで管理できない
+        if key.key_manager == KEY_MANAGER_AWS || key.key_state == KEY_STATE_PENDING_DELETION {
+            return PolicyResult::default();
+        }
+
+        let operation = "GetKeyPolicy";
+        let policy_document =
+            match with_throttling_retry(operation, || self.kms.get_key_policy(&key_id))
+                .await
+                .and_then(|document| parse_policy(&document))
+            {
+                Ok(policy_document) => policy_document,
+                Err(e) => {
+                    warn!(key_id = %key_id, error = %e, "キーポリシーの取得に失敗");
+                    return PolicyResult::failed("kms_key", &key_id, operation, &e);
+                }
+            };
+
+        let mut resource = json!({
+            "key_id": key.key_id,
+            "key_arn": key.key_arn,
+            "policy_document": policy_document,
+        });
+        if let Some(description) = &key.description {
+            resource["description"] = json!(description);
+        }
+        PolicyResult {
+            resource: Some(resource),
+            warning: None,
+        }
+    }
+
+    async fn scan_sqs_queue_policies(&self, warnings: &mut Vec<ScanWarning>) -> Vec<Value> {
+        let queue_urls = match with_throttling_retry("ListQueues", || self.sqs.list_queues()).await
+        {
+            Ok(queue_urls) => queue_urls,
+            Err(e) => {
+                warn!(error = %e, "SQSキュー一覧の取得に失敗");
+                warnings.push(scan_warning("sqs_queue", "", "ListQueues", &e));
+                return Vec::new();
+            }
+        };
+        debug!(
+            count = queue_urls.len(),
+            "SQSキューポリシーのスキャンを開始"
+        );
+
+        let results = stream::iter(queue_urls)
+            .map(|queue_url| self.scan_queue_policy(queue_url))
+            .buffered(self.concurrency);
+        Self::collect(results, warnings).await
+    }
+
+    async fn scan_queue_policy(&self, queue_url: String) -> PolicyResult {
+        let operation = "GetQueueAttributes";
+        let queue =
+            match with_throttling_retry(operation, || self.sqs.get_queue_attributes(&queue_url))
+                .await
+            {
+                Ok(queue) => queue,
+                Err(e) => {
+                    warn!(queue_url = %queue_url, error = %e, "キュー属性の取得に失敗");
+                    return PolicyResult::failed("sqs_queue", &queue_url, operation, &e);
+                }
+            };
+        let Some(document) = queue.policy else {
+            return PolicyResult::default();
+        };
+
+        match parse_policy(&document) {
+            Ok(policy_document) => PolicyResult {
+                resource: Some(json!({
+                    "queue_url": queue.queue_url,
+                    "queue_arn": queue.queue_arn,
+                    "queue_name": last_segment(&queue.queue_url, '/'),
+                    "policy_document": policy_document,
+                })),
+                warning: None,
+            },
+            Err(e) => PolicyResult::failed("sqs_queue", &queue_url, operation, &e),
+        }
+    }
+
+    async fn scan_sns_topic_policies(&self, warnings: &mut Vec<ScanWarning>) -> Vec<Value> {
+        let topic_arns = match with_throttling_retry("ListTopics", || self.sns.list_topics()).await
+        {
+            Ok(topic_arns) => topic_arns,
+            Err(e) => {
+                warn!(error = %e, "SNSトピック一覧の取得に失敗");
+                warnings.push(scan_warning("sns_topic", "", "ListTopics", &e));
+                return Vec::new();
+            }
+        };
+        debug!(
+            count = topic_arns.len(),
+            "SNSトピックポリシーのスキャンを開始"
+        );
+
+        let results = stream::iter(topic_arns)
+            .map(|topic_arn| self.scan_topic_policy(topic_arn))
+            .buffered(self.concurrency);
+        Self::collect(results, warnings).await
+    }
+
+    async fn scan_topic_policy(&self, topic_arn: String) -> PolicyResult {
+        let operation = "GetTopicAttributes";
+        match with_throttling_retry(operation, || self.sns.get_topic_policy(&topic_arn)).await {
+            Ok(Some(document)) => match parse_policy(&document) {
+                Ok(policy_document) => PolicyResult {
+                    resource: Some(json!({
+                        "topic_arn": topic_arn,
+                        "topic_name": last_segment(&topic_arn, ':'),
+                        "policy_document": policy_document,
+                    })),
+                    warning: None,
+                },
+                Err(e) => PolicyResult::failed("sns_topic", &topic_arn, operation, &e),
+            },
+            Ok(None) => PolicyResult::default(),
+            Err(e) => {
+                warn!(topic_arn = %topic_arn, error = %e, "トピック属性の取得に失敗");
+                PolicyResult::failed("sns_topic", &topic_arn, operation, &e)
+            }
+        }
+    }
+
+    /// リソース単位の結果を列挙順に集約し、警告を`warnings`に移す
+    async fn collect(
+        mut results: impl stream::Stream<Item = PolicyResult> + Unpin,
+        warnings: &mut Vec<ScanWarning>,
+    ) -> Vec<Value> {
+        let mut resources = Vec::new();
+        while let Some(result) = results.next().await {
+            resources.extend(result.resource);
+            warnings.extend(result.warning);
+        }
+        resources
+    }
+}
+
+/// ポリシー文書（JSON文字列）をパース
+fn parse_policy(document: &str) -> Result<Value> {
+    serde_json::from_str(document).map_err(|e| anyhow!("Failed to parse policy: {}", e))
+}
+
+/// 区切り文字で分割した最後の要素を取得（キューURL・トピックARNから名前を得る）
+fn last_segment(value: &str, separator: char) -> &str {
+    value.rsplit(separator).next().unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::aws::kms_client_trait::mock::MockKmsClient;
+    use crate::infra::aws::kms_client_trait::KmsKeyInfo;
+    use crate::infra::aws::s3_client_trait::mock::MockS3Client;
+    use crate::infra::aws::sns_client_trait::mock::MockSnsClient;
+    use crate::infra::aws::sqs_client_trait::mock::MockSqsClient;
+    use crate::infra::aws::sqs_client_trait::SqsQueueInfo;
+
+    const BUCKET_POLICY: &str = r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow","Principal":{"AWS":"arn:aws:iam::111111111111:role/app"},"Action":"s3:GetObject","Resource":"arn:aws:s3:::app-assets/*"}]}"#;
+
+    fn key(key_id: &str, key_manager: &str, key_state: &str) -> KmsKeyInfo {
+        KmsKeyInfo {
+            key_id: key_id.to_string(),
+            key_arn: format!("arn:aws:kms:us-east-1:111111111111:key/{}", key_id),
+            description: Some(format!("{} key", key_id)),
+            key_manager: key_manager.to_string(),
+            key_state: key_state.to_string(),
+        }
+    }
+
+    fn empty_sqs() -> MockSqsClient {
+        let mut sqs = MockSqsClient::new();
+        sqs.expect_list_queues().returning(|| Ok(Vec::new()));
+        sqs
+    }
+
+    fn empty_sns() -> MockSnsClient {
+        let mut sns = MockSnsClient::new();
+        sns.expect_list_topics().returning(|| Ok(Vec::new()));
+        sns
+    }
+
+    #[tokio::test]
+    async fn test_scan_resource_policies() {
+        let mut s3 = MockS3Client::new();
+        s3.expect_list_buckets()
+            .returning(|| Ok(vec!["app-assets".to_string(), "no-policy".to_string()]));
+        s3.expect_get_bucket_policy()
+            .returning(|bucket| match bucket {
+                "app-assets" => Ok(Some(BUCKET_POLICY.to_string())),
+                _ => Ok(None),
+            });
+
+        let mut kms = MockKmsClient::new();
+        kms.expect_list_keys().returning(|| {
+            Ok(vec![
+                "customer".to_string(),
+                "aws-managed".to_string(),
+                "deleting".to_string(),
+            ])
+        });
+        kms.expect_describe_key().returning(|key_id| match key_id {
+            "customer" => Ok(key("customer", "CUSTOMER", "Enabled")),
+            "aws-managed" => Ok(key("aws-managed", "AWS", "Enabled")),
+            _ => Ok(key("deleting", "CUSTOMER", "PendingDeletion")),
+        });
+        kms.expect_get_key_policy()
+            .times(1)
+            .returning(|_| Ok(BUCKET_POLICY.to_string()));
+
+        let mut sqs = MockSqsClient::new();
+        sqs.expect_list_queues().returning(|| {
+            Ok(vec![
+                "https://sqs.us-east-1.amazonaws.com/111111111111/jobs".to_string(),
+            ])
+        });
+        sqs.expect_get_queue_attributes().returning(|url| {
+            Ok(SqsQueueInfo {
+                queue_url: url.to_string(),
+                queue_arn: "arn:aws:sqs:us-east-1:111111111111:jobs".to_string(),
+                policy: Some(BUCKET_POLICY.to_string()),
+            })
+        });
+
+        let mut sns = MockSnsClient::new();
+        sns.expect_list_topics()
+            .returning(|| Ok(vec!["arn:aws:sns:us-east-1:111111111111:alerts".to_string()]));
+        sns.expect_get_topic_policy()
+            .returning(|_| Ok(Some(BUCKET_POLICY.to_string())));
+
+        let scanner = AwsResourcePolicyScanner::new_with_clients(
+            Arc::new(s3),
+            Arc::new(kms),
+            Arc::new(sqs),
+            Arc::new(sns),
+            4,
+        );
+        let mut warnings = Vec::new();
+
+        let resources = scanner.scan(&mut warnings).await;
+
+        assert!(warnings.is_empty());
+        assert_eq!(resources.s3_bucket_policies.len(), 1);
+        assert_eq!(resources.s3_bucket_policies[0]["bucket"], "app-assets");
+        assert_eq!(
+            resources.s3_bucket_policies[0]["policy_document"]["Statement"][0]["Principal"]["AWS"],
+            "arn:aws:iam::111111111111:role/app"
+        );
+
+        // AWS管理キー・削除待ちキーは対象外
+        assert_eq!(resources.kms_key_policies.len(), 1);
+        assert_eq!(resources.kms_key_policies[0]["key_id"], "customer");
+        assert_eq!(resources.kms_key_policies[0]["description"], "customer key");
+
+        assert_eq!(resources.sqs_queue_policies[0]["queue_name"], "jobs");
+        assert_eq!(
+            resources.sqs_queue_policies[0]["queue_arn"],
+            "arn:aws:sqs:us-east-1:111111111111:jobs"
+        );
+        assert_eq!(resources.sns_topic_policies[0]["topic_name"], "alerts");
+        assert_eq!(resources.count(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_scan_records_warnings_per_service() {
+        let mut s3 = MockS3Client::new();
+        s3.expect_list_buckets()
+            .returning(|| Err(anyhow!("AccessDenied: not authorized")));
+        let mut kms = MockKmsClient::new();
+        kms.expect_list_keys()
+            .returning(|| Ok(vec!["customer".to_string()]));
+        kms.expect_describe_key()
+            .returning(|_| Ok(key("customer", "CUSTOMER", "Enabled")));
+        kms.expect_get_key_policy()
+            .returning(|_| Ok("not json".to_string()));
+
+        let scanner = AwsResourcePolicyScanner::new_with_clients(
+            Arc::new(s3),
+            Arc::new(kms),
+            Arc::new(empty_sqs()),
+            Arc::new(empty_sns()),
+            1,
+        );
+        let mut warnings = Vec::new();
+
+        let resources = scanner.scan(&mut warnings).await;
+
+        assert_eq!(resources.count(), 0);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].resource_type, "s3_bucket");
+        assert_eq!(warnings[0].operation, "ListBuckets");
+        assert_eq!(warnings[0].error_kind, "access_denied");
+        assert_eq!(warnings[1].resource_type, "kms_key");
+        assert_eq!(warnings[1].resource_name, "customer");
+        assert_eq!(warnings[1].operation, "GetKeyPolicy");
+    }
+}
@@ -0,0 +1,128 @@
+//! AWS API呼び出しのリトライ処理
+//!
+//! IAM等のAPIはアカウント単位でレート制限されるため、並列にスキャンすると
+//! `Throttling`エラーが返ることがあります。このモジュールは、
+//! スロットリングエラーのみを指数バックオフでリトライするヘルパーを提供します。
+
+use anyhow::Result;
+use std::future::Future;
+use std::time::Duration;
+use tracing::warn;
+
+/// 最大リトライ回数（初回呼び出しを除く）
+const MAX_RETRIES: u32 = 5;
+
+/// バックオフの初期待機時間
+const BASE_DELAY: Duration = Duration::from_millis(100);
+
+/// バックオフの最大待機時間
+const MAX_DELAY: Duration = Duration::from_secs(10);
+
+/// スロットリング（レート制限）によるエラーかどうかを判定
+pub fn is_throttling_error(error: &anyhow::Error) -> bool {
+    let message = format!("{:#}", error);
+    ["Throttling", "Rate exceeded", "TooManyRequests"]
+        .iter()
+        .any(|pattern| message.contains(pattern))
+}
+
+/// `attempt`回目のリトライ前の待機時間（指数バックオフ）
+fn backoff_delay(attempt: u32) -> Duration {
+    BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_DELAY)
+}
+
+/// スロットリングエラーの場合のみ指数バックオフでリトライ
+///
+/// スロットリング以外のエラー、または最大リトライ回数を超えた場合はそのままエラーを返します。
+pub async fn with_throttling_retry<T, F, Fut>(operation: &str, mut call: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        match call().await {
+            Err(e) if attempt < MAX_RETRIES && is_throttling_error(&e) => {
+                let delay = backoff_delay(attempt);
+                warn!(
+                    operation,
+                    attempt = attempt + 1,
+                    delay_ms = delay.as_millis() as u64,
+                    "スロットリングのためリトライします"
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn test_is_throttling_error() {
+        assert!(is_throttling_error(&anyhow!(
+            "Failed to list role policies: Throttling: Rate exceeded"
+        )));
+        assert!(!is_throttling_error(&anyhow!(
+            "Failed to list role policies: AccessDenied"
+        )));
+    }
+
+    #[test]
+    fn test_backoff_delay_is_exponential_and_capped() {
+        assert_eq!(backoff_delay(0), Duration::from_millis(100));
+        assert_eq!(backoff_delay(1), Duration::from_millis(200));
+        assert_eq!(backoff_delay(3), Duration::from_millis(800));
+        assert_eq!(backoff_delay(20), MAX_DELAY);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_with_throttling_retry_retries_until_success() {
+        let calls = AtomicU32::new(0);
+        let result = with_throttling_retry("test", || async {
+            if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+                Err(anyhow!("Throttling: Rate exceeded"))
+            } else {
+                Ok("done")
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), "done");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_with_throttling_retry_does_not_retry_other_errors() {
+        let calls = AtomicU32::new(0);
+        let result: Result<()> = with_throttling_retry("test", || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(anyhow!("AccessDenied"))
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_with_throttling_retry_gives_up_after_max_retries() {
+        let calls = AtomicU32::new(0);
+        let result: Result<()> = with_throttling_retry("test", || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(anyhow!("Throttling: Rate exceeded"))
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), MAX_RETRIES + 1);
+    }
+}
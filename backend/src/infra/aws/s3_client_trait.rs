@@ -0,0 +1,92 @@
+//! S3クライアント操作の抽象化トレイト
+//!
+//! このモジュールは、S3の操作を抽象化し、
+//! テスト時にモック実装を注入できるようにします。
+
+use anyhow::Result;
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// バケットのデフォルト暗号化設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BucketEncryptionInfo {
+    pub sse_algorithm: String,
+    /// KMSキーID（SSE-KMSでキーを指定している場合のみ）
+    pub kms_master_key_id: Option<String>,
+    pub bucket_key_enabled: bool,
+}
+
+/// バケットのパブリックアクセスブロック設定
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicAccessBlockInfo {
+    pub block_public_acls: bool,
+    pub block_public_policy: bool,
+    pub ignore_public_acls: bool,
+    pub restrict_public_buckets: bool,
+}
+
+/// S3クライアント操作を抽象化するトレイト
+///
+/// バケット設定の取得メソッドは、設定が存在しない場合にエラーではなくNone（空）を返します。
+#[async_trait]
+pub trait S3ClientOps: Send + Sync {
+    /// バケット名の一覧を取得
+    async fn list_buckets(&self) -> Result<Vec<String>>;
+
+    /// バケットポリシーを取得（未設定の場合はNone）
+    async fn get_bucket_policy(&self, bucket: &str) -> Result<Option<String>>;
+
+    /// バケットのリージョンを取得
+    async fn get_bucket_location(&self, bucket: &str) -> Result<String>;
+
+    /// バケットのタグを取得
+    async fn get_bucket_tagging(&self, bucket: &str) -> Result<HashMap<String, String>>;
+
+    /// バージョニングの状態（"Enabled" | "Suspended"）を取得（一度も有効化していない場合はNone）
+    async fn get_bucket_versioning(&self, bucket: &str) -> Result<Option<String>>;
+
+    /// デフォルト暗号化設定を取得
+    async fn get_bucket_encryption(&self, bucket: &str) -> Result<Option<BucketEncryptionInfo>>;
+
+    /// ライフサイクルルールを取得（キーをスネークケースに変換したJSON）
+    async fn get_bucket_lifecycle_configuration(&self, bucket: &str) -> Result<Option<Vec<Value>>>;
+
+    /// パブリックアクセスブロック設定を取得
+    async fn get_public_access_block(&self, bucket: &str) -> Result<Option<PublicAccessBlockInfo>>;
+
+    /// オブジェクト所有者の設定（"BucketOwnerEnforced"等）を取得
+    async fn get_bucket_ownership_controls(&self, bucket: &str) -> Result<Option<String>>;
+}
+
+#[cfg(test)]
+pub mod mock {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub S3Client {}
+
+        #[async_trait]
+        impl S3ClientOps for S3Client {
+            async fn list_buckets(&self) -> Result<Vec<String>>;
+            async fn get_bucket_policy(&self, bucket: &str) -> Result<Option<String>>;
+            async fn get_bucket_location(&self, bucket: &str) -> Result<String>;
+            async fn get_bucket_tagging(&self, bucket: &str) -> Result<HashMap<String, String>>;
+            async fn get_bucket_versioning(&self, bucket: &str) -> Result<Option<String>>;
+            async fn get_bucket_encryption(
+                &self,
+                bucket: &str,
+            ) -> Result<Option<BucketEncryptionInfo>>;
+            async fn get_bucket_lifecycle_configuration(
+                &self,
+                bucket: &str,
+            ) -> Result<Option<Vec<Value>>>;
+            async fn get_public_access_block(
+                &self,
+                bucket: &str,
+            ) -> Result<Option<PublicAccessBlockInfo>>;
+            async fn get_bucket_ownership_controls(&self, bucket: &str) -> Result<Option<String>>;
+        }
+    }
+}
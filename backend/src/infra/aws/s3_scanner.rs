@@ -0,0 +1,320 @@
+//! AWS S3バケットスキャナー
+//!
+//! S3バケットと、AWSプロバイダーv4以降で別リソースに分割された設定
+//! （バージョニング、暗号化、ライフサイクル、パブリックアクセスブロック、
+//! オブジェクト所有者）をスキャンし、Terraform生成用のデータ構造に変換します。
+//!
+//! バケットはスキャンを実行したリージョン以外にも存在するため、リージョンが異なる
+//! バケットにはTerraformのプロバイダーエイリアス名（`provider_alias`）を付与します。
+
+use anyhow::Result;
+use aws_config::SdkConfig;
+use futures::stream::{self, StreamExt};
+use serde_json::{json, Value};
+use std::future::Future;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+use crate::infra::aws::cli::AwsCliRunner;
+use crate::infra::aws::real_s3_client::RealS3Client;
+use crate::infra::aws::retry::with_throttling_retry;
+use crate::infra::aws::s3_client_trait::S3ClientOps;
+use crate::infra::aws::scanner::scan_warning;
+use crate::models::{ScanConfig, ScanWarning};
+
+/// バケット1件分のスキャン結果
+#[derive(Default)]
+struct BucketResult {
+    bucket: Option<Value>,
+    warnings: Vec<ScanWarning>,
+}
+
+/// AWS S3バケットスキャナー
+///
+/// S3クライアントを抽象化することで、テスト時にモックを注入可能にしています。
+pub struct AwsS3Scanner<S: S3ClientOps + ?Sized> {
+    client: Arc<S>,
+    /// スキャンを実行したリージョン（これと異なるリージョンのバケットにエイリアスを付与）
+    home_region: Option<String>,
+    /// バケット単位の同時取得数
+    concurrency: usize,
+}
+
+impl AwsS3Scanner<dyn S3ClientOps> {
+    /// 本番用のスキャナーを作成（AWS CLI経由でS3 APIを呼び出す）
+    pub async fn from_sdk_config(sdk_config: &SdkConfig, config: &ScanConfig) -> Result<Self> {
+        let cli = AwsCliRunner::new(sdk_config);
+        let home_region = cli.region().map(|region| region.to_string());
+        Ok(Self::new_with_client(
+            Arc::new(RealS3Client::new(cli)),
+            home_region,
+            config.max_concurrency(),
+        ))
+    }
+}
+
+impl<S: S3ClientOps + ?Sized> AwsS3Scanner<S> {
+    pub fn new_with_client(
+        client: Arc<S>,
+        home_region: Option<String>,
+        concurrency: usize,
+    ) -> Self {
+        Self {
+            client,
+            home_region,
+            concurrency: concurrency.max(1),
+        }
+    }
+
+    /// 全バケットとその設定をスキャン
+    ///
+    /// リージョンを取得できないバケットはスキップし、個別の設定の取得に失敗した場合は
+    /// その設定を含めずに出力します。いずれも`warnings`に記録します。
+    pub async fn scan(&self, warnings: &mut Vec<ScanWarning>) -> Vec<Value> {
+        let buckets =
+            match with_throttling_retry("ListBuckets", || self.client.list_buckets()).await {
+                Ok(buckets) => buckets,
+                Err(e) => {
+                    warn!(error = %e, "S3バケット一覧の取得に失敗");
+                    warnings.push(scan_warning("s3_bucket", "", "ListBuckets", &e));
+                    return Vec::new();
+                }
+            };
+        debug!(count = buckets.len(), "S3バケットのスキャンを開始");
+
+        // 同時実行数を制限して並列に取得（結果は列挙順で返る）
+        let mut results = stream::iter(buckets)
+            .map(|bucket| self.scan_bucket(bucket))
+            .buffered(self.concurrency);
+
+        let mut resources = Vec::new();
+        while let Some(result) = results.next().await {
+            resources.extend(result.bucket);
+            warnings.extend(result.warnings);
+        }
+        resources
+    }
+
+    /// バケット1件のリージョンと設定を取得
+    async fn scan_bucket(&self, bucket: String) -> BucketResult {
+        let name = bucket.as_str();
+        let mut result = BucketResult::default();
+        let warnings = &mut result.warnings;
+
+        let Some(region) = fetch(name, "GetBucketLocation", warnings, || {
+            self.client.get_bucket_location(name)
+        })
+        .await
+        else {
+            return result;
+        };
+
+        let mut bucket_json = json!({
+            "bucket": name,
+            "region": region,
+        });
+        if self
+            .home_region
+            .as_deref()
+            .is_some_and(|home_region| home_region != region)
+        {
+            bucket_json["provider_alias"] = json!(region.replace('-', "_"));
+        }
+
+        if let Some(tags) = fetch(name, "GetBucketTagging", warnings, || {
+            self.client.get_bucket_tagging(name)
+        })
+        .await
+        {
+            bucket_json["tags"] = json!(tags);
+        }
+        if let Some(Some(status)) = fetch(name, "GetBucketVersioning", warnings, || {
+            self.client.get_bucket_versioning(name)
+        })
+        .await
+        {
+            bucket_json["versioning_status"] = json!(status);
+        }
+        if let Some(Some(encryption)) = fetch(name, "GetBucketEncryption", warnings, || {
+            self.client.get_bucket_encryption(name)
+        })
+        .await
+        {
+            let mut encryption_json = json!({
+                "sse_algorithm": encryption.sse_algorithm,
+                "bucket_key_enabled": encryption.bucket_key_enabled,
+            });
+            if let Some(kms_master_key_id) = encryption.kms_master_key_id {
+                encryption_json["kms_master_key_id"] = json!(kms_master_key_id);
+            }
+            bucket_json["encryption"] = encryption_json;
+        }
+        if let Some(Some(rules)) = fetch(name, "GetBucketLifecycleConfiguration", warnings, || {
+            self.client.get_bucket_lifecycle_configuration(name)
+        })
+        .await
+        {
+            if !rules.is_empty() {
+                bucket_json["lifecycle_rules"] = Value::Array(rules);
+            }
+        }
+        if let Some(Some(block)) = fetch(name, "GetPublicAccessBlock", warnings, || {
+            self.client.get_public_access_block(name)
+        })
+        .await
+        {
+            bucket_json["public_access_block"] = json!({
+                "block_public_acls": block.block_public_acls,
+                "block_public_policy": block.block_public_policy,
+                "ignore_public_acls": block.ignore_public_acls,
+                "restrict_public_buckets": block.restrict_public_buckets,
+            });
+        }
+        if let Some(Some(object_ownership)) =
+            fetch(name, "GetBucketOwnershipControls", warnings, || {
+                self.client.get_bucket_ownership_controls(name)
+            })
+            .await
+        {
+            bucket_json["object_ownership"] = json!(object_ownership);
+        }
+
+        result.bucket = Some(bucket_json);
+        result
+    }
+}
+
+/// バケットの設定を1つ取得し、失敗した場合は警告を記録してNoneを返す
+async fn fetch<T, F, Fut>(
+    bucket: &str,
+    operation: &str,
+    warnings: &mut Vec<ScanWarning>,
+    call: F,
+) -> Option<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    match with_throttling_retry(operation, call).await {
+        Ok(value) => Some(value),
+        Err(e) => {
+            warn!(bucket, operation, error = %e, "S3バケット設定の取得に失敗");
+            warnings.push(scan_warning("s3_bucket", bucket, operation, &e));
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::aws::s3_client_trait::mock::MockS3Client;
+    use crate::infra::aws::s3_client_trait::{BucketEncryptionInfo, PublicAccessBlockInfo};
+    use anyhow::anyhow;
+    use std::collections::HashMap;
+
+    fn mock_buckets() -> MockS3Client {
+        let mut mock = MockS3Client::new();
+        mock.expect_list_buckets()
+            .returning(|| Ok(vec!["app-assets".to_string(), "eu-archive".to_string()]));
+        mock.expect_get_bucket_location()
+            .returning(|bucket| match bucket {
+                "eu-archive" => Ok("eu-west-1".to_string()),
+                _ => Ok("us-east-1".to_string()),
+            });
+        mock.expect_get_bucket_tagging()
+            .returning(|bucket| match bucket {
+                "app-assets" => Ok(HashMap::from([("env".to_string(), "prod".to_string())])),
+                _ => Ok(HashMap::new()),
+            });
+        mock.expect_get_bucket_versioning()
+            .returning(|bucket| match bucket {
+                "app-assets" => Ok(Some("Enabled".to_string())),
+                _ => Ok(None),
+            });
+        mock.expect_get_bucket_encryption().returning(|_| {
+            Ok(Some(BucketEncryptionInfo {
+                sse_algorithm: "AES256".to_string(),
+                kms_master_key_id: None,
+                bucket_key_enabled: false,
+            }))
+        });
+        mock.expect_get_bucket_lifecycle_configuration()
+            .returning(|bucket| match bucket {
+                "eu-archive" => Ok(Some(vec![json!({
+                    "id": "expire",
+                    "status": "Enabled",
+                    "filter": {},
+                    "expiration": {"days": 365}
+                })])),
+                _ => Ok(None),
+            });
+        mock.expect_get_public_access_block().returning(|_| {
+            Ok(Some(PublicAccessBlockInfo {
+                block_public_acls: true,
+                block_public_policy: true,
+                ignore_public_acls: true,
+                restrict_public_buckets: true,
+            }))
+        });
+        mock.expect_get_bucket_ownership_controls()
+            .returning(|bucket| match bucket {
+                "app-assets" => Err(anyhow!("AccessDenied: not authorized")),
+                _ => Ok(Some("BucketOwnerEnforced".to_string())),
+            });
+        mock
+    }
+
+    #[tokio::test]
+    async fn test_scan_buckets_with_configuration() {
+        let scanner = AwsS3Scanner::new_with_client(
+            Arc::new(mock_buckets()),
+            Some("us-east-1".to_string()),
+            2,
+        );
+        let mut warnings = Vec::new();
+
+        let buckets = scanner.scan(&mut warnings).await;
+
+        assert_eq!(buckets.len(), 2);
+        let assets = &buckets[0];
+        assert_eq!(assets["bucket"], "app-assets");
+        assert_eq!(assets["region"], "us-east-1");
+        assert!(assets.get("provider_alias").is_none());
+        assert_eq!(assets["tags"]["env"], "prod");
+        assert_eq!(assets["versioning_status"], "Enabled");
+        assert_eq!(assets["encryption"]["sse_algorithm"], "AES256");
+        assert!(assets.get("lifecycle_rules").is_none());
+        assert_eq!(assets["public_access_block"]["block_public_acls"], true);
+        // 取得に失敗した設定は含めず、警告として記録する
+        assert!(assets.get("object_ownership").is_none());
+
+        let archive = &buckets[1];
+        assert_eq!(archive["provider_alias"], "eu_west_1");
+        assert!(archive.get("versioning_status").is_none());
+        assert_eq!(archive["lifecycle_rules"][0]["expiration"]["days"], 365);
+        assert_eq!(archive["object_ownership"], "BucketOwnerEnforced");
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].resource_name, "app-assets");
+        assert_eq!(warnings[0].operation, "GetBucketOwnershipControls");
+    }
+
+    #[tokio::test]
+    async fn test_scan_skips_bucket_without_location() {
+        let mut mock = MockS3Client::new();
+        mock.expect_list_buckets()
+            .returning(|| Ok(vec!["restricted".to_string()]));
+        mock.expect_get_bucket_location()
+            .returning(|_| Err(anyhow!("AccessDenied: not authorized")));
+        let scanner = AwsS3Scanner::new_with_client(Arc::new(mock), None, 1);
+        let mut warnings = Vec::new();
+
+        let buckets = scanner.scan(&mut warnings).await;
+
+        assert!(buckets.is_empty());
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].operation, "GetBucketLocation");
+        assert_eq!(warnings[0].error_kind, "access_denied");
+    }
+}
@@ -4,15 +4,29 @@
 //! Terraform生成用のデータ構造に変換します。
 
 use anyhow::{Context, Result};
+use aws_config::SdkConfig;
+use aws_sdk_iam::Client as IamClient;
+use futures::stream::{self, StreamExt};
 use serde_json::{json, Value};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
 use crate::domain::iam_policy::IamPolicyDocument;
-use crate::infra::aws::client_factory::AwsClientFactory;
-use crate::infra::aws::iam_client_trait::IamClientOps;
-use crate::infra::aws::real_iam_client::RealIamClient;
-use crate::models::ScanConfig;
+use crate::infra::aws::client_factory::{AssumeRoleOptions, AwsClientFactory, AwsEndpointOptions};
+use crate::infra::aws::ec2::ec2_client_trait::Ec2ClientOps;
+use crate::infra::aws::ec2::scanner::AwsEc2Scanner;
+use crate::infra::aws::iam_client_strategy::StrategyIamClient;
+use crate::infra::aws::iam_client_trait::{IamClientOps, IamRoleInfo, IamUserInfo};
+use crate::infra::aws::organizations_client_trait::OrganizationsClientOps;
+use crate::infra::aws::organizations_scanner::AwsOrganizationsScanner;
+use crate::infra::aws::resource_policy_scanner::AwsResourcePolicyScanner;
+use crate::infra::aws::retry::{is_throttling_error, with_throttling_retry};
+use crate::infra::aws::s3_client_trait::S3ClientOps;
+use crate::infra::aws::s3_scanner::AwsS3Scanner;
+use crate::infra::aws::sso_admin_client_trait::SsoAdminClientOps;
+use crate::infra::aws::sso_scanner::AwsSsoScanner;
+use crate::models::{ProgressCallback, ScanConfig, ScanPhase, ScanProgress, ScanWarning};
 
 /// AWS IAMスキャナー
 ///
@@ -20,15 +34,141 @@ use crate::models::ScanConfig;
 pub struct AwsIamScanner<C: IamClientOps> {
     config: ScanConfig,
     iam_client: Arc<C>,
+    /// IAM Identity Centerのスキャナー（`identity_center`がスキャン対象の場合のみ）
+    sso_scanner: Option<AwsSsoScanner<dyn SsoAdminClientOps>>,
+    /// リソースベースポリシーのスキャナー（`resource_policies`がスキャン対象の場合のみ）
+    resource_policy_scanner: Option<AwsResourcePolicyScanner>,
+    /// Organizationsのスキャナー（`organizations`がスキャン対象の場合のみ）
+    organizations_scanner: Option<AwsOrganizationsScanner<dyn OrganizationsClientOps>>,
+    /// ネットワーキングのスキャナー（`vpcs`・`subnets`・`security_groups`のいずれかがスキャン対象の場合のみ）
+    ec2_scanner: Option<AwsEc2Scanner<dyn Ec2ClientOps>>,
+    /// S3バケットのスキャナー（`s3_buckets`がスキャン対象の場合のみ）
+    s3_scanner: Option<AwsS3Scanner<dyn S3ClientOps>>,
 }
 
-impl AwsIamScanner<RealIamClient> {
+/// EC2スキャナーを使用するスキャン対象
+const EC2_SCAN_TARGETS: [&str; 3] = ["vpcs", "subnets", "security_groups"];
+
+/// 進捗のステップとしてスキャンするスキャン対象
+///
+/// `cleanup`のようにアタッチメントと合わせて取得する対象は含みません。
+const PROGRESS_SCAN_TARGETS: [&str; 15] = [
+    "users",
+    "groups",
+    "roles",
+    "instance_profiles",
+    "oidc_providers",
+    "saml_providers",
+    "account_settings",
+    "identity_center",
+    "organizations",
+    "resource_policies",
+    "vpcs",
+    "subnets",
+    "security_groups",
+    "s3_buckets",
+    "policies",
+];
+
+/// アタッチメントをスキャンするエンティティの種類
+#[derive(Debug, Clone, Copy)]
+enum AttachmentEntity {
+    User,
+    Group,
+    Role,
+}
+
+impl AttachmentEntity {
+    /// スキャン結果でエンティティ名を格納するフィールド名
+    fn name_field(self) -> &'static str {
+        match self {
+            Self::User => "user_name",
+            Self::Group => "group_name",
+            Self::Role => "role_name",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::User => "User",
+            Self::Group => "Group",
+            Self::Role => "Role",
+        }
+    }
+
+    /// スキャン警告に記録するリソース種別
+    fn resource_type(self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Group => "group",
+            Self::Role => "role",
+        }
+    }
+}
+
+/// API呼び出しのエラー種別を判定
+fn error_kind(error: &anyhow::Error) -> &'static str {
+    let message = format!("{:#}", error);
+    if is_throttling_error(error) {
+        "throttling"
+    } else if ["AccessDenied", "UnauthorizedOperation", "not authorized"]
+        .iter()
+        .any(|pattern| message.contains(pattern))
+    {
+        "access_denied"
+    } else if message.contains("NoSuchEntity") {
+        "not_found"
+    } else {
+        "other"
+    }
+}
+
+/// API呼び出しの失敗をスキャン警告に変換
+pub(crate) fn scan_warning(
+    resource_type: &str,
+    resource_name: &str,
+    operation: &str,
+    error: &anyhow::Error,
+) -> ScanWarning {
+    ScanWarning {
+        resource_type: resource_type.to_string(),
+        resource_name: resource_name.to_string(),
+        operation: operation.to_string(),
+        error_kind: error_kind(error).to_string(),
+        message: format!("{:#}", error),
+        account_id: None,
+    }
+}
+
+/// アタッチメントスキャンの進捗をスキャン全体の進捗に換算して通知する
+struct AttachmentProgress<'a> {
+    callback: &'a (dyn Fn(ScanProgress) + Send + Sync),
+    /// アタッチメントスキャン開始時点の進捗（%）
+    start: u32,
+    /// アタッチメントスキャン完了時点の進捗（%）
+    end: u32,
+}
+
+impl AttachmentProgress<'_> {
+    fn report(&self, completed: usize, total: usize, message: String) {
+        let span = self.end.saturating_sub(self.start) as usize;
+        let progress = self.start + (span * completed / total.max(1)) as u32;
+        (self.callback)(
+            ScanProgress::scanning(progress, "attachments", message).with_counts(completed, total),
+        );
+    }
+}
+
+impl AwsIamScanner<StrategyIamClient> {
     /// 本番用のスキャナーを作成
+    ///
+    /// 認証情報（AssumeRoleを含む）は一度だけ解決し、
+    /// 作成した設定をIAMと全てのサブスキャナーで共有します。
     pub async fn new(config: ScanConfig) -> Result<Self> {
-        let iam_client = AwsClientFactory::create_iam_client(
+        let sdk_config = AwsClientFactory::create_verified_config(
             config.profile.clone(),
-            config.assume_role_arn.clone(),
-            config.assume_role_session_name.clone(),
+            AssumeRoleOptions::from_scan_config(&config),
+            &AwsEndpointOptions::from_scan_config(&config),
         )
         .await
         .with_context(|| {
@@ -39,9 +179,78 @@ impl AwsIamScanner<RealIamClient> {
             )
         })?;
 
+        Self::from_sdk_config(config, &sdk_config).await
+    }
+
+    /// 解決済みの設定からスキャナーを作成
+    ///
+    /// `scan_strategy`が"authorization_details"の場合は、
+    /// GetAccountAuthorizationDetailsでIAM情報を一括取得します。
+    pub async fn from_sdk_config(config: ScanConfig, sdk_config: &SdkConfig) -> Result<Self> {
+        let iam_client =
+            StrategyIamClient::from_config(IamClient::new(sdk_config), &config).await?;
+
+        let sso_scanner = if config
+            .scan_targets
+            .get("identity_center")
+            .copied()
+            .unwrap_or(false)
+        {
+            Some(AwsSsoScanner::from_sdk_config(sdk_config, &config).await?)
+        } else {
+            None
+        };
+
+        let resource_policy_scanner = if config
+            .scan_targets
+            .get("resource_policies")
+            .copied()
+            .unwrap_or(false)
+        {
+            Some(AwsResourcePolicyScanner::from_sdk_config(sdk_config, &config).await?)
+        } else {
+            None
+        };
+
+        let organizations_scanner = if config
+            .scan_targets
+            .get("organizations")
+            .copied()
+            .unwrap_or(false)
+        {
+            Some(AwsOrganizationsScanner::from_sdk_config(sdk_config, &config).await?)
+        } else {
+            None
+        };
+
+        let ec2_scanner = if EC2_SCAN_TARGETS
+            .iter()
+            .any(|target| config.scan_targets.get(*target).copied().unwrap_or(false))
+        {
+            Some(AwsEc2Scanner::from_sdk_config(sdk_config, &config).await?)
+        } else {
+            None
+        };
+
+        let s3_scanner = if config
+            .scan_targets
+            .get("s3_buckets")
+            .copied()
+            .unwrap_or(false)
+        {
+            Some(AwsS3Scanner::from_sdk_config(sdk_config, &config).await?)
+        } else {
+            None
+        };
+
         Ok(Self {
             config,
-            iam_client: Arc::new(RealIamClient::new(iam_client)),
+            iam_client: Arc::new(iam_client),
+            sso_scanner,
+            resource_policy_scanner,
+            organizations_scanner,
+            ec2_scanner,
+            s3_scanner,
         })
     }
 }
@@ -53,48 +262,125 @@ impl<C: IamClientOps> AwsIamScanner<C> {
         Self {
             config,
             iam_client: Arc::new(client),
+            sso_scanner: None,
+            resource_policy_scanner: None,
+            organizations_scanner: None,
+            ec2_scanner: None,
+            s3_scanner: None,
         }
     }
 
+    /// テスト用：IAM Identity Centerのモッククライアントを設定
+    #[cfg(test)]
+    pub fn with_sso_client<S: SsoAdminClientOps + 'static>(mut self, client: S) -> Self {
+        let concurrency = self.config.max_concurrency();
+        self.sso_scanner = Some(AwsSsoScanner::new_with_client(
+            Arc::new(client),
+            concurrency,
+        ));
+        self
+    }
+
+    /// テスト用：Organizationsのモッククライアントを設定
+    #[cfg(test)]
+    pub fn with_organizations_client<O: OrganizationsClientOps + 'static>(
+        mut self,
+        client: O,
+    ) -> Self {
+        let concurrency = self.config.max_concurrency();
+        self.organizations_scanner = Some(AwsOrganizationsScanner::new_with_client(
+            Arc::new(client),
+            concurrency,
+        ));
+        self
+    }
+
+    /// テスト用：EC2のモッククライアントを設定
+    #[cfg(test)]
+    pub fn with_ec2_client<E: Ec2ClientOps + 'static>(mut self, client: E) -> Self {
+        let concurrency = self.config.max_concurrency();
+        self.ec2_scanner = Some(AwsEc2Scanner::new_with_client(
+            Arc::new(client),
+            concurrency,
+        ));
+        self
+    }
+
+    /// テスト用：S3バケットのスキャナーを設定
+    #[cfg(test)]
+    pub fn with_s3_client<S: S3ClientOps + 'static>(
+        mut self,
+        client: S,
+        home_region: Option<String>,
+    ) -> Self {
+        let concurrency = self.config.max_concurrency();
+        self.s3_scanner = Some(AwsS3Scanner::new_with_client(
+            Arc::new(client),
+            home_region,
+            concurrency,
+        ));
+        self
+    }
+
+    /// テスト用：リソースベースポリシーのスキャナーを設定
+    #[cfg(test)]
+    pub fn with_resource_policy_scanner(mut self, scanner: AwsResourcePolicyScanner) -> Self {
+        self.resource_policy_scanner = Some(scanner);
+        self
+    }
+
     /// IAMリソースをスキャン
-    pub async fn scan(
-        &self,
-        progress_callback: Box<dyn Fn(u32, String) + Send + Sync>,
-    ) -> Result<Value> {
+    pub async fn scan(&self, progress_callback: ProgressCallback) -> Result<Value> {
         let start_time = std::time::Instant::now();
         info!("AWS IAMスキャンを開始");
-        progress_callback(0, "AWS IAMスキャンを開始しています...".to_string());
+        progress_callback(ScanProgress::new(
+            ScanPhase::Started,
+            0,
+            "AWS IAMスキャンを開始しています...",
+        ));
 
         let mut results = serde_json::Map::new();
         results.insert("provider".to_string(), Value::String("aws".to_string()));
 
         let scan_targets = &self.config.scan_targets;
 
-        // スキャン対象の数をカウント
-        let total_targets = scan_targets.values().filter(|&&v| v).count();
-        if total_targets == 0 {
-            progress_callback(100, "スキャン対象が選択されていません".to_string());
+        if !scan_targets.values().any(|&v| v) {
+            progress_callback(ScanProgress::new(
+                ScanPhase::Completed,
+                100,
+                "スキャン対象が選択されていません",
+            ));
             return Ok(Value::Object(results));
         }
 
+        // 進捗のステップを持つ対象の数に、アタッチメントの取得の1ステップを加える
+        let total_steps = PROGRESS_SCAN_TARGETS
+            .iter()
+            .filter(|target| scan_targets.get(**target).copied().unwrap_or(false))
+            .count()
+            + 1;
         let mut completed_targets = 0;
+        let mut warnings = Vec::new();
 
         // Users
         let users = if scan_targets.get("users").copied().unwrap_or(false) {
             debug!("IAM Usersのスキャンを開始");
-            progress_callback(
-                (completed_targets * 100 / total_targets) as u32,
-                "IAM Usersのスキャン中...".to_string(),
-            );
-            let users = self.scan_users().await?;
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "users",
+                "IAM Usersのスキャン中...",
+            ));
+            let users = self.scan_users(&mut warnings).await?;
             let count = users.len();
             results.insert("users".to_string(), Value::Array(users.clone()));
             completed_targets += 1;
             debug!(count, "IAM Usersのスキャン完了");
-            progress_callback(
-                (completed_targets * 100 / total_targets) as u32,
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "users",
+                count,
                 format!("IAM Usersのスキャン完了: {}件", count),
-            );
+            ));
             users
         } else {
             results.insert("users".to_string(), Value::Array(Vec::new()));
@@ -104,19 +390,22 @@ impl<C: IamClientOps> AwsIamScanner<C> {
         // Groups
         let groups = if scan_targets.get("groups").copied().unwrap_or(false) {
             debug!("IAM Groupsのスキャンを開始");
-            progress_callback(
-                (completed_targets * 100 / total_targets) as u32,
-                "IAM Groupsのスキャン中...".to_string(),
-            );
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "groups",
+                "IAM Groupsのスキャン中...",
+            ));
             let groups = self.scan_groups().await?;
             let count = groups.len();
             results.insert("groups".to_string(), Value::Array(groups.clone()));
             completed_targets += 1;
             debug!(count, "IAM Groupsのスキャン完了");
-            progress_callback(
-                (completed_targets * 100 / total_targets) as u32,
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "groups",
+                count,
                 format!("IAM Groupsのスキャン完了: {}件", count),
-            );
+            ));
             groups
         } else {
             results.insert("groups".to_string(), Value::Array(Vec::new()));
@@ -126,60 +415,495 @@ impl<C: IamClientOps> AwsIamScanner<C> {
         // Roles
         let roles = if scan_targets.get("roles").copied().unwrap_or(false) {
             debug!("IAM Rolesのスキャンを開始");
-            progress_callback(
-                (completed_targets * 100 / total_targets) as u32,
-                "IAM Rolesのスキャン中...".to_string(),
-            );
-            let roles = self.scan_roles().await?;
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "roles",
+                "IAM Rolesのスキャン中...",
+            ));
+            let roles = self.scan_roles(&mut warnings).await?;
             let count = roles.len();
             results.insert("roles".to_string(), Value::Array(roles.clone()));
             completed_targets += 1;
             debug!(count, "IAM Rolesのスキャン完了");
-            progress_callback(
-                (completed_targets * 100 / total_targets) as u32,
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "roles",
+                count,
                 format!("IAM Rolesのスキャン完了: {}件", count),
-            );
+            ));
             roles
         } else {
             results.insert("roles".to_string(), Value::Array(Vec::new()));
             Vec::new()
         };
 
+        // Instance Profiles
+        if scan_targets
+            .get("instance_profiles")
+            .copied()
+            .unwrap_or(false)
+        {
+            debug!("IAM Instance Profilesのスキャンを開始");
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "instance_profiles",
+                "IAM Instance Profilesのスキャン中...",
+            ));
+            let instance_profiles = self.scan_instance_profiles().await?;
+            let count = instance_profiles.len();
+            results.insert(
+                "instance_profiles".to_string(),
+                Value::Array(instance_profiles),
+            );
+            completed_targets += 1;
+            debug!(count, "IAM Instance Profilesのスキャン完了");
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "instance_profiles",
+                count,
+                format!("IAM Instance Profilesのスキャン完了: {}件", count),
+            ));
+        } else {
+            results.insert("instance_profiles".to_string(), Value::Array(Vec::new()));
+        }
+
+        // OIDC Providers
+        if scan_targets.get("oidc_providers").copied().unwrap_or(false) {
+            debug!("IAM OIDC Providersのスキャンを開始");
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "oidc_providers",
+                "IAM OIDC Providersのスキャン中...",
+            ));
+            let oidc_providers = self.scan_oidc_providers().await?;
+            let count = oidc_providers.len();
+            results.insert("oidc_providers".to_string(), Value::Array(oidc_providers));
+            completed_targets += 1;
+            debug!(count, "IAM OIDC Providersのスキャン完了");
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "oidc_providers",
+                count,
+                format!("IAM OIDC Providersのスキャン完了: {}件", count),
+            ));
+        } else {
+            results.insert("oidc_providers".to_string(), Value::Array(Vec::new()));
+        }
+
+        // SAML Providers
+        if scan_targets.get("saml_providers").copied().unwrap_or(false) {
+            debug!("IAM SAML Providersのスキャンを開始");
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "saml_providers",
+                "IAM SAML Providersのスキャン中...",
+            ));
+            let saml_providers = self.scan_saml_providers().await?;
+            let count = saml_providers.len();
+            results.insert("saml_providers".to_string(), Value::Array(saml_providers));
+            completed_targets += 1;
+            debug!(count, "IAM SAML Providersのスキャン完了");
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "saml_providers",
+                count,
+                format!("IAM SAML Providersのスキャン完了: {}件", count),
+            ));
+        } else {
+            results.insert("saml_providers".to_string(), Value::Array(Vec::new()));
+        }
+
+        // Account Settings（パスワードポリシー・アカウントエイリアス）
+        if scan_targets
+            .get("account_settings")
+            .copied()
+            .unwrap_or(false)
+        {
+            debug!("IAMアカウント設定のスキャンを開始");
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "account_settings",
+                "IAMアカウント設定のスキャン中...",
+            ));
+            let (password_policy, account_aliases) = self.scan_account_settings().await?;
+            let count = password_policy.len() + account_aliases.len();
+            results.insert(
+                "account_password_policy".to_string(),
+                Value::Array(password_policy),
+            );
+            results.insert("account_aliases".to_string(), Value::Array(account_aliases));
+            completed_targets += 1;
+            debug!(count, "IAMアカウント設定のスキャン完了");
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "account_settings",
+                count,
+                format!("IAMアカウント設定のスキャン完了: {}件", count),
+            ));
+        } else {
+            results.insert(
+                "account_password_policy".to_string(),
+                Value::Array(Vec::new()),
+            );
+            results.insert("account_aliases".to_string(), Value::Array(Vec::new()));
+        }
+
+        // IAM Identity Center（許可セット・アカウント割り当て）
+        if scan_targets
+            .get("identity_center")
+            .copied()
+            .unwrap_or(false)
+        {
+            debug!("IAM Identity Centerのスキャンを開始");
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "identity_center",
+                "IAM Identity Centerのスキャン中...",
+            ));
+            let identity_center = match &self.sso_scanner {
+                Some(sso_scanner) => sso_scanner.scan(&mut warnings).await,
+                None => Default::default(),
+            };
+            let count = identity_center.count();
+            results.insert(
+                "sso_permission_sets".to_string(),
+                Value::Array(identity_center.permission_sets),
+            );
+            results.insert(
+                "sso_managed_policy_attachments".to_string(),
+                Value::Array(identity_center.managed_policy_attachments),
+            );
+            results.insert(
+                "sso_inline_policies".to_string(),
+                Value::Array(identity_center.inline_policies),
+            );
+            results.insert(
+                "sso_account_assignments".to_string(),
+                Value::Array(identity_center.account_assignments),
+            );
+            completed_targets += 1;
+            debug!(count, "IAM Identity Centerのスキャン完了");
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "identity_center",
+                count,
+                format!("IAM Identity Centerのスキャン完了: {}件", count),
+            ));
+        } else {
+            for key in [
+                "sso_permission_sets",
+                "sso_managed_policy_attachments",
+                "sso_inline_policies",
+                "sso_account_assignments",
+            ] {
+                results.insert(key.to_string(), Value::Array(Vec::new()));
+            }
+        }
+
+        // AWS Organizations（OUツリー・サービスコントロールポリシー）
+        if scan_targets.get("organizations").copied().unwrap_or(false) {
+            debug!("AWS Organizationsのスキャンを開始");
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "organizations",
+                "AWS Organizationsのスキャン中...",
+            ));
+            let organizations = match &self.organizations_scanner {
+                Some(scanner) => scanner.scan(&mut warnings).await,
+                None => Default::default(),
+            };
+            let count = organizations.count();
+            results.insert(
+                "organizational_units".to_string(),
+                Value::Array(organizations.organizational_units),
+            );
+            results.insert(
+                "service_control_policies".to_string(),
+                Value::Array(organizations.service_control_policies),
+            );
+            results.insert(
+                "service_control_policy_attachments".to_string(),
+                Value::Array(organizations.service_control_policy_attachments),
+            );
+            completed_targets += 1;
+            debug!(count, "AWS Organizationsのスキャン完了");
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "organizations",
+                count,
+                format!("AWS Organizationsのスキャン完了: {}件", count),
+            ));
+        } else {
+            for key in [
+                "organizational_units",
+                "service_control_policies",
+                "service_control_policy_attachments",
+            ] {
+                results.insert(key.to_string(), Value::Array(Vec::new()));
+            }
+        }
+
+        // リソースベースポリシー（S3・KMS・SQS・SNS）
+        if scan_targets
+            .get("resource_policies")
+            .copied()
+            .unwrap_or(false)
+        {
+            debug!("リソースベースポリシーのスキャンを開始");
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "resource_policies",
+                "リソースベースポリシーのスキャン中...",
+            ));
+            let resource_policies = match &self.resource_policy_scanner {
+                Some(scanner) => scanner.scan(&mut warnings).await,
+                None => Default::default(),
+            };
+            let count = resource_policies.count();
+            results.insert(
+                "s3_bucket_policies".to_string(),
+                Value::Array(resource_policies.s3_bucket_policies),
+            );
+            results.insert(
+                "kms_key_policies".to_string(),
+                Value::Array(resource_policies.kms_key_policies),
+            );
+            results.insert(
+                "sqs_queue_policies".to_string(),
+                Value::Array(resource_policies.sqs_queue_policies),
+            );
+            results.insert(
+                "sns_topic_policies".to_string(),
+                Value::Array(resource_policies.sns_topic_policies),
+            );
+            completed_targets += 1;
+            debug!(count, "リソースベースポリシーのスキャン完了");
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "resource_policies",
+                count,
+                format!("リソースベースポリシーのスキャン完了: {}件", count),
+            ));
+        } else {
+            for key in [
+                "s3_bucket_policies",
+                "kms_key_policies",
+                "sqs_queue_policies",
+                "sns_topic_policies",
+            ] {
+                results.insert(key.to_string(), Value::Array(Vec::new()));
+            }
+        }
+
+        // VPC
+        if scan_targets.get("vpcs").copied().unwrap_or(false) {
+            debug!("VPCのスキャンを開始");
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "vpcs",
+                "VPCのスキャン中...",
+            ));
+            let vpcs = match &self.ec2_scanner {
+                Some(scanner) => scanner.scan_vpcs(&mut warnings).await,
+                None => Vec::new(),
+            };
+            let count = vpcs.len();
+            results.insert("vpcs".to_string(), Value::Array(vpcs));
+            completed_targets += 1;
+            debug!(count, "VPCのスキャン完了");
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "vpcs",
+                count,
+                format!("VPCのスキャン完了: {}件", count),
+            ));
+        } else {
+            results.insert("vpcs".to_string(), Value::Array(Vec::new()));
+        }
+
+        // サブネット
+        if scan_targets.get("subnets").copied().unwrap_or(false) {
+            debug!("サブネットのスキャンを開始");
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "subnets",
+                "サブネットのスキャン中...",
+            ));
+            let subnets = match &self.ec2_scanner {
+                Some(scanner) => scanner.scan_subnets(&mut warnings).await,
+                None => Vec::new(),
+            };
+            let count = subnets.len();
+            results.insert("subnets".to_string(), Value::Array(subnets));
+            completed_targets += 1;
+            debug!(count, "サブネットのスキャン完了");
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "subnets",
+                count,
+                format!("サブネットのスキャン完了: {}件", count),
+            ));
+        } else {
+            results.insert("subnets".to_string(), Value::Array(Vec::new()));
+        }
+
+        // セキュリティグループ（受信・送信ルールを含む）
+        if scan_targets
+            .get("security_groups")
+            .copied()
+            .unwrap_or(false)
+        {
+            debug!("セキュリティグループのスキャンを開始");
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "security_groups",
+                "セキュリティグループのスキャン中...",
+            ));
+            let security_groups = match &self.ec2_scanner {
+                Some(scanner) => scanner.scan_security_groups(&mut warnings).await,
+                None => Default::default(),
+            };
+            let count = security_groups.count();
+            results.insert(
+                "security_groups".to_string(),
+                Value::Array(security_groups.security_groups),
+            );
+            results.insert(
+                "security_group_ingress_rules".to_string(),
+                Value::Array(security_groups.ingress_rules),
+            );
+            results.insert(
+                "security_group_egress_rules".to_string(),
+                Value::Array(security_groups.egress_rules),
+            );
+            completed_targets += 1;
+            debug!(count, "セキュリティグループのスキャン完了");
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "security_groups",
+                count,
+                format!("セキュリティグループのスキャン完了: {}件", count),
+            ));
+        } else {
+            for key in [
+                "security_groups",
+                "security_group_ingress_rules",
+                "security_group_egress_rules",
+            ] {
+                results.insert(key.to_string(), Value::Array(Vec::new()));
+            }
+        }
+
+        // S3バケット（分割された設定リソースを含む）
+        if scan_targets.get("s3_buckets").copied().unwrap_or(false) {
+            debug!("S3バケットのスキャンを開始");
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "s3_buckets",
+                "S3バケットのスキャン中...",
+            ));
+            let buckets = match &self.s3_scanner {
+                Some(scanner) => scanner.scan(&mut warnings).await,
+                None => Vec::new(),
+            };
+            let count = buckets.len();
+            results.insert("s3_buckets".to_string(), Value::Array(buckets));
+            completed_targets += 1;
+            debug!(count, "S3バケットのスキャン完了");
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "s3_buckets",
+                count,
+                format!("S3バケットのスキャン完了: {}件", count),
+            ));
+        } else {
+            results.insert("s3_buckets".to_string(), Value::Array(Vec::new()));
+        }
+
         // Policies
         if scan_targets.get("policies").copied().unwrap_or(false) {
             debug!("IAM Policiesのスキャンを開始");
-            progress_callback(
-                (completed_targets * 100 / total_targets) as u32,
-                "IAM Policiesのスキャン中...".to_string(),
-            );
+            progress_callback(ScanProgress::scanning(
+                (completed_targets * 100 / total_steps) as u32,
+                "policies",
+                "IAM Policiesのスキャン中...",
+            ));
             let policies = self.scan_policies().await?;
             let count = policies.len();
             results.insert("policies".to_string(), Value::Array(policies));
             completed_targets += 1;
             debug!(count, "IAM Policiesのスキャン完了");
-            progress_callback(
-                (completed_targets * 100 / total_targets) as u32,
+            progress_callback(ScanProgress::resource_completed(
+                (completed_targets * 100 / total_steps) as u32,
+                "policies",
+                count,
                 format!("IAM Policiesのスキャン完了: {}件", count),
-            );
+            ));
         } else {
             results.insert("policies".to_string(), Value::Array(Vec::new()));
         }
 
         // リソース間の接続情報を取得（既にスキャン済みのデータを再利用）
-        let attachments = self
-            .scan_attachments_with_data(&users, &groups, &roles)
+        let attachment_progress = AttachmentProgress {
+            callback: progress_callback.as_ref(),
+            start: (completed_targets * 100 / total_steps) as u32,
+            end: ((completed_targets + 1) * 100 / total_steps) as u32,
+        };
+        progress_callback(ScanProgress::scanning(
+            attachment_progress.start,
+            "attachments",
+            "アタッチメントのスキャン中...",
+        ));
+        let (attachments, mut cleanup) = self
+            .scan_attachments_with_data(
+                &users,
+                &groups,
+                &roles,
+                &attachment_progress,
+                &mut warnings,
+            )
             .await?;
+        // ユーザーがスキャン対象外の場合、認証情報はアタッチメントと別に取得する
+        if self.scans_user_credentials() && !scan_targets.get("users").copied().unwrap_or(false) {
+            cleanup = self.scan_cleanup_credentials(&mut warnings).await?;
+        }
         results.insert("attachments".to_string(), attachments);
+        results.insert("cleanup".to_string(), Value::Array(cleanup));
+
+        // 最終使用日時から未使用のユーザー/ロールを判定
+        Self::annotate_last_used(
+            &mut results,
+            chrono::Utc::now().timestamp(),
+            self.config.unused_threshold_days(),
+            self.scans_user_credentials(),
+        );
+        if self.config.include_service_last_accessed {
+            self.fill_service_last_accessed(&mut results, &mut warnings)
+                .await;
+        }
+
+        // マネージドポリシーのデフォルトバージョンのドキュメントを補完
+        self.fill_policy_documents(&mut results, &mut warnings)
+            .await?;
 
-        // クリーンアップ（マネージドポリシーのバージョン等を補完）
-        self.scan_cleanup(&mut results).await?;
+        if !warnings.is_empty() {
+            warn!(
+                count = warnings.len(),
+                "一部のリソースの取得に失敗しました（スキャン結果が不完全な可能性があります）"
+            );
+        }
+        results.insert("warnings".to_string(), json!(warnings));
 
         let duration = start_time.elapsed();
         info!(
             "AWS IAMスキャン完了 (所要時間: {:.2}秒)",
             duration.as_secs_f64()
         );
-        progress_callback(100, "AWS IAMスキャンが完了しました".to_string());
+        progress_callback(ScanProgress::new(
+            ScanPhase::Completed,
+            100,
+            "AWS IAMスキャンが完了しました",
+        ));
 
         Ok(Value::Object(results))
     }
@@ -193,19 +917,50 @@ impl<C: IamClientOps> AwsIamScanner<C> {
         }
     }
 
+    /// AWS管理ロールの扱いを判定（`filters`で"include"/"skip"を指定）
+    ///
+    /// サービスリンクロールは既定で`aws_iam_service_linked_role`として生成し、
+    /// IAM Identity Center等が作成する予約ロールは既定でスキップします。
+    fn includes_role_category(&self, category: &str) -> bool {
+        let (filter_key, default) = match category {
+            "service_linked" => ("service_linked_roles", true),
+            "reserved" => ("reserved_roles", false),
+            _ => return true,
+        };
+        match self.config.filters.get(filter_key).map(String::as_str) {
+            Some("include") => true,
+            Some("skip") => false,
+            _ => default,
+        }
+    }
+
+    /// ロールのパスからカテゴリを判定
+    ///
+    /// `/aws-service-role/`配下はサービスリンクロール、`/aws-reserved/`配下は
+    /// AWSが予約するロール（IAM Identity Centerの許可セット等）です。
+    fn role_category(path: &str) -> &'static str {
+        if path.starts_with("/aws-service-role/") {
+            "service_linked"
+        } else if path.starts_with("/aws-reserved/") {
+            "reserved"
+        } else {
+            "standard"
+        }
+    }
+
     /// IAMユーザーをスキャン
-    pub async fn scan_users(&self) -> Result<Vec<Value>> {
-        let users_info = self
+    pub async fn scan_users(&self, warnings: &mut Vec<ScanWarning>) -> Result<Vec<Value>> {
+        let users_info: Vec<IamUserInfo> = self
             .iam_client
             .list_users_with_options(self.config.include_tags)
-            .await?;
+            .await?
+            .into_iter()
+            .filter(|user| self.apply_name_prefix_filter(&user.user_name))
+            .collect();
+        let users_info = self.fill_user_details(users_info, warnings).await;
         let mut users = Vec::new();
 
         for user in users_info {
-            if !self.apply_name_prefix_filter(&user.user_name) {
-                continue;
-            }
-
             let mut user_json = json!({
                 "user_name": user.user_name,
                 "user_id": user.user_id,
@@ -218,6 +973,14 @@ impl<C: IamClientOps> AwsIamScanner<C> {
                 user_json["tags"] = json!(user.tags);
             }
 
+            if let Some(boundary) = user.permissions_boundary {
+                user_json["permissions_boundary"] = json!(boundary);
+            }
+
+            if let Some(password_last_used) = user.password_last_used {
+                user_json["password_last_used"] = json!(password_last_used);
+            }
+
             users.push(user_json);
         }
 
@@ -249,17 +1012,22 @@ impl<C: IamClientOps> AwsIamScanner<C> {
     }
 
     /// IAMロールをスキャン
-    pub async fn scan_roles(&self) -> Result<Vec<Value>> {
-        let roles_info = self
+    pub async fn scan_roles(&self, warnings: &mut Vec<ScanWarning>) -> Result<Vec<Value>> {
+        let roles_info: Vec<IamRoleInfo> = self
             .iam_client
             .list_roles_with_options(self.config.include_tags)
-            .await?;
+            .await?
+            .into_iter()
+            .filter(|role| {
+                self.apply_name_prefix_filter(&role.role_name)
+                    && self.includes_role_category(Self::role_category(&role.path))
+            })
+            .collect();
+        let roles_info = self.fill_role_details(roles_info, warnings).await;
         let mut roles = Vec::new();
 
         for role in roles_info {
-            if !self.apply_name_prefix_filter(&role.role_name) {
-                continue;
-            }
+            let role_category = Self::role_category(&role.path);
 
             let assume_role_statements = role
                 .assume_role_policy_document
@@ -273,9 +1041,27 @@ impl<C: IamClientOps> AwsIamScanner<C> {
                 "arn": role.arn,
                 "create_date": role.create_date,
                 "path": role.path,
+                "role_category": role_category,
                 "assume_role_statements": assume_role_statements,
             });
 
+            // サービスリンクロールのパスは`/aws-service-role/<サービス名>/`、
+            // 名前は`AWSServiceRoleFor<サービス>[_<サフィックス>]`の形式
+            if role_category == "service_linked" {
+                if let Some(service_name) = role
+                    .path
+                    .trim_start_matches("/aws-service-role/")
+                    .split('/')
+                    .next()
+                    .filter(|s| !s.is_empty())
+                {
+                    role_json["aws_service_name"] = json!(service_name);
+                }
+                if let Some((_, suffix)) = role.role_name.split_once('_') {
+                    role_json["custom_suffix"] = json!(suffix);
+                }
+            }
+
             // 生のassume_role_policy_documentも保存（Terraform生成やパース失敗時のために必要）
             // テンプレートでjsonencode()を使用するため、URLデコードされたJSON文字列として保存する
             if let Some(ref policy_doc) = role.assume_role_policy_document {
@@ -293,12 +1079,246 @@ impl<C: IamClientOps> AwsIamScanner<C> {
                 role_json["tags"] = json!(role.tags);
             }
 
+            if let Some(description) = role.description.filter(|d| !d.is_empty()) {
+                role_json["description"] = json!(description);
+            }
+            if let Some(max_session_duration) = role.max_session_duration {
+                role_json["max_session_duration"] = json!(max_session_duration);
+            }
+            if let Some(boundary) = role.permissions_boundary {
+                role_json["permissions_boundary"] = json!(boundary);
+            }
+            if let Some(last_used_date) = role.last_used_date {
+                role_json["last_used_date"] = json!(last_used_date);
+            }
+            if let Some(region) = role.last_used_region {
+                role_json["last_used_region"] = json!(region);
+            }
+
             roles.push(role_json);
         }
 
         Ok(roles)
     }
 
+    /// ユーザーに一覧で取得できない詳細情報（アクセス許可の境界・タグ）を補完
+    ///
+    /// アタッチメントと同様に同時実行数を制限して並列に取得します（結果は一覧の順で返る）。
+    /// 取得に失敗したユーザーは詳細情報なしで結果に含め、`warnings`に記録します。
+    async fn fill_user_details(
+        &self,
+        users: Vec<IamUserInfo>,
+        warnings: &mut Vec<ScanWarning>,
+    ) -> Vec<IamUserInfo> {
+        let include_tags = self.config.include_tags;
+        let mut results = stream::iter(users)
+            .map(|mut user| async move {
+                let detail = with_throttling_retry("GetUser", || {
+                    self.iam_client
+                        .get_user_detail(&user.user_name, include_tags)
+                })
+                .await;
+                let warning = match detail {
+                    Ok(Some(detail)) => {
+                        user.permissions_boundary = detail.permissions_boundary;
+                        user.tags = detail.tags;
+                        None
+                    }
+                    Ok(None) => None,
+                    Err(e) => {
+                        warn!(user_name = %user.user_name, error = %e, "ユーザーの詳細情報の取得に失敗");
+                        Some(scan_warning("user", &user.user_name, "GetUser", &e))
+                    }
+                };
+                (user, warning)
+            })
+            .buffered(self.config.max_concurrency());
+
+        let mut filled = Vec::new();
+        while let Some((user, warning)) = results.next().await {
+            filled.push(user);
+            warnings.extend(warning);
+        }
+        filled
+    }
+
+    /// ロールに一覧で取得できない詳細情報（アクセス許可の境界・タグ・最終使用日時）を補完
+    ///
+    /// 同時実行数と失敗時の扱いは`fill_user_details`と同じです。
+    async fn fill_role_details(
+        &self,
+        roles: Vec<IamRoleInfo>,
+        warnings: &mut Vec<ScanWarning>,
+    ) -> Vec<IamRoleInfo> {
+        let include_tags = self.config.include_tags;
+        let mut results = stream::iter(roles)
+            .map(|mut role| async move {
+                let detail = with_throttling_retry("GetRole", || {
+                    self.iam_client
+                        .get_role_detail(&role.role_name, include_tags)
+                })
+                .await;
+                let warning = match detail {
+                    Ok(Some(detail)) => {
+                        role.permissions_boundary = detail.permissions_boundary;
+                        role.tags = detail.tags;
+                        role.last_used_date = detail.last_used_date;
+                        role.last_used_region = detail.last_used_region;
+                        None
+                    }
+                    Ok(None) => None,
+                    Err(e) => {
+                        warn!(role_name = %role.role_name, error = %e, "ロールの詳細情報の取得に失敗");
+                        Some(scan_warning("role", &role.role_name, "GetRole", &e))
+                    }
+                };
+                (role, warning)
+            })
+            .buffered(self.config.max_concurrency());
+
+        let mut filled = Vec::new();
+        while let Some((role, warning)) = results.next().await {
+            filled.push(role);
+            warnings.extend(warning);
+        }
+        filled
+    }
+
+    /// アカウント単位のIAM設定をスキャン
+    ///
+    /// パスワードポリシーとアカウントエイリアスはいずれもアカウントに最大1つのため、
+    /// 要素数0または1の配列として`(password_policy, account_aliases)`を返します。
+    pub async fn scan_account_settings(&self) -> Result<(Vec<Value>, Vec<Value>)> {
+        let password_policy = self
+            .iam_client
+            .get_account_password_policy()
+            .await?
+            .map(|policy| {
+                let mut policy_json = json!({
+                    "require_symbols": policy.require_symbols,
+                    "require_numbers": policy.require_numbers,
+                    "require_uppercase_characters": policy.require_uppercase_characters,
+                    "require_lowercase_characters": policy.require_lowercase_characters,
+                    "allow_users_to_change_password": policy.allow_users_to_change_password,
+                });
+                if let Some(length) = policy.minimum_password_length {
+                    policy_json["minimum_password_length"] = json!(length);
+                }
+                if let Some(max_age) = policy.max_password_age {
+                    policy_json["max_password_age"] = json!(max_age);
+                }
+                if let Some(reuse_prevention) = policy.password_reuse_prevention {
+                    policy_json["password_reuse_prevention"] = json!(reuse_prevention);
+                }
+                if let Some(hard_expiry) = policy.hard_expiry {
+                    policy_json["hard_expiry"] = json!(hard_expiry);
+                }
+                policy_json
+            });
+
+        let account_aliases = self
+            .iam_client
+            .list_account_aliases()
+            .await?
+            .into_iter()
+            .map(|alias| json!({"account_alias": alias}))
+            .collect();
+
+        Ok((password_policy.into_iter().collect(), account_aliases))
+    }
+
+    /// インスタンスプロファイルをスキャン
+    ///
+    /// インスタンスプロファイルに関連付けられるロールは最大1つのため、`role_name`として保持します。
+    pub async fn scan_instance_profiles(&self) -> Result<Vec<Value>> {
+        let profiles_info = self.iam_client.list_instance_profiles().await?;
+        let mut instance_profiles = Vec::new();
+
+        for profile in profiles_info {
+            if !self.apply_name_prefix_filter(&profile.instance_profile_name) {
+                continue;
+            }
+
+            let mut profile_json = json!({
+                "instance_profile_name": profile.instance_profile_name,
+                "instance_profile_id": profile.instance_profile_id,
+                "arn": profile.arn,
+                "create_date": profile.create_date,
+                "path": profile.path,
+            });
+
+            if let Some(role_name) = profile.role_names.first() {
+                profile_json["role_name"] = json!(role_name);
+            }
+
+            instance_profiles.push(profile_json);
+        }
+
+        Ok(instance_profiles)
+    }
+
+    /// OIDCプロバイダーをスキャン
+    ///
+    /// IAM APIはURLをスキーム無しで返しますが、`aws_iam_openid_connect_provider`の
+    /// `url`は`https://`付きで指定する必要があるため補完します。
+    /// OIDCプロバイダーには名前が無いため、名前プレフィックスフィルタは適用しません。
+    pub async fn scan_oidc_providers(&self) -> Result<Vec<Value>> {
+        let providers_info = self.iam_client.list_open_id_connect_providers().await?;
+        let mut providers = Vec::new();
+
+        for provider in providers_info {
+            let url = if provider.url.starts_with("https://") {
+                provider.url
+            } else {
+                format!("https://{}", provider.url)
+            };
+
+            let mut provider_json = json!({
+                "arn": provider.arn,
+                "url": url,
+                "client_id_list": provider.client_id_list,
+                "thumbprint_list": provider.thumbprint_list,
+                "create_date": provider.create_date,
+            });
+
+            if !provider.tags.is_empty() {
+                provider_json["tags"] = json!(provider.tags);
+            }
+
+            providers.push(provider_json);
+        }
+
+        Ok(providers)
+    }
+
+    /// SAMLプロバイダーをスキャン
+    pub async fn scan_saml_providers(&self) -> Result<Vec<Value>> {
+        let providers_info = self.iam_client.list_saml_providers().await?;
+        let mut providers = Vec::new();
+
+        for provider in providers_info {
+            if !self.apply_name_prefix_filter(&provider.name) {
+                continue;
+            }
+
+            let mut provider_json = json!({
+                "arn": provider.arn,
+                "name": provider.name,
+                "saml_metadata_document": provider.saml_metadata_document,
+                "create_date": provider.create_date,
+                "valid_until": provider.valid_until,
+            });
+
+            if !provider.tags.is_empty() {
+                provider_json["tags"] = json!(provider.tags);
+            }
+
+            providers.push(provider_json);
+        }
+
+        Ok(providers)
+    }
+
     /// IAMポリシーをスキャン
     pub async fn scan_policies(&self) -> Result<Vec<Value>> {
         let policies_info = self.iam_client.list_policies().await?;
@@ -330,175 +1350,653 @@ impl<C: IamClientOps> AwsIamScanner<C> {
     /// 既にスキャン済みのデータを使用してリソース間の接続情報をスキャン
     ///
     /// この関数は、scan_users, scan_groups, scan_rolesで取得済みのデータを再利用し、
-    /// 重複するAPI呼び出しを削減します。
+    /// 重複するAPI呼び出しを削減します。個別の取得失敗は`warnings`に記録します。
+    /// `cleanup`がスキャン対象の場合は、ユーザーの認証情報（クリーンアップ対象）も
+    /// 合わせて取得し、`(attachments, cleanup)`として返します。
     async fn scan_attachments_with_data(
         &self,
         users: &[Value],
         groups: &[Value],
         roles: &[Value],
-    ) -> Result<Value> {
-        let mut attachments = serde_json::Map::new();
+        progress: &AttachmentProgress<'_>,
+        warnings: &mut Vec<ScanWarning>,
+    ) -> Result<(Value, Vec<Value>)> {
+        // スキャン対象のエンティティを列挙（ユーザー → グループ → ロールの順）
+        let mut entities: Vec<(AttachmentEntity, String)> = Vec::new();
+        for (entity, items) in [
+            (AttachmentEntity::User, users),
+            (AttachmentEntity::Group, groups),
+            (AttachmentEntity::Role, roles),
+        ] {
+            entities.extend(
+                items
+                    .iter()
+                    .filter_map(|item| item.get(entity.name_field()).and_then(|v| v.as_str()))
+                    .map(|name| (entity, name.to_string())),
+            );
+        }
 
-        // ユーザー名を抽出
-        let user_names: Vec<&str> = users
-            .iter()
-            .filter_map(|u| u.get("user_name").and_then(|v| v.as_str()))
-            .collect();
+        let total = entities.len();
+        let concurrency = self.config.max_concurrency();
+        let scan_credentials = self.scans_user_credentials();
+        debug!(total, concurrency, "アタッチメントのスキャンを開始");
+
+        // 同時実行数を制限して並列に取得（結果は列挙順で返る）
+        let mut results = stream::iter(entities)
+            .map(|(entity, name)| async move {
+                let mut entity_warnings = Vec::new();
+                let policies = self
+                    .scan_entity_policies(entity, &name, &mut entity_warnings)
+                    .await;
+                let (user_groups, credentials) = match entity {
+                    AttachmentEntity::User => {
+                        let user_groups = self.scan_user_groups(&name, &mut entity_warnings).await;
+                        // 認証情報はユーザーごとに3回以上のAPI呼び出しが必要なため、
+                        // クリーンアップが対象の場合のみ取得する
+                        let credentials = if scan_credentials {
+                            self.scan_user_credentials(&name, &mut entity_warnings)
+                                .await
+                        } else {
+                            Vec::new()
+                        };
+                        (user_groups, credentials)
+                    }
+                    _ => (Vec::new(), Vec::new()),
+                };
+                (
+                    entity,
+                    name,
+                    policies,
+                    user_groups,
+                    credentials,
+                    entity_warnings,
+                )
+            })
+            .buffered(concurrency);
 
-        // グループ名を抽出
-        let group_names: Vec<&str> = groups
-            .iter()
-            .filter_map(|g| g.get("group_name").and_then(|v| v.as_str()))
-            .collect();
+        let mut user_policies = Vec::new();
+        let mut group_policies = Vec::new();
+        let mut role_policies = Vec::new();
+        let mut user_groups = Vec::new();
+        let mut cleanup = Vec::new();
+        let mut completed = 0;
+
+        while let Some((entity, name, policies, groups_for_user, credentials, entity_warnings)) =
+            results.next().await
+        {
+            match entity {
+                AttachmentEntity::User => user_policies.extend(policies),
+                AttachmentEntity::Group => group_policies.extend(policies),
+                AttachmentEntity::Role => role_policies.extend(policies),
+            }
+            user_groups.extend(groups_for_user);
+            cleanup.extend(credentials);
+            warnings.extend(entity_warnings);
+
+            completed += 1;
+            progress.report(
+                completed,
+                total,
+                format!(
+                    "アタッチメントのスキャン中: {}/{} ({} {})",
+                    completed,
+                    total,
+                    entity.label(),
+                    name
+                ),
+            );
+        }
 
-        // ロール名を抽出
-        let role_names: Vec<&str> = roles
-            .iter()
-            .filter_map(|r| r.get("role_name").and_then(|v| v.as_str()))
-            .collect();
+        let mut attachments = serde_json::Map::new();
+        attachments.insert("user_policies".to_string(), Value::Array(user_policies));
+        attachments.insert("group_policies".to_string(), Value::Array(group_policies));
+        attachments.insert("role_policies".to_string(), Value::Array(role_policies));
+        attachments.insert("user_groups".to_string(), Value::Array(user_groups));
 
-        // UserとPolicyの接続
-        let mut user_policies = Vec::new();
-        for user_name in &user_names {
-            // インラインポリシーを取得
-            if let Ok(inline_policies) = self.iam_client.list_user_policies(user_name).await {
+        Ok((Value::Object(attachments), cleanup))
+    }
+
+    /// エンティティのインラインポリシーとアタッチされたマネージドポリシーを取得
+    ///
+    /// 取得に失敗した場合（リトライ後も失敗した場合を含む）は`warnings`に記録して空として扱います。
+    async fn scan_entity_policies(
+        &self,
+        entity: AttachmentEntity,
+        name: &str,
+        warnings: &mut Vec<ScanWarning>,
+    ) -> Vec<Value> {
+        let name_field = entity.name_field();
+        let mut entries = Vec::new();
+
+        // インラインポリシーを取得
+        let list_operation = format!("List{}Policies", entity.label());
+        let inline_policies = with_throttling_retry(&list_operation, || async {
+            match entity {
+                AttachmentEntity::User => self.iam_client.list_user_policies(name).await,
+                AttachmentEntity::Group => self.iam_client.list_group_policies(name).await,
+                AttachmentEntity::Role => self.iam_client.list_role_policies(name).await,
+            }
+        })
+        .await;
+        match inline_policies {
+            Ok(inline_policies) => {
                 for policy_name in inline_policies {
-                    user_policies.push(json!({
-                        "user_name": user_name,
+                    let mut entry = json!({
+                        name_field: name,
                         "policy_name": policy_name,
                         "policy_type": "inline",
-                    }));
+                    });
+                    let get_operation = format!("Get{}Policy", entity.label());
+                    let policy_doc = with_throttling_retry(&get_operation, || async {
+                        match entity {
+                            AttachmentEntity::User => {
+                                self.iam_client.get_user_policy(name, &policy_name).await
+                            }
+                            AttachmentEntity::Group => {
+                                self.iam_client.get_group_policy(name, &policy_name).await
+                            }
+                            AttachmentEntity::Role => {
+                                self.iam_client.get_role_policy(name, &policy_name).await
+                            }
+                        }
+                    })
+                    .await;
+                    match policy_doc {
+                        Ok(Some(policy_doc)) => {
+                            if let Some(parsed_doc) =
+                                Self::decode_policy_document(&policy_doc.document)
+                            {
+                                entry["policy_document"] = json!(parsed_doc);
+                            }
+                        }
+                        Ok(None) => {}
+                        Err(e) => {
+                            warn!(entity = entity.label(), name, policy_name, error = %e, "インラインポリシーの取得に失敗");
+                            let e = e.context(format!("inline policy {}", policy_name));
+                            warnings.push(scan_warning(
+                                entity.resource_type(),
+                                name,
+                                &get_operation,
+                                &e,
+                            ));
+                        }
+                    }
+                    entries.push(entry);
                 }
             }
+            Err(e) => {
+                warn!(entity = entity.label(), name, error = %e, "インラインポリシーの取得に失敗");
+                warnings.push(scan_warning(
+                    entity.resource_type(),
+                    name,
+                    &list_operation,
+                    &e,
+                ));
+            }
+        }
 
-            // アタッチされたマネージドポリシーを取得
-            if let Ok(attached_policies) =
-                self.iam_client.list_attached_user_policies(user_name).await
-            {
+        // アタッチされたマネージドポリシーを取得
+        let attached_operation = format!("ListAttached{}Policies", entity.label());
+        let attached_policies = with_throttling_retry(&attached_operation, || async {
+            match entity {
+                AttachmentEntity::User => self.iam_client.list_attached_user_policies(name).await,
+                AttachmentEntity::Group => self.iam_client.list_attached_group_policies(name).await,
+                AttachmentEntity::Role => self.iam_client.list_attached_role_policies(name).await,
+            }
+        })
+        .await;
+        match attached_policies {
+            Ok(attached_policies) => {
                 for policy in attached_policies {
-                    user_policies.push(json!({
-                        "user_name": user_name,
+                    entries.push(json!({
+                        name_field: name,
                         "policy_arn": policy.policy_arn,
                         "policy_type": "managed",
                     }));
                 }
             }
+            Err(e) => {
+                warn!(entity = entity.label(), name, error = %e, "マネージドポリシーの取得に失敗");
+                warnings.push(scan_warning(
+                    entity.resource_type(),
+                    name,
+                    &attached_operation,
+                    &e,
+                ));
+            }
         }
-        attachments.insert("user_policies".to_string(), Value::Array(user_policies));
 
-        // GroupとPolicyの接続
-        let mut group_policies = Vec::new();
-        for group_name in &group_names {
-            // インラインポリシーを取得
-            if let Ok(inline_policies) = self.iam_client.list_group_policies(group_name).await {
-                for policy_name in inline_policies {
-                    group_policies.push(json!({
+        entries
+    }
+
+    /// ユーザーが所属するグループを取得
+    async fn scan_user_groups(
+        &self,
+        user_name: &str,
+        warnings: &mut Vec<ScanWarning>,
+    ) -> Vec<Value> {
+        match with_throttling_retry("ListGroupsForUser", || {
+            self.iam_client.list_groups_for_user(user_name)
+        })
+        .await
+        {
+            Ok(groups) => groups
+                .into_iter()
+                .map(|group_name| {
+                    json!({
+                        "user_name": user_name,
                         "group_name": group_name,
-                        "policy_name": policy_name,
-                        "policy_type": "inline",
-                    }));
-                }
+                    })
+                })
+                .collect(),
+            Err(e) => {
+                warn!(user_name, error = %e, "所属グループの取得に失敗");
+                warnings.push(scan_warning("user", user_name, "ListGroupsForUser", &e));
+                Vec::new()
             }
+        }
+    }
 
-            // アタッチされたマネージドポリシーを取得
-            if let Ok(attached_policies) = self
-                .iam_client
-                .list_attached_group_policies(group_name)
-                .await
-            {
-                for policy in attached_policies {
-                    group_policies.push(json!({
-                        "group_name": group_name,
-                        "policy_arn": policy.policy_arn,
-                        "policy_type": "managed",
-                    }));
+    /// ユーザーの認証情報（クリーンアップ対象）をスキャンするかどうか
+    fn scans_user_credentials(&self) -> bool {
+        self.config
+            .scan_targets
+            .get("cleanup")
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// `users`をスキャンせずに`cleanup`のみが対象の場合の認証情報を取得
+    ///
+    /// ユーザー名のみを列挙し、アタッチメントは取得せずに認証情報だけを返します。
+    async fn scan_cleanup_credentials(
+        &self,
+        warnings: &mut Vec<ScanWarning>,
+    ) -> Result<Vec<Value>> {
+        let user_names: Vec<String> = self
+            .iam_client
+            .list_users_with_options(false)
+            .await?
+            .into_iter()
+            .map(|user| user.user_name)
+            .filter(|name| self.apply_name_prefix_filter(name))
+            .collect();
+
+        let mut results = stream::iter(user_names)
+            .map(|name| async move {
+                let mut user_warnings = Vec::new();
+                let credentials = self.scan_user_credentials(&name, &mut user_warnings).await;
+                (credentials, user_warnings)
+            })
+            .buffered(self.config.max_concurrency());
+
+        let mut cleanup = Vec::new();
+        while let Some((credentials, user_warnings)) = results.next().await {
+            cleanup.extend(credentials);
+            warnings.extend(user_warnings);
+        }
+        Ok(cleanup)
+    }
+
+    /// ユーザーの認証情報（アクセスキー・ログインプロファイル・MFAデバイス）を取得
+    ///
+    /// `cleanup`配列の要素（`{type, resource}`形式）として返します。
+    /// メタデータのみを扱い、シークレットアクセスキーやパスワード等の秘密情報は含みません。
+    async fn scan_user_credentials(
+        &self,
+        user_name: &str,
+        warnings: &mut Vec<ScanWarning>,
+    ) -> Vec<Value> {
+        let mut entries = Vec::new();
+
+        match with_throttling_retry("ListAccessKeys", || {
+            self.iam_client.list_access_keys(user_name)
+        })
+        .await
+        {
+            Ok(access_keys) => {
+                for key in access_keys {
+                    let mut resource = json!({
+                        "user_name": key.user_name,
+                        "access_key_id": key.access_key_id,
+                        "status": key.status,
+                        "create_date": key.create_date,
+                    });
+                    if let Some(last_used_date) = key.last_used_date {
+                        resource["last_used_date"] = json!(last_used_date);
+                    }
+                    entries.push(json!({"type": "access_key", "resource": resource}));
                 }
             }
+            Err(e) => {
+                warn!(user_name, error = %e, "アクセスキーの取得に失敗");
+                warnings.push(scan_warning("user", user_name, "ListAccessKeys", &e));
+            }
         }
-        attachments.insert("group_policies".to_string(), Value::Array(group_policies));
 
-        // RoleとPolicyの接続
-        let mut role_policies = Vec::new();
-        for role_name in &role_names {
-            // インラインポリシーを取得
-            if let Ok(inline_policies) = self.iam_client.list_role_policies(role_name).await {
-                for policy_name in inline_policies {
-                    role_policies.push(json!({
-                        "role_name": role_name,
-                        "policy_name": policy_name,
-                        "policy_type": "inline",
+        match with_throttling_retry("GetLoginProfile", || {
+            self.iam_client.get_login_profile(user_name)
+        })
+        .await
+        {
+            Ok(Some(profile)) => entries.push(json!({
+                "type": "login_profile",
+                "resource": {
+                    "user_name": profile.user_name,
+                    "create_date": profile.create_date,
+                    "password_reset_required": profile.password_reset_required,
+                },
+            })),
+            Ok(None) => {}
+            Err(e) => {
+                warn!(user_name, error = %e, "ログインプロファイルの取得に失敗");
+                warnings.push(scan_warning("user", user_name, "GetLoginProfile", &e));
+            }
+        }
+
+        match with_throttling_retry("ListMFADevices", || {
+            self.iam_client.list_mfa_devices(user_name)
+        })
+        .await
+        {
+            Ok(devices) => {
+                for device in devices {
+                    entries.push(json!({
+                        "type": "mfa_device",
+                        "resource": {
+                            "user_name": device.user_name,
+                            "device_type": Self::mfa_device_type(&device.serial_number),
+                            "serial_number": device.serial_number,
+                            "enable_date": device.enable_date,
+                        },
                     }));
                 }
             }
+            Err(e) => {
+                warn!(user_name, error = %e, "MFAデバイスの取得に失敗");
+                warnings.push(scan_warning("user", user_name, "ListMFADevices", &e));
+            }
+        }
 
-            // アタッチされたマネージドポリシーを取得
-            if let Ok(attached_policies) =
-                self.iam_client.list_attached_role_policies(role_name).await
-            {
-                for policy in attached_policies {
-                    role_policies.push(json!({
-                        "role_name": role_name,
-                        "policy_arn": policy.policy_arn,
-                        "policy_type": "managed",
-                    }));
+        entries
+    }
+
+    /// MFAデバイスの種類を判定
+    ///
+    /// 仮想MFA・セキュリティキー（U2F）のシリアル番号はARN形式、
+    /// ハードウェアMFAはデバイスのシリアル番号そのものです。
+    fn mfa_device_type(serial_number: &str) -> &'static str {
+        if !serial_number.starts_with("arn:") {
+            "hardware"
+        } else if serial_number.contains(":u2f/") {
+            "u2f"
+        } else {
+            "virtual"
+        }
+    }
+
+    /// ユーザー/ロールに最終使用日時と経過日数を付与し、未使用IDレポートを作成
+    ///
+    /// ユーザーの最終使用日時はコンソールパスワードとアクセスキー（`cleanup`の
+    /// `access_key`エントリ）のうち最も新しいものです。一度も使用されていない場合は
+    /// 作成日時からの経過日数を`last_used_days`とします。
+    /// 経過日数が`threshold_days`を超えるものを`unused_identities`に列挙します。
+    /// アクセスキーをスキャンしていない場合（`access_keys_scanned`がfalse）は
+    /// ユーザーの最終使用日時が判定できないため、ユーザーには`last_used_unknown`を付与し、
+    /// 未使用の判定を行いません。
+    fn annotate_last_used(
+        results: &mut serde_json::Map<String, Value>,
+        now: i64,
+        threshold_days: u32,
+        access_keys_scanned: bool,
+    ) {
+        // ユーザーごとのアクセスキー最終使用日時（最も新しいもの）
+        let mut key_last_used: HashMap<String, i64> = HashMap::new();
+        if let Some(Value::Array(cleanup)) = results.get("cleanup") {
+            for entry in cleanup {
+                if entry.get("type").and_then(|v| v.as_str()) != Some("access_key") {
+                    continue;
+                }
+                let resource = &entry["resource"];
+                if let (Some(user_name), Some(last_used)) = (
+                    resource.get("user_name").and_then(|v| v.as_str()),
+                    resource.get("last_used_date").and_then(|v| v.as_i64()),
+                ) {
+                    let latest = key_last_used
+                        .entry(user_name.to_string())
+                        .or_insert(last_used);
+                    *latest = (*latest).max(last_used);
                 }
             }
         }
-        attachments.insert("role_policies".to_string(), Value::Array(role_policies));
 
-        // UserとGroupの接続
-        let mut user_groups = Vec::new();
-        for user_name in &user_names {
-            if let Ok(groups) = self.iam_client.list_groups_for_user(user_name).await {
-                for group_name in groups {
-                    user_groups.push(json!({
-                        "user_name": user_name,
-                        "group_name": group_name,
+        let mut unused_identities = Vec::new();
+        for (key, principal_type, name_field) in [
+            ("users", "user", "user_name"),
+            ("roles", "role", "role_name"),
+        ] {
+            let Some(Value::Array(items)) = results.get_mut(key) else {
+                continue;
+            };
+            for item in items.iter_mut() {
+                if principal_type == "user" && !access_keys_scanned {
+                    item["last_used_unknown"] = json!(true);
+                    continue;
+                }
+                let name = item
+                    .get(name_field)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string();
+                let last_used = match principal_type {
+                    "user" => item
+                        .get("password_last_used")
+                        .and_then(|v| v.as_i64())
+                        .into_iter()
+                        .chain(key_last_used.get(&name).copied())
+                        .max(),
+                    _ => item.get("last_used_date").and_then(|v| v.as_i64()),
+                };
+                let Some(since) = last_used.or_else(|| item.get("create_date")?.as_i64()) else {
+                    continue;
+                };
+                let last_used_days = (now - since).max(0) / 86_400;
+                let unused = last_used_days > i64::from(threshold_days);
+
+                if let Some(last_used) = last_used {
+                    item["last_used_date"] = json!(last_used);
+                }
+                item["last_used_days"] = json!(last_used_days);
+                item["unused"] = json!(unused);
+
+                if unused {
+                    unused_identities.push(json!({
+                        "principal_type": principal_type,
+                        "name": name,
+                        "arn": item.get("arn").cloned().unwrap_or(Value::Null),
+                        "last_used_date": last_used,
+                        "last_used_days": last_used_days,
+                        "never_used": last_used.is_none(),
                     }));
                 }
             }
         }
-        attachments.insert("user_groups".to_string(), Value::Array(user_groups));
 
-        Ok(Value::Object(attachments))
+        results.insert(
+            "unused_identities".to_string(),
+            Value::Array(unused_identities),
+        );
     }
 
-    /// クリーンアップ処理（ポリシードキュメントを補完）
-    async fn scan_cleanup(&self, results: &mut serde_json::Map<String, Value>) -> Result<()> {
-        // Policiesにポリシードキュメントを追加
-        if let Some(Value::Array(policies)) = results.get_mut("policies") {
-            for policy in policies.iter_mut() {
-                if let Some(policy_arn) = policy.get("arn").and_then(|v| v.as_str()) {
-                    if let Some(default_version_id) = policy
-                        .get("default_version_id")
-                        .and_then(|v| v.as_str())
-                        .filter(|s| !s.is_empty())
+    /// ユーザー/ロールにサービス最終アクセス情報（`service_last_accessed`）を補完
+    ///
+    /// 取得に失敗した場合は`warnings`に記録してスキップします。
+    async fn fill_service_last_accessed(
+        &self,
+        results: &mut serde_json::Map<String, Value>,
+        warnings: &mut Vec<ScanWarning>,
+    ) {
+        let mut targets = Vec::new();
+        for (key, entity) in [
+            ("users", AttachmentEntity::User),
+            ("roles", AttachmentEntity::Role),
+        ] {
+            if let Some(Value::Array(items)) = results.get(key) {
+                for (index, item) in items.iter().enumerate() {
+                    if let Some(arn) = item.get("arn").and_then(|v| v.as_str()) {
+                        let name = item
+                            .get(entity.name_field())
+                            .and_then(|v| v.as_str())
+                            .unwrap_or(arn);
+                        targets.push((entity, index, name.to_string(), arn.to_string()));
+                    }
+                }
+            }
+        }
+
+        debug!(
+            count = targets.len(),
+            "サービス最終アクセス情報の取得を開始"
+        );
+        let mut fetched = stream::iter(targets)
+            .map(|(entity, index, name, arn)| async move {
+                let result = self.scan_service_last_accessed(&arn).await;
+                (entity, index, name, result)
+            })
+            .buffered(self.config.max_concurrency());
+
+        while let Some((entity, index, name, result)) = fetched.next().await {
+            let key = match entity {
+                AttachmentEntity::User => "users",
+                AttachmentEntity::Group => "groups",
+                AttachmentEntity::Role => "roles",
+            };
+            match result {
+                Ok(services) => {
+                    if let Some(item) = results
+                        .get_mut(key)
+                        .and_then(|v| v.as_array_mut())
+                        .and_then(|items| items.get_mut(index))
                     {
-                        if let Ok(Some(policy_doc)) = self
-                            .iam_client
-                            .get_policy_version(policy_arn, default_version_id)
-                            .await
-                        {
-                            // URLデコードしてJSONパース
-                            if let Ok(decoded) = urlencoding::decode(&policy_doc.document) {
-                                if let Ok(parsed_doc) =
-                                    serde_json::from_str::<IamPolicyDocument>(&decoded)
-                                {
-                                    policy
-                                        .as_object_mut()
-                                        .unwrap()
-                                        .insert("policy_document".to_string(), json!(parsed_doc));
-                                }
-                            }
-                        }
+                        item["service_last_accessed"] = Value::Array(services);
                     }
                 }
+                Err(e) => {
+                    warn!(name, error = %e, "サービス最終アクセス情報の取得に失敗");
+                    warnings.push(scan_warning(
+                        entity.resource_type(),
+                        &name,
+                        "GetServiceLastAccessedDetails",
+                        &e,
+                    ));
+                }
             }
         }
+    }
+
+    /// ユーザー/ロールのサービス最終アクセス情報を取得
+    async fn scan_service_last_accessed(&self, arn: &str) -> Result<Vec<Value>> {
+        let services = with_throttling_retry("GetServiceLastAccessedDetails", || {
+            self.iam_client.get_service_last_accessed(arn)
+        })
+        .await?;
+
+        Ok(services
+            .into_iter()
+            .map(|service| {
+                let mut service_json = json!({
+                    "service_name": service.service_name,
+                    "service_namespace": service.service_namespace,
+                });
+                if let Some(last_authenticated) = service.last_authenticated {
+                    service_json["last_authenticated"] = json!(last_authenticated);
+                }
+                if let Some(entity) = service.last_authenticated_entity {
+                    service_json["last_authenticated_entity"] = json!(entity);
+                }
+                if let Some(region) = service.last_authenticated_region {
+                    service_json["last_authenticated_region"] = json!(region);
+                }
+                service_json
+            })
+            .collect())
+    }
+
+    /// マネージドポリシーにデフォルトバージョンのポリシードキュメントを補完
+    ///
+    /// 同時実行数を制限して並列に取得し、失敗したポリシーは`warnings`に記録して
+    /// ドキュメントなしのまま残します。
+    async fn fill_policy_documents(
+        &self,
+        results: &mut serde_json::Map<String, Value>,
+        warnings: &mut Vec<ScanWarning>,
+    ) -> Result<()> {
+        let Some(Value::Array(policies)) = results.get_mut("policies") else {
+            return Ok(());
+        };
+
+        let mut filled = stream::iter(std::mem::take(policies))
+            .map(|mut policy| async move {
+                let policy_arn = policy.get("arn").and_then(|v| v.as_str());
+                let default_version_id = policy
+                    .get("default_version_id")
+                    .and_then(|v| v.as_str())
+                    .filter(|s| !s.is_empty());
+                let (Some(policy_arn), Some(default_version_id)) = (policy_arn, default_version_id)
+                else {
+                    return (policy, None);
+                };
+                let (policy_arn, default_version_id) =
+                    (policy_arn.to_string(), default_version_id.to_string());
+
+                let warning = match with_throttling_retry("GetPolicyVersion", || {
+                    self.iam_client
+                        .get_policy_version(&policy_arn, &default_version_id)
+                })
+                .await
+                {
+                    Ok(Some(policy_doc)) => {
+                        if let Some(parsed_doc) = Self::decode_policy_document(&policy_doc.document)
+                        {
+                            policy["policy_document"] = json!(parsed_doc);
+                        }
+                        None
+                    }
+                    Ok(None) => None,
+                    Err(e) => {
+                        warn!(policy_arn, error = %e, "ポリシードキュメントの取得に失敗");
+                        Some(scan_warning("policy", &policy_arn, "GetPolicyVersion", &e))
+                    }
+                };
+                (policy, warning)
+            })
+            .buffered(self.config.max_concurrency());
+
+        let mut documents = Vec::new();
+        while let Some((policy, warning)) = filled.next().await {
+            documents.push(policy);
+            warnings.extend(warning);
+        }
+        *policies = documents;
 
         Ok(())
     }
 
+    /// URLエンコードされたポリシードキュメントをデコードしてパース
+    ///
+    /// IAM APIはポリシードキュメントをURLエンコードされたJSON文字列として返すため、
+    /// デコード後に`IamPolicyDocument`としてパースします。
+    pub fn decode_policy_document(document: &str) -> Option<IamPolicyDocument> {
+        let decoded = urlencoding::decode(document).ok()?;
+        match serde_json::from_str::<IamPolicyDocument>(&decoded) {
+            Ok(parsed_doc) => Some(parsed_doc),
+            Err(e) => {
+                warn!("Failed to parse policy document: {}", e);
+                None
+            }
+        }
+    }
+
     /// AssumeRoleポリシーをパース
     pub fn parse_assume_role_policy(policy_doc: &str) -> Vec<Value> {
         if policy_doc.is_empty() {
@@ -629,10 +2127,23 @@ impl<C: IamClientOps> AwsIamScanner<C> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::infra::aws::ec2::ec2_client_trait::mock::MockEc2Client;
+    use crate::infra::aws::ec2::ec2_client_trait::SubnetInfo;
     use crate::infra::aws::iam_client_trait::mock::MockIamClient;
     use crate::infra::aws::iam_client_trait::{
-        IamGroupInfo, IamPolicyInfo, IamRoleInfo, IamUserInfo,
+        IamAccessKeyInfo, IamEntityDetail, IamGroupInfo, IamInstanceProfileInfo,
+        IamLoginProfileInfo, IamMfaDeviceInfo, IamOidcProviderInfo, IamPasswordPolicyInfo,
+        IamPolicyInfo, IamRoleInfo, IamSamlProviderInfo, IamServiceLastAccessedInfo, IamUserInfo,
+        PolicyAttachment, PolicyDocument,
     };
+    use crate::infra::aws::kms_client_trait::mock::MockKmsClient;
+    use crate::infra::aws::organizations_client_trait::mock::MockOrganizationsClient;
+    use crate::infra::aws::organizations_client_trait::OrganizationPolicySummary;
+    use crate::infra::aws::s3_client_trait::mock::MockS3Client;
+    use crate::infra::aws::sns_client_trait::mock::MockSnsClient;
+    use crate::infra::aws::sqs_client_trait::mock::MockSqsClient;
+    use crate::infra::aws::sso_admin_client_trait::mock::MockSsoAdminClient;
+    use crate::infra::aws::sso_admin_client_trait::SsoInstanceInfo;
     use std::collections::HashMap;
 
     fn create_test_config(
@@ -645,6 +2156,15 @@ mod tests {
             profile: None,
             assume_role_arn: None,
             assume_role_session_name: None,
+            assume_role_external_id: None,
+            assume_role_duration_seconds: None,
+            mfa_serial: None,
+            mfa_token_code: None,
+            account_ids: Vec::new(),
+            discover_accounts: false,
+            member_role_name: None,
+            region: None,
+            endpoint_url: None,
             subscription_id: None,
             tenant_id: None,
             auth_method: None,
@@ -654,6 +2174,20 @@ mod tests {
             scan_targets,
             filters,
             include_tags: true,
+            max_concurrency: None,
+            scan_strategy: None,
+            include_service_last_accessed: false,
+            unused_threshold_days: None,
+        }
+    }
+
+    fn ignore_progress(_: ScanProgress) {}
+
+    fn no_progress() -> AttachmentProgress<'static> {
+        AttachmentProgress {
+            callback: &ignore_progress,
+            start: 0,
+            end: 100,
         }
     }
 
@@ -880,6 +2414,9 @@ mod tests {
     async fn test_scan_users_returns_filtered_users() {
         let mut mock_client = MockIamClient::new();
 
+        mock_client
+            .expect_get_user_detail()
+            .returning(|_, _| Ok(None));
         mock_client.expect_list_users_with_options().returning(|_| {
             Ok(vec![
                 IamUserInfo {
@@ -889,6 +2426,8 @@ mod tests {
                     create_date: 1609459200,
                     path: "/".to_string(),
                     tags: HashMap::new(),
+                    permissions_boundary: None,
+                    password_last_used: None,
                 },
                 IamUserInfo {
                     user_name: "prod-user".to_string(),
@@ -897,6 +2436,8 @@ mod tests {
                     create_date: 1609459200,
                     path: "/".to_string(),
                     tags: HashMap::new(),
+                    permissions_boundary: None,
+                    password_last_used: None,
                 },
                 IamUserInfo {
                     user_name: "test-user-2".to_string(),
@@ -909,6 +2450,8 @@ mod tests {
                         tags.insert("Environment".to_string(), "test".to_string());
                         tags
                     },
+                    permissions_boundary: None,
+                    password_last_used: None,
                 },
             ])
         });
@@ -921,7 +2464,7 @@ mod tests {
             mock_client,
         );
 
-        let users = scanner.scan_users().await.unwrap();
+        let users = scanner.scan_users(&mut Vec::new()).await.unwrap();
 
         // test-で始まるユーザーのみが返される
         assert_eq!(users.len(), 2);
@@ -970,6 +2513,128 @@ mod tests {
         assert_eq!(groups[1]["group_name"], "admins");
     }
 
+    // ========================================
+    // scan_instance_profiles のモックテスト
+    // ========================================
+
+    #[tokio::test]
+    async fn test_scan_instance_profiles_with_role() {
+        let mut mock_client = MockIamClient::new();
+
+        mock_client.expect_list_instance_profiles().returning(|| {
+            Ok(vec![
+                IamInstanceProfileInfo {
+                    instance_profile_name: "web-profile".to_string(),
+                    instance_profile_id: "AIPA1234567890".to_string(),
+                    arn: "arn:aws:iam::123456789012:instance-profile/web-profile".to_string(),
+                    create_date: 1609459200,
+                    path: "/".to_string(),
+                    role_names: vec!["web-role".to_string()],
+                },
+                IamInstanceProfileInfo {
+                    instance_profile_name: "empty-profile".to_string(),
+                    instance_profile_id: "AIPA0987654321".to_string(),
+                    arn: "arn:aws:iam::123456789012:instance-profile/empty-profile".to_string(),
+                    create_date: 1609459200,
+                    path: "/".to_string(),
+                    role_names: vec![],
+                },
+            ])
+        });
+
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), HashMap::new()),
+            mock_client,
+        );
+
+        let instance_profiles = scanner.scan_instance_profiles().await.unwrap();
+
+        assert_eq!(instance_profiles.len(), 2);
+        assert_eq!(instance_profiles[0]["instance_profile_name"], "web-profile");
+        assert_eq!(instance_profiles[0]["role_name"], "web-role");
+        assert!(instance_profiles[1].get("role_name").is_none());
+    }
+
+    // ========================================
+    // scan_oidc_providers / scan_saml_providers のモックテスト
+    // ========================================
+
+    #[tokio::test]
+    async fn test_scan_oidc_providers_adds_https_scheme() {
+        let mut mock_client = MockIamClient::new();
+
+        mock_client
+            .expect_list_open_id_connect_providers()
+            .returning(|| {
+                Ok(vec![IamOidcProviderInfo {
+                    arn: "arn:aws:iam::123456789012:oidc-provider/token.actions.githubusercontent.com"
+                        .to_string(),
+                    url: "token.actions.githubusercontent.com".to_string(),
+                    client_id_list: vec!["sts.amazonaws.com".to_string()],
+                    thumbprint_list: vec!["6938fd4d98bab03faadb97b34396831e3780aea1".to_string()],
+                    create_date: 1609459200,
+                    tags: HashMap::new(),
+                }])
+            });
+
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), HashMap::new()),
+            mock_client,
+        );
+
+        let providers = scanner.scan_oidc_providers().await.unwrap();
+
+        assert_eq!(providers.len(), 1);
+        assert_eq!(
+            providers[0]["url"],
+            "https://token.actions.githubusercontent.com"
+        );
+        assert_eq!(providers[0]["client_id_list"][0], "sts.amazonaws.com");
+        assert!(providers[0].get("tags").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_scan_saml_providers_with_prefix_filter() {
+        let mut mock_client = MockIamClient::new();
+
+        mock_client.expect_list_saml_providers().returning(|| {
+            Ok(vec![
+                IamSamlProviderInfo {
+                    arn: "arn:aws:iam::123456789012:saml-provider/corp-okta".to_string(),
+                    name: "corp-okta".to_string(),
+                    saml_metadata_document: "<EntityDescriptor/>".to_string(),
+                    create_date: 1609459200,
+                    valid_until: 1893456000,
+                    tags: HashMap::new(),
+                },
+                IamSamlProviderInfo {
+                    arn: "arn:aws:iam::123456789012:saml-provider/legacy-adfs".to_string(),
+                    name: "legacy-adfs".to_string(),
+                    saml_metadata_document: "<EntityDescriptor/>".to_string(),
+                    create_date: 1609459200,
+                    valid_until: 1893456000,
+                    tags: HashMap::new(),
+                },
+            ])
+        });
+
+        let mut filters = HashMap::new();
+        filters.insert("name_prefix".to_string(), "corp-".to_string());
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(filters, HashMap::new()),
+            mock_client,
+        );
+
+        let providers = scanner.scan_saml_providers().await.unwrap();
+
+        assert_eq!(providers.len(), 1);
+        assert_eq!(providers[0]["name"], "corp-okta");
+        assert_eq!(
+            providers[0]["saml_metadata_document"],
+            "<EntityDescriptor/>"
+        );
+    }
+
     // ========================================
     // scan_roles のモックテスト
     // ========================================
@@ -978,6 +2643,9 @@ mod tests {
     async fn test_scan_roles_with_assume_role_policy() {
         let mut mock_client = MockIamClient::new();
 
+        mock_client
+            .expect_get_role_detail()
+            .returning(|_, _| Ok(None));
         mock_client.expect_list_roles_with_options().returning(|_| {
             Ok(vec![
                 IamRoleInfo {
@@ -988,6 +2656,11 @@ mod tests {
                     path: "/service-role/".to_string(),
                     assume_role_policy_document: Some(r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow","Principal":{"Service":"lambda.amazonaws.com"},"Action":"sts:AssumeRole"}]}"#.to_string()),
                     tags: HashMap::new(),
+                    description: None,
+                    max_session_duration: None,
+                    permissions_boundary: None,
+                    last_used_date: None,
+                    last_used_region: None,
                 },
             ])
         });
@@ -997,7 +2670,7 @@ mod tests {
             mock_client,
         );
 
-        let roles = scanner.scan_roles().await.unwrap();
+        let roles = scanner.scan_roles(&mut Vec::new()).await.unwrap();
 
         assert_eq!(roles.len(), 1);
         assert_eq!(roles[0]["role_name"], "lambda-execution-role");
@@ -1022,6 +2695,9 @@ mod tests {
         let encoded_policy = "%7B%22Version%22%3A%222012-10-17%22%2C%22Statement%22%3A%5B%7B%22Effect%22%3A%22Allow%22%2C%22Principal%22%3A%7B%22Service%22%3A%22lambda.amazonaws.com%22%7D%2C%22Action%22%3A%22sts%3AAssumeRole%22%7D%5D%7D";
         let decoded_policy = r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow","Principal":{"Service":"lambda.amazonaws.com"},"Action":"sts:AssumeRole"}]}"#;
 
+        mock_client
+            .expect_get_role_detail()
+            .returning(|_, _| Ok(None));
         mock_client
             .expect_list_roles_with_options()
             .returning(move |_| {
@@ -1033,6 +2709,11 @@ mod tests {
                     path: "/service-role/".to_string(),
                     assume_role_policy_document: Some(encoded_policy.to_string()),
                     tags: HashMap::new(),
+                    description: None,
+                    max_session_duration: None,
+                    permissions_boundary: None,
+                    last_used_date: None,
+                    last_used_region: None,
                 }])
             });
 
@@ -1041,7 +2722,7 @@ mod tests {
             mock_client,
         );
 
-        let roles = scanner.scan_roles().await.unwrap();
+        let roles = scanner.scan_roles(&mut Vec::new()).await.unwrap();
 
         assert_eq!(roles.len(), 1);
 
@@ -1057,19 +2738,192 @@ mod tests {
         assert!(serde_json::from_str::<serde_json::Value>(stored_policy).is_ok());
     }
 
-    // ========================================
-    // scan_policies のモックテスト
-    // ========================================
-
     #[tokio::test]
-    async fn test_scan_policies_returns_local_policies() {
+    async fn test_scan_roles_with_boundary_and_session_settings() {
         let mut mock_client = MockIamClient::new();
 
-        mock_client.expect_list_policies().returning(|| {
-            Ok(vec![IamPolicyInfo {
-                policy_name: "CustomS3Policy".to_string(),
-                policy_id: "ANPA1234567890".to_string(),
-                arn: "arn:aws:iam::123456789012:policy/CustomS3Policy".to_string(),
+        mock_client
+            .expect_get_role_detail()
+            .returning(|_, _| Ok(None));
+        mock_client.expect_list_roles_with_options().returning(|_| {
+            Ok(vec![IamRoleInfo {
+                role_name: "guarded-role".to_string(),
+                role_id: "AROA1234567890".to_string(),
+                arn: "arn:aws:iam::123456789012:role/guarded-role".to_string(),
+                create_date: 1609459200,
+                path: "/".to_string(),
+                assume_role_policy_document: None,
+                tags: HashMap::new(),
+                description: Some("Guarded by boundary".to_string()),
+                max_session_duration: Some(43200),
+                permissions_boundary: Some(
+                    "arn:aws:iam::123456789012:policy/guardrail-boundary".to_string(),
+                ),
+                last_used_date: None,
+                last_used_region: None,
+            }])
+        });
+
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), HashMap::new()),
+            mock_client,
+        );
+
+        let roles = scanner.scan_roles(&mut Vec::new()).await.unwrap();
+
+        assert_eq!(roles[0]["description"], "Guarded by boundary");
+        assert_eq!(roles[0]["max_session_duration"], 43200);
+        assert_eq!(
+            roles[0]["permissions_boundary"],
+            "arn:aws:iam::123456789012:policy/guardrail-boundary"
+        );
+    }
+
+    fn mock_with_aws_managed_roles() -> MockIamClient {
+        let mut mock_client = MockIamClient::new();
+        mock_client
+            .expect_get_role_detail()
+            .returning(|_, _| Ok(None));
+        mock_client.expect_list_roles_with_options().returning(|_| {
+            let role = |name: &str, path: &str| IamRoleInfo {
+                role_name: name.to_string(),
+                role_id: format!("AROA{}", name),
+                arn: format!("arn:aws:iam::123456789012:role{}{}", path, name),
+                create_date: 1609459200,
+                path: path.to_string(),
+                assume_role_policy_document: None,
+                tags: HashMap::new(),
+                description: None,
+                max_session_duration: None,
+                permissions_boundary: None,
+                last_used_date: None,
+                last_used_region: None,
+            };
+            Ok(vec![
+                role("app-role", "/"),
+                role(
+                    "AWSServiceRoleForAutoScaling_custom",
+                    "/aws-service-role/autoscaling.amazonaws.com/",
+                ),
+                role(
+                    "AWSReservedSSO_AdministratorAccess_0123456789abcdef",
+                    "/aws-reserved/sso.amazonaws.com/",
+                ),
+            ])
+        });
+        mock_client
+    }
+
+    #[tokio::test]
+    async fn test_scan_roles_classifies_aws_managed_roles() {
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), HashMap::new()),
+            mock_with_aws_managed_roles(),
+        );
+
+        let roles = scanner.scan_roles(&mut Vec::new()).await.unwrap();
+
+        // 予約ロールは既定でスキップされる
+        assert_eq!(roles.len(), 2);
+        assert_eq!(roles[0]["role_category"], "standard");
+        assert!(roles[0].get("aws_service_name").is_none());
+        assert_eq!(roles[1]["role_category"], "service_linked");
+        assert_eq!(roles[1]["aws_service_name"], "autoscaling.amazonaws.com");
+        assert_eq!(roles[1]["custom_suffix"], "custom");
+    }
+
+    #[tokio::test]
+    async fn test_scan_roles_aws_managed_role_filters_override_defaults() {
+        let mut filters = HashMap::new();
+        filters.insert("service_linked_roles".to_string(), "skip".to_string());
+        filters.insert("reserved_roles".to_string(), "include".to_string());
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(filters, HashMap::new()),
+            mock_with_aws_managed_roles(),
+        );
+
+        let roles = scanner.scan_roles(&mut Vec::new()).await.unwrap();
+
+        let categories: Vec<&str> = roles
+            .iter()
+            .map(|role| role["role_category"].as_str().unwrap())
+            .collect();
+        assert_eq!(categories, vec!["standard", "reserved"]);
+    }
+
+    #[tokio::test]
+    async fn test_scan_account_settings() {
+        let mut mock_client = MockIamClient::new();
+        mock_client
+            .expect_get_account_password_policy()
+            .returning(|| {
+                Ok(Some(IamPasswordPolicyInfo {
+                    minimum_password_length: Some(14),
+                    require_symbols: true,
+                    require_numbers: true,
+                    require_uppercase_characters: true,
+                    require_lowercase_characters: true,
+                    allow_users_to_change_password: true,
+                    max_password_age: Some(90),
+                    password_reuse_prevention: Some(24),
+                    hard_expiry: None,
+                }))
+            });
+        mock_client
+            .expect_list_account_aliases()
+            .returning(|| Ok(vec!["example-prod".to_string()]));
+
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), HashMap::new()),
+            mock_client,
+        );
+
+        let (password_policy, account_aliases) = scanner.scan_account_settings().await.unwrap();
+
+        assert_eq!(password_policy.len(), 1);
+        assert_eq!(password_policy[0]["minimum_password_length"], 14);
+        assert_eq!(password_policy[0]["max_password_age"], 90);
+        assert!(password_policy[0].get("hard_expiry").is_none());
+        assert_eq!(
+            account_aliases,
+            vec![json!({"account_alias": "example-prod"})]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scan_account_settings_without_password_policy() {
+        let mut mock_client = MockIamClient::new();
+        mock_client
+            .expect_get_account_password_policy()
+            .returning(|| Ok(None));
+        mock_client
+            .expect_list_account_aliases()
+            .returning(|| Ok(vec![]));
+
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), HashMap::new()),
+            mock_client,
+        );
+
+        let (password_policy, account_aliases) = scanner.scan_account_settings().await.unwrap();
+
+        assert!(password_policy.is_empty());
+        assert!(account_aliases.is_empty());
+    }
+
+    // ========================================
+    // scan_policies のモックテスト
+    // ========================================
+
+    #[tokio::test]
+    async fn test_scan_policies_returns_local_policies() {
+        let mut mock_client = MockIamClient::new();
+
+        mock_client.expect_list_policies().returning(|| {
+            Ok(vec![IamPolicyInfo {
+                policy_name: "CustomS3Policy".to_string(),
+                policy_id: "ANPA1234567890".to_string(),
+                arn: "arn:aws:iam::123456789012:policy/CustomS3Policy".to_string(),
                 path: "/".to_string(),
                 default_version_id: "v1".to_string(),
                 attachment_count: 2,
@@ -1084,11 +2938,759 @@ mod tests {
             mock_client,
         );
 
-        let policies = scanner.scan_policies().await.unwrap();
+        let policies = scanner.scan_policies().await.unwrap();
+
+        assert_eq!(policies.len(), 1);
+        assert_eq!(policies[0]["policy_name"], "CustomS3Policy");
+        assert_eq!(policies[0]["attachment_count"], 2);
+    }
+
+    // ========================================
+    // インラインポリシーのテスト
+    // ========================================
+
+    #[tokio::test]
+    async fn test_scan_attachments_fetches_inline_policy_documents() {
+        let mut mock_client = MockIamClient::new();
+
+        mock_client
+            .expect_list_role_policies()
+            .returning(|_| Ok(vec!["s3-read".to_string()]));
+        mock_client
+            .expect_list_attached_role_policies()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_get_role_policy()
+            .withf(|role_name, policy_name| role_name == "app-role" && policy_name == "s3-read")
+            .returning(|_, _| {
+                Ok(Some(PolicyDocument {
+                    document: "%7B%22Version%22%3A%222012-10-17%22%2C%22Statement%22%3A%5B%7B%22Effect%22%3A%22Allow%22%2C%22Action%22%3A%22s3%3AGetObject%22%2C%22Resource%22%3A%22*%22%7D%5D%7D".to_string(),
+                }))
+            });
+
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), HashMap::new()),
+            mock_client,
+        );
+
+        let roles = vec![json!({"role_name": "app-role"})];
+        let (attachments, _) = scanner
+            .scan_attachments_with_data(&[], &[], &roles, &no_progress(), &mut Vec::new())
+            .await
+            .unwrap();
+
+        let role_policies = attachments["role_policies"].as_array().unwrap();
+        assert_eq!(role_policies.len(), 1);
+        assert_eq!(role_policies[0]["policy_type"], "inline");
+        assert_eq!(role_policies[0]["policy_name"], "s3-read");
+        assert_eq!(
+            role_policies[0]["policy_document"]["Statement"][0]["Action"],
+            "s3:GetObject"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scan_attachments_keeps_inline_policy_without_document() {
+        let mut mock_client = MockIamClient::new();
+
+        mock_client
+            .expect_list_user_policies()
+            .returning(|_| Ok(vec!["broken".to_string()]));
+        mock_client
+            .expect_list_attached_user_policies()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_get_user_policy()
+            .returning(|_, _| Err(anyhow::anyhow!("AccessDenied")));
+        mock_client
+            .expect_list_groups_for_user()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_list_access_keys()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_get_login_profile()
+            .returning(|_| Ok(None));
+        mock_client
+            .expect_list_mfa_devices()
+            .returning(|_| Ok(vec![]));
+
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), HashMap::new()),
+            mock_client,
+        );
+
+        let users = vec![json!({"user_name": "alice"})];
+        let (attachments, _) = scanner
+            .scan_attachments_with_data(&users, &[], &[], &no_progress(), &mut Vec::new())
+            .await
+            .unwrap();
+
+        let user_policies = attachments["user_policies"].as_array().unwrap();
+        assert_eq!(user_policies.len(), 1);
+        assert_eq!(user_policies[0]["policy_name"], "broken");
+        assert!(user_policies[0].get("policy_document").is_none());
+    }
+
+    fn mock_with_user_credentials() -> MockIamClient {
+        let mut mock_client = MockIamClient::new();
+        mock_client
+            .expect_list_user_policies()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_list_attached_user_policies()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_list_groups_for_user()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_list_access_keys()
+            .returning(|user_name| {
+                Ok(vec![IamAccessKeyInfo {
+                    user_name: user_name.to_string(),
+                    access_key_id: "AKIAEXAMPLE".to_string(),
+                    status: "Active".to_string(),
+                    create_date: 1_700_000_000,
+                    last_used_date: Some(1_710_000_000),
+                }])
+            });
+        mock_client
+            .expect_get_login_profile()
+            .returning(|user_name| {
+                Ok(Some(IamLoginProfileInfo {
+                    user_name: user_name.to_string(),
+                    create_date: 1_700_000_000,
+                    password_reset_required: true,
+                }))
+            });
+        mock_client
+            .expect_list_mfa_devices()
+            .returning(|user_name| {
+                Ok(vec![
+                    IamMfaDeviceInfo {
+                        user_name: user_name.to_string(),
+                        serial_number: format!("arn:aws:iam::123456789012:mfa/{}", user_name),
+                        enable_date: 1_700_000_000,
+                    },
+                    IamMfaDeviceInfo {
+                        user_name: user_name.to_string(),
+                        serial_number: "GAHT12345678".to_string(),
+                        enable_date: 1_700_000_000,
+                    },
+                ])
+            });
+        mock_client
+    }
+
+    #[tokio::test]
+    async fn test_scan_attachments_collects_user_credentials() {
+        let mut scan_targets = HashMap::new();
+        scan_targets.insert("cleanup".to_string(), true);
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), scan_targets),
+            mock_with_user_credentials(),
+        );
+
+        let users = vec![json!({"user_name": "alice"})];
+        let (_, cleanup) = scanner
+            .scan_attachments_with_data(&users, &[], &[], &no_progress(), &mut Vec::new())
+            .await
+            .unwrap();
+
+        let types: Vec<&str> = cleanup
+            .iter()
+            .map(|entry| entry["type"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            types,
+            vec!["access_key", "login_profile", "mfa_device", "mfa_device"]
+        );
+
+        let access_key = &cleanup[0]["resource"];
+        assert_eq!(access_key["user_name"], "alice");
+        assert_eq!(access_key["access_key_id"], "AKIAEXAMPLE");
+        assert_eq!(access_key["status"], "Active");
+        assert_eq!(access_key["last_used_date"], 1_710_000_000);
+        assert_eq!(cleanup[1]["resource"]["password_reset_required"], true);
+        assert_eq!(cleanup[2]["resource"]["device_type"], "virtual");
+        assert_eq!(cleanup[3]["resource"]["device_type"], "hardware");
+
+        // 秘密情報は一切含まれない
+        let serialized = serde_json::to_string(&cleanup).unwrap();
+        assert!(!serialized.contains("secret"));
+        assert!(!serialized.contains("password\""));
+    }
+
+    #[tokio::test]
+    async fn test_scan_attachments_skips_user_credentials_unless_cleanup_targeted() {
+        let mut mock_client = MockIamClient::new();
+        mock_client
+            .expect_list_user_policies()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_list_attached_user_policies()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_list_groups_for_user()
+            .returning(|_| Ok(vec![]));
+        mock_client.expect_list_access_keys().times(0);
+        mock_client.expect_get_login_profile().times(0);
+        mock_client.expect_list_mfa_devices().times(0);
+
+        let mut scan_targets = HashMap::new();
+        scan_targets.insert("users".to_string(), true);
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), scan_targets),
+            mock_client,
+        );
+
+        let users = vec![json!({"user_name": "alice"})];
+        let (_, cleanup) = scanner
+            .scan_attachments_with_data(&users, &[], &[], &no_progress(), &mut Vec::new())
+            .await
+            .unwrap();
+
+        assert!(cleanup.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_scan_collects_user_credentials_when_only_cleanup_targeted() {
+        let mut mock_client = mock_with_user_credentials();
+        mock_client.expect_list_users_with_options().returning(|_| {
+            Ok(vec![IamUserInfo {
+                user_name: "alice".to_string(),
+                user_id: "id1".to_string(),
+                arn: "arn:aws:iam::123456789012:user/alice".to_string(),
+                create_date: 0,
+                path: "/".to_string(),
+                tags: HashMap::new(),
+                permissions_boundary: None,
+                password_last_used: None,
+            }])
+        });
+
+        let mut scan_targets = HashMap::new();
+        scan_targets.insert("cleanup".to_string(), true);
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), scan_targets),
+            mock_client,
+        );
+
+        let result = scanner.scan(Box::new(|_| {})).await.unwrap();
+
+        assert!(result["users"].as_array().unwrap().is_empty());
+        let types: Vec<&str> = result["cleanup"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["type"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            types,
+            vec!["access_key", "login_profile", "mfa_device", "mfa_device"]
+        );
+        assert_eq!(result["cleanup"][0]["resource"]["user_name"], "alice");
+    }
+
+    #[tokio::test]
+    async fn test_scan_user_credentials_records_warning_on_failure() {
+        let mut mock_client = MockIamClient::new();
+        mock_client
+            .expect_list_access_keys()
+            .returning(|_| Err(anyhow::anyhow!("AccessDenied")));
+        mock_client
+            .expect_get_login_profile()
+            .returning(|_| Ok(None));
+        mock_client
+            .expect_list_mfa_devices()
+            .returning(|_| Ok(vec![]));
+
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), HashMap::new()),
+            mock_client,
+        );
+
+        let mut warnings = Vec::new();
+        let entries = scanner.scan_user_credentials("alice", &mut warnings).await;
+
+        assert!(entries.is_empty());
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].resource_type, "user");
+        assert_eq!(warnings[0].operation, "ListAccessKeys");
+    }
+
+    // ========================================
+    // 最終使用日時・未使用ID判定のテスト
+    // ========================================
+
+    const DAY: i64 = 86_400;
+
+    #[test]
+    fn test_annotate_last_used_skips_users_without_access_key_scan() {
+        let now = 1_700_000_000;
+        let mut results = serde_json::Map::new();
+        results.insert(
+            "users".to_string(),
+            json!([
+                {"user_name": "alice", "arn": "arn:aws:iam::123456789012:user/alice",
+                 "create_date": now - 400 * DAY},
+            ]),
+        );
+        results.insert(
+            "roles".to_string(),
+            json!([
+                {"role_name": "deploy", "arn": "arn:aws:iam::123456789012:role/deploy",
+                 "create_date": now - 400 * DAY},
+            ]),
+        );
+
+        AwsIamScanner::<MockIamClient>::annotate_last_used(&mut results, now, 90, false);
+
+        // アクセスキーの使用状況が不明なユーザーは未使用と判定しない
+        let alice = &results["users"][0];
+        assert_eq!(alice["last_used_unknown"], true);
+        assert!(alice.get("unused").is_none());
+        assert_eq!(results["roles"][0]["unused"], true);
+        let unused = results["unused_identities"].as_array().unwrap();
+        assert_eq!(unused.len(), 1);
+        assert_eq!(unused[0]["principal_type"], "role");
+    }
+
+    #[test]
+    fn test_annotate_last_used_flags_idle_identities() {
+        let now = 1_700_000_000;
+        let mut results = serde_json::Map::new();
+        results.insert(
+            "users".to_string(),
+            json!([
+                // パスワードは200日前、アクセスキーは10日前に使用
+                {"user_name": "alice", "arn": "arn:aws:iam::123456789012:user/alice",
+                 "create_date": now - 400 * DAY, "password_last_used": now - 200 * DAY},
+                // 一度も使用されていない（作成日からの経過日数で判定）
+                {"user_name": "bob", "arn": "arn:aws:iam::123456789012:user/bob",
+                 "create_date": now - 120 * DAY},
+            ]),
+        );
+        results.insert(
+            "roles".to_string(),
+            json!([
+                {"role_name": "deploy", "arn": "arn:aws:iam::123456789012:role/deploy",
+                 "create_date": now - 400 * DAY, "last_used_date": now - 91 * DAY},
+                {"role_name": "fresh", "arn": "arn:aws:iam::123456789012:role/fresh",
+                 "create_date": now - 5 * DAY},
+            ]),
+        );
+        results.insert(
+            "cleanup".to_string(),
+            json!([
+                {"type": "access_key", "resource": {"user_name": "alice", "last_used_date": now - 10 * DAY}},
+                {"type": "access_key", "resource": {"user_name": "alice", "last_used_date": now - 30 * DAY}},
+                {"type": "login_profile", "resource": {"user_name": "bob"}},
+            ]),
+        );
+
+        AwsIamScanner::<MockIamClient>::annotate_last_used(&mut results, now, 90, true);
+
+        let alice = &results["users"][0];
+        assert_eq!(alice["last_used_date"], now - 10 * DAY);
+        assert_eq!(alice["last_used_days"], 10);
+        assert_eq!(alice["unused"], false);
+        let bob = &results["users"][1];
+        assert!(bob.get("last_used_date").is_none());
+        assert_eq!(bob["last_used_days"], 120);
+        assert_eq!(bob["unused"], true);
+        assert_eq!(results["roles"][0]["last_used_days"], 91);
+        assert_eq!(results["roles"][0]["unused"], true);
+        assert_eq!(results["roles"][1]["unused"], false);
+
+        let unused = results["unused_identities"].as_array().unwrap();
+        assert_eq!(unused.len(), 2);
+        assert_eq!(unused[0]["principal_type"], "user");
+        assert_eq!(unused[0]["name"], "bob");
+        assert_eq!(unused[0]["never_used"], true);
+        assert_eq!(unused[1]["principal_type"], "role");
+        assert_eq!(unused[1]["name"], "deploy");
+        assert_eq!(unused[1]["last_used_date"], now - 91 * DAY);
+        assert_eq!(unused[1]["never_used"], false);
+    }
+
+    #[tokio::test]
+    async fn test_fill_service_last_accessed() {
+        let mut mock_client = MockIamClient::new();
+        mock_client
+            .expect_get_service_last_accessed()
+            .returning(|arn| {
+                if arn.ends_with("role/broken") {
+                    return Err(anyhow::anyhow!("AccessDenied"));
+                }
+                Ok(vec![
+                    IamServiceLastAccessedInfo {
+                        service_name: "Amazon S3".to_string(),
+                        service_namespace: "s3".to_string(),
+                        last_authenticated: Some(1_700_000_000),
+                        last_authenticated_entity: Some(arn.to_string()),
+                        last_authenticated_region: Some("us-east-1".to_string()),
+                    },
+                    IamServiceLastAccessedInfo {
+                        service_name: "AWS Lambda".to_string(),
+                        service_namespace: "lambda".to_string(),
+                        last_authenticated: None,
+                        last_authenticated_entity: None,
+                        last_authenticated_region: None,
+                    },
+                ])
+            });
+
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), HashMap::new()),
+            mock_client,
+        );
+
+        let mut results = serde_json::Map::new();
+        results.insert(
+            "users".to_string(),
+            json!([{"user_name": "alice", "arn": "arn:aws:iam::123456789012:user/alice"}]),
+        );
+        results.insert(
+            "roles".to_string(),
+            json!([{"role_name": "broken", "arn": "arn:aws:iam::123456789012:role/broken"}]),
+        );
+
+        let mut warnings = Vec::new();
+        scanner
+            .fill_service_last_accessed(&mut results, &mut warnings)
+            .await;
+
+        let services = results["users"][0]["service_last_accessed"]
+            .as_array()
+            .unwrap();
+        assert_eq!(services.len(), 2);
+        assert_eq!(services[0]["service_namespace"], "s3");
+        assert_eq!(services[0]["last_authenticated"], 1_700_000_000);
+        assert!(services[1].get("last_authenticated").is_none());
+
+        assert!(results["roles"][0].get("service_last_accessed").is_none());
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].resource_type, "role");
+        assert_eq!(warnings[0].resource_name, "broken");
+        assert_eq!(warnings[0].operation, "GetServiceLastAccessedDetails");
+    }
+
+    // ========================================
+    // 並列アタッチメントスキャンのテスト
+    // ========================================
+
+    /// エンティティごとに異なる遅延を挟み、同時実行数を記録するクライアント
+    ///
+    /// 先に列挙されたエンティティほど応答を遅くすることで、
+    /// 完了順が列挙順と逆になる状況を再現します。
+    struct DelayedIamClient {
+        inner: MockIamClient,
+        delays_ms: HashMap<String, u64>,
+        in_flight: std::sync::atomic::AtomicUsize,
+        max_in_flight: std::sync::atomic::AtomicUsize,
+    }
+
+    impl DelayedIamClient {
+        fn new(inner: MockIamClient, names: &[&str]) -> Self {
+            let delays_ms = names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| (name.to_string(), ((names.len() - i) * 10) as u64))
+                .collect();
+            Self {
+                inner,
+                delays_ms,
+                in_flight: Default::default(),
+                max_in_flight: Default::default(),
+            }
+        }
+
+        async fn delay(&self, name: &str) {
+            use std::sync::atomic::Ordering;
+            let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(current, Ordering::SeqCst);
+            let delay = self.delays_ms.get(name).copied().unwrap_or(0);
+            tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl IamClientOps for DelayedIamClient {
+        async fn list_users(&self) -> Result<Vec<IamUserInfo>> {
+            self.inner.list_users().await
+        }
+        async fn list_users_with_options(&self, include_tags: bool) -> Result<Vec<IamUserInfo>> {
+            self.inner.list_users_with_options(include_tags).await
+        }
+        async fn list_groups(&self) -> Result<Vec<IamGroupInfo>> {
+            self.inner.list_groups().await
+        }
+        async fn list_roles(&self) -> Result<Vec<IamRoleInfo>> {
+            self.inner.list_roles().await
+        }
+        async fn list_roles_with_options(&self, include_tags: bool) -> Result<Vec<IamRoleInfo>> {
+            self.inner.list_roles_with_options(include_tags).await
+        }
+        async fn get_user_detail(
+            &self,
+            user_name: &str,
+            include_tags: bool,
+        ) -> Result<Option<IamEntityDetail>> {
+            self.inner.get_user_detail(user_name, include_tags).await
+        }
+        async fn get_role_detail(
+            &self,
+            role_name: &str,
+            include_tags: bool,
+        ) -> Result<Option<IamEntityDetail>> {
+            self.inner.get_role_detail(role_name, include_tags).await
+        }
+        async fn list_policies(&self) -> Result<Vec<IamPolicyInfo>> {
+            self.inner.list_policies().await
+        }
+
+        async fn list_instance_profiles(&self) -> Result<Vec<IamInstanceProfileInfo>> {
+            self.inner.list_instance_profiles().await
+        }
+
+        async fn list_open_id_connect_providers(&self) -> Result<Vec<IamOidcProviderInfo>> {
+            self.inner.list_open_id_connect_providers().await
+        }
+
+        async fn list_saml_providers(&self) -> Result<Vec<IamSamlProviderInfo>> {
+            self.inner.list_saml_providers().await
+        }
+        async fn list_access_keys(&self, user_name: &str) -> Result<Vec<IamAccessKeyInfo>> {
+            self.inner.list_access_keys(user_name).await
+        }
+
+        async fn get_login_profile(&self, user_name: &str) -> Result<Option<IamLoginProfileInfo>> {
+            self.inner.get_login_profile(user_name).await
+        }
+
+        async fn list_mfa_devices(&self, user_name: &str) -> Result<Vec<IamMfaDeviceInfo>> {
+            self.inner.list_mfa_devices(user_name).await
+        }
+
+        async fn get_account_password_policy(&self) -> Result<Option<IamPasswordPolicyInfo>> {
+            self.inner.get_account_password_policy().await
+        }
+
+        async fn list_account_aliases(&self) -> Result<Vec<String>> {
+            self.inner.list_account_aliases().await
+        }
+
+        async fn get_service_last_accessed(
+            &self,
+            arn: &str,
+        ) -> Result<Vec<IamServiceLastAccessedInfo>> {
+            self.inner.get_service_last_accessed(arn).await
+        }
+
+        async fn list_user_policies(&self, user_name: &str) -> Result<Vec<String>> {
+            self.delay(user_name).await;
+            self.inner.list_user_policies(user_name).await
+        }
+        async fn list_attached_user_policies(
+            &self,
+            user_name: &str,
+        ) -> Result<Vec<PolicyAttachment>> {
+            self.delay(user_name).await;
+            self.inner.list_attached_user_policies(user_name).await
+        }
+        async fn list_group_policies(&self, group_name: &str) -> Result<Vec<String>> {
+            self.delay(group_name).await;
+            self.inner.list_group_policies(group_name).await
+        }
+        async fn list_attached_group_policies(
+            &self,
+            group_name: &str,
+        ) -> Result<Vec<PolicyAttachment>> {
+            self.delay(group_name).await;
+            self.inner.list_attached_group_policies(group_name).await
+        }
+        async fn list_role_policies(&self, role_name: &str) -> Result<Vec<String>> {
+            self.delay(role_name).await;
+            self.inner.list_role_policies(role_name).await
+        }
+        async fn list_attached_role_policies(
+            &self,
+            role_name: &str,
+        ) -> Result<Vec<PolicyAttachment>> {
+            self.delay(role_name).await;
+            self.inner.list_attached_role_policies(role_name).await
+        }
+        async fn list_groups_for_user(&self, user_name: &str) -> Result<Vec<String>> {
+            self.delay(user_name).await;
+            self.inner.list_groups_for_user(user_name).await
+        }
+        async fn get_policy_version(
+            &self,
+            policy_arn: &str,
+            version_id: &str,
+        ) -> Result<Option<PolicyDocument>> {
+            self.inner.get_policy_version(policy_arn, version_id).await
+        }
+        async fn get_user_policy(
+            &self,
+            user_name: &str,
+            policy_name: &str,
+        ) -> Result<Option<PolicyDocument>> {
+            self.inner.get_user_policy(user_name, policy_name).await
+        }
+        async fn get_group_policy(
+            &self,
+            group_name: &str,
+            policy_name: &str,
+        ) -> Result<Option<PolicyDocument>> {
+            self.inner.get_group_policy(group_name, policy_name).await
+        }
+        async fn get_role_policy(
+            &self,
+            role_name: &str,
+            policy_name: &str,
+        ) -> Result<Option<PolicyDocument>> {
+            self.inner.get_role_policy(role_name, policy_name).await
+        }
+    }
+
+    /// ロールごとに1件のマネージドポリシーを返すモックを作成
+    fn mock_with_role_attachments() -> MockIamClient {
+        let mut mock_client = MockIamClient::new();
+        mock_client
+            .expect_list_role_policies()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_list_attached_role_policies()
+            .returning(|role_name| {
+                Ok(vec![PolicyAttachment {
+                    policy_arn: format!("arn:aws:iam::123456789012:policy/{}-policy", role_name),
+                    policy_name: None,
+                }])
+            });
+        mock_client
+    }
+
+    async fn scan_roles_with_concurrency(
+        role_names: &[&str],
+        max_concurrency: usize,
+    ) -> (Value, usize) {
+        let mut config = create_test_config(HashMap::new(), HashMap::new());
+        config.max_concurrency = Some(max_concurrency);
+        let client = DelayedIamClient::new(mock_with_role_attachments(), role_names);
+        let scanner = AwsIamScanner::new_with_client(config, client);
+
+        let roles: Vec<Value> = role_names
+            .iter()
+            .map(|name| json!({"role_name": name}))
+            .collect();
+        let (attachments, _) = scanner
+            .scan_attachments_with_data(&[], &[], &roles, &no_progress(), &mut Vec::new())
+            .await
+            .unwrap();
+        let max_in_flight = scanner
+            .iam_client
+            .max_in_flight
+            .load(std::sync::atomic::Ordering::SeqCst);
+        (attachments, max_in_flight)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_scan_attachments_results_independent_of_completion_order() {
+        let role_names = ["role-a", "role-b", "role-c", "role-d", "role-e"];
+
+        let (sequential, sequential_in_flight) = scan_roles_with_concurrency(&role_names, 1).await;
+        let (concurrent, concurrent_in_flight) = scan_roles_with_concurrency(&role_names, 3).await;
+
+        // 完了順が逆転しても結果は列挙順で、逐次実行と同一
+        assert_eq!(sequential, concurrent);
+        let role_policies: Vec<&str> = concurrent["role_policies"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["role_name"].as_str().unwrap())
+            .collect();
+        assert_eq!(role_policies, role_names);
+
+        // 同時実行数は設定値を上限とする
+        assert_eq!(sequential_in_flight, 1);
+        assert!(concurrent_in_flight > 1);
+        assert!(concurrent_in_flight <= 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_scan_attachments_retries_throttling() {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+
+        let mut mock_client = MockIamClient::new();
+        mock_client
+            .expect_list_role_policies()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_list_attached_role_policies()
+            .returning(move |_| {
+                if calls_clone.fetch_add(1, std::sync::atomic::Ordering::SeqCst) < 2 {
+                    return Err(anyhow::anyhow!(
+                        "Failed to list attached role policies: Throttling: Rate exceeded"
+                    ));
+                }
+                Ok(vec![PolicyAttachment {
+                    policy_arn: "arn:aws:iam::aws:policy/ReadOnlyAccess".to_string(),
+                    policy_name: None,
+                }])
+            });
+
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), HashMap::new()),
+            mock_client,
+        );
+        let (attachments, _) = scanner
+            .scan_attachments_with_data(
+                &[],
+                &[],
+                &[json!({"role_name": "app"})],
+                &no_progress(),
+                &mut Vec::new(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 3);
+        assert_eq!(attachments["role_policies"].as_array().unwrap().len(), 1);
+    }
 
-        assert_eq!(policies.len(), 1);
-        assert_eq!(policies[0]["policy_name"], "CustomS3Policy");
-        assert_eq!(policies[0]["attachment_count"], 2);
+    #[tokio::test]
+    async fn test_scan_attachments_reports_per_entity_progress() {
+        let progress_values = std::sync::Mutex::new(Vec::new());
+        let callback = |event: ScanProgress| {
+            progress_values.lock().unwrap().push(event);
+        };
+        let progress = AttachmentProgress {
+            callback: &callback,
+            start: 50,
+            end: 90,
+        };
+
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), HashMap::new()),
+            mock_with_role_attachments(),
+        );
+        let roles = vec![json!({"role_name": "a"}), json!({"role_name": "b"})];
+        scanner
+            .scan_attachments_with_data(&[], &[], &roles, &progress, &mut Vec::new())
+            .await
+            .unwrap();
+
+        let values = progress_values.lock().unwrap();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[0].progress, 70);
+        assert_eq!(values[0].resource_type.as_deref(), Some("attachments"));
+        assert_eq!((values[0].completed, values[0].total), (Some(1), Some(2)));
+        assert!(values[0].message.contains("1/2"));
+        assert_eq!(values[1].progress, 90);
     }
 
     // ========================================
@@ -1100,6 +3702,9 @@ mod tests {
         let mut mock_client = MockIamClient::new();
 
         // 全ての必要なメソッドにモック設定
+        mock_client
+            .expect_get_user_detail()
+            .returning(|_, _| Ok(None));
         mock_client.expect_list_users_with_options().returning(|_| {
             Ok(vec![IamUserInfo {
                 user_name: "user1".to_string(),
@@ -1108,9 +3713,14 @@ mod tests {
                 create_date: 0,
                 path: "/".to_string(),
                 tags: HashMap::new(),
+                permissions_boundary: None,
+                password_last_used: None,
             }])
         });
         mock_client.expect_list_groups().returning(|| Ok(vec![]));
+        mock_client
+            .expect_get_role_detail()
+            .returning(|_, _| Ok(None));
         mock_client
             .expect_list_roles_with_options()
             .returning(|_| Ok(vec![]));
@@ -1124,6 +3734,15 @@ mod tests {
         mock_client
             .expect_list_groups_for_user()
             .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_list_access_keys()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_get_login_profile()
+            .returning(|_| Ok(None));
+        mock_client
+            .expect_list_mfa_devices()
+            .returning(|_| Ok(vec![]));
 
         let mut scan_targets = HashMap::new();
         scan_targets.insert("users".to_string(), true);
@@ -1136,10 +3755,9 @@ mod tests {
         let progress_values = Arc::new(std::sync::Mutex::new(Vec::new()));
         let progress_clone = progress_values.clone();
 
-        let callback: Box<dyn Fn(u32, String) + Send + Sync> =
-            Box::new(move |progress, message| {
-                progress_clone.lock().unwrap().push((progress, message));
-            });
+        let callback: ProgressCallback = Box::new(move |event| {
+            progress_clone.lock().unwrap().push(event);
+        });
 
         let result = scanner.scan(callback).await.unwrap();
 
@@ -1150,7 +3768,59 @@ mod tests {
         // 進捗コールバックの検証
         let values = progress_values.lock().unwrap();
         assert!(values.len() >= 2); // 開始と完了のコールバック
-        assert_eq!(values.last().unwrap().0, 100); // 最後は100%
+        assert_eq!(values[0].phase, ScanPhase::Started);
+        let users = values
+            .iter()
+            .find(|event| event.phase == ScanPhase::ResourceCompleted)
+            .unwrap();
+        assert_eq!(users.resource_type.as_deref(), Some("users"));
+        assert_eq!(users.completed, Some(1));
+        let last = values.last().unwrap();
+        assert_eq!(last.progress, 100); // 最後は100%
+        assert_eq!(last.phase, ScanPhase::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_scan_progress_reaches_100_with_non_step_targets() {
+        let mut mock_client = mock_with_user_credentials();
+        mock_client
+            .expect_get_user_detail()
+            .returning(|_, _| Ok(None));
+        mock_client.expect_list_users_with_options().returning(|_| {
+            Ok(vec![IamUserInfo {
+                user_name: "alice".to_string(),
+                user_id: "id1".to_string(),
+                arn: "arn:aws:iam::123456789012:user/alice".to_string(),
+                create_date: 0,
+                path: "/".to_string(),
+                tags: HashMap::new(),
+                permissions_boundary: None,
+                password_last_used: None,
+            }])
+        });
+
+        // cleanupは進捗のステップを持たない
+        let mut scan_targets = HashMap::new();
+        scan_targets.insert("users".to_string(), true);
+        scan_targets.insert("cleanup".to_string(), true);
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), scan_targets),
+            mock_client,
+        );
+
+        let progress_values = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let progress_clone = progress_values.clone();
+        let callback: ProgressCallback = Box::new(move |event| {
+            progress_clone.lock().unwrap().push(event);
+        });
+        scanner.scan(callback).await.unwrap();
+
+        let values = progress_values.lock().unwrap();
+        let progress: Vec<u32> = values.iter().map(|event| event.progress).collect();
+        assert_eq!(progress, vec![0, 0, 50, 50, 100, 100]);
+        let attachments = &values[values.len() - 2];
+        assert_eq!(attachments.resource_type.as_deref(), Some("attachments"));
+        assert_eq!(attachments.progress, 100);
     }
 
     // ========================================
@@ -1161,6 +3831,9 @@ mod tests {
     async fn test_scan_users_error_handling() {
         let mut mock_client = MockIamClient::new();
 
+        mock_client
+            .expect_get_user_detail()
+            .returning(|_, _| Ok(None));
         mock_client.expect_list_users_with_options().returning(|_| {
             Err(anyhow::anyhow!(
                 "Authentication failed: invalid credentials"
@@ -1172,7 +3845,7 @@ mod tests {
             mock_client,
         );
 
-        let result = scanner.scan_users().await;
+        let result = scanner.scan_users(&mut Vec::new()).await;
 
         assert!(result.is_err());
         let err = result.unwrap_err();
@@ -1183,6 +3856,9 @@ mod tests {
     async fn test_scan_with_permission_denied() {
         let mut mock_client = MockIamClient::new();
 
+        mock_client
+            .expect_get_user_detail()
+            .returning(|_, _| Ok(None));
         mock_client.expect_list_users_with_options().returning(|_| {
             Err(anyhow::anyhow!(
                 "Access Denied: iam:ListUsers permission required"
@@ -1197,10 +3873,424 @@ mod tests {
             mock_client,
         );
 
-        let callback: Box<dyn Fn(u32, String) + Send + Sync> = Box::new(|_, _| {});
+        let callback: ProgressCallback = Box::new(|_| {});
         let result = scanner.scan(callback).await;
 
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Access Denied"));
     }
+
+    #[tokio::test]
+    async fn test_scan_records_warnings_for_partial_failures() {
+        let mut mock_client = MockIamClient::new();
+        mock_client
+            .expect_get_role_detail()
+            .returning(|_, _| Ok(None));
+        mock_client.expect_list_roles_with_options().returning(|_| {
+            Ok(vec![IamRoleInfo {
+                role_name: "app".to_string(),
+                role_id: "id1".to_string(),
+                arn: "arn:aws:iam::123456789012:role/app".to_string(),
+                create_date: 0,
+                path: "/".to_string(),
+                assume_role_policy_document: None,
+                tags: HashMap::new(),
+                description: None,
+                max_session_duration: None,
+                permissions_boundary: None,
+                last_used_date: None,
+                last_used_region: None,
+            }])
+        });
+        mock_client.expect_list_policies().returning(|| {
+            Ok(vec![IamPolicyInfo {
+                policy_name: "custom".to_string(),
+                policy_id: "pid".to_string(),
+                arn: "arn:aws:iam::123456789012:policy/custom".to_string(),
+                path: "/".to_string(),
+                default_version_id: "v1".to_string(),
+                attachment_count: 1,
+                create_date: 0,
+                update_date: 0,
+                description: String::new(),
+            }])
+        });
+        mock_client
+            .expect_list_role_policies()
+            .returning(|_| Ok(vec![]));
+        mock_client
+            .expect_list_attached_role_policies()
+            .returning(|_| {
+                Err(anyhow::anyhow!(
+                    "Failed to list attached role policies: AccessDenied: not authorized"
+                ))
+            });
+        mock_client.expect_get_policy_version().returning(|_, _| {
+            Err(anyhow::anyhow!(
+                "Failed to get policy version: NoSuchEntity: version not found"
+            ))
+        });
+
+        let mut scan_targets = HashMap::new();
+        scan_targets.insert("roles".to_string(), true);
+        scan_targets.insert("policies".to_string(), true);
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), scan_targets),
+            mock_client,
+        );
+
+        let result = scanner.scan(Box::new(|_| {})).await.unwrap();
+
+        let warnings: Vec<ScanWarning> =
+            serde_json::from_value(result["warnings"].clone()).unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].resource_type, "role");
+        assert_eq!(warnings[0].resource_name, "app");
+        assert_eq!(warnings[0].operation, "ListAttachedRolePolicies");
+        assert_eq!(warnings[0].error_kind, "access_denied");
+        assert_eq!(warnings[1].resource_type, "policy");
+        assert_eq!(warnings[1].operation, "GetPolicyVersion");
+        assert_eq!(warnings[1].error_kind, "not_found");
+        // 取得できなかった部分以外はスキャン結果に含まれる
+        assert_eq!(result["roles"].as_array().unwrap().len(), 1);
+        assert!(result["policies"][0].get("policy_document").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_scan_users_keeps_user_when_get_user_fails() {
+        let mut mock_client = MockIamClient::new();
+        mock_client.expect_list_users_with_options().returning(|_| {
+            Ok(["alice", "bob"]
+                .iter()
+                .map(|name| IamUserInfo {
+                    user_name: name.to_string(),
+                    user_id: format!("id-{}", name),
+                    arn: format!("arn:aws:iam::123456789012:user/{}", name),
+                    create_date: 0,
+                    path: "/".to_string(),
+                    tags: HashMap::new(),
+                    permissions_boundary: None,
+                    password_last_used: None,
+                })
+                .collect())
+        });
+        mock_client
+            .expect_get_user_detail()
+            .returning(|user_name, _| match user_name {
+                "alice" => Err(anyhow::anyhow!(
+                    "Failed to get user: AccessDenied: not authorized"
+                )),
+                _ => Ok(Some(IamEntityDetail {
+                    permissions_boundary: Some(
+                        "arn:aws:iam::123456789012:policy/boundary".to_string(),
+                    ),
+                    tags: HashMap::from([("Team".to_string(), "dev".to_string())]),
+                    last_used_date: None,
+                    last_used_region: None,
+                })),
+            });
+
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), HashMap::new()),
+            mock_client,
+        );
+
+        let mut warnings = Vec::new();
+        let users = scanner.scan_users(&mut warnings).await.unwrap();
+
+        // 詳細を取得できなかったユーザーも境界・タグなしで結果に含まれる
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0]["user_name"], "alice");
+        assert!(users[0].get("permissions_boundary").is_none());
+        assert!(users[0].get("tags").is_none());
+        assert_eq!(
+            users[1]["permissions_boundary"],
+            "arn:aws:iam::123456789012:policy/boundary"
+        );
+        assert_eq!(users[1]["tags"]["Team"], "dev");
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].resource_type, "user");
+        assert_eq!(warnings[0].resource_name, "alice");
+        assert_eq!(warnings[0].operation, "GetUser");
+        assert_eq!(warnings[0].error_kind, "access_denied");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_fill_policy_documents_retries_throttling_and_records_failures() {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+
+        let mut mock_client = MockIamClient::new();
+        mock_client
+            .expect_get_policy_version()
+            .returning(move |policy_arn, _| {
+                if policy_arn.ends_with("/denied") {
+                    return Err(anyhow::anyhow!(
+                        "Failed to get policy version: AccessDenied: not authorized"
+                    ));
+                }
+                if calls_clone.fetch_add(1, std::sync::atomic::Ordering::SeqCst) < 2 {
+                    return Err(anyhow::anyhow!(
+                        "Failed to get policy version: Throttling: Rate exceeded"
+                    ));
+                }
+                Ok(Some(PolicyDocument {
+                    document: r#"{"Version":"2012-10-17","Statement":[]}"#.to_string(),
+                }))
+            });
+
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), HashMap::new()),
+            mock_client,
+        );
+        let mut results = serde_json::Map::new();
+        results.insert(
+            "policies".to_string(),
+            json!([
+                {"arn": "arn:aws:iam::123456789012:policy/deploy", "default_version_id": "v1"},
+                {"arn": "arn:aws:iam::123456789012:policy/denied", "default_version_id": "v2"},
+                {"arn": "arn:aws:iam::123456789012:policy/draft", "default_version_id": ""}
+            ]),
+        );
+
+        let mut warnings = Vec::new();
+        scanner
+            .fill_policy_documents(&mut results, &mut warnings)
+            .await
+            .unwrap();
+
+        // スロットリングはリトライされ、順序は保たれる
+        let policies = results["policies"].as_array().unwrap();
+        assert_eq!(policies.len(), 3);
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 3);
+        assert_eq!(policies[0]["policy_document"]["Version"], "2012-10-17");
+        assert!(policies[1].get("policy_document").is_none());
+        assert!(policies[2].get("policy_document").is_none());
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].resource_type, "policy");
+        assert_eq!(
+            warnings[0].resource_name,
+            "arn:aws:iam::123456789012:policy/denied"
+        );
+        assert_eq!(warnings[0].operation, "GetPolicyVersion");
+        assert_eq!(warnings[0].error_kind, "access_denied");
+    }
+
+    #[tokio::test]
+    async fn test_scan_identity_center_records_warnings() {
+        let mut sso_client = MockSsoAdminClient::new();
+        sso_client.expect_list_instances().returning(|| {
+            Ok(vec![SsoInstanceInfo {
+                instance_arn: "arn:aws:sso:::instance/ssoins-1111".to_string(),
+                identity_store_id: "d-1111".to_string(),
+            }])
+        });
+        sso_client
+            .expect_list_permission_sets()
+            .returning(|_| Err(anyhow::anyhow!("AccessDeniedException: not authorized")));
+
+        let mut scan_targets = HashMap::new();
+        scan_targets.insert("identity_center".to_string(), true);
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), scan_targets),
+            MockIamClient::new(),
+        )
+        .with_sso_client(sso_client);
+
+        let result = scanner.scan(Box::new(|_| {})).await.unwrap();
+
+        assert!(result["sso_permission_sets"].as_array().unwrap().is_empty());
+        assert!(result["sso_account_assignments"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+        let warnings: Vec<ScanWarning> =
+            serde_json::from_value(result["warnings"].clone()).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].resource_type, "identity_center");
+        assert_eq!(warnings[0].operation, "ListPermissionSets");
+    }
+
+    #[tokio::test]
+    async fn test_scan_organizations_target() {
+        let mut organizations = MockOrganizationsClient::new();
+        organizations
+            .expect_list_roots()
+            .returning(|| Ok(Vec::new()));
+        organizations.expect_list_policies().returning(|_| {
+            Ok(vec![OrganizationPolicySummary {
+                policy_id: "p-denyleave".to_string(),
+                name: "DenyLeaveOrg".to_string(),
+                description: None,
+                arn: "arn:aws:organizations::111111111111:policy/o-example/service_control_policy/p-denyleave".to_string(),
+                aws_managed: false,
+            }])
+        });
+        organizations
+            .expect_describe_policy()
+            .returning(|_| Err(anyhow::anyhow!("AccessDeniedException: not authorized")));
+
+        let mut scan_targets = HashMap::new();
+        scan_targets.insert("organizations".to_string(), true);
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), scan_targets),
+            MockIamClient::new(),
+        )
+        .with_organizations_client(organizations);
+
+        let result = scanner.scan(Box::new(|_| {})).await.unwrap();
+
+        assert!(result["organizational_units"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+        assert!(result["service_control_policies"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+        let warnings: Vec<ScanWarning> =
+            serde_json::from_value(result["warnings"].clone()).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].resource_type, "service_control_policy");
+        assert_eq!(warnings[0].operation, "DescribePolicy");
+    }
+
+    #[tokio::test]
+    async fn test_scan_networking_targets() {
+        let mut ec2 = MockEc2Client::new();
+        ec2.expect_describe_subnets().returning(|| {
+            Ok(vec![SubnetInfo {
+                subnet_id: "subnet-0app".to_string(),
+                vpc_id: "vpc-0main".to_string(),
+                cidr_block: "10.0.1.0/24".to_string(),
+                availability_zone: "ap-northeast-1a".to_string(),
+                map_public_ip_on_launch: false,
+                tags: HashMap::new(),
+            }])
+        });
+        ec2.expect_describe_security_groups()
+            .returning(|| Err(anyhow::anyhow!("UnauthorizedOperation: not authorized")));
+
+        let mut scan_targets = HashMap::new();
+        scan_targets.insert("subnets".to_string(), true);
+        scan_targets.insert("security_groups".to_string(), true);
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), scan_targets),
+            MockIamClient::new(),
+        )
+        .with_ec2_client(ec2);
+
+        let result = scanner.scan(Box::new(|_| {})).await.unwrap();
+
+        // 対象外のVPCは空配列として出力される
+        assert!(result["vpcs"].as_array().unwrap().is_empty());
+        assert_eq!(result["subnets"][0]["subnet_id"], "subnet-0app");
+        assert!(result["security_groups"].as_array().unwrap().is_empty());
+        assert!(result["security_group_ingress_rules"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+        let warnings: Vec<ScanWarning> =
+            serde_json::from_value(result["warnings"].clone()).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].resource_type, "security_group");
+        assert_eq!(warnings[0].error_kind, "access_denied");
+    }
+
+    #[tokio::test]
+    async fn test_scan_s3_buckets_target() {
+        let mut s3 = MockS3Client::new();
+        s3.expect_list_buckets()
+            .returning(|| Ok(vec!["tokyo-logs".to_string()]));
+        s3.expect_get_bucket_location()
+            .returning(|_| Ok("ap-northeast-1".to_string()));
+        s3.expect_get_bucket_tagging()
+            .returning(|_| Ok(HashMap::new()));
+        s3.expect_get_bucket_versioning().returning(|_| Ok(None));
+        s3.expect_get_bucket_encryption().returning(|_| Ok(None));
+        s3.expect_get_bucket_lifecycle_configuration()
+            .returning(|_| Ok(None));
+        s3.expect_get_public_access_block().returning(|_| Ok(None));
+        s3.expect_get_bucket_ownership_controls()
+            .returning(|_| Ok(None));
+
+        let mut scan_targets = HashMap::new();
+        scan_targets.insert("s3_buckets".to_string(), true);
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), scan_targets),
+            MockIamClient::new(),
+        )
+        .with_s3_client(s3, Some("us-east-1".to_string()));
+
+        let result = scanner.scan(Box::new(|_| {})).await.unwrap();
+
+        assert_eq!(result["s3_buckets"][0]["bucket"], "tokyo-logs");
+        assert_eq!(result["s3_buckets"][0]["provider_alias"], "ap_northeast_1");
+        // 対象外のリソースベースポリシーは空配列として出力される
+        assert!(result["s3_bucket_policies"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_scan_resource_policies_target() {
+        let mut s3 = MockS3Client::new();
+        s3.expect_list_buckets()
+            .returning(|| Ok(vec!["app-assets".to_string()]));
+        s3.expect_get_bucket_policy().returning(|_| {
+            Ok(Some(
+                r#"{"Version":"2012-10-17","Statement":[{"Effect":"Deny","Principal":"*","Action":"s3:*","Resource":"*"}]}"#
+                    .to_string(),
+            ))
+        });
+        let mut kms = MockKmsClient::new();
+        kms.expect_list_keys()
+            .returning(|| Err(anyhow::anyhow!("AccessDeniedException: not authorized")));
+        let mut sqs = MockSqsClient::new();
+        sqs.expect_list_queues().returning(|| Ok(Vec::new()));
+        let mut sns = MockSnsClient::new();
+        sns.expect_list_topics().returning(|| Ok(Vec::new()));
+
+        let mut scan_targets = HashMap::new();
+        scan_targets.insert("resource_policies".to_string(), true);
+        let scanner = AwsIamScanner::new_with_client(
+            create_test_config(HashMap::new(), scan_targets),
+            MockIamClient::new(),
+        )
+        .with_resource_policy_scanner(AwsResourcePolicyScanner::new_with_clients(
+            Arc::new(s3),
+            Arc::new(kms),
+            Arc::new(sqs),
+            Arc::new(sns),
+            1,
+        ));
+
+        let result = scanner.scan(Box::new(|_| {})).await.unwrap();
+
+        assert_eq!(result["s3_bucket_policies"][0]["bucket"], "app-assets");
+        assert!(result["kms_key_policies"].as_array().unwrap().is_empty());
+        assert!(result["sns_topic_policies"].as_array().unwrap().is_empty());
+        let warnings: Vec<ScanWarning> =
+            serde_json::from_value(result["warnings"].clone()).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].resource_type, "kms_key");
+        assert_eq!(warnings[0].operation, "ListKeys");
+    }
+
+    #[test]
+    fn test_error_kind() {
+        assert_eq!(
+            error_kind(&anyhow::anyhow!("Throttling: Rate exceeded")),
+            "throttling"
+        );
+        assert_eq!(
+            error_kind(&anyhow::anyhow!("AccessDenied: not authorized")),
+            "access_denied"
+        );
+        assert_eq!(
+            error_kind(&anyhow::anyhow!("NoSuchEntity: not found")),
+            "not_found"
+        );
+        assert_eq!(error_kind(&anyhow::anyhow!("connection reset")), "other");
+    }
 }
@@ -0,0 +1,33 @@
+//! SNSクライアント操作の抽象化トレイト
+//!
+//! このモジュールは、SNSの操作を抽象化し、
+//! テスト時にモック実装を注入できるようにします。
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+/// SNSクライアント操作を抽象化するトレイト
+#[async_trait]
+pub trait SnsClientOps: Send + Sync {
+    /// トピックARNの一覧を取得
+    async fn list_topics(&self) -> Result<Vec<String>>;
+
+    /// トピックのアクセスポリシーを取得（未設定の場合はNone）
+    async fn get_topic_policy(&self, topic_arn: &str) -> Result<Option<String>>;
+}
+
+#[cfg(test)]
+pub mod mock {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub SnsClient {}
+
+        #[async_trait]
+        impl SnsClientOps for SnsClient {
+            async fn list_topics(&self) -> Result<Vec<String>>;
+            async fn get_topic_policy(&self, topic_arn: &str) -> Result<Option<String>>;
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! SQSクライアント操作の抽象化トレイト
+//!
+//! このモジュールは、SQSの操作を抽象化し、
+//! テスト時にモック実装を注入できるようにします。
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+/// SQSキュー情報
+#[derive(Debug, Clone)]
+pub struct SqsQueueInfo {
+    pub queue_url: String,
+    pub queue_arn: String,
+    /// キューポリシー（未設定の場合はNone）
+    pub policy: Option<String>,
+}
+
+/// SQSクライアント操作を抽象化するトレイト
+#[async_trait]
+pub trait SqsClientOps: Send + Sync {
+    /// キューURLの一覧を取得
+    async fn list_queues(&self) -> Result<Vec<String>>;
+
+    /// キューのARNとポリシーを取得
+    async fn get_queue_attributes(&self, queue_url: &str) -> Result<SqsQueueInfo>;
+}
+
+#[cfg(test)]
+pub mod mock {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub SqsClient {}
+
+        #[async_trait]
+        impl SqsClientOps for SqsClient {
+            async fn list_queues(&self) -> Result<Vec<String>>;
+            async fn get_queue_attributes(&self, queue_url: &str) -> Result<SqsQueueInfo>;
+        }
+    }
+}
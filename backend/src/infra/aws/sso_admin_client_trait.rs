@@ -0,0 +1,134 @@
+//! IAM Identity Center（SSO Admin）クライアント操作の抽象化トレイト
+//!
+//! このモジュールは、IAM Identity Centerの操作を抽象化し、
+//! テスト時にモック実装を注入できるようにします。
+
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// IAM Identity Centerのインスタンス情報
+#[derive(Debug, Clone)]
+pub struct SsoInstanceInfo {
+    pub instance_arn: String,
+    pub identity_store_id: String,
+}
+
+/// 許可セット情報
+#[derive(Debug, Clone)]
+pub struct SsoPermissionSetInfo {
+    pub permission_set_arn: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// セッション時間（ISO 8601形式、例: `PT1H`）
+    pub session_duration: Option<String>,
+    pub relay_state: Option<String>,
+    pub tags: HashMap<String, String>,
+}
+
+/// 許可セットにアタッチされたAWS管理ポリシー
+#[derive(Debug, Clone)]
+pub struct SsoManagedPolicyInfo {
+    pub name: String,
+    pub arn: String,
+}
+
+/// アカウント割り当て情報
+#[derive(Debug, Clone)]
+pub struct SsoAccountAssignmentInfo {
+    pub account_id: String,
+    #[allow(dead_code)]
+    pub permission_set_arn: String,
+    /// プリンシパルの種類（`USER` / `GROUP`）
+    pub principal_type: String,
+    /// Identity Storeのユーザー/グループID
+    pub principal_id: String,
+}
+
+/// IAM Identity Centerクライアント操作を抽象化するトレイト
+#[async_trait]
+pub trait SsoAdminClientOps: Send + Sync {
+    /// IAM Identity Centerのインスタンス一覧を取得
+    async fn list_instances(&self) -> Result<Vec<SsoInstanceInfo>>;
+
+    /// インスタンス内の許可セットARN一覧を取得
+    async fn list_permission_sets(&self, instance_arn: &str) -> Result<Vec<String>>;
+
+    /// 許可セットの詳細（タグを含む）を取得
+    async fn describe_permission_set(
+        &self,
+        instance_arn: &str,
+        permission_set_arn: &str,
+    ) -> Result<SsoPermissionSetInfo>;
+
+    /// 許可セットのインラインポリシーを取得（未設定の場合はNone）
+    async fn get_inline_policy_for_permission_set(
+        &self,
+        instance_arn: &str,
+        permission_set_arn: &str,
+    ) -> Result<Option<String>>;
+
+    /// 許可セットにアタッチされたAWS管理ポリシー一覧を取得
+    async fn list_managed_policies_in_permission_set(
+        &self,
+        instance_arn: &str,
+        permission_set_arn: &str,
+    ) -> Result<Vec<SsoManagedPolicyInfo>>;
+
+    /// 許可セットがプロビジョニングされているアカウントID一覧を取得
+    async fn list_accounts_for_provisioned_permission_set(
+        &self,
+        instance_arn: &str,
+        permission_set_arn: &str,
+    ) -> Result<Vec<String>>;
+
+    /// アカウント・許可セット単位のアカウント割り当て一覧を取得
+    async fn list_account_assignments(
+        &self,
+        instance_arn: &str,
+        account_id: &str,
+        permission_set_arn: &str,
+    ) -> Result<Vec<SsoAccountAssignmentInfo>>;
+}
+
+#[cfg(test)]
+pub mod mock {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub SsoAdminClient {}
+
+        #[async_trait]
+        impl SsoAdminClientOps for SsoAdminClient {
+            async fn list_instances(&self) -> Result<Vec<SsoInstanceInfo>>;
+            async fn list_permission_sets(&self, instance_arn: &str) -> Result<Vec<String>>;
+            async fn describe_permission_set(
+                &self,
+                instance_arn: &str,
+                permission_set_arn: &str,
+            ) -> Result<SsoPermissionSetInfo>;
+            async fn get_inline_policy_for_permission_set(
+                &self,
+                instance_arn: &str,
+                permission_set_arn: &str,
+            ) -> Result<Option<String>>;
+            async fn list_managed_policies_in_permission_set(
+                &self,
+                instance_arn: &str,
+                permission_set_arn: &str,
+            ) -> Result<Vec<SsoManagedPolicyInfo>>;
+            async fn list_accounts_for_provisioned_permission_set(
+                &self,
+                instance_arn: &str,
+                permission_set_arn: &str,
+            ) -> Result<Vec<String>>;
+            async fn list_account_assignments(
+                &self,
+                instance_arn: &str,
+                account_id: &str,
+                permission_set_arn: &str,
+            ) -> Result<Vec<SsoAccountAssignmentInfo>>;
+        }
+    }
+}
@@ -0,0 +1,471 @@
+//! AWS IAM Identity Centerスキャナー
+//!
+//! IAM Identity Centerの許可セット（インラインポリシー・AWS管理ポリシーを含む）と
+//! アカウント割り当てをスキャンし、Terraform生成用のデータ構造に変換します。
+//!
+//! Identity Centerは管理アカウント（または委任管理者アカウント）でのみ参照できるため、
+//! API呼び出しの失敗はスキャン全体を失敗させず、スキャン警告として記録します。
+
+use anyhow::Result;
+use aws_config::SdkConfig;
+use futures::stream::{self, StreamExt};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+use crate::infra::aws::cli::AwsCliRunner;
+use crate::infra::aws::real_sso_admin_client::RealSsoAdminClient;
+use crate::infra::aws::retry::with_throttling_retry;
+use crate::infra::aws::scanner::scan_warning;
+use crate::infra::aws::sso_admin_client_trait::{SsoAdminClientOps, SsoInstanceInfo};
+use crate::models::{ScanConfig, ScanWarning};
+
+/// アカウント割り当てのターゲット種別（Identity CenterはAWSアカウントのみサポート）
+const TARGET_TYPE_AWS_ACCOUNT: &str = "AWS_ACCOUNT";
+
+/// IAM Identity Centerのスキャン結果
+#[derive(Debug, Default)]
+pub struct IdentityCenterResources {
+    pub permission_sets: Vec<Value>,
+    pub managed_policy_attachments: Vec<Value>,
+    pub inline_policies: Vec<Value>,
+    pub account_assignments: Vec<Value>,
+}
+
+impl IdentityCenterResources {
+    /// 全リソースの件数
+    pub fn count(&self) -> usize {
+        self.permission_sets.len()
+            + self.managed_policy_attachments.len()
+            + self.inline_policies.len()
+            + self.account_assignments.len()
+    }
+}
+
+/// 許可セット1件分のスキャン結果
+#[derive(Default)]
+struct PermissionSetResources {
+    permission_set: Option<Value>,
+    managed_policy_attachments: Vec<Value>,
+    inline_policy: Option<Value>,
+    account_assignments: Vec<Value>,
+    warnings: Vec<ScanWarning>,
+}
+
+/// AWS IAM Identity Centerスキャナー
+///
+/// SSO Adminクライアントを抽象化することで、テスト時にモックを注入可能にしています。
+pub struct AwsSsoScanner<S: SsoAdminClientOps + ?Sized> {
+    client: Arc<S>,
+    /// 許可セット単位の同時取得数
+    concurrency: usize,
+}
+
+impl AwsSsoScanner<dyn SsoAdminClientOps> {
+    /// 本番用のスキャナーを作成（AWS CLI経由でSSO Admin APIを呼び出す）
+    pub async fn from_sdk_config(sdk_config: &SdkConfig, config: &ScanConfig) -> Result<Self> {
+        let cli = AwsCliRunner::new(sdk_config);
+        Ok(Self::new_with_client(
+            Arc::new(RealSsoAdminClient::new(cli)),
+            config.max_concurrency(),
+        ))
+    }
+}
+
+impl<S: SsoAdminClientOps + ?Sized> AwsSsoScanner<S> {
+    pub fn new_with_client(client: Arc<S>, concurrency: usize) -> Self {
+        Self {
+            client,
+            concurrency: concurrency.max(1),
+        }
+    }
+
+    /// 全インスタンスの許可セットとアカウント割り当てをスキャン
+    ///
+    /// 取得に失敗したインスタンス・許可セットは`warnings`に記録してスキップします。
+    pub async fn scan(&self, warnings: &mut Vec<ScanWarning>) -> IdentityCenterResources {
+        let mut resources = IdentityCenterResources::default();
+
+        let instances =
+            match with_throttling_retry("ListInstances", || self.client.list_instances()).await {
+                Ok(instances) => instances,
+                Err(e) => {
+                    warn!(error = %e, "IAM Identity Centerインスタンスの取得に失敗");
+                    warnings.push(scan_warning("identity_center", "", "ListInstances", &e));
+                    return resources;
+                }
+            };
+
+        for instance in &instances {
+            let permission_set_arns = match with_throttling_retry("ListPermissionSets", || {
+                self.client.list_permission_sets(&instance.instance_arn)
+            })
+            .await
+            {
+                Ok(arns) => arns,
+                Err(e) => {
+                    warn!(instance_arn = %instance.instance_arn, error = %e, "許可セットの取得に失敗");
+                    warnings.push(scan_warning(
+                        "identity_center",
+                        &instance.instance_arn,
+                        "ListPermissionSets",
+                        &e,
+                    ));
+                    continue;
+                }
+            };
+            debug!(
+                instance_arn = %instance.instance_arn,
+                count = permission_set_arns.len(),
+                "許可セットのスキャンを開始"
+            );
+
+            // 同時実行数を制限して並列に取得（結果は列挙順で返る）
+            let mut results = stream::iter(permission_set_arns)
+                .map(|permission_set_arn| async move {
+                    self.scan_permission_set(instance, &permission_set_arn)
+                        .await
+                })
+                .buffered(self.concurrency);
+
+            while let Some(result) = results.next().await {
+                resources.permission_sets.extend(result.permission_set);
+                resources
+                    .managed_policy_attachments
+                    .extend(result.managed_policy_attachments);
+                resources.inline_policies.extend(result.inline_policy);
+                resources
+                    .account_assignments
+                    .extend(result.account_assignments);
+                warnings.extend(result.warnings);
+            }
+        }
+
+        resources
+    }
+
+    /// 許可セット1件の詳細・ポリシー・アカウント割り当てを取得
+    async fn scan_permission_set(
+        &self,
+        instance: &SsoInstanceInfo,
+        permission_set_arn: &str,
+    ) -> PermissionSetResources {
+        let instance_arn = instance.instance_arn.as_str();
+        let mut result = PermissionSetResources::default();
+
+        let permission_set = match with_throttling_retry("DescribePermissionSet", || {
+            self.client
+                .describe_permission_set(instance_arn, permission_set_arn)
+        })
+        .await
+        {
+            Ok(permission_set) => permission_set,
+            Err(e) => {
+                warn!(permission_set_arn, error = %e, "許可セットの詳細の取得に失敗");
+                result.warnings.push(scan_warning(
+                    "permission_set",
+                    permission_set_arn,
+                    "DescribePermissionSet",
+                    &e,
+                ));
+                return result;
+            }
+        };
+        let name = permission_set.name.as_str();
+
+        let mut permission_set_json = json!({
+            "instance_arn": instance_arn,
+            "permission_set_arn": permission_set.permission_set_arn,
+            "name": name,
+        });
+        if !instance.identity_store_id.is_empty() {
+            permission_set_json["identity_store_id"] = json!(instance.identity_store_id);
+        }
+        if let Some(description) = &permission_set.description {
+            permission_set_json["description"] = json!(description);
+        }
+        if let Some(session_duration) = &permission_set.session_duration {
+            permission_set_json["session_duration"] = json!(session_duration);
+        }
+        if let Some(relay_state) = &permission_set.relay_state {
+            permission_set_json["relay_state"] = json!(relay_state);
+        }
+        if !permission_set.tags.is_empty() {
+            permission_set_json["tags"] = json!(permission_set.tags);
+        }
+        result.permission_set = Some(permission_set_json);
+
+        // 派生リソースには所有者の許可セットを参照するための共通フィールドを持たせる
+        let owner = |mut resource: Value| {
+            resource["instance_arn"] = json!(instance_arn);
+            resource["permission_set_arn"] = json!(permission_set_arn);
+            resource["permission_set_name"] = json!(name);
+            resource
+        };
+
+        match with_throttling_retry("GetInlinePolicyForPermissionSet", || {
+            self.client
+                .get_inline_policy_for_permission_set(instance_arn, permission_set_arn)
+        })
+        .await
+        {
+            Ok(Some(document)) => match serde_json::from_str::<Value>(&document) {
+                Ok(policy_document) => {
+                    result.inline_policy = Some(owner(json!({"policy_document": policy_document})));
+                }
+                Err(e) => {
+                    warn!(permission_set = name, error = %e, "インラインポリシーの解析に失敗");
+                    result.warnings.push(scan_warning(
+                        "permission_set",
+                        name,
+                        "GetInlinePolicyForPermissionSet",
+                        &anyhow::anyhow!("Failed to parse inline policy: {}", e),
+                    ));
+                }
+            },
+            Ok(None) => {}
+            Err(e) => {
+                warn!(permission_set = name, error = %e, "インラインポリシーの取得に失敗");
+                result.warnings.push(scan_warning(
+                    "permission_set",
+                    name,
+                    "GetInlinePolicyForPermissionSet",
+                    &e,
+                ));
+            }
+        }
+
+        match with_throttling_retry("ListManagedPoliciesInPermissionSet", || {
+            self.client
+                .list_managed_policies_in_permission_set(instance_arn, permission_set_arn)
+        })
+        .await
+        {
+            Ok(policies) => {
+                result.managed_policy_attachments = policies
+                    .into_iter()
+                    .map(|policy| {
+                        owner(json!({
+                            "managed_policy_arn": policy.arn,
+                            "managed_policy_name": policy.name,
+                        }))
+                    })
+                    .collect();
+            }
+            Err(e) => {
+                warn!(permission_set = name, error = %e, "AWS管理ポリシーの取得に失敗");
+                result.warnings.push(scan_warning(
+                    "permission_set",
+                    name,
+                    "ListManagedPoliciesInPermissionSet",
+                    &e,
+                ));
+            }
+        }
+
+        let account_ids = match with_throttling_retry(
+            "ListAccountsForProvisionedPermissionSet",
+            || {
+                self.client
+                    .list_accounts_for_provisioned_permission_set(instance_arn, permission_set_arn)
+            },
+        )
+        .await
+        {
+            Ok(account_ids) => account_ids,
+            Err(e) => {
+                warn!(permission_set = name, error = %e, "プロビジョニング先アカウントの取得に失敗");
+                result.warnings.push(scan_warning(
+                    "permission_set",
+                    name,
+                    "ListAccountsForProvisionedPermissionSet",
+                    &e,
+                ));
+                Vec::new()
+            }
+        };
+
+        for account_id in &account_ids {
+            match with_throttling_retry("ListAccountAssignments", || {
+                self.client
+                    .list_account_assignments(instance_arn, account_id, permission_set_arn)
+            })
+            .await
+            {
+                Ok(assignments) => {
+                    result
+                        .account_assignments
+                        .extend(assignments.into_iter().map(|assignment| {
+                            owner(json!({
+                                "target_id": assignment.account_id,
+                                "target_type": TARGET_TYPE_AWS_ACCOUNT,
+                                "principal_type": assignment.principal_type,
+                                "principal_id": assignment.principal_id,
+                            }))
+                        }));
+                }
+                Err(e) => {
+                    warn!(permission_set = name, account_id = %account_id, error = %e, "アカウント割り当ての取得に失敗");
+                    result.warnings.push(scan_warning(
+                        "permission_set",
+                        name,
+                        "ListAccountAssignments",
+                        &e,
+                    ));
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::aws::sso_admin_client_trait::mock::MockSsoAdminClient;
+    use crate::infra::aws::sso_admin_client_trait::{
+        SsoAccountAssignmentInfo, SsoManagedPolicyInfo, SsoPermissionSetInfo,
+    };
+    use std::collections::HashMap;
+
+    const INSTANCE_ARN: &str = "arn:aws:sso:::instance/ssoins-1111";
+    const ADMIN_ARN: &str = "arn:aws:sso:::permissionSet/ssoins-1111/ps-aaaa";
+    const READONLY_ARN: &str = "arn:aws:sso:::permissionSet/ssoins-1111/ps-bbbb";
+
+    fn permission_set(arn: &str, name: &str) -> SsoPermissionSetInfo {
+        SsoPermissionSetInfo {
+            permission_set_arn: arn.to_string(),
+            name: name.to_string(),
+            description: Some(format!("{} access", name)),
+            session_duration: Some("PT1H".to_string()),
+            relay_state: None,
+            tags: HashMap::new(),
+        }
+    }
+
+    fn mock_identity_center() -> MockSsoAdminClient {
+        let mut mock = MockSsoAdminClient::new();
+        mock.expect_list_instances().returning(|| {
+            Ok(vec![SsoInstanceInfo {
+                instance_arn: INSTANCE_ARN.to_string(),
+                identity_store_id: "d-1111".to_string(),
+            }])
+        });
+        mock.expect_list_permission_sets()
+            .returning(|_| Ok(vec![ADMIN_ARN.to_string(), READONLY_ARN.to_string()]));
+        mock.expect_describe_permission_set()
+            .returning(|_, arn| match arn {
+                ADMIN_ARN => Ok(permission_set(ADMIN_ARN, "Admin")),
+                _ => Ok(permission_set(READONLY_ARN, "ReadOnly")),
+            });
+        mock.expect_get_inline_policy_for_permission_set()
+            .returning(|_, arn| match arn {
+                ADMIN_ARN => Ok(Some(
+                    r#"{"Version":"2012-10-17","Statement":[{"Effect":"Deny","Action":"iam:*","Resource":"*"}]}"#
+                        .to_string(),
+                )),
+                _ => Ok(None),
+            });
+        mock.expect_list_managed_policies_in_permission_set()
+            .returning(|_, arn| match arn {
+                ADMIN_ARN => Ok(vec![SsoManagedPolicyInfo {
+                    name: "AdministratorAccess".to_string(),
+                    arn: "arn:aws:iam::aws:policy/AdministratorAccess".to_string(),
+                }]),
+                _ => Ok(Vec::new()),
+            });
+        mock.expect_list_accounts_for_provisioned_permission_set()
+            .returning(|_, arn| match arn {
+                ADMIN_ARN => Ok(vec!["111111111111".to_string(), "222222222222".to_string()]),
+                _ => Ok(vec!["111111111111".to_string()]),
+            });
+        mock.expect_list_account_assignments()
+            .returning(|_, account_id, arn| {
+                Ok(vec![SsoAccountAssignmentInfo {
+                    account_id: account_id.to_string(),
+                    permission_set_arn: arn.to_string(),
+                    principal_type: "GROUP".to_string(),
+                    principal_id: "90676a1f-aaaa-bbbb-cccc-000000000000".to_string(),
+                }])
+            });
+        mock
+    }
+
+    #[tokio::test]
+    async fn test_scan_permission_sets_and_assignments() {
+        let scanner = AwsSsoScanner::new_with_client(Arc::new(mock_identity_center()), 4);
+        let mut warnings = Vec::new();
+
+        let resources = scanner.scan(&mut warnings).await;
+
+        assert!(warnings.is_empty());
+        assert_eq!(resources.permission_sets.len(), 2);
+        assert_eq!(resources.permission_sets[0]["name"], "Admin");
+        assert_eq!(resources.permission_sets[0]["instance_arn"], INSTANCE_ARN);
+        assert_eq!(resources.permission_sets[0]["session_duration"], "PT1H");
+        assert!(resources.permission_sets[0].get("relay_state").is_none());
+
+        assert_eq!(resources.inline_policies.len(), 1);
+        assert_eq!(resources.inline_policies[0]["permission_set_name"], "Admin");
+        assert_eq!(
+            resources.inline_policies[0]["policy_document"]["Statement"][0]["Effect"],
+            "Deny"
+        );
+
+        assert_eq!(resources.managed_policy_attachments.len(), 1);
+        assert_eq!(
+            resources.managed_policy_attachments[0]["managed_policy_arn"],
+            "arn:aws:iam::aws:policy/AdministratorAccess"
+        );
+
+        // Admin: 2アカウント、ReadOnly: 1アカウント
+        assert_eq!(resources.account_assignments.len(), 3);
+        let assignment = &resources.account_assignments[0];
+        assert_eq!(assignment["target_id"], "111111111111");
+        assert_eq!(assignment["target_type"], "AWS_ACCOUNT");
+        assert_eq!(assignment["principal_type"], "GROUP");
+        assert_eq!(assignment["permission_set_arn"], ADMIN_ARN);
+        assert_eq!(resources.count(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_scan_records_warning_when_identity_center_is_unavailable() {
+        let mut mock = MockSsoAdminClient::new();
+        mock.expect_list_instances()
+            .returning(|| Err(anyhow::anyhow!("AccessDeniedException: not authorized")));
+        let scanner = AwsSsoScanner::new_with_client(Arc::new(mock), 1);
+        let mut warnings = Vec::new();
+
+        let resources = scanner.scan(&mut warnings).await;
+
+        assert_eq!(resources.count(), 0);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].operation, "ListInstances");
+        assert_eq!(warnings[0].error_kind, "access_denied");
+    }
+
+    #[tokio::test]
+    async fn test_scan_skips_permission_set_that_cannot_be_described() {
+        let mut mock = MockSsoAdminClient::new();
+        mock.expect_list_instances().returning(|| {
+            Ok(vec![SsoInstanceInfo {
+                instance_arn: INSTANCE_ARN.to_string(),
+                identity_store_id: String::new(),
+            }])
+        });
+        mock.expect_list_permission_sets()
+            .returning(|_| Ok(vec![ADMIN_ARN.to_string()]));
+        mock.expect_describe_permission_set()
+            .returning(|_, _| Err(anyhow::anyhow!("ResourceNotFoundException")));
+        let scanner = AwsSsoScanner::new_with_client(Arc::new(mock), 1);
+        let mut warnings = Vec::new();
+
+        let resources = scanner.scan(&mut warnings).await;
+
+        assert_eq!(resources.count(), 0);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].resource_type, "permission_set");
+        assert_eq!(warnings[0].resource_name, ADMIN_ARN);
+    }
+}
@@ -1,6 +1,6 @@
 //! Azureクライアント操作の抽象化トレイト
 //!
-//! このモジュールは、Azure CLI および Azure REST API 操作を抽象化し、
+//! このモジュールは、Azure Resource Manager および Microsoft Graph の REST API 操作を抽象化し、
 //! テスト時にモック実装を注入できるようにします。
 
 use anyhow::Result;
@@ -10,12 +10,15 @@ use serde_json::Value;
 
 /// Azureクライアント操作を抽象化するトレイト
 ///
-/// このトレイトを実装することで、本番用のAzure CLI/APIクライアントと
+/// このトレイトを実装することで、本番用のAzure APIクライアントと
 /// テスト用のモッククライアントを切り替えることができます。
 #[async_trait]
 pub trait AzureClientOps: Send + Sync {
-    /// Azure CLIコマンドを実行してJSONを取得
-    async fn execute_az_command(&self, args: Vec<String>) -> Result<Value>;
+    /// スコープで利用可能なRole Definitionsを取得（`properties`はトップレベルに展開）
+    async fn list_role_definitions(&self, scope: &str) -> Result<Vec<Value>>;
+
+    /// スコープに直接割り当てられたRole Assignmentsを取得（`properties`はトップレベルに展開）
+    async fn list_role_assignments(&self, scope: &str) -> Result<Vec<Value>>;
 
     /// 認証トークンを取得
     async fn get_auth_token(&self, scope: &str) -> Option<String>;
@@ -50,7 +53,8 @@ pub mod mock {
 
         #[async_trait]
         impl AzureClientOps for AzureClient {
-            async fn execute_az_command(&self, args: Vec<String>) -> Result<Value>;
+            async fn list_role_definitions(&self, scope: &str) -> Result<Vec<Value>>;
+            async fn list_role_assignments(&self, scope: &str) -> Result<Vec<Value>>;
             async fn get_auth_token(&self, scope: &str) -> Option<String>;
             fn get_http_client(&self) -> Option<HttpClient>;
             async fn get_principal_display_name(
@@ -1,123 +1,481 @@
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
+use azure_core::credentials::{Secret, TokenCredential};
+use azure_identity::{
+    AzureCliCredential, AzureCliCredentialOptions, ClientCertificateCredential,
+    ClientCertificateCredentialOptions, ClientSecretCredential, WorkloadIdentityCredential,
+    WorkloadIdentityCredentialOptions,
+};
+use reqwest::Client as HttpClient;
 use serde_json::Value;
 use std::collections::HashMap;
-use tokio::process::Command;
+use std::path::PathBuf;
+use std::sync::Arc;
 
-use crate::models::{AzureResourceGroup, AzureSubscription, ConnectionTestResponse};
+use super::management_api::{self, MANAGEMENT_ENDPOINT, MANAGEMENT_SCOPE};
+use crate::models::{AzureResourceGroup, AzureSubscription, ConnectionTestResponse, ScanConfig};
 
-pub struct AzureClientFactory;
+/// Azure CLIのログインセッションを使用する認証方式（既定）
+pub const AUTH_METHOD_AZ_LOGIN: &str = "az_login";
+/// サービスプリンシパル（クライアントシークレットまたは証明書）の認証方式
+pub const AUTH_METHOD_SERVICE_PRINCIPAL: &str = "service_principal";
+/// ワークロードIDフェデレーション（フェデレーションされたトークン）の認証方式
+pub const AUTH_METHOD_WORKLOAD_IDENTITY: &str = "workload_identity";
 
-impl AzureClientFactory {
-    /// Azure CLIコマンドを実行してJSONを取得
-    async fn execute_az_command(args: &[&str]) -> Result<Value> {
-        let output = Command::new("az")
-            .args(args)
-            .output()
-            .await
-            .context("Azure CLIがインストールされていないか、PATHに含まれていません")?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            anyhow::bail!("Azure CLIコマンドが失敗しました: {}", stderr);
+/// サブスクリプション一覧APIのバージョン
+const SUBSCRIPTIONS_API_VERSION: &str = "2022-12-01";
+/// リソースグループ一覧APIのバージョン
+const RESOURCE_GROUPS_API_VERSION: &str = "2021-04-01";
+
+/// 認証方式ごとの資格情報の設定
+///
+/// `auth_method`と`service_principal_config`から作成します。
+/// シークレットを含むため`Debug`は実装していません。
+#[derive(Clone)]
+pub enum AzureCredentialOptions {
+    /// `az login`済みのAzure CLIセッション
+    AzureCli { tenant_id: Option<String> },
+    /// サービスプリンシパルのクライアントシークレット
+    ClientSecret {
+        tenant_id: String,
+        client_id: String,
+        client_secret: String,
+    },
+    /// サービスプリンシパルのクライアント証明書（PKCS#12形式のファイル）
+    ClientCertificate {
+        tenant_id: String,
+        client_id: String,
+        certificate_path: String,
+        password: Option<String>,
+    },
+    /// ワークロードIDフェデレーション
+    ///
+    /// 未指定の値は`AZURE_TENANT_ID`・`AZURE_CLIENT_ID`・`AZURE_FEDERATED_TOKEN_FILE`から取得します。
+    WorkloadIdentity {
+        tenant_id: Option<String>,
+        client_id: Option<String>,
+        token_file_path: Option<String>,
+    },
+}
+
+impl AzureCredentialOptions {
+    /// スキャン設定から資格情報の設定を作成
+    pub fn from_scan_config(config: &ScanConfig) -> Result<Self> {
+        Self::from_parts(
+            config.auth_method.as_deref(),
+            config.tenant_id.as_deref(),
+            config.service_principal_config.as_ref(),
+        )
+    }
+
+    /// 認証方式・テナントID・サービスプリンシパル設定から資格情報の設定を作成
+    ///
+    /// テナントIDは引数を優先し、未指定の場合は`service_principal_config`の`tenant_id`を使用します。
+    /// サービスプリンシパルは`client_secret`を優先し、無い場合は`client_certificate_path`を使用します。
+    pub fn from_parts(
+        auth_method: Option<&str>,
+        tenant_id: Option<&str>,
+        service_principal_config: Option<&HashMap<String, String>>,
+    ) -> Result<Self> {
+        let config_value = |key: &str| {
+            service_principal_config
+                .and_then(|config| config.get(key))
+                .filter(|value| !value.is_empty())
+                .cloned()
+        };
+        let tenant_id = tenant_id
+            .filter(|tenant_id| !tenant_id.is_empty())
+            .map(|tenant_id| tenant_id.to_string())
+            .or_else(|| config_value("tenant_id"));
+
+        match auth_method.unwrap_or(AUTH_METHOD_AZ_LOGIN) {
+            "" | AUTH_METHOD_AZ_LOGIN => Ok(Self::AzureCli { tenant_id }),
+            AUTH_METHOD_SERVICE_PRINCIPAL => {
+                let tenant_id = tenant_id.ok_or_else(|| {
+                    anyhow!("tenant_id is required for service principal authentication")
+                })?;
+                let client_id = config_value("client_id").ok_or_else(|| {
+                    anyhow!("service_principal_config.client_id is required for service principal authentication")
+                })?;
+                if let Some(client_secret) = config_value("client_secret") {
+                    return Ok(Self::ClientSecret {
+                        tenant_id,
+                        client_id,
+                        client_secret,
+                    });
+                }
+                if let Some(certificate_path) = config_value("client_certificate_path") {
+                    return Ok(Self::ClientCertificate {
+                        tenant_id,
+                        client_id,
+                        certificate_path,
+                        password: config_value("client_certificate_password"),
+                    });
+                }
+                Err(anyhow!(
+                    "service_principal_config requires client_secret or client_certificate_path"
+                ))
+            }
+            AUTH_METHOD_WORKLOAD_IDENTITY => Ok(Self::WorkloadIdentity {
+                tenant_id,
+                client_id: config_value("client_id"),
+                token_file_path: config_value("federated_token_file"),
+            }),
+            other => Err(anyhow!("Unsupported Azure auth_method: {}", other)),
         }
+    }
+}
 
-        let stdout = String::from_utf8(output.stdout)
-            .context("Azure CLIの出力をUTF-8として解析できませんでした")?;
+pub struct AzureClientFactory;
 
-        let json: Value = serde_json::from_str(&stdout)
-            .context("Azure CLIの出力をJSONとして解析できませんでした")?;
+impl AzureClientFactory {
+    /// 認証方式に応じた`azure_identity`の資格情報を作成
+    pub fn create_credential(options: &AzureCredentialOptions) -> Result<Arc<dyn TokenCredential>> {
+        let credential: Arc<dyn TokenCredential> = match options {
+            AzureCredentialOptions::AzureCli { tenant_id } => {
+                AzureCliCredential::new(Some(AzureCliCredentialOptions {
+                    tenant_id: tenant_id.clone(),
+                    ..Default::default()
+                }))
+                .map_err(|e| anyhow!("Failed to create Azure CLI credential: {}", e))?
+            }
+            AzureCredentialOptions::ClientSecret {
+                tenant_id,
+                client_id,
+                client_secret,
+            } => ClientSecretCredential::new(
+                tenant_id,
+                client_id.clone(),
+                Secret::new(client_secret.clone()),
+                None,
+            )
+            .map_err(|e| anyhow!("Failed to create client secret credential: {}", e))?,
+            AzureCredentialOptions::ClientCertificate {
+                tenant_id,
+                client_id,
+                certificate_path,
+                password,
+            } => {
+                let certificate = std::fs::read(certificate_path).with_context(|| {
+                    format!("Failed to read client certificate: {}", certificate_path)
+                })?;
+                ClientCertificateCredential::new(
+                    tenant_id.clone(),
+                    client_id.clone(),
+                    azure_core::base64::encode(certificate),
+                    Some(ClientCertificateCredentialOptions {
+                        password: password.clone().map(Secret::new),
+                        ..Default::default()
+                    }),
+                )
+                .map_err(|e| anyhow!("Failed to create client certificate credential: {}", e))?
+            }
+            AzureCredentialOptions::WorkloadIdentity {
+                tenant_id,
+                client_id,
+                token_file_path,
+            } => WorkloadIdentityCredential::new(Some(WorkloadIdentityCredentialOptions {
+                tenant_id: tenant_id.clone(),
+                client_id: client_id.clone(),
+                token_file_path: token_file_path.as_ref().map(PathBuf::from),
+                ..Default::default()
+            }))
+            .map_err(|e| anyhow!("Failed to create workload identity credential: {}", e))?,
+        };
+        Ok(credential)
+    }
 
-        Ok(json)
+    /// 認証設定から資格情報を作成し、Azure Resource Manager用のトークンを取得
+    async fn management_token(
+        auth_method: Option<String>,
+        tenant_id: Option<String>,
+        service_principal_config: Option<HashMap<String, String>>,
+    ) -> Result<String> {
+        let options = AzureCredentialOptions::from_parts(
+            auth_method.as_deref(),
+            tenant_id.as_deref(),
+            service_principal_config.as_ref(),
+        )?;
+        let credential = Self::create_credential(&options)?;
+        management_api::get_token(credential.as_ref(), MANAGEMENT_SCOPE).await
     }
 
+    /// 資格情報でアクセス可能なサブスクリプションを取得して接続を確認
     pub async fn test_connection(
-        _auth_method: Option<String>,
-        _tenant_id: Option<String>,
-        _service_principal_config: Option<HashMap<String, String>>,
+        auth_method: Option<String>,
+        tenant_id: Option<String>,
+        service_principal_config: Option<HashMap<String, String>>,
     ) -> Result<ConnectionTestResponse> {
-        // Azure CLIで現在のアカウント情報を取得
-        let json = Self::execute_az_command(&["account", "show", "--output", "json"]).await?;
-
-        let subscription_id = json
-            .get("id")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
+        let subscriptions =
+            Self::list_subscriptions(auth_method, tenant_id, service_principal_config).await?;
 
-        let subscription_name = json
-            .get("name")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
+        // 有効なサブスクリプションを優先して表示する
+        let subscription = subscriptions
+            .iter()
+            .find(|subscription| subscription.state == "Enabled")
+            .or_else(|| subscriptions.first());
 
         Ok(ConnectionTestResponse {
             success: true,
             message: Some("Connection successful".to_string()),
-            account_id: subscription_id.clone(),
+            account_id: subscription.map(|s| s.subscription_id.clone()),
             user_arn: None,
-            subscription_name,
+            assumed_role_arn: None,
+            subscription_name: subscription.map(|s| s.display_name.clone()),
         })
     }
 
     pub async fn list_subscriptions(
-        _auth_method: Option<String>,
-        _tenant_id: Option<String>,
-        _service_principal_config: Option<HashMap<String, String>>,
+        auth_method: Option<String>,
+        tenant_id: Option<String>,
+        service_principal_config: Option<HashMap<String, String>>,
     ) -> Result<Vec<AzureSubscription>> {
-        // Azure CLIでサブスクリプション一覧を取得
-        let json = Self::execute_az_command(&["account", "list", "--output", "json"]).await?;
+        let token =
+            Self::management_token(auth_method, tenant_id, service_principal_config).await?;
+        let items = management_api::list_all(
+            &HttpClient::new(),
+            &token,
+            format!(
+                "{}/subscriptions?api-version={}",
+                MANAGEMENT_ENDPOINT, SUBSCRIPTIONS_API_VERSION
+            ),
+        )
+        .await
+        .context("サブスクリプション一覧の取得に失敗しました")?;
 
-        let subscriptions = json
-            .as_array()
-            .context("サブスクリプション一覧が配列形式ではありません")?
-            .iter()
-            .filter_map(|sub| {
-                Some(AzureSubscription {
-                    subscription_id: sub.get("id")?.as_str()?.to_string(),
-                    display_name: sub.get("name")?.as_str()?.to_string(),
-                    state: sub
-                        .get("state")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("Unknown")
-                        .to_string(),
-                })
-            })
-            .collect();
-
-        Ok(subscriptions)
+        Ok(parse_subscriptions(&items))
     }
 
     pub async fn list_resource_groups(
         subscription_id: String,
-        _auth_method: Option<String>,
-        _tenant_id: Option<String>,
-        _service_principal_config: Option<HashMap<String, String>>,
+        auth_method: Option<String>,
+        tenant_id: Option<String>,
+        service_principal_config: Option<HashMap<String, String>>,
     ) -> Result<Vec<AzureResourceGroup>> {
-        // Azure CLIでリソースグループ一覧を取得
-        let json = Self::execute_az_command(&[
-            "group",
-            "list",
-            "--subscription",
-            &subscription_id,
-            "--output",
-            "json",
-        ])
-        .await?;
-
-        let resource_groups = json
-            .as_array()
-            .context("リソースグループ一覧が配列形式ではありません")?
-            .iter()
-            .filter_map(|rg| {
-                Some(AzureResourceGroup {
-                    name: rg.get("name")?.as_str()?.to_string(),
-                    location: rg
-                        .get("location")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("Unknown")
-                        .to_string(),
-                })
+        let token =
+            Self::management_token(auth_method, tenant_id, service_principal_config).await?;
+        let items = management_api::list_all(
+            &HttpClient::new(),
+            &token,
+            format!(
+                "{}/subscriptions/{}/resourcegroups?api-version={}",
+                MANAGEMENT_ENDPOINT, subscription_id, RESOURCE_GROUPS_API_VERSION
+            ),
+        )
+        .await
+        .context("リソースグループ一覧の取得に失敗しました")?;
+
+        Ok(parse_resource_groups(&items))
+    }
+}
+
+/// サブスクリプション一覧APIの`value`をサブスクリプション情報に変換
+fn parse_subscriptions(items: &[Value]) -> Vec<AzureSubscription> {
+    items
+        .iter()
+        .filter_map(|sub| {
+            Some(AzureSubscription {
+                subscription_id: sub.get("subscriptionId")?.as_str()?.to_string(),
+                display_name: sub.get("displayName")?.as_str()?.to_string(),
+                state: sub
+                    .get("state")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("Unknown")
+                    .to_string(),
             })
-            .collect();
+        })
+        .collect()
+}
+
+/// リソースグループ一覧APIの`value`をリソースグループ情報に変換
+fn parse_resource_groups(items: &[Value]) -> Vec<AzureResourceGroup> {
+    items
+        .iter()
+        .filter_map(|rg| {
+            Some(AzureResourceGroup {
+                name: rg.get("name")?.as_str()?.to_string(),
+                location: rg
+                    .get("location")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("Unknown")
+                    .to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn service_principal_config(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_credential_options_default_to_azure_cli() {
+        let options = AzureCredentialOptions::from_parts(None, Some("tenant-1"), None).unwrap();
+        assert!(matches!(
+            options,
+            AzureCredentialOptions::AzureCli { tenant_id: Some(ref t) } if t == "tenant-1"
+        ));
+
+        let options = AzureCredentialOptions::from_parts(Some("az_login"), None, None).unwrap();
+        assert!(matches!(
+            options,
+            AzureCredentialOptions::AzureCli { tenant_id: None }
+        ));
+    }
+
+    #[test]
+    fn test_credential_options_service_principal_secret() {
+        let config = service_principal_config(&[
+            ("client_id", "app-1"),
+            ("client_secret", "secret"),
+            ("tenant_id", "tenant-from-config"),
+        ]);
+
+        let options =
+            AzureCredentialOptions::from_parts(Some("service_principal"), None, Some(&config))
+                .unwrap();
+
+        match options {
+            AzureCredentialOptions::ClientSecret {
+                tenant_id,
+                client_id,
+                client_secret,
+            } => {
+                assert_eq!(tenant_id, "tenant-from-config");
+                assert_eq!(client_id, "app-1");
+                assert_eq!(client_secret, "secret");
+            }
+            _ => panic!("expected client secret credential options"),
+        }
+    }
+
+    #[test]
+    fn test_credential_options_service_principal_certificate() {
+        let config = service_principal_config(&[
+            ("client_id", "app-1"),
+            ("client_secret", ""),
+            ("client_certificate_path", "/secrets/sp.pfx"),
+            ("client_certificate_password", "pass"),
+        ]);
+
+        let options = AzureCredentialOptions::from_parts(
+            Some("service_principal"),
+            Some("tenant-1"),
+            Some(&config),
+        )
+        .unwrap();
+
+        match options {
+            AzureCredentialOptions::ClientCertificate {
+                certificate_path,
+                password,
+                ..
+            } => {
+                assert_eq!(certificate_path, "/secrets/sp.pfx");
+                assert_eq!(password.as_deref(), Some("pass"));
+            }
+            _ => panic!("expected client certificate credential options"),
+        }
+    }
+
+    #[test]
+    fn test_credential_options_service_principal_requires_settings() {
+        let config = service_principal_config(&[("client_id", "app-1")]);
+
+        // テナントIDが無い
+        assert!(
+            AzureCredentialOptions::from_parts(Some("service_principal"), None, Some(&config))
+                .is_err()
+        );
+        // シークレットも証明書も無い
+        assert!(AzureCredentialOptions::from_parts(
+            Some("service_principal"),
+            Some("tenant-1"),
+            Some(&config)
+        )
+        .is_err());
+        // クライアントIDが無い
+        assert!(AzureCredentialOptions::from_parts(
+            Some("service_principal"),
+            Some("tenant-1"),
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_credential_options_workload_identity() {
+        let config = service_principal_config(&[
+            ("client_id", "app-1"),
+            ("federated_token_file", "/var/run/secrets/token"),
+        ]);
+
+        let options = AzureCredentialOptions::from_parts(
+            Some("workload_identity"),
+            Some("tenant-1"),
+            Some(&config),
+        )
+        .unwrap();
+
+        match options {
+            AzureCredentialOptions::WorkloadIdentity {
+                tenant_id,
+                client_id,
+                token_file_path,
+            } => {
+                assert_eq!(tenant_id.as_deref(), Some("tenant-1"));
+                assert_eq!(client_id.as_deref(), Some("app-1"));
+                assert_eq!(token_file_path.as_deref(), Some("/var/run/secrets/token"));
+            }
+            _ => panic!("expected workload identity credential options"),
+        }
+        assert!(AzureCredentialOptions::from_parts(Some("managed_identity"), None, None).is_err());
+    }
+
+    #[test]
+    fn test_create_client_secret_credential() {
+        let options = AzureCredentialOptions::ClientSecret {
+            tenant_id: "tenant-1".to_string(),
+            client_id: "app-1".to_string(),
+            client_secret: "secret".to_string(),
+        };
+        assert!(AzureClientFactory::create_credential(&options).is_ok());
+
+        let options = AzureCredentialOptions::ClientCertificate {
+            tenant_id: "tenant-1".to_string(),
+            client_id: "app-1".to_string(),
+            certificate_path: "/nonexistent/sp.pfx".to_string(),
+            password: None,
+        };
+        assert!(AzureClientFactory::create_credential(&options).is_err());
+    }
+
+    #[test]
+    fn test_parse_subscriptions_and_resource_groups() {
+        let subscriptions = parse_subscriptions(&[
+            json!({
+                "id": "/subscriptions/sub-123",
+                "subscriptionId": "sub-123",
+                "displayName": "Production",
+                "state": "Enabled"
+            }),
+            json!({"id": "/subscriptions/broken"}),
+        ]);
+        assert_eq!(subscriptions.len(), 1);
+        assert_eq!(subscriptions[0].subscription_id, "sub-123");
+        assert_eq!(subscriptions[0].display_name, "Production");
+        assert_eq!(subscriptions[0].state, "Enabled");
 
-        Ok(resource_groups)
+        let resource_groups =
+            parse_resource_groups(&[json!({"name": "rg-app", "location": "japaneast"})]);
+        assert_eq!(resource_groups[0].name, "rg-app");
+        assert_eq!(resource_groups[0].location, "japaneast");
     }
 }
@@ -0,0 +1,141 @@
+//! Azure Resource Manager REST APIヘルパー
+//!
+//! `azure_identity`の資格情報で取得したトークンを使用してARMのREST APIを呼び出します。
+//! Azure CLIのログインセッションに依存しないため、サービスプリンシパルや
+//! ワークロードIDフェデレーションでも同じ処理でスキャンできます。
+
+use anyhow::{anyhow, Context, Result};
+use azure_core::credentials::TokenCredential;
+use reqwest::Client as HttpClient;
+use serde_json::{Map, Value};
+
+/// Azure Resource ManagerのエンドポイントURL
+pub const MANAGEMENT_ENDPOINT: &str = "https://management.azure.com";
+
+/// Azure Resource Manager用のトークンスコープ
+pub const MANAGEMENT_SCOPE: &str = "https://management.azure.com/.default";
+
+/// 資格情報からアクセストークンを取得
+pub(crate) async fn get_token(credential: &dyn TokenCredential, scope: &str) -> Result<String> {
+    let token = credential
+        .get_token(&[scope], None)
+        .await
+        .map_err(|e| anyhow!("Failed to acquire Azure access token for {}: {}", scope, e))?;
+    Ok(token.token.secret().to_string())
+}
+
+/// 一覧APIを`nextLink`に従って最後まで取得し、`value`の要素を結合して返す
+pub(crate) async fn list_all(
+    http_client: &HttpClient,
+    token: &str,
+    url: String,
+) -> Result<Vec<Value>> {
+    let mut items = Vec::new();
+    let mut next_url = Some(url);
+
+    while let Some(url) = next_url {
+        let response = http_client
+            .get(&url)
+            .bearer_auth(token)
+            .send()
+            .await
+            .with_context(|| format!("Failed to request {}", url))?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .with_context(|| format!("Failed to read response of {}", url))?;
+        let page: Value = serde_json::from_str(&body).unwrap_or(Value::Null);
+
+        if !status.is_success() {
+            return Err(anyhow!(
+                "Azure Resource Manager request failed ({}): {}",
+                status,
+                error_message(&page).unwrap_or(body)
+            ));
+        }
+
+        items.extend(
+            page.get("value")
+                .and_then(|v| v.as_array())
+                .cloned()
+                .unwrap_or_default(),
+        );
+        next_url = page
+            .get("nextLink")
+            .and_then(|v| v.as_str())
+            .filter(|link| !link.is_empty())
+            .map(|link| link.to_string());
+    }
+
+    Ok(items)
+}
+
+/// エラーレスポンス（`{"error": {"code", "message"}}`）からメッセージを取得
+fn error_message(body: &Value) -> Option<String> {
+    let error = body.get("error")?;
+    let message = error.get("message").and_then(|v| v.as_str())?;
+    match error.get("code").and_then(|v| v.as_str()) {
+        Some(code) => Some(format!("{}: {}", code, message)),
+        None => Some(message.to_string()),
+    }
+}
+
+/// ARMリソースの`properties`をトップレベルに展開（Azure CLIの出力形式に合わせる）
+///
+/// `renamed`に指定したプロパティは別名で展開し、トップレベルに同名のキーが
+/// 既にある場合はトップレベルの値を優先します。
+pub(crate) fn flatten_properties(item: &Value, renamed: &[(&str, &str)]) -> Value {
+    let mut flattened: Map<String, Value> = item.as_object().cloned().unwrap_or_default();
+    if let Some(Value::Object(properties)) = flattened.remove("properties") {
+        for (key, value) in properties {
+            let key = renamed
+                .iter()
+                .find(|(from, _)| *from == key)
+                .map(|(_, to)| to.to_string())
+                .unwrap_or(key);
+            flattened.entry(key).or_insert(value);
+        }
+    }
+    Value::Object(flattened)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_flatten_properties() {
+        let item = json!({
+            "id": "/subscriptions/sub-123/providers/Microsoft.Authorization/roleDefinitions/role-1",
+            "name": "role-1",
+            "type": "Microsoft.Authorization/roleDefinitions",
+            "properties": {
+                "roleName": "Reader",
+                "type": "BuiltInRole",
+                "assignableScopes": ["/"]
+            }
+        });
+
+        let flattened = flatten_properties(&item, &[("type", "roleType")]);
+
+        assert_eq!(flattened["roleName"], "Reader");
+        assert_eq!(flattened["roleType"], "BuiltInRole");
+        assert_eq!(flattened["type"], "Microsoft.Authorization/roleDefinitions");
+        assert_eq!(flattened["assignableScopes"], json!(["/"]));
+        assert!(flattened.get("properties").is_none());
+    }
+
+    #[test]
+    fn test_error_message() {
+        let body = json!({
+            "error": {"code": "AuthorizationFailed", "message": "The client does not have authorization"}
+        });
+        assert_eq!(
+            error_message(&body).as_deref(),
+            Some("AuthorizationFailed: The client does not have authorization")
+        );
+        assert!(error_message(&Value::Null).is_none());
+    }
+}
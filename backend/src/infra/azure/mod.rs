@@ -1,4 +1,5 @@
 pub mod azure_client_trait;
 pub mod client_factory;
+pub mod management_api;
 pub mod real_azure_client;
 pub mod scanner;
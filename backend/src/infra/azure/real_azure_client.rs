@@ -1,70 +1,90 @@
-//! Azure CLI/API クライアントの本番実装
+//! Azure API クライアントの本番実装
 //!
 //! このモジュールは、`AzureClientOps`トレイトの本番実装を提供します。
+//! 認証には`azure_identity`の資格情報を使用し、Azure CLIのログインセッションには依存しません。
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 use async_trait::async_trait;
 use azure_core::credentials::TokenCredential;
-use azure_identity::AzureCliCredential;
 use reqwest::Client as HttpClient;
 use serde_json::Value;
-use tokio::process::Command;
+use std::sync::Arc;
 
 use super::azure_client_trait::AzureClientOps;
+use super::management_api::{self, MANAGEMENT_ENDPOINT, MANAGEMENT_SCOPE};
 
-/// Azure CLI/API クライアントをラップした本番実装
+/// Role Definitions/Assignments APIのバージョン
+const AUTHORIZATION_API_VERSION: &str = "2022-04-01";
+
+/// Azure API クライアントをラップした本番実装
 pub struct RealAzureClient {
     http_client: Option<HttpClient>,
+    credential: Arc<dyn TokenCredential>,
 }
 
 impl RealAzureClient {
-    pub fn new() -> Self {
+    pub fn new(credential: Arc<dyn TokenCredential>) -> Self {
         let http_client = HttpClient::builder().build().ok();
-        Self { http_client }
+        Self {
+            http_client,
+            credential,
+        }
     }
-}
 
-impl Default for RealAzureClient {
-    fn default() -> Self {
-        Self::new()
+    /// Microsoft.Authorizationの一覧APIを呼び出し、`properties`を展開して返す
+    async fn list_authorization_resources(
+        &self,
+        scope: &str,
+        resource: &str,
+        filter: Option<&str>,
+    ) -> Result<Vec<Value>> {
+        let http_client = self.http_client.clone().unwrap_or_default();
+        let token = management_api::get_token(self.credential.as_ref(), MANAGEMENT_SCOPE).await?;
+        let mut url = format!(
+            "{}{}/providers/Microsoft.Authorization/{}?api-version={}",
+            MANAGEMENT_ENDPOINT, scope, resource, AUTHORIZATION_API_VERSION
+        );
+        if let Some(filter) = filter {
+            url.push_str(&format!("&$filter={}", filter));
+        }
+
+        let items = management_api::list_all(&http_client, &token, url).await?;
+        // Azure CLIの出力と同じく、properties.typeはroleTypeとして展開する
+        Ok(items
+            .iter()
+            .map(|item| management_api::flatten_properties(item, &[("type", "roleType")]))
+            .collect())
     }
 }
 
 #[async_trait]
 impl AzureClientOps for RealAzureClient {
-    async fn execute_az_command(&self, args: Vec<String>) -> Result<Value> {
-        let output = Command::new("az")
-            .args(&args)
-            .output()
+    async fn list_role_definitions(&self, scope: &str) -> Result<Vec<Value>> {
+        self.list_authorization_resources(scope, "roleDefinitions", None)
             .await
-            .context("Azure CLIがインストールされていないか、PATHに含まれていません")?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            anyhow::bail!("Azure CLIコマンドが失敗しました: {}", stderr);
-        }
-
-        let stdout = String::from_utf8(output.stdout)
-            .context("Azure CLIの出力をUTF-8として解析できませんでした")?;
-
-        let json: Value = serde_json::from_str(&stdout)
-            .context("Azure CLIの出力をJSONとして解析できませんでした")?;
+    }
 
-        Ok(json)
+    async fn list_role_assignments(&self, scope: &str) -> Result<Vec<Value>> {
+        let assignments = self
+            .list_authorization_resources(scope, "roleAssignments", Some("atScope()"))
+            .await?;
+        // atScope()は上位スコープから継承した割り当ても返すため、
+        // Azure CLIの既定と同じくスコープに直接割り当てられたものだけに絞り込む
+        Ok(assignments
+            .into_iter()
+            .filter(|assignment| {
+                assignment
+                    .get("scope")
+                    .and_then(|v| v.as_str())
+                    .is_some_and(|assignment_scope| assignment_scope.eq_ignore_ascii_case(scope))
+            })
+            .collect())
     }
 
     async fn get_auth_token(&self, scope: &str) -> Option<String> {
-        let credential = match AzureCliCredential::new(None) {
-            Ok(cred) => cred,
-            Err(_) => return None,
-        };
-
-        let scopes = &[scope];
-        let token_response = match credential.get_token(scopes, None).await {
-            Ok(token) => token,
-            Err(_) => return None,
-        };
-        Some(token_response.token.secret().to_string())
+        management_api::get_token(self.credential.as_ref(), scope)
+            .await
+            .ok()
     }
 
     fn get_http_client(&self) -> Option<HttpClient> {
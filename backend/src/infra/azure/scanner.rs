@@ -1,4 +1,4 @@
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use futures::future::join_all;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -7,8 +7,9 @@ use tokio::sync::Semaphore;
 use tracing::{debug, info, warn};
 
 use super::azure_client_trait::AzureClientOps;
+use super::client_factory::{AzureClientFactory, AzureCredentialOptions};
 use super::real_azure_client::RealAzureClient;
-use crate::models::ScanConfig;
+use crate::models::{ProgressCallback, ScanConfig, ScanPhase, ScanProgress};
 
 pub struct AzureIamScanner<C: AzureClientOps> {
     config: ScanConfig,
@@ -17,9 +18,11 @@ pub struct AzureIamScanner<C: AzureClientOps> {
 
 impl AzureIamScanner<RealAzureClient> {
     pub async fn new(config: ScanConfig) -> Result<Self> {
+        let options = AzureCredentialOptions::from_scan_config(&config)?;
+        let credential = AzureClientFactory::create_credential(&options)?;
         Ok(Self {
             config,
-            client: Arc::new(RealAzureClient::new()),
+            client: Arc::new(RealAzureClient::new(credential)),
         })
     }
 }
@@ -69,58 +72,37 @@ impl<C: AzureClientOps> AzureIamScanner<C> {
         Value::Object(transformed)
     }
 
-    /// スコープに基づいてAzure CLIコマンドの引数を構築
-    fn get_scope_args(&self) -> Vec<String> {
-        let mut args = Vec::new();
+    /// スキャン設定からAzure Resource Managerのスコープを構築
+    fn get_scope(&self) -> Result<String> {
+        let subscription_scope = || {
+            self.config
+                .subscription_id
+                .as_deref()
+                .filter(|id| !id.is_empty())
+                .map(|id| format!("/subscriptions/{}", id))
+                .ok_or_else(|| anyhow!("subscription_id is required for Azure scanning"))
+        };
+        let scope_value = || {
+            self.config
+                .scope_value
+                .as_deref()
+                .filter(|value| !value.is_empty())
+                .ok_or_else(|| anyhow!("scope_value is required for the selected scope_type"))
+        };
 
-        if let Some(scope_type) = &self.config.scope_type {
-            match scope_type.as_str() {
-                "subscription" => {
-                    if let Some(subscription_id) = &self.config.subscription_id {
-                        args.push("--subscription".to_string());
-                        args.push(subscription_id.clone());
-                    }
-                }
-                "resource_group" => {
-                    if let Some(scope_value) = &self.config.scope_value {
-                        // For role commands, use --scope instead of --resource-group
-                        args.push("--scope".to_string());
-                        if let Some(subscription_id) = &self.config.subscription_id {
-                            args.push(format!(
-                                "/subscriptions/{}/resourceGroups/{}",
-                                subscription_id, scope_value
-                            ));
-                        } else {
-                            args.push(format!("/resourceGroups/{}", scope_value));
-                        }
-                    }
-                }
-                "management_group" => {
-                    if let Some(scope_value) = &self.config.scope_value {
-                        args.push("--scope".to_string());
-                        args.push(format!(
-                            "/providers/Microsoft.Management/managementGroups/{}",
-                            scope_value
-                        ));
-                    }
-                }
-                _ => {
-                    // Default: use subscription if available
-                    if let Some(subscription_id) = &self.config.subscription_id {
-                        args.push("--subscription".to_string());
-                        args.push(subscription_id.clone());
-                    }
-                }
-            }
-        } else {
-            // No scope type specified, use subscription if available
-            if let Some(subscription_id) = &self.config.subscription_id {
-                args.push("--subscription".to_string());
-                args.push(subscription_id.clone());
-            }
+        match self.config.scope_type.as_deref() {
+            Some("resource_group") => Ok(format!(
+                "{}/resourceGroups/{}",
+                subscription_scope()?,
+                scope_value()?
+            )),
+            Some("management_group") => Ok(format!(
+                "/providers/Microsoft.Management/managementGroups/{}",
+                scope_value()?
+            )),
+            // 既定はサブスクリプションスコープ
+            _ => subscription_scope(),
         }
-
-        args
     }
 
     /// Role Definitionsを取得
@@ -138,31 +120,19 @@ impl<C: AzureClientOps> AzureIamScanner<C> {
         let start_time = std::time::Instant::now();
         info!("Role Definitionsスキャンを開始");
 
-        let mut args: Vec<String> = vec![
-            "role".to_string(),
-            "definition".to_string(),
-            "list".to_string(),
-            "--output".to_string(),
-            "json".to_string(),
-        ];
-        let scope_args = self.get_scope_args();
-
-        // スコープ引数を追加
-        args.extend(scope_args);
+        let scope = self.get_scope()?;
 
-        let az_start = std::time::Instant::now();
-        debug!("Azure CLIコマンド実行開始: az role definition list");
-        let json = self.client.execute_az_command(args.clone()).await?;
+        let api_start = std::time::Instant::now();
+        debug!(scope = %scope, "Role Definitions一覧の取得を開始");
+        let role_definitions = self.client.list_role_definitions(&scope).await?;
         debug!(
-            elapsed_ms = az_start.elapsed().as_millis(),
-            "Azure CLIコマンド完了"
+            elapsed_ms = api_start.elapsed().as_millis(),
+            "Role Definitions一覧の取得完了"
         );
 
         // まず、すべてのrole definitionを収集
         let filter_start = std::time::Instant::now();
-        let role_definitions_vec: Vec<Value> = json
-            .as_array()
-            .context("Role Definitions一覧が配列形式ではありません")?
+        let role_definitions_vec: Vec<Value> = role_definitions
             .iter()
             .filter_map(|rd| {
                 // 名前プレフィックスフィルタを適用
@@ -393,31 +363,19 @@ impl<C: AzureClientOps> AzureIamScanner<C> {
         let start_time = std::time::Instant::now();
         info!("Role Assignmentsスキャンを開始");
 
-        let mut args: Vec<String> = vec![
-            "role".to_string(),
-            "assignment".to_string(),
-            "list".to_string(),
-            "--output".to_string(),
-            "json".to_string(),
-        ];
-        let scope_args = self.get_scope_args();
-
-        // スコープ引数を追加
-        args.extend(scope_args);
+        let scope = self.get_scope()?;
 
-        let az_start = std::time::Instant::now();
-        debug!("Azure CLIコマンド実行開始: az role assignment list");
-        let json = self.client.execute_az_command(args.clone()).await?;
+        let api_start = std::time::Instant::now();
+        debug!(scope = %scope, "Role Assignments一覧の取得を開始");
+        let role_assignments = self.client.list_role_assignments(&scope).await?;
         debug!(
-            elapsed_ms = az_start.elapsed().as_millis(),
-            "Azure CLIコマンド完了"
+            elapsed_ms = api_start.elapsed().as_millis(),
+            "Role Assignments一覧の取得完了"
         );
 
         // まず、すべてのrole assignmentを収集
         let filter_start = std::time::Instant::now();
-        let role_assignments_vec: Vec<Value> = json
-            .as_array()
-            .context("Role Assignments一覧が配列形式ではありません")?
+        let role_assignments_vec: Vec<Value> = role_assignments
             .iter()
             .filter_map(|ra| {
                 // 名前プレフィックスフィルタを適用
@@ -690,13 +648,14 @@ impl<C: AzureClientOps> AzureIamScanner<C> {
         Ok(transformed_assignments)
     }
 
-    pub async fn scan(
-        &self,
-        progress_callback: Box<dyn Fn(u32, String) + Send + Sync>,
-    ) -> Result<Value> {
+    pub async fn scan(&self, progress_callback: ProgressCallback) -> Result<Value> {
         let scan_start = std::time::Instant::now();
         info!("Azure IAMスキャン開始");
-        progress_callback(0, "Azure IAMスキャンを開始しています...".to_string());
+        progress_callback(ScanProgress::new(
+            ScanPhase::Started,
+            0,
+            "Azure IAMスキャンを開始しています...",
+        ));
 
         let mut results = serde_json::Map::new();
 
@@ -704,7 +663,11 @@ impl<C: AzureClientOps> AzureIamScanner<C> {
         results.insert("provider".to_string(), Value::String("azure".to_string()));
 
         // Role Definitionsをスキャン
-        progress_callback(20, "Role Definitionsのスキャン中...".to_string());
+        progress_callback(ScanProgress::scanning(
+            20,
+            "role_definitions",
+            "Role Definitionsのスキャン中...",
+        ));
         let role_definitions = self
             .scan_role_definitions()
             .await
@@ -714,13 +677,19 @@ impl<C: AzureClientOps> AzureIamScanner<C> {
             "role_definitions".to_string(),
             Value::Array(role_definitions),
         );
-        progress_callback(
+        progress_callback(ScanProgress::resource_completed(
             50,
+            "role_definitions",
+            role_def_count,
             format!("Role Definitionsのスキャン完了: {}件", role_def_count),
-        );
+        ));
 
         // Role Assignmentsをスキャン
-        progress_callback(60, "Role Assignmentsのスキャン中...".to_string());
+        progress_callback(ScanProgress::scanning(
+            60,
+            "role_assignments",
+            "Role Assignmentsのスキャン中...",
+        ));
         let role_assignments = self
             .scan_role_assignments()
             .await
@@ -730,22 +699,25 @@ impl<C: AzureClientOps> AzureIamScanner<C> {
             "role_assignments".to_string(),
             Value::Array(role_assignments),
         );
-        progress_callback(
+        progress_callback(ScanProgress::resource_completed(
             90,
+            "role_assignments",
+            role_assign_count,
             format!("Role Assignmentsのスキャン完了: {}件", role_assign_count),
-        );
+        ));
 
         info!(
             elapsed_ms = scan_start.elapsed().as_millis(),
             "Azure IAMスキャン完了"
         );
-        progress_callback(
+        progress_callback(ScanProgress::new(
+            ScanPhase::Completed,
             100,
             format!(
                 "Azure IAMスキャン完了: 合計{}ms",
                 scan_start.elapsed().as_millis()
             ),
-        );
+        ));
         Ok(Value::Object(results))
     }
 }
@@ -768,6 +740,15 @@ mod tests {
             profile: None,
             assume_role_arn: None,
             assume_role_session_name: None,
+            assume_role_external_id: None,
+            assume_role_duration_seconds: None,
+            mfa_serial: None,
+            mfa_token_code: None,
+            account_ids: Vec::new(),
+            discover_accounts: false,
+            member_role_name: None,
+            region: None,
+            endpoint_url: None,
             tenant_id: Some("test-tenant-id".to_string()),
             subscription_id: Some("test-subscription-id".to_string()),
             auth_method: Some("az_login".to_string()),
@@ -777,6 +758,10 @@ mod tests {
             scan_targets,
             filters: HashMap::new(),
             include_tags: true,
+            max_concurrency: None,
+            scan_strategy: None,
+            include_service_last_accessed: false,
+            unused_threshold_days: None,
         }
     }
 
@@ -887,24 +872,25 @@ mod tests {
     async fn test_scan_role_definitions_returns_all_definitions() {
         let mut mock_client = MockAzureClient::new();
 
-        // Azure CLIコマンドの結果を設定
+        // Role Definitions一覧APIの結果を設定
         mock_client
-            .expect_execute_az_command()
-            .returning(|_args| {
-                Ok(json!([
-                    {
+            .expect_list_role_definitions()
+            .withf(|scope| scope == "/subscriptions/test-subscription-id")
+            .returning(|_scope| {
+                Ok(vec![
+                    json!({
                         "id": "/subscriptions/sub-123/providers/Microsoft.Authorization/roleDefinitions/role-1",
                         "name": "Reader",
                         "description": "View all resources",
                         "type": "BuiltInRole"
-                    },
-                    {
+                    }),
+                    json!({
                         "id": "/subscriptions/sub-123/providers/Microsoft.Authorization/roleDefinitions/role-2",
                         "name": "Contributor",
                         "description": "Manage all resources",
                         "type": "BuiltInRole"
-                    }
-                ]))
+                    }),
+                ])
             });
 
         // トークン取得を設定（失敗してフォールバック）
@@ -925,25 +911,25 @@ mod tests {
         let mut mock_client = MockAzureClient::new();
 
         mock_client
-            .expect_execute_az_command()
-            .returning(|_args| {
-                Ok(json!([
-                    {
+            .expect_list_role_definitions()
+            .returning(|_scope| {
+                Ok(vec![
+                    json!({
                         "id": "/subscriptions/sub-123/providers/Microsoft.Authorization/roleDefinitions/role-1",
                         "name": "Custom-Reader",
                         "type": "CustomRole"
-                    },
-                    {
+                    }),
+                    json!({
                         "id": "/subscriptions/sub-123/providers/Microsoft.Authorization/roleDefinitions/role-2",
                         "name": "Contributor",
                         "type": "BuiltInRole"
-                    },
-                    {
+                    }),
+                    json!({
                         "id": "/subscriptions/sub-123/providers/Microsoft.Authorization/roleDefinitions/role-3",
                         "name": "Custom-Admin",
                         "type": "CustomRole"
-                    }
-                ]))
+                    }),
+                ])
             });
 
         mock_client.expect_get_auth_token().returning(|_| None);
@@ -982,26 +968,27 @@ mod tests {
     async fn test_scan_role_assignments_returns_all_assignments() {
         let mut mock_client = MockAzureClient::new();
 
-        // Azure CLIコマンドの結果を設定
+        // Role Assignments一覧APIの結果を設定
         mock_client
-            .expect_execute_az_command()
-            .returning(|_args| {
-                Ok(json!([
-                    {
+            .expect_list_role_assignments()
+            .withf(|scope| scope == "/subscriptions/test-subscription-id")
+            .returning(|_scope| {
+                Ok(vec![
+                    json!({
                         "name": "assignment-1",
                         "roleDefinitionId": "/subscriptions/sub-123/providers/Microsoft.Authorization/roleDefinitions/role-1",
                         "principalId": "principal-1",
                         "principalType": "User",
                         "scope": "/subscriptions/sub-123"
-                    },
-                    {
+                    }),
+                    json!({
                         "name": "assignment-2",
                         "roleDefinitionId": "/subscriptions/sub-123/providers/Microsoft.Authorization/roleDefinitions/role-2",
                         "principalId": "principal-2",
                         "principalType": "ServicePrincipal",
                         "scope": "/subscriptions/sub-123/resourceGroups/rg-1"
-                    }
-                ]))
+                    }),
+                ])
             });
 
         // トークン取得を設定
@@ -1044,16 +1031,19 @@ mod tests {
 
         // Role definitions用
         mock_client
-            .expect_execute_az_command()
-            .returning(|_args| {
-                Ok(json!([
-                    {
-                        "id": "/subscriptions/sub-123/providers/Microsoft.Authorization/roleDefinitions/role-1",
-                        "name": "Reader"
-                    }
-                ]))
+            .expect_list_role_definitions()
+            .returning(|_scope| {
+                Ok(vec![json!({
+                    "id": "/subscriptions/sub-123/providers/Microsoft.Authorization/roleDefinitions/role-1",
+                    "name": "Reader"
+                })])
             });
 
+        // Role assignments用
+        mock_client
+            .expect_list_role_assignments()
+            .returning(|_scope| Ok(Vec::new()));
+
         mock_client.expect_get_auth_token().returning(|_| None);
 
         mock_client.expect_get_http_client().returning(|| None);
@@ -1064,11 +1054,8 @@ mod tests {
         let progress_values = Arc::new(std::sync::Mutex::new(Vec::new()));
         let progress_values_clone = Arc::clone(&progress_values);
 
-        let callback = Box::new(move |progress: u32, message: String| {
-            progress_values_clone
-                .lock()
-                .unwrap()
-                .push((progress, message));
+        let callback = Box::new(move |event: ScanProgress| {
+            progress_values_clone.lock().unwrap().push(event);
         });
 
         let result = scanner.scan(callback).await.unwrap();
@@ -1079,8 +1066,18 @@ mod tests {
         // プログレスが記録されていることを確認
         let values = progress_values.lock().unwrap();
         assert!(!values.is_empty());
-        assert_eq!(values[0].0, 0); // 開始時は0%
-        assert!(values.last().unwrap().0 >= 90); // 終了時は90%以上
+        assert_eq!(values[0].progress, 0); // 開始時は0%
+        assert_eq!(values[0].phase, ScanPhase::Started);
+        assert!(values.last().unwrap().progress >= 90); // 終了時は90%以上
+        assert_eq!(values.last().unwrap().phase, ScanPhase::Completed);
+        let definitions = values
+            .iter()
+            .find(|event| event.phase == ScanPhase::ResourceCompleted)
+            .unwrap();
+        assert_eq!(
+            definitions.resource_type.as_deref(),
+            Some("role_definitions")
+        );
     }
 
     #[tokio::test]
@@ -1089,42 +1086,41 @@ mod tests {
 
         // エラーを返す
         mock_client
-            .expect_execute_az_command()
-            .returning(|_args| Err(anyhow::anyhow!("Azure CLI not found")));
+            .expect_list_role_definitions()
+            .returning(|_scope| Err(anyhow::anyhow!("AuthorizationFailed")));
 
         let config = create_test_config();
         let scanner = AzureIamScanner::new_with_client(config, mock_client);
 
-        let callback = Box::new(|_progress: u32, _message: String| {});
+        let callback = Box::new(|_event: ScanProgress| {});
         let result = scanner.scan(callback).await;
 
         assert!(result.is_err());
         // エラーは context() でラップされるので、エラーチェーンに含まれることを確認
         let error_msg = format!("{:?}", result.unwrap_err());
         assert!(
-            error_msg.contains("Azure CLI not found") || error_msg.contains("Role Definitions"),
-            "Expected error to contain 'Azure CLI not found' or 'Role Definitions', got: {}",
+            error_msg.contains("AuthorizationFailed") || error_msg.contains("Role Definitions"),
+            "Expected error to contain 'AuthorizationFailed' or 'Role Definitions', got: {}",
             error_msg
         );
     }
 
-    // ==================== get_scope_args テスト ====================
+    // ==================== get_scope テスト ====================
 
     #[test]
-    fn test_get_scope_args_subscription() {
+    fn test_get_scope_subscription() {
         let mock_client = MockAzureClient::new();
         let mut config = create_test_config();
         config.scope_type = Some("subscription".to_string());
         config.subscription_id = Some("my-sub-123".to_string());
 
         let scanner = AzureIamScanner::new_with_client(config, mock_client);
-        let args = scanner.get_scope_args();
 
-        assert_eq!(args, vec!["--subscription", "my-sub-123"]);
+        assert_eq!(scanner.get_scope().unwrap(), "/subscriptions/my-sub-123");
     }
 
     #[test]
-    fn test_get_scope_args_resource_group() {
+    fn test_get_scope_resource_group() {
         let mock_client = MockAzureClient::new();
         let mut config = create_test_config();
         config.scope_type = Some("resource_group".to_string());
@@ -1132,30 +1128,39 @@ mod tests {
         config.scope_value = Some("my-rg".to_string());
 
         let scanner = AzureIamScanner::new_with_client(config, mock_client);
-        let args = scanner.get_scope_args();
 
         assert_eq!(
-            args,
-            vec!["--scope", "/subscriptions/my-sub-123/resourceGroups/my-rg"]
+            scanner.get_scope().unwrap(),
+            "/subscriptions/my-sub-123/resourceGroups/my-rg"
         );
     }
 
     #[test]
-    fn test_get_scope_args_management_group() {
+    fn test_get_scope_management_group() {
         let mock_client = MockAzureClient::new();
         let mut config = create_test_config();
         config.scope_type = Some("management_group".to_string());
         config.scope_value = Some("my-mg".to_string());
 
         let scanner = AzureIamScanner::new_with_client(config, mock_client);
-        let args = scanner.get_scope_args();
 
         assert_eq!(
-            args,
-            vec![
-                "--scope",
-                "/providers/Microsoft.Management/managementGroups/my-mg"
-            ]
+            scanner.get_scope().unwrap(),
+            "/providers/Microsoft.Management/managementGroups/my-mg"
         );
     }
+
+    #[test]
+    fn test_get_scope_requires_values() {
+        let mut config = create_test_config();
+        config.subscription_id = None;
+        let scanner = AzureIamScanner::new_with_client(config, MockAzureClient::new());
+        assert!(scanner.get_scope().is_err());
+
+        let mut config = create_test_config();
+        config.scope_type = Some("resource_group".to_string());
+        config.scope_value = None;
+        let scanner = AzureIamScanner::new_with_client(config, MockAzureClient::new());
+        assert!(scanner.get_scope().is_err());
+    }
 }
@@ -1,15 +1,53 @@
 use anyhow::{Context, Result};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::infra::generators::naming::NamingGenerator;
 use crate::infra::templates::manager::TemplateManager;
-use crate::models::GenerationConfig;
+use crate::models::{GenerationConfig, ScanWarning};
 
 pub struct TerraformGenerator;
 
+/// ARNのパラメータ化で、レンダリング後に`${data.aws_partition.current.partition}`へ置換するプレースホルダー
+///
+/// テンプレート内の`${`エスケープの影響を受けないよう、レンダリング前は補間式ではなく
+/// プレースホルダーとしてコンテキストに埋め込みます。
+const PARTITION_PLACEHOLDER: &str = "__TFKOSMOS_AWS_PARTITION__";
+/// ARNのパラメータ化で、レンダリング後に`${data.aws_caller_identity.current.account_id}`へ置換するプレースホルダー
+const ACCOUNT_ID_PLACEHOLDER: &str = "__TFKOSMOS_AWS_ACCOUNT_ID__";
+
+/// ARNのパラメータ化で参照するデータソースの定義
+const ARN_DATA_SOURCES: &str = r#"data "aws_caller_identity" "current" {}
+
+data "aws_partition" "current" {}
+"#;
+
+/// ARNのパラメータ化で、値がアカウントIDそのものでも置換するプリンシパルのフィールド
+const PRINCIPAL_FIELDS: &[&str] = &["Principal", "NotPrincipal", "principal_identifiers"];
+
+/// ARNのパラメータ化で、値がアカウントIDそのものでも置換する条件キー
+const ACCOUNT_CONDITION_KEYS: &[&str] = &[
+    "aws:SourceAccount",
+    "aws:PrincipalAccount",
+    "aws:ResourceAccount",
+];
+
+/// 他のリソースからTerraformアドレスで参照されるリソースタイプ
+const REFERENCED_RESOURCE_TYPES: &[&str] = &[
+    "users",
+    "groups",
+    "roles",
+    "policies",
+    "oidc_providers",
+    "saml_providers",
+    "sso_permission_sets",
+    "service_control_policies",
+    "vpcs",
+    "security_groups",
+];
+
 // Resource type to template file mapping
 #[allow(dead_code)]
 struct ResourceTemplate {
@@ -35,6 +73,89 @@ impl TerraformGenerator {
         println!("[GENERATE] Output path: {:?}", output_path);
         println!("[GENERATE] Selected resources: {:?}", selected_resources);
 
+        let account_ids = Self::account_ids(scan_data);
+        let mut generated_files = if account_ids.is_empty() {
+            Self::generate_files(scan_data, config, selected_resources, output_path).await?
+        } else {
+            // マルチアカウントスキャンはアカウントごとのサブディレクトリに出力
+            let mut generated_files = Vec::new();
+            for account_id in &account_ids {
+                let account_path = output_path.join(account_id);
+                fs::create_dir_all(&account_path).with_context(|| {
+                    format!("Failed to create account directory: {:?}", account_path)
+                })?;
+                let account_data = Self::scan_data_for_account(scan_data, account_id);
+                let account_selection = Self::selection_for_account(selected_resources, account_id);
+                let files =
+                    Self::generate_files(&account_data, config, &account_selection, &account_path)
+                        .await
+                        .with_context(|| {
+                            format!("Failed to generate files for account {}", account_id)
+                        })?;
+                println!(
+                    "[GENERATE] Account {}: generated {} files",
+                    account_id,
+                    files.len()
+                );
+                if files.is_empty() {
+                    let _ = fs::remove_dir(&account_path);
+                }
+                generated_files.extend(
+                    files
+                        .into_iter()
+                        .map(|file| format!("{}/{}", account_id, file)),
+                );
+            }
+            generated_files
+        };
+
+        // Generate README if requested
+        if config.generate_readme {
+            println!("[GENERATE] Generating README");
+            let warnings: Vec<ScanWarning> = scan_data
+                .get("warnings")
+                .and_then(|v| serde_json::from_value(v.clone()).ok())
+                .unwrap_or_default();
+            let readme_path =
+                Self::generate_readme(config, output_path, &generated_files, &warnings)
+                    .await
+                    .with_context(|| "Failed to generate README")?;
+            println!("[GENERATE] Generated README: {}", readme_path);
+            generated_files.push(readme_path);
+        }
+
+        println!(
+            "[GENERATE] Generation complete. Generated {} files",
+            generated_files.len()
+        );
+        if generated_files.is_empty() {
+            return Err(anyhow::anyhow!(
+                "No files were generated. This may be because:\n\
+                1. No resources were found in the scan data\n\
+                2. All resources were filtered out by selection\n\
+                3. Template files could not be loaded\n\
+                Please check the scan data and ensure resources exist."
+            ));
+        }
+
+        Ok(generated_files)
+    }
+
+    /// 単一アカウント分のスキャンデータからTerraformファイルを生成
+    async fn generate_files(
+        scan_data: &Value,
+        config: &GenerationConfig,
+        selected_resources: &HashMap<String, Vec<Value>>,
+        output_path: &Path,
+    ) -> Result<Vec<String>> {
+        let provider = scan_data
+            .get("provider")
+            .and_then(|v| v.as_str())
+            .unwrap_or("aws");
+        let config = &Self::with_arn_account_id(config, scan_data);
+        // 参照先のリソースは生成対象に選択されたものだけをTerraformアドレスで参照する
+        let scan_data = &Self::selected_scan_data(scan_data, selected_resources);
+
         // Define resource templates based on provider
         let templates = Self::get_templates_for_provider(provider);
         println!(
@@ -44,17 +165,15 @@ impl TerraformGenerator {
         );
 
         let mut generated_files = Vec::new();
+        // 生成したリソースが参照するプロバイダーエイリアス（エイリアス名 → リージョン）
+        let mut provider_aliases = BTreeMap::new();
 
         // Process each resource type
         for template_info in templates {
             let resource_type = template_info.resource_type;
 
             // Get resources from scan data
-            let resources = scan_data
-                .get(resource_type)
-                .and_then(|v| v.as_array())
-                .cloned()
-                .unwrap_or_default();
+            let resources = Self::collect_resources(scan_data, resource_type);
 
             println!(
                 "[GENERATE] Resource type '{}': found {} resources",
@@ -70,103 +189,14 @@ impl TerraformGenerator {
                 continue;
             }
 
-            // Filter by selected resources if provided
-            // If selected_resources is empty or doesn't contain this resource type, use all resources
-            let resources_to_process = if selected_resources.is_empty() {
-                println!(
-                    "[GENERATE] No selection filter provided, using all {} resources for type '{}'",
-                    resources.len(),
-                    resource_type
-                );
-                resources
-            } else if let Some(selected) = selected_resources.get(resource_type) {
-                println!(
-                    "[GENERATE] Filtering resources for type '{}': {} selected",
-                    resource_type,
-                    selected.len()
-                );
-                if selected.is_empty() {
-                    println!(
-                        "[GENERATE] Skipping resource type '{}' (empty selection)",
-                        resource_type
-                    );
-                    continue; // Skip if empty selection
-                }
-
-                // Extract selected IDs (handle both string IDs and object IDs)
-                let selected_ids: Vec<String> = selected
-                    .iter()
-                    .filter_map(|s| {
-                        // If it's a string, use it directly
-                        if let Some(id_str) = s.as_str() {
-                            Some(id_str.to_string())
-                        } else if let Some(obj) = s.as_object() {
-                            // If it's an object, try to extract ID from common fields
-                            obj.get("user_name")
-                                .or_else(|| obj.get("group_name"))
-                                .or_else(|| obj.get("role_name"))
-                                .or_else(|| obj.get("arn"))
-                                .or_else(|| obj.get("id"))
-                                .and_then(|v| v.as_str())
-                                .map(|s| s.to_string())
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                println!(
-                    "[GENERATE] Extracted {} selected IDs: {:?}",
-                    selected_ids.len(),
-                    selected_ids
-                );
-
-                // Filter resources that match selected IDs
-                let filtered: Vec<_> = resources
-                    .iter()
-                    .filter(|r| {
-                        // Get resource identifier based on resource type
-                        let resource_id = match resource_type {
-                            "users" => r.get("user_name").and_then(|v| v.as_str()),
-                            "groups" => r.get("group_name").and_then(|v| v.as_str()),
-                            "roles" => r.get("role_name").and_then(|v| v.as_str()),
-                            "policies" => r
-                                .get("arn")
-                                .or_else(|| r.get("policy_name"))
-                                .and_then(|v| v.as_str()),
-                            _ => r
-                                .get("arn")
-                                .or_else(|| r.get("id"))
-                                .or_else(|| r.get("name"))
-                                .and_then(|v| v.as_str()),
-                        };
-
-                        if let Some(id) = resource_id {
-                            let matches = selected_ids.contains(&id.to_string());
-                            if matches {
-                                println!("[GENERATE] Resource '{}' matches selected ID", id);
-                            }
-                            matches
-                        } else {
-                            false
-                        }
-                    })
-                    .cloned()
-                    .collect();
-                println!(
-                    "[GENERATE] Filtered to {} resources for type '{}'",
-                    filtered.len(),
-                    resource_type
-                );
-                filtered
-            } else {
-                println!(
-                    "[GENERATE] No selection filter for type '{}', using all {} resources",
-                    resource_type,
-                    resources.len()
-                );
-                resources
-            };
+            // 選択フィルタが指定されている場合は選択されたリソースのみを生成
+            let resources_to_process =
+                Self::select_resources(resources, resource_type, selected_resources);
+            println!(
+                "[GENERATE] Selected {} resources for type '{}'",
+                resources_to_process.len(),
+                resource_type
+            );
 
             if resources_to_process.is_empty() {
                 println!(
@@ -181,9 +211,23 @@ impl TerraformGenerator {
                 resources_to_process.len(),
                 resource_type
             );
+            for resource in &resources_to_process {
+                if let (Some(alias), Some(region)) = (
+                    resource.get("provider_alias").and_then(|v| v.as_str()),
+                    resource.get("region").and_then(|v| v.as_str()),
+                ) {
+                    provider_aliases.insert(alias.to_string(), region.to_string());
+                }
+            }
 
             // Generate files based on file split rule
-            match config.file_split_rule.as_str() {
+            // アカウント単位のシングルトンリソースは分割ルールに関わらず1ファイルにまとめる
+            let file_split_rule = if Self::is_singleton(resource_type) {
+                "single"
+            } else {
+                config.file_split_rule.as_str()
+            };
+            match file_split_rule {
                 "single" => {
                     println!(
                         "[GENERATE] Generating single file for type '{}'",
@@ -276,977 +320,4941 @@ impl TerraformGenerator {
             }
         }
 
-        // Generate README if requested
-        if config.generate_readme {
-            println!("[GENERATE] Generating README");
-            let readme_path = Self::generate_readme(config, output_path, &generated_files)
-                .await
-                .with_context(|| "Failed to generate README")?;
-            println!("[GENERATE] Generated README: {}", readme_path);
-            generated_files.push(readme_path);
+        if provider == "aws" && !provider_aliases.is_empty() {
+            let file_name = "provider_aliases.tf".to_string();
+            let file_path = output_path.join(&file_name);
+            fs::write(&file_path, Self::provider_alias_blocks(&provider_aliases))
+                .with_context(|| format!("Failed to write file: {:?}", file_path))?;
+            generated_files.push(file_name);
         }
 
-        println!(
-            "[GENERATE] Generation complete. Generated {} files",
-            generated_files.len()
-        );
-        if generated_files.is_empty() {
-            return Err(anyhow::anyhow!(
-                "No files were generated. This may be because:\n\
-                1. No resources were found in the scan data\n\
-                2. All resources were filtered out by selection\n\
-                3. Template files could not be loaded\n\
-                Please check the scan data and ensure resources exist."
-            ));
+        if config.parameterize_arns && provider == "aws" && !generated_files.is_empty() {
+            let file_name = "arn_data_sources.tf".to_string();
+            let file_path = output_path.join(&file_name);
+            fs::write(&file_path, ARN_DATA_SOURCES)
+                .with_context(|| format!("Failed to write file: {:?}", file_path))?;
+            generated_files.push(file_name);
         }
 
         Ok(generated_files)
     }
 
-    fn get_templates_for_provider(provider: &str) -> Vec<ResourceTemplate> {
-        match provider {
-            "aws" => vec![
-                ResourceTemplate {
-                    resource_type: "users",
-                    template_path: "aws/iam_user.tf.j2",
-                    provider: "aws",
-                },
-                ResourceTemplate {
-                    resource_type: "groups",
-                    template_path: "aws/iam_group.tf.j2",
-                    provider: "aws",
-                },
-                ResourceTemplate {
-                    resource_type: "roles",
-                    template_path: "aws/iam_role.tf.j2",
-                    provider: "aws",
-                },
-                ResourceTemplate {
-                    resource_type: "policies",
-                    template_path: "aws/iam_policy.tf.j2",
-                    provider: "aws",
-                },
-            ],
-            "azure" => vec![
-                ResourceTemplate {
-                    resource_type: "role_definitions",
-                    template_path: "azure/role_definition.tf.j2",
-                    provider: "azure",
-                },
-                ResourceTemplate {
-                    resource_type: "role_assignments",
-                    template_path: "azure/role_assignment.tf.j2",
-                    provider: "azure",
-                },
-            ],
-            _ => vec![],
+    /// スキャンしたリージョン以外のリソース用に、リージョンごとのプロバイダーエイリアスを定義
+    fn provider_alias_blocks(provider_aliases: &BTreeMap<String, String>) -> String {
+        provider_aliases
+            .iter()
+            .map(|(alias, region)| {
+                format!(
+                    "provider \"aws\" {{\n  alias  = \"{}\"\n  region = \"{}\"\n}}\n",
+                    alias, region
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// ARNのパラメータ化で置き換えるアカウントIDを確定した生成設定を返す
+    ///
+    /// `account_id`が未指定の場合は、スキャン結果のARNからアカウントIDを推定します。
+    fn with_arn_account_id(config: &GenerationConfig, scan_data: &Value) -> GenerationConfig {
+        let mut config = config.clone();
+        if config.parameterize_arns && config.account_id.is_none() {
+            config.account_id = ["users", "groups", "roles", "policies", "instance_profiles"]
+                .iter()
+                .filter_map(|key| scan_data.get(*key).and_then(|v| v.as_array()))
+                .flatten()
+                .filter_map(|item| item.get("arn").and_then(|v| v.as_str()))
+                .filter_map(|arn| arn.split(':').nth(4))
+                .find(|account| account.len() == 12 && account.bytes().all(|b| b.is_ascii_digit()))
+                .map(str::to_string);
         }
+        config
     }
 
-    async fn generate_single_file(
-        resources: &[Value],
-        template_info: &ResourceTemplate,
-        config: &GenerationConfig,
-        output_path: &Path,
-    ) -> Result<String> {
-        println!(
-            "[GENERATE] Generating single file for {} resources",
-            resources.len()
-        );
-        let mut content = String::new();
+    /// マルチアカウントスキャンの対象アカウントID一覧（単一アカウントの場合は空）
+    fn account_ids(scan_data: &Value) -> Vec<String> {
+        scan_data
+            .get("accounts")
+            .and_then(|v| v.as_object())
+            .map(|accounts| accounts.keys().cloned().collect())
+            .unwrap_or_default()
+    }
 
-        for (idx, resource) in resources.iter().enumerate() {
-            println!(
-                "[GENERATE] Rendering resource {} of {}",
-                idx + 1,
-                resources.len()
-            );
-            let rendered = Self::render_resource(resource, template_info, config)
-                .await
-                .with_context(|| {
-                    format!(
-                        "Failed to render resource {} of type '{}'",
-                        idx + 1,
-                        template_info.resource_type
-                    )
-                })?;
-            content.push_str(&rendered);
-            content.push_str("\n\n");
+    /// マルチアカウントのスキャンデータから指定アカウント分のみを抽出
+    ///
+    /// 配列および`attachments`配下の配列を`account_id`で絞り込みます。
+    fn scan_data_for_account(scan_data: &Value, account_id: &str) -> Value {
+        let belongs =
+            |item: &Value| item.get("account_id").and_then(|v| v.as_str()) == Some(account_id);
+
+        let mut data = serde_json::Map::new();
+        if let Some(obj) = scan_data.as_object() {
+            for (key, value) in obj {
+                if key == "accounts" {
+                    continue;
+                }
+                let filtered = match value {
+                    Value::Array(items) => {
+                        Value::Array(items.iter().filter(|i| belongs(i)).cloned().collect())
+                    }
+                    Value::Object(groups) => Value::Object(
+                        groups
+                            .iter()
+                            .map(|(sub_key, sub_value)| {
+                                let sub_value = match sub_value.as_array() {
+                                    Some(items) => Value::Array(
+                                        items.iter().filter(|i| belongs(i)).cloned().collect(),
+                                    ),
+                                    None => sub_value.clone(),
+                                };
+                                (sub_key.clone(), sub_value)
+                            })
+                            .collect(),
+                    ),
+                    other => other.clone(),
+                };
+                data.insert(key.clone(), filtered);
+            }
+        }
+        Value::Object(data)
+    }
+
+    /// スキャンデータから指定リソースタイプのリソース一覧を取得
+    ///
+    /// インラインポリシーやポリシーアタッチメントのように`attachments`配下に
+    /// 格納されている派生リソースは、該当する配列から抽出して返します。
+    fn collect_resources(scan_data: &Value, resource_type: &str) -> Vec<Value> {
+        match resource_type {
+            "user_inline_policies" => Self::collect_inline_policies(scan_data, "user_policies"),
+            "group_inline_policies" => Self::collect_inline_policies(scan_data, "group_policies"),
+            "role_inline_policies" => Self::collect_inline_policies(scan_data, "role_policies"),
+            "user_policy_attachments" => {
+                Self::collect_managed_attachments(scan_data, "user_policies")
+            }
+            "group_policy_attachments" => {
+                Self::collect_managed_attachments(scan_data, "group_policies")
+            }
+            "role_policy_attachments" => {
+                Self::collect_managed_attachments(scan_data, "role_policies")
+            }
+            "user_group_memberships" => Self::collect_group_memberships(scan_data),
+            "instance_profiles" => Self::collect_instance_profiles(scan_data),
+            "roles" => Self::collect_roles(scan_data),
+            "service_linked_roles" => Self::collect_service_linked_roles(scan_data),
+            "aws_managed_policies" => Self::collect_aws_managed_policies(scan_data),
+            "service_control_policies" => Self::collect_customer_managed_scps(scan_data),
+            "service_control_policy_attachments" => Self::collect_with_managed_references(
+                scan_data,
+                resource_type,
+                &[(
+                    "policy_id",
+                    "service_control_policies",
+                    "policy_id",
+                    "policy_managed",
+                )],
+            ),
+            "sso_managed_policy_attachments"
+            | "sso_inline_policies"
+            | "sso_account_assignments" => Self::collect_with_managed_references(
+                scan_data,
+                resource_type,
+                &[(
+                    "permission_set_arn",
+                    "sso_permission_sets",
+                    "permission_set_arn",
+                    "permission_set_managed",
+                )],
+            ),
+            "s3_bucket_versionings" => {
+                Self::collect_bucket_configurations(scan_data, "versioning_status")
+            }
+            "s3_bucket_encryptions" => Self::collect_bucket_configurations(scan_data, "encryption"),
+            "s3_bucket_lifecycle_configurations" => {
+                Self::collect_bucket_configurations(scan_data, "lifecycle_rules")
+            }
+            "s3_bucket_public_access_blocks" => {
+                Self::collect_bucket_configurations(scan_data, "public_access_block")
+            }
+            "s3_bucket_ownership_controls" => {
+                Self::collect_bucket_configurations(scan_data, "object_ownership")
+            }
+            "subnets" | "security_groups" => Self::collect_with_managed_references(
+                scan_data,
+                resource_type,
+                &[("vpc_id", "vpcs", "vpc_id", "vpc_managed")],
+            ),
+            "security_group_ingress_rules" | "security_group_egress_rules" => {
+                Self::collect_with_managed_references(
+                    scan_data,
+                    resource_type,
+                    &[
+                        ("group_id", "security_groups", "group_id", "group_managed"),
+                        (
+                            "referenced_group_id",
+                            "security_groups",
+                            "group_id",
+                            "referenced_group_managed",
+                        ),
+                    ],
+                )
+            }
+            "cleanup_access_keys" => Self::collect_cleanup(scan_data, "access_key"),
+            "cleanup_login_profiles" => Self::collect_cleanup(scan_data, "login_profile"),
+            "cleanup_mfa_devices" => Self::collect_cleanup(scan_data, "mfa_device")
+                .into_iter()
+                .filter_map(Self::with_virtual_mfa_device_name)
+                .collect(),
+            _ => scan_data
+                .get(resource_type)
+                .and_then(|v| v.as_array())
+                .cloned()
+                .unwrap_or_default(),
         }
+    }
 
-        let file_name = format!("{}.tf", template_info.resource_type);
-        let file_path = output_path.join(&file_name);
+    /// `attachments`からポリシードキュメントを取得できたインラインポリシーを抽出
+    fn collect_inline_policies(scan_data: &Value, attachment_key: &str) -> Vec<Value> {
+        scan_data
+            .get("attachments")
+            .and_then(|a| a.get(attachment_key))
+            .and_then(|v| v.as_array())
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter(|e| e.get("policy_type").and_then(|v| v.as_str()) == Some("inline"))
+                    .filter(|e| e.get("policy_document").is_some())
+                    .filter(|e| !Self::is_service_linked_role_entry(scan_data, e))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 
-        println!(
-            "[GENERATE] Writing file: {:?} ({} bytes)",
-            file_path,
-            content.len()
-        );
-        fs::write(&file_path, content)
-            .with_context(|| format!("Failed to write file: {:?}", file_path))?;
+    /// `attachments`からマネージドポリシーのアタッチメントを抽出
+    ///
+    /// アタッチ先のポリシーがTerraformで参照できる場合は`policy_reference`を付与します。
+    /// AWS管理ポリシーは`data.aws_iam_policy`、生成対象のカスタマー管理ポリシーは
+    /// `aws_iam_policy`を参照し、それ以外はARNをそのまま指定します。
+    fn collect_managed_attachments(scan_data: &Value, attachment_key: &str) -> Vec<Value> {
+        let customer_policies: HashMap<&str, String> = scan_data
+            .get("policies")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|policy| {
+                let arn = policy.get("arn").and_then(|v| v.as_str())?;
+                let name = Self::get_resource_name(policy, "policies").ok()?;
+                Some((arn, name))
+            })
+            .collect();
+
+        scan_data
+            .get("attachments")
+            .and_then(|a| a.get(attachment_key))
+            .and_then(|v| v.as_array())
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter(|e| e.get("policy_type").and_then(|v| v.as_str()) == Some("managed"))
+                    .filter(|e| e.get("policy_arn").and_then(|v| v.as_str()).is_some())
+                    .filter(|e| !Self::is_service_linked_role_entry(scan_data, e))
+                    .map(|e| {
+                        let mut attachment = e.clone();
+                        let policy_arn = e.get("policy_arn").and_then(|v| v.as_str()).unwrap_or("");
+                        let reference = if Self::is_aws_managed_policy_arn(policy_arn) {
+                            Some((
+                                "data.aws_iam_policy",
+                                Self::policy_name_from_arn(policy_arn),
+                            ))
+                        } else {
+                            customer_policies
+                                .get(policy_arn)
+                                .map(|name| ("aws_iam_policy", name.as_str()))
+                        };
+                        if let Some((resource_type, name)) = reference {
+                            attachment["policy_reference"] =
+                                serde_json::json!({"resource_type": resource_type, "name": name});
+                        }
+                        attachment
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 
-        // Verify file was written
-        if !file_path.exists() {
-            return Err(anyhow::anyhow!("File was not created: {:?}", file_path));
+    /// AWS管理ポリシーのARN（`arn:<partition>:iam::aws:policy/...`）かどうか
+    fn is_aws_managed_policy_arn(policy_arn: &str) -> bool {
+        let mut parts = policy_arn.splitn(6, ':');
+        parts.nth(2) == Some("iam")
+            && parts.nth(1) == Some("aws")
+            && parts.next().is_some_and(|r| r.starts_with("policy/"))
+    }
+
+    /// アタッチメントで参照されているAWS管理ポリシーを重複なく抽出
+    ///
+    /// `data "aws_iam_policy"`として名前とパスから参照し、
+    /// パーティションごとに異なるARNを生成コードに含めないようにします。
+    fn collect_aws_managed_policies(scan_data: &Value) -> Vec<Value> {
+        let mut policy_arns: Vec<&str> = Vec::new();
+        for attachment_key in ["user_policies", "group_policies", "role_policies"] {
+            for entry in scan_data
+                .get("attachments")
+                .and_then(|a| a.get(attachment_key))
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+            {
+                if entry.get("policy_type").and_then(|v| v.as_str()) != Some("managed")
+                    || Self::is_service_linked_role_entry(scan_data, entry)
+                {
+                    continue;
+                }
+                if let Some(arn) = entry.get("policy_arn").and_then(|v| v.as_str()) {
+                    if Self::is_aws_managed_policy_arn(arn) && !policy_arns.contains(&arn) {
+                        policy_arns.push(arn);
+                    }
+                }
+            }
         }
-        let metadata = fs::metadata(&file_path)?;
-        println!(
-            "[GENERATE] File written successfully: {:?} ({} bytes)",
-            file_path,
-            metadata.len()
-        );
 
-        Ok(file_name)
+        policy_arns
+            .into_iter()
+            .map(|arn| {
+                // arn:<partition>:iam::aws:policy<path><name>
+                let resource_path = arn.split_once(":policy").map_or("/", |(_, p)| p);
+                let name = Self::policy_name_from_arn(arn);
+                let path = &resource_path[..resource_path.len() - name.len()];
+                serde_json::json!({
+                    "policy_name": name,
+                    "path": path,
+                    "arn": arn,
+                })
+            })
+            .collect()
     }
 
-    async fn generate_by_resource_type(
-        resources: &[Value],
-        template_info: &ResourceTemplate,
-        config: &GenerationConfig,
-        output_path: &Path,
-    ) -> Result<String> {
-        // Same as single file for now
-        Self::generate_single_file(resources, template_info, config, output_path).await
+    /// サービスリンクロールかどうか
+    fn is_service_linked_role(role: &Value) -> bool {
+        role.get("role_category").and_then(|v| v.as_str()) == Some("service_linked")
     }
 
-    async fn generate_by_resource_name(
-        resources: &[Value],
-        template_info: &ResourceTemplate,
-        config: &GenerationConfig,
-        output_path: &Path,
-    ) -> Result<Vec<String>> {
-        let mut files = Vec::new();
-
-        for resource in resources {
-            let rendered = Self::render_resource(resource, template_info, config).await?;
-
-            // Get resource name for file name
-            let resource_name = Self::get_resource_name(resource, template_info.resource_type)?;
-            let file_name = format!(
-                "{}_{}.tf",
-                template_info.resource_type,
-                NamingGenerator::apply_naming_convention(&resource_name, &config.naming_convention)
-            );
-            let file_path = output_path.join(&file_name);
-
-            fs::write(&file_path, rendered)
-                .with_context(|| format!("Failed to write file: {:?}", file_path))?;
+    /// アタッチメントの所有者がサービスリンクロールかどうか
+    ///
+    /// サービスリンクロールのポリシーはAWSが管理し変更できないため、
+    /// インラインポリシー・アタッチメントは生成対象から除外します。
+    fn is_service_linked_role_entry(scan_data: &Value, entry: &Value) -> bool {
+        let Some(role_name) = entry.get("role_name").and_then(|v| v.as_str()) else {
+            return false;
+        };
+        scan_data
+            .get("roles")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .any(|role| {
+                role.get("role_name").and_then(|v| v.as_str()) == Some(role_name)
+                    && Self::is_service_linked_role(role)
+            })
+    }
 
-            files.push(file_name);
-        }
+    /// サービスリンクロールを抽出（`aws_iam_service_linked_role`として生成）
+    fn collect_service_linked_roles(scan_data: &Value) -> Vec<Value> {
+        scan_data
+            .get("roles")
+            .and_then(|v| v.as_array())
+            .map(|roles| {
+                roles
+                    .iter()
+                    .filter(|role| Self::is_service_linked_role(role))
+                    .filter(|role| role.get("aws_service_name").is_some())
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 
-        Ok(files)
+    /// カスタマー管理のSCPを抽出
+    ///
+    /// `FullAWSAccess`等のAWS管理ポリシーはTerraformで作成できないため除外し、
+    /// アタッチメントからはポリシーIDで直接参照します。
+    fn collect_customer_managed_scps(scan_data: &Value) -> Vec<Value> {
+        scan_data
+            .get("service_control_policies")
+            .and_then(|v| v.as_array())
+            .map(|policies| {
+                policies
+                    .iter()
+                    .filter(|p| p.get("aws_managed").and_then(|v| v.as_bool()) != Some(true))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 
-    async fn render_resource(
-        resource: &Value,
-        template_info: &ResourceTemplate,
-        config: &GenerationConfig,
-    ) -> Result<String> {
-        // Get resource name for Terraform resource identifier
-        let resource_name = Self::get_resource_name(resource, template_info.resource_type)?;
-        let terraform_resource_name =
-            NamingGenerator::apply_naming_convention(&resource_name, &config.naming_convention);
+    /// `attachments.user_groups`をユーザー単位のグループメンバーシップに集約
+    ///
+    /// `aws_iam_user_group_membership`はユーザーごとに1リソースとなるため、
+    /// `{user_name, group_names}`の形式にまとめます（スキャン順を維持）。
+    /// グループのうち生成対象に含まれるものを`managed_group_names`に付与します。
+    fn collect_group_memberships(scan_data: &Value) -> Vec<Value> {
+        let managed_groups: Vec<&str> = scan_data
+            .get("groups")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|group| group.get("group_name").and_then(|v| v.as_str()))
+            .collect();
+
+        let entries = match scan_data
+            .get("attachments")
+            .and_then(|a| a.get("user_groups"))
+            .and_then(|v| v.as_array())
+        {
+            Some(entries) => entries,
+            None => return Vec::new(),
+        };
 
-        // Prepare context for template
-        let mut context = serde_json::Map::new();
-        context.insert(
-            "resource_name".to_string(),
-            Value::String(terraform_resource_name),
-        );
+        let mut memberships: Vec<(String, Vec<String>)> = Vec::new();
+        for entry in entries {
+            let (Some(user_name), Some(group_name)) = (
+                entry.get("user_name").and_then(|v| v.as_str()),
+                entry.get("group_name").and_then(|v| v.as_str()),
+            ) else {
+                continue;
+            };
 
-        // Add resource data based on resource type
-        match template_info.resource_type {
-            "users" => {
-                context.insert("user".to_string(), resource.clone());
-            }
-            "groups" => {
-                context.insert("group".to_string(), resource.clone());
-            }
-            "roles" => {
-                context.insert("role".to_string(), resource.clone());
-            }
-            "policies" => {
-                context.insert("policy".to_string(), resource.clone());
-            }
-            _ => {
-                // For other types, use generic "resource" key
-                context.insert("resource".to_string(), resource.clone());
+            match memberships.iter_mut().find(|(name, _)| name == user_name) {
+                Some((_, groups)) => groups.push(group_name.to_string()),
+                None => memberships.push((user_name.to_string(), vec![group_name.to_string()])),
             }
         }
 
-        let context_value = Value::Object(context);
-
-        // Render template
-        println!(
-            "[GENERATE] Rendering template: {}",
-            template_info.template_path
-        );
-        let rendered =
-            TemplateManager::render_template(template_info.template_path, &context_value)
-                .await
-                .with_context(|| {
-                    format!("Failed to render template: {}", template_info.template_path)
-                })?;
-        println!(
-            "[GENERATE] Template rendered successfully ({} bytes)",
-            rendered.len()
-        );
-        Ok(rendered)
+        memberships
+            .into_iter()
+            .map(|(user_name, group_names)| {
+                let managed_group_names: Vec<&String> = group_names
+                    .iter()
+                    .filter(|name| managed_groups.contains(&name.as_str()))
+                    .collect();
+                serde_json::json!({
+                    "user_name": user_name,
+                    "group_names": group_names,
+                    "managed_group_names": managed_group_names,
+                })
+            })
+            .collect()
     }
 
-    fn get_resource_name(resource: &Value, resource_type: &str) -> Result<String> {
-        match resource_type {
-            "users" => Ok(resource
-                .get("user_name")
-                .and_then(|v| v.as_str())
-                .ok_or_else(|| anyhow::anyhow!("Missing user_name"))?
-                .to_string()),
-            "groups" => Ok(resource
-                .get("group_name")
-                .and_then(|v| v.as_str())
-                .ok_or_else(|| anyhow::anyhow!("Missing group_name"))?
-                .to_string()),
-            "roles" => Ok(resource
-                .get("role_name")
-                .and_then(|v| v.as_str())
-                .ok_or_else(|| anyhow::anyhow!("Missing role_name"))?
-                .to_string()),
-            "policies" => Ok(resource
-                .get("policy_name")
-                .and_then(|v| v.as_str())
-                .ok_or_else(|| anyhow::anyhow!("Missing policy_name"))?
-                .to_string()),
-            _ => {
-                // Try common fields
-                if let Some(name) = resource.get("name").and_then(|v| v.as_str()) {
-                    Ok(name.to_string())
-                } else if let Some(name) = resource.get("display_name").and_then(|v| v.as_str()) {
-                    Ok(name.to_string())
-                } else {
-                    Err(anyhow::anyhow!("Cannot determine resource name"))
-                }
-            }
-        }
+    /// インスタンスプロファイルに、関連付けられたロールが生成対象に含まれるかを付与
+    ///
+    /// ロールがTerraform管理下（生成対象に含まれる）の場合のみ`aws_iam_role`を
+    /// アドレスで参照し、それ以外はロール名をそのまま指定します。
+    fn collect_instance_profiles(scan_data: &Value) -> Vec<Value> {
+        let role_names: Vec<&str> = scan_data
+            .get("roles")
+            .and_then(|v| v.as_array())
+            .map(|roles| {
+                roles
+                    .iter()
+                    .filter(|r| !Self::is_service_linked_role(r))
+                    .filter_map(|r| r.get("role_name").and_then(|v| v.as_str()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        scan_data
+            .get("instance_profiles")
+            .and_then(|v| v.as_array())
+            .map(|profiles| {
+                profiles
+                    .iter()
+                    .map(|profile| {
+                        let mut profile = profile.clone();
+                        let role_managed = profile
+                            .get("role_name")
+                            .and_then(|v| v.as_str())
+                            .is_some_and(|name| role_names.contains(&name));
+                        profile["role_managed"] = Value::Bool(role_managed);
+                        profile
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 
-    async fn generate_readme(
-        _config: &GenerationConfig,
-        output_path: &Path,
-        files: &[String],
-    ) -> Result<String> {
-        let mut readme = String::new();
-        readme.push_str("# Terraform Code Generation\n\n");
-        readme.push_str("This directory contains Terraform code generated by TFKosmos.\n\n");
-        readme.push_str("## Generated Files\n\n");
+    /// `cleanup`配列から指定種別の認証情報を抽出
+    ///
+    /// 各要素の`resource`を展開し、フロントエンドの選択IDと照合するための
+    /// `cleanup_id`と、ユーザーが生成対象に含まれるかどうか（`user_managed`）を付与します。
+    fn collect_cleanup(scan_data: &Value, cleanup_type: &str) -> Vec<Value> {
+        let managed_users: Vec<&str> = scan_data
+            .get("users")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|user| user.get("user_name").and_then(|v| v.as_str()))
+            .collect();
+
+        scan_data
+            .get("cleanup")
+            .and_then(|v| v.as_array())
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter(|entry| {
+                        entry.get("type").and_then(|v| v.as_str()) == Some(cleanup_type)
+                    })
+                    .filter_map(|entry| {
+                        let mut resource = entry.get("resource")?.clone();
+                        let cleanup_id = match cleanup_type {
+                            "access_key" => resource.get("access_key_id")?.as_str()?.to_string(),
+                            "login_profile" => {
+                                format!("login_profile_{}", resource.get("user_name")?.as_str()?)
+                            }
+                            _ => resource.get("serial_number")?.as_str()?.to_string(),
+                        };
+                        resource["cleanup_id"] = Value::String(cleanup_id);
+                        let user_managed = resource
+                            .get("user_name")
+                            .and_then(|v| v.as_str())
+                            .is_some_and(|name| managed_users.contains(&name));
+                        resource["user_managed"] = Value::Bool(user_managed);
+                        Some(resource)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 
-        for file in files {
-            readme.push_str(&format!("- {}\n", file));
+    /// 仮想MFAデバイスのARNからデバイス名とパスを付与
+    ///
+    /// ハードウェアMFAやセキュリティキーに対応するTerraformリソースは無いため、
+    /// 仮想MFAデバイス以外は除外します。
+    fn with_virtual_mfa_device_name(mut device: Value) -> Option<Value> {
+        if device.get("device_type").and_then(|v| v.as_str()) != Some("virtual") {
+            return None;
         }
-
-        readme.push_str("\n## Usage\n\n");
-        readme.push_str("1. Review the generated Terraform files\n");
-        readme.push_str("2. Run `terraform init` to initialize the Terraform working directory\n");
-        readme.push_str("3. Run `terraform plan` to review the changes\n");
-        readme.push_str("4. Run `terraform apply` to apply the changes\n\n");
-        readme.push_str("## Import Script\n\n");
-        readme.push_str(
-            "Use the generated import script to import existing resources into Terraform state.\n",
-        );
-
-        let readme_path = output_path.join("README.md");
-        fs::write(&readme_path, readme)
-            .with_context(|| format!("Failed to write README: {:?}", readme_path))?;
-
-        Ok("README.md".to_string())
+        // arn:aws:iam::<account>:mfa/<path><name>
+        let resource_path = device
+            .get("serial_number")?
+            .as_str()?
+            .split_once(":mfa/")?
+            .1
+            .to_string();
+        let (path, name) = match resource_path.rsplit_once('/') {
+            Some((path, name)) => (format!("/{}/", path), name.to_string()),
+            None => ("/".to_string(), resource_path),
+        };
+        device["virtual_mfa_device_name"] = Value::String(name);
+        device["path"] = Value::String(path);
+        Some(device)
     }
 
-    pub async fn generate_import_script(
-        scan_data: &Value,
-        config: &GenerationConfig,
-        selected_resources: &HashMap<String, Vec<Value>>,
-        output_path: &Path,
-    ) -> Result<Option<String>> {
-        let provider = scan_data
-            .get("provider")
-            .and_then(|v| v.as_str())
-            .unwrap_or("aws");
-
-        let mut import_commands = Vec::new();
+    /// ロールの信頼ポリシーのFederatedプリンシパルを、生成対象のIDプロバイダーに関連付け
+    ///
+    /// プリンシパルのARNが`oidc_providers`/`saml_providers`に含まれる場合、ステートメントの
+    /// `principal_providers`に参照先のリソース種別とリソース名を付与します（該当しない場合はnull）。
+    fn collect_roles(scan_data: &Value) -> Vec<Value> {
+        let mut providers: HashMap<&str, Value> = HashMap::new();
+        for (key, terraform_type) in [
+            ("oidc_providers", "aws_iam_openid_connect_provider"),
+            ("saml_providers", "aws_iam_saml_provider"),
+        ] {
+            for provider in scan_data
+                .get(key)
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+            {
+                let (Some(arn), Ok(name)) = (
+                    provider.get("arn").and_then(|v| v.as_str()),
+                    Self::get_resource_name(provider, key),
+                ) else {
+                    continue;
+                };
+                providers.insert(
+                    arn,
+                    serde_json::json!({"resource_type": terraform_type, "name": name}),
+                );
+            }
+        }
 
-        // Process each resource type
-        let templates = Self::get_templates_for_provider(provider);
-        for template_info in templates {
-            let resource_type = template_info.resource_type;
+        // サービスリンクロールは`service_linked_roles`として別途生成する
+        let mut roles: Vec<Value> = scan_data
+            .get("roles")
+            .and_then(|v| v.as_array())
+            .map(|roles| {
+                roles
+                    .iter()
+                    .filter(|role| !Self::is_service_linked_role(role))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        if providers.is_empty() {
+            return roles;
+        }
 
-            let resources = scan_data
-                .get(resource_type)
+        for statement in roles.iter_mut().flat_map(|role| {
+            role.get_mut("assume_role_statements")
+                .and_then(|v| v.as_array_mut())
+                .into_iter()
+                .flatten()
+        }) {
+            if statement.get("principal_type").and_then(|v| v.as_str()) != Some("Federated") {
+                continue;
+            }
+            let principal_providers: Vec<Value> = statement
+                .get("principal_identifiers")
                 .and_then(|v| v.as_array())
-                .cloned()
+                .map(|identifiers| {
+                    identifiers
+                        .iter()
+                        .map(|id| {
+                            id.as_str()
+                                .and_then(|arn| providers.get(arn))
+                                .cloned()
+                                .unwrap_or(Value::Null)
+                        })
+                        .collect()
+                })
                 .unwrap_or_default();
+            statement["principal_providers"] = Value::Array(principal_providers);
+        }
 
-            if resources.is_empty() {
-                continue;
-            }
+        roles
+    }
 
-            // Filter by selected resources if provided
-            // If selected_resources is empty or doesn't contain this resource type, use all resources
-            let resources_to_process = if selected_resources.is_empty() {
-                println!("[GENERATE_IMPORT] No selection filter provided, using all {} resources for type '{}'", resources.len(), resource_type);
-                resources
-            } else if let Some(selected) = selected_resources.get(resource_type) {
-                println!(
-                    "[GENERATE_IMPORT] Filtering resources for type '{}': {} selected",
-                    resource_type,
-                    selected.len()
-                );
-                if selected.is_empty() {
-                    println!(
-                        "[GENERATE_IMPORT] Skipping resource type '{}' (empty selection)",
-                        resource_type
-                    );
-                    continue;
-                }
+    /// `s3_buckets`から指定の設定を持つバケットを抽出
+    ///
+    /// 分割された設定リソース（バージョニング等）はバケットのデータをそのまま使用します。
+    fn collect_bucket_configurations(scan_data: &Value, config_field: &str) -> Vec<Value> {
+        scan_data
+            .get("s3_buckets")
+            .and_then(|v| v.as_array())
+            .map(|buckets| {
+                buckets
+                    .iter()
+                    .filter(|bucket| {
+                        bucket
+                            .get(config_field)
+                            .is_some_and(|config| !config.is_null())
+                    })
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 
-                // Extract selected IDs (handle both string IDs and object IDs)
-                let selected_ids: Vec<String> = selected
+    /// 参照先のリソースがTerraform管理下（生成対象に含まれる）かどうかのフラグを付与
+    ///
+    /// `references`は（参照元のIDフィールド, 参照先のリソースタイプ, 参照先のIDフィールド,
+    /// 付与するフラグ名）の組です。フラグがtrueの場合のみ、テンプレートから
+    /// Terraformアドレスで参照します。
+    fn collect_with_managed_references(
+        scan_data: &Value,
+        resource_type: &str,
+        references: &[(&str, &str, &str, &str)],
+    ) -> Vec<Value> {
+        let managed_ids: Vec<Vec<&str>> = references
+            .iter()
+            .map(|(_, target_type, target_id_field, _)| {
+                scan_data
+                    .get(*target_type)
+                    .and_then(|v| v.as_array())
+                    .map(|targets| {
+                        targets
+                            .iter()
+                            .filter_map(|t| t.get(*target_id_field).and_then(|v| v.as_str()))
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        scan_data
+            .get(resource_type)
+            .and_then(|v| v.as_array())
+            .map(|resources| {
+                resources
                     .iter()
-                    .filter_map(|s| {
-                        // If it's a string, use it directly
-                        if let Some(id_str) = s.as_str() {
-                            Some(id_str.to_string())
-                        } else if let Some(obj) = s.as_object() {
-                            // If it's an object, try to extract ID from common fields
-                            obj.get("user_name")
-                                .or_else(|| obj.get("group_name"))
-                                .or_else(|| obj.get("role_name"))
-                                .or_else(|| obj.get("arn"))
-                                .or_else(|| obj.get("id"))
+                    .map(|resource| {
+                        let mut resource = resource.clone();
+                        for ((id_field, _, _, flag), ids) in references.iter().zip(&managed_ids) {
+                            let managed = resource
+                                .get(*id_field)
                                 .and_then(|v| v.as_str())
-                                .map(|s| s.to_string())
-                        } else {
-                            None
+                                .is_some_and(|id| ids.contains(&id));
+                            resource[*flag] = Value::Bool(managed);
                         }
+                        resource
                     })
-                    .collect();
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 
-                println!(
-                    "[GENERATE_IMPORT] Extracted {} selected IDs: {:?}",
-                    selected_ids.len(),
-                    selected_ids
-                );
+    /// 選択フィルタに一致するリソースのみを返す
+    ///
+    /// リソースタイプ（派生リソースは所有者のリソースタイプ）の選択が無い場合は全件を返し、
+    /// 空の選択が指定された場合は空を返します。
+    fn select_resources(
+        resources: Vec<Value>,
+        resource_type: &str,
+        selected_resources: &HashMap<String, Vec<Value>>,
+    ) -> Vec<Value> {
+        let Some(selected) = selected_resources.get(Self::selection_key(resource_type)) else {
+            return resources;
+        };
+        let selected_ids: Vec<&str> = selected.iter().filter_map(Self::selected_id).collect();
+        resources
+            .into_iter()
+            .filter(|resource| {
+                Self::selection_id(resource, resource_type)
+                    .is_some_and(|id| selected_ids.contains(&id))
+            })
+            .collect()
+    }
 
-                // Filter resources that match selected IDs
-                let filtered: Vec<_> = resources
-                    .iter()
-                    .filter(|r| {
-                        // Get resource identifier based on resource type
-                        let resource_id = match resource_type {
-                            "users" => r.get("user_name").and_then(|v| v.as_str()),
-                            "groups" => r.get("group_name").and_then(|v| v.as_str()),
-                            "roles" => r.get("role_name").and_then(|v| v.as_str()),
-                            "policies" => r
-                                .get("arn")
-                                .or_else(|| r.get("policy_name"))
-                                .and_then(|v| v.as_str()),
-                            _ => r
-                                .get("arn")
-                                .or_else(|| r.get("id"))
-                                .or_else(|| r.get("name"))
-                                .and_then(|v| v.as_str()),
-                        };
+    /// 選択フィルタの要素からIDを取得（文字列IDと、IDフィールドを持つオブジェクトに対応）
+    fn selected_id(selected: &Value) -> Option<&str> {
+        match selected {
+            Value::String(id) => Some(id),
+            Value::Object(obj) => obj
+                .get("user_name")
+                .or_else(|| obj.get("group_name"))
+                .or_else(|| obj.get("role_name"))
+                .or_else(|| obj.get("instance_profile_name"))
+                .or_else(|| obj.get("arn"))
+                .or_else(|| obj.get("id"))
+                .and_then(|v| v.as_str()),
+            _ => None,
+        }
+    }
 
-                        if let Some(id) = resource_id {
-                            selected_ids.contains(&id.to_string())
-                        } else {
-                            false
+    /// マルチアカウントの選択フィルタから指定アカウント分のみを抽出
+    ///
+    /// 選択IDは`{account_id}:{id}`の形式でアカウントを区別します（オブジェクトの場合は
+    /// `account_id`フィールド）。アカウント接頭辞を取り除いたIDを返し、アカウントの指定が
+    /// 無いIDは全アカウントに適用します。
+    fn selection_for_account(
+        selected_resources: &HashMap<String, Vec<Value>>,
+        account_id: &str,
+    ) -> HashMap<String, Vec<Value>> {
+        selected_resources
+            .iter()
+            .map(|(resource_type, selected)| {
+                let ids = selected
+                    .iter()
+                    .filter_map(|item| match item {
+                        Value::String(id) => match Self::split_account_selection(id) {
+                            (Some(account), id) => {
+                                (account == account_id).then(|| Value::String(id.to_string()))
+                            }
+                            (None, _) => Some(item.clone()),
+                        },
+                        Value::Object(obj) => {
+                            match obj.get("account_id").and_then(|v| v.as_str()) {
+                                Some(account) if account != account_id => None,
+                                _ => Some(item.clone()),
+                            }
                         }
+                        _ => Some(item.clone()),
                     })
-                    .cloned()
                     .collect();
+                (resource_type.clone(), ids)
+            })
+            .collect()
+    }
 
-                println!(
-                    "[GENERATE_IMPORT] Filtered to {} resources for type '{}'",
-                    filtered.len(),
-                    resource_type
-                );
-                filtered
-            } else {
-                println!(
-                    "[GENERATE_IMPORT] No selection filter for type '{}', using all {} resources",
-                    resource_type,
-                    resources.len()
-                );
-                resources
-            };
-
-            println!(
-                "[GENERATE_IMPORT] Processing {} resources for type '{}'",
-                resources_to_process.len(),
-                resource_type
-            );
-            for resource in resources_to_process {
-                match Self::generate_import_command(&resource, resource_type, provider) {
-                    Ok(import_cmd) => {
-                        println!("[GENERATE_IMPORT] Generated import command: {}", import_cmd);
-                        import_commands.push(import_cmd);
-                    }
-                    Err(e) => {
-                        eprintln!(
-                            "[GENERATE_IMPORT] Failed to generate import command for resource: {}",
-                            e
-                        );
-                    }
-                }
+    /// 選択IDをアカウントID接頭辞（12桁の数字と`:`）とリソースIDに分割
+    fn split_account_selection(id: &str) -> (Option<&str>, &str) {
+        match id.split_once(':') {
+            Some((account, rest))
+                if account.len() == 12 && account.bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                (Some(account), rest)
             }
+            _ => (None, id),
         }
+    }
 
+    /// 他のリソースから参照されるリソースを、生成対象に選択されたものに絞り込んだスキャンデータ
+    ///
+    /// 参照元はこのデータに含まれるリソースのみをTerraformアドレスで参照するため、
+    /// 一部のリソースだけを選択した場合も生成されないリソースへの参照は出力されません。
+    /// ポリシーのアタッチメントも所有者の選択で絞り込み、選択されていないユーザー・
+    /// グループ・ロールにのみアタッチされたAWS管理ポリシーのデータソースは生成しません。
+    fn selected_scan_data(
+        scan_data: &Value,
+        selected_resources: &HashMap<String, Vec<Value>>,
+    ) -> Value {
+        let mut data = scan_data.clone();
+        for resource_type in REFERENCED_RESOURCE_TYPES {
+            if let Some(resources) = data.get_mut(*resource_type) {
+                if let Value::Array(items) = resources.take() {
+                    *resources = Value::Array(Self::select_resources(
+                        items,
+                        resource_type,
+                        selected_resources,
+                    ));
+                }
+            }
+        }
+        for (attachment_key, resource_type) in [
+            ("user_policies", "user_policy_attachments"),
+            ("group_policies", "group_policy_attachments"),
+            ("role_policies", "role_policy_attachments"),
+        ] {
+            if let Some(entries) = data
+                .get_mut("attachments")
+                .and_then(|a| a.get_mut(attachment_key))
+            {
+                if let Value::Array(items) = entries.take() {
+                    *entries = Value::Array(Self::select_resources(
+                        items,
+                        resource_type,
+                        selected_resources,
+                    ));
+                }
+            }
+        }
+        data
+    }
+
+    /// アカウントに1つしか存在しないリソースタイプかどうか
+    fn is_singleton(resource_type: &str) -> bool {
+        matches!(resource_type, "account_password_policy" | "account_aliases")
+    }
+
+    /// Terraformのデータソースとして生成するリソースタイプかどうか
+    fn is_data_source(resource_type: &str) -> bool {
+        resource_type == "aws_managed_policies"
+    }
+
+    /// 選択フィルタの参照先となるリソースタイプ
+    ///
+    /// 派生リソース（インラインポリシー等）は所有者のリソースタイプの選択に従います。
+    fn selection_key(resource_type: &str) -> &str {
+        match resource_type {
+            "user_inline_policies" | "user_policy_attachments" | "user_group_memberships" => {
+                "users"
+            }
+            "group_inline_policies" | "group_policy_attachments" => "groups",
+            "role_inline_policies" | "role_policy_attachments" | "service_linked_roles" => "roles",
+            "cleanup_access_keys" | "cleanup_login_profiles" | "cleanup_mfa_devices" => "cleanup",
+            "sso_managed_policy_attachments"
+            | "sso_inline_policies"
+            | "sso_account_assignments" => "sso_permission_sets",
+            "service_control_policy_attachments" => "service_control_policies",
+            "security_group_ingress_rules" | "security_group_egress_rules" => "security_groups",
+            "s3_bucket_versionings"
+            | "s3_bucket_encryptions"
+            | "s3_bucket_lifecycle_configurations"
+            | "s3_bucket_public_access_blocks"
+            | "s3_bucket_ownership_controls" => "s3_buckets",
+            _ => resource_type,
+        }
+    }
+
+    /// 選択フィルタと照合するリソース識別子を取得
+    fn selection_id<'a>(resource: &'a Value, resource_type: &str) -> Option<&'a str> {
+        match resource_type {
+            "users"
+            | "user_inline_policies"
+            | "user_policy_attachments"
+            | "user_group_memberships" => resource.get("user_name").and_then(|v| v.as_str()),
+            "groups" | "group_inline_policies" | "group_policy_attachments" => {
+                resource.get("group_name").and_then(|v| v.as_str())
+            }
+            "roles"
+            | "service_linked_roles"
+            | "role_inline_policies"
+            | "role_policy_attachments" => resource.get("role_name").and_then(|v| v.as_str()),
+            "instance_profiles" => resource
+                .get("instance_profile_name")
+                .and_then(|v| v.as_str()),
+            "cleanup_access_keys" | "cleanup_login_profiles" | "cleanup_mfa_devices" => {
+                resource.get("cleanup_id").and_then(|v| v.as_str())
+            }
+            "sso_permission_sets"
+            | "sso_managed_policy_attachments"
+            | "sso_inline_policies"
+            | "sso_account_assignments" => {
+                resource.get("permission_set_arn").and_then(|v| v.as_str())
+            }
+            "service_control_policies" | "service_control_policy_attachments" => {
+                resource.get("policy_id").and_then(|v| v.as_str())
+            }
+            "s3_bucket_policies"
+            | "s3_buckets"
+            | "s3_bucket_versionings"
+            | "s3_bucket_encryptions"
+            | "s3_bucket_lifecycle_configurations"
+            | "s3_bucket_public_access_blocks"
+            | "s3_bucket_ownership_controls" => resource.get("bucket").and_then(|v| v.as_str()),
+            "kms_key_policies" => resource.get("key_arn").and_then(|v| v.as_str()),
+            "vpcs" => resource.get("vpc_id").and_then(|v| v.as_str()),
+            "subnets" => resource.get("subnet_id").and_then(|v| v.as_str()),
+            "security_groups" | "security_group_ingress_rules" | "security_group_egress_rules" => {
+                resource.get("group_id").and_then(|v| v.as_str())
+            }
+            "sqs_queue_policies" => resource.get("queue_arn").and_then(|v| v.as_str()),
+            "sns_topic_policies" => resource.get("topic_arn").and_then(|v| v.as_str()),
+            // パスワードポリシーはアカウントに1つのみのため、リソースタイプ名で選択する
+            "account_password_policy" => Some("account_password_policy"),
+            "account_aliases" => resource.get("account_alias").and_then(|v| v.as_str()),
+            "policies" => resource
+                .get("arn")
+                .or_else(|| resource.get("policy_name"))
+                .and_then(|v| v.as_str()),
+            _ => resource
+                .get("arn")
+                .or_else(|| resource.get("id"))
+                .or_else(|| resource.get("name"))
+                .and_then(|v| v.as_str()),
+        }
+    }
+
+    fn get_templates_for_provider(provider: &str) -> Vec<ResourceTemplate> {
+        match provider {
+            "aws" => vec![
+                ResourceTemplate {
+                    resource_type: "users",
+                    template_path: "aws/iam_user.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "groups",
+                    template_path: "aws/iam_group.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "roles",
+                    template_path: "aws/iam_role.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "service_linked_roles",
+                    template_path: "aws/iam_service_linked_role.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "instance_profiles",
+                    template_path: "aws/iam_instance_profile.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "oidc_providers",
+                    template_path: "aws/iam_openid_connect_provider.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "saml_providers",
+                    template_path: "aws/iam_saml_provider.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "account_password_policy",
+                    template_path: "aws/iam_account_password_policy.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "account_aliases",
+                    template_path: "aws/iam_account_alias.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "policies",
+                    template_path: "aws/iam_policy.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "aws_managed_policies",
+                    template_path: "aws/iam_managed_policy_data.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "user_inline_policies",
+                    template_path: "aws/iam_user_policy.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "group_inline_policies",
+                    template_path: "aws/iam_group_policy.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "role_inline_policies",
+                    template_path: "aws/iam_role_policy.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "user_policy_attachments",
+                    template_path: "aws/iam_user_policy_attachment.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "group_policy_attachments",
+                    template_path: "aws/iam_group_policy_attachment.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "role_policy_attachments",
+                    template_path: "aws/iam_role_policy_attachment.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "user_group_memberships",
+                    template_path: "aws/iam_group_membership.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "sso_permission_sets",
+                    template_path: "aws/ssoadmin_permission_set.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "sso_managed_policy_attachments",
+                    template_path: "aws/ssoadmin_managed_policy_attachment.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "sso_inline_policies",
+                    template_path: "aws/ssoadmin_permission_set_inline_policy.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "sso_account_assignments",
+                    template_path: "aws/ssoadmin_account_assignment.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "service_control_policies",
+                    template_path: "aws/organizations_policy.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "service_control_policy_attachments",
+                    template_path: "aws/organizations_policy_attachment.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "s3_buckets",
+                    template_path: "aws/s3_bucket.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "s3_bucket_versionings",
+                    template_path: "aws/s3_bucket_versioning.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "s3_bucket_encryptions",
+                    template_path: "aws/s3_bucket_server_side_encryption_configuration.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "s3_bucket_lifecycle_configurations",
+                    template_path: "aws/s3_bucket_lifecycle_configuration.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "s3_bucket_public_access_blocks",
+                    template_path: "aws/s3_bucket_public_access_block.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "s3_bucket_ownership_controls",
+                    template_path: "aws/s3_bucket_ownership_controls.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "s3_bucket_policies",
+                    template_path: "aws/s3_bucket_policy.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "kms_key_policies",
+                    template_path: "aws/kms_key_policy.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "sqs_queue_policies",
+                    template_path: "aws/sqs_queue_policy.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "sns_topic_policies",
+                    template_path: "aws/sns_topic_policy.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "vpcs",
+                    template_path: "aws/vpc.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "subnets",
+                    template_path: "aws/subnet.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "security_groups",
+                    template_path: "aws/security_group.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "security_group_ingress_rules",
+                    template_path: "aws/vpc_security_group_ingress_rule.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "security_group_egress_rules",
+                    template_path: "aws/vpc_security_group_egress_rule.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "cleanup_access_keys",
+                    template_path: "aws/cleanup_access_key.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "cleanup_login_profiles",
+                    template_path: "aws/cleanup_login_profile.tf.j2",
+                    provider: "aws",
+                },
+                ResourceTemplate {
+                    resource_type: "cleanup_mfa_devices",
+                    template_path: "aws/cleanup_mfa.tf.j2",
+                    provider: "aws",
+                },
+            ],
+            "azure" => vec![
+                ResourceTemplate {
+                    resource_type: "role_definitions",
+                    template_path: "azure/role_definition.tf.j2",
+                    provider: "azure",
+                },
+                ResourceTemplate {
+                    resource_type: "role_assignments",
+                    template_path: "azure/role_assignment.tf.j2",
+                    provider: "azure",
+                },
+            ],
+            _ => vec![],
+        }
+    }
+
+    async fn generate_single_file(
+        resources: &[Value],
+        template_info: &ResourceTemplate,
+        config: &GenerationConfig,
+        output_path: &Path,
+    ) -> Result<String> {
         println!(
-            "[GENERATE_IMPORT] Total import commands generated: {}",
-            import_commands.len()
+            "[GENERATE] Generating single file for {} resources",
+            resources.len()
         );
-        if import_commands.is_empty() {
-            println!("[GENERATE_IMPORT] No import commands generated, returning None");
-            return Ok(None);
+        let mut content = String::new();
+
+        for (idx, resource) in resources.iter().enumerate() {
+            println!(
+                "[GENERATE] Rendering resource {} of {}",
+                idx + 1,
+                resources.len()
+            );
+            let rendered = Self::render_resource(resource, template_info, config)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to render resource {} of type '{}'",
+                        idx + 1,
+                        template_info.resource_type
+                    )
+                })?;
+            content.push_str(&rendered);
+            content.push_str("\n\n");
+        }
+
+        let file_name = format!("{}.tf", template_info.resource_type);
+        let file_path = output_path.join(&file_name);
+
+        println!(
+            "[GENERATE] Writing file: {:?} ({} bytes)",
+            file_path,
+            content.len()
+        );
+        fs::write(&file_path, content)
+            .with_context(|| format!("Failed to write file: {:?}", file_path))?;
+
+        // Verify file was written
+        if !file_path.exists() {
+            return Err(anyhow::anyhow!("File was not created: {:?}", file_path));
         }
+        let metadata = fs::metadata(&file_path)?;
+        println!(
+            "[GENERATE] File written successfully: {:?} ({} bytes)",
+            file_path,
+            metadata.len()
+        );
+
+        Ok(file_name)
+    }
+
+    async fn generate_by_resource_type(
+        resources: &[Value],
+        template_info: &ResourceTemplate,
+        config: &GenerationConfig,
+        output_path: &Path,
+    ) -> Result<String> {
+        // Same as single file for now
+        Self::generate_single_file(resources, template_info, config, output_path).await
+    }
+
+    async fn generate_by_resource_name(
+        resources: &[Value],
+        template_info: &ResourceTemplate,
+        config: &GenerationConfig,
+        output_path: &Path,
+    ) -> Result<Vec<String>> {
+        let mut files = Vec::new();
+
+        for resource in resources {
+            let rendered = Self::render_resource(resource, template_info, config).await?;
+
+            // Get resource name for file name
+            let resource_name = Self::get_resource_name(resource, template_info.resource_type)?;
+            let file_name = format!(
+                "{}_{}.tf",
+                template_info.resource_type,
+                NamingGenerator::apply_naming_convention(&resource_name, &config.naming_convention)
+            );
+            let file_path = output_path.join(&file_name);
+
+            fs::write(&file_path, rendered)
+                .with_context(|| format!("Failed to write file: {:?}", file_path))?;
+
+            files.push(file_name);
+        }
+
+        Ok(files)
+    }
+
+    async fn render_resource(
+        resource: &Value,
+        template_info: &ResourceTemplate,
+        config: &GenerationConfig,
+    ) -> Result<String> {
+        // Get resource name for Terraform resource identifier
+        let resource_name = Self::get_resource_name(resource, template_info.resource_type)?;
+        let terraform_resource_name =
+            NamingGenerator::apply_naming_convention(&resource_name, &config.naming_convention);
+
+        // Prepare context for template
+        let mut context = serde_json::Map::new();
+        context.insert(
+            "resource_name".to_string(),
+            Value::String(terraform_resource_name),
+        );
+
+        // Add resource data based on resource type
+        match template_info.resource_type {
+            "users" => {
+                context.insert("user".to_string(), resource.clone());
+            }
+            "groups" => {
+                context.insert("group".to_string(), resource.clone());
+            }
+            "roles" => {
+                context.insert(
+                    "role".to_string(),
+                    Self::with_principal_references(resource, config),
+                );
+            }
+            "service_linked_roles" => {
+                context.insert("role".to_string(), resource.clone());
+            }
+            "aws_managed_policies" => {
+                context.insert("policy".to_string(), resource.clone());
+            }
+            "account_password_policy" => {
+                context.insert("password_policy".to_string(), resource.clone());
+            }
+            "account_aliases" => {
+                context.insert("account_alias".to_string(), resource.clone());
+            }
+            "policies" => {
+                context.insert("policy".to_string(), resource.clone());
+            }
+            "oidc_providers" | "saml_providers" => {
+                context.insert("provider".to_string(), resource.clone());
+            }
+            "instance_profiles" => {
+                if resource.get("role_managed").and_then(|v| v.as_bool()) == Some(true) {
+                    Self::insert_owner_resource_name(&mut context, resource, "role", config);
+                }
+                context.insert("instance_profile".to_string(), resource.clone());
+            }
+            "user_inline_policies" => {
+                Self::insert_owner_resource_name(&mut context, resource, "user", config);
+                Self::insert_policy_json(&mut context, resource.get("policy_document"));
+                context.insert("inline_policy".to_string(), resource.clone());
+            }
+            "group_inline_policies" => {
+                Self::insert_owner_resource_name(&mut context, resource, "group", config);
+                Self::insert_policy_json(&mut context, resource.get("policy_document"));
+                context.insert("inline_policy".to_string(), resource.clone());
+            }
+            "role_inline_policies" => {
+                Self::insert_owner_resource_name(&mut context, resource, "role", config);
+                Self::insert_policy_json(&mut context, resource.get("policy_document"));
+                context.insert("inline_policy".to_string(), resource.clone());
+            }
+            "user_policy_attachments" => {
+                Self::insert_owner_resource_name(&mut context, resource, "user", config);
+                Self::insert_policy_reference(&mut context, resource, config);
+                context.insert("attachment".to_string(), resource.clone());
+            }
+            "group_policy_attachments" => {
+                Self::insert_owner_resource_name(&mut context, resource, "group", config);
+                Self::insert_policy_reference(&mut context, resource, config);
+                context.insert("attachment".to_string(), resource.clone());
+            }
+            "role_policy_attachments" => {
+                Self::insert_owner_resource_name(&mut context, resource, "role", config);
+                Self::insert_policy_reference(&mut context, resource, config);
+                context.insert("attachment".to_string(), resource.clone());
+            }
+            "user_group_memberships" => {
+                Self::insert_owner_resource_name(&mut context, resource, "user", config);
+                let managed_group_names = Self::string_list(resource.get("managed_group_names"));
+                let (managed, unmanaged): (Vec<String>, Vec<String>) =
+                    Self::string_list(resource.get("group_names"))
+                        .into_iter()
+                        .partition(|name| managed_group_names.contains(name));
+                let group_resource_names: Vec<Value> = managed
+                    .iter()
+                    .map(|name| {
+                        Value::String(NamingGenerator::apply_naming_convention(
+                            name,
+                            &config.naming_convention,
+                        ))
+                    })
+                    .collect();
+                context.insert(
+                    "group_resource_names".to_string(),
+                    Value::Array(group_resource_names),
+                );
+                // 生成対象外のグループはグループ名で指定する
+                context.insert(
+                    "unmanaged_group_names".to_string(),
+                    serde_json::json!(unmanaged),
+                );
+                context.insert("membership".to_string(), resource.clone());
+            }
+            "sso_permission_sets" => {
+                context.insert("permission_set".to_string(), resource.clone());
+            }
+            "sso_managed_policy_attachments" => {
+                if resource
+                    .get("permission_set_managed")
+                    .and_then(|v| v.as_bool())
+                    == Some(true)
+                {
+                    Self::insert_owner_resource_name(
+                        &mut context,
+                        resource,
+                        "permission_set",
+                        config,
+                    );
+                }
+                context.insert("attachment".to_string(), resource.clone());
+            }
+            "sso_inline_policies" => {
+                if resource
+                    .get("permission_set_managed")
+                    .and_then(|v| v.as_bool())
+                    == Some(true)
+                {
+                    Self::insert_owner_resource_name(
+                        &mut context,
+                        resource,
+                        "permission_set",
+                        config,
+                    );
+                }
+                Self::insert_policy_json(&mut context, resource.get("policy_document"));
+                context.insert("inline_policy".to_string(), resource.clone());
+            }
+            "sso_account_assignments" => {
+                if resource
+                    .get("permission_set_managed")
+                    .and_then(|v| v.as_bool())
+                    == Some(true)
+                {
+                    Self::insert_owner_resource_name(
+                        &mut context,
+                        resource,
+                        "permission_set",
+                        config,
+                    );
+                }
+                context.insert("assignment".to_string(), resource.clone());
+            }
+            "service_control_policies" => {
+                Self::insert_policy_json(&mut context, resource.get("policy_document"));
+                context.insert("policy".to_string(), resource.clone());
+            }
+            "service_control_policy_attachments" => {
+                // AWS管理ポリシーと生成対象外のポリシーは、ポリシーIDを直接指定する
+                if resource.get("aws_managed").and_then(|v| v.as_bool()) != Some(true)
+                    && resource.get("policy_managed").and_then(|v| v.as_bool()) == Some(true)
+                {
+                    Self::insert_owner_resource_name(&mut context, resource, "policy", config);
+                }
+                context.insert("attachment".to_string(), resource.clone());
+            }
+            "s3_bucket_policies" => {
+                Self::insert_policy_statements(&mut context, resource.get("policy_document"));
+                context.insert("bucket_policy".to_string(), resource.clone());
+            }
+            "kms_key_policies" => {
+                Self::insert_policy_statements(&mut context, resource.get("policy_document"));
+                context.insert("key_policy".to_string(), resource.clone());
+            }
+            "sqs_queue_policies" => {
+                Self::insert_policy_statements(&mut context, resource.get("policy_document"));
+                context.insert("queue_policy".to_string(), resource.clone());
+            }
+            "sns_topic_policies" => {
+                Self::insert_policy_statements(&mut context, resource.get("policy_document"));
+                context.insert("topic_policy".to_string(), resource.clone());
+            }
+            "s3_buckets"
+            | "s3_bucket_versionings"
+            | "s3_bucket_encryptions"
+            | "s3_bucket_lifecycle_configurations"
+            | "s3_bucket_public_access_blocks"
+            | "s3_bucket_ownership_controls" => {
+                context.insert("bucket".to_string(), resource.clone());
+            }
+            "vpcs" => {
+                context.insert("vpc".to_string(), resource.clone());
+            }
+            "subnets" => {
+                Self::insert_managed_reference(
+                    &mut context,
+                    resource,
+                    "vpc_id",
+                    "vpc_managed",
+                    "vpc_resource_name",
+                    config,
+                );
+                context.insert("subnet".to_string(), resource.clone());
+            }
+            "security_groups" => {
+                Self::insert_managed_reference(
+                    &mut context,
+                    resource,
+                    "vpc_id",
+                    "vpc_managed",
+                    "vpc_resource_name",
+                    config,
+                );
+                context.insert("security_group".to_string(), resource.clone());
+            }
+            "security_group_ingress_rules" | "security_group_egress_rules" => {
+                Self::insert_managed_reference(
+                    &mut context,
+                    resource,
+                    "group_id",
+                    "group_managed",
+                    "security_group_resource_name",
+                    config,
+                );
+                Self::insert_managed_reference(
+                    &mut context,
+                    resource,
+                    "referenced_group_id",
+                    "referenced_group_managed",
+                    "referenced_security_group_resource_name",
+                    config,
+                );
+                context.insert("rule".to_string(), resource.clone());
+            }
+            "cleanup_access_keys" => {
+                if resource.get("user_managed").and_then(|v| v.as_bool()) == Some(true) {
+                    Self::insert_owner_resource_name(&mut context, resource, "user", config);
+                }
+                context.insert("access_key".to_string(), resource.clone());
+            }
+            "cleanup_login_profiles" => {
+                if resource.get("user_managed").and_then(|v| v.as_bool()) == Some(true) {
+                    Self::insert_owner_resource_name(&mut context, resource, "user", config);
+                }
+                context.insert("login_profile".to_string(), resource.clone());
+            }
+            "cleanup_mfa_devices" => {
+                Self::insert_owner_resource_name(&mut context, resource, "user", config);
+                context.insert("mfa_device".to_string(), resource.clone());
+            }
+            _ => {
+                // For other types, use generic "resource" key
+                context.insert("resource".to_string(), resource.clone());
+            }
+        }
+
+        let mut context_value = Value::Object(context);
+        if config.parameterize_arns {
+            Self::parameterize_arns(&mut context_value, config.account_id.as_deref());
+        }
+
+        // Render template
+        println!(
+            "[GENERATE] Rendering template: {}",
+            template_info.template_path
+        );
+        let mut rendered =
+            TemplateManager::render_template(template_info.template_path, &context_value)
+                .await
+                .with_context(|| {
+                    format!("Failed to render template: {}", template_info.template_path)
+                })?;
+        if config.parameterize_arns {
+            rendered = rendered
+                .replace(
+                    PARTITION_PLACEHOLDER,
+                    "${data.aws_partition.current.partition}",
+                )
+                .replace(
+                    ACCOUNT_ID_PLACEHOLDER,
+                    "${data.aws_caller_identity.current.account_id}",
+                );
+        }
+        println!(
+            "[GENERATE] Template rendered successfully ({} bytes)",
+            rendered.len()
+        );
+        Ok(rendered)
+    }
+
+    /// コンテキスト内の文字列に含まれるARNのパーティションとアカウントIDをプレースホルダーに置換
+    ///
+    /// アカウントIDは`account_id`（生成対象のアカウント）と一致する場合のみ置換し、
+    /// 他アカウントを指すARNはリテラルのまま残します。ARN以外のアカウントIDのみの文字列は、
+    /// プリンシパルとアカウントの条件キーの値に限り置換し、タグや説明文はそのまま残します。
+    fn parameterize_arns(value: &mut Value, account_id: Option<&str>) {
+        Self::parameterize_arns_with(value, account_id, false);
+    }
+
+    /// `parameterize_arns`の本体（`account_field`はアカウントIDを置換するフィールド内かどうか）
+    fn parameterize_arns_with(value: &mut Value, account_id: Option<&str>, account_field: bool) {
+        match value {
+            Value::String(text) => {
+                if account_field && account_id == Some(text.as_str()) {
+                    *text = ACCOUNT_ID_PLACEHOLDER.to_string();
+                } else if text.contains("arn:") {
+                    *text = Self::parameterize_arns_in(text, account_id);
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    Self::parameterize_arns_with(item, account_id, account_field);
+                }
+            }
+            Value::Object(map) => {
+                // 条件の`{test, variable, values}`形式
+                let account_condition = map
+                    .get("variable")
+                    .and_then(|v| v.as_str())
+                    .is_some_and(|variable| ACCOUNT_CONDITION_KEYS.contains(&variable));
+                for (key, item) in map.iter_mut() {
+                    let account_field = account_field
+                        || PRINCIPAL_FIELDS.contains(&key.as_str())
+                        || ACCOUNT_CONDITION_KEYS.contains(&key.as_str())
+                        || (account_condition && key == "values");
+                    Self::parameterize_arns_with(item, account_id, account_field);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// 文字列中のARN（`arn:<partition>:<service>:<region>:<account>:...`）を置換
+    fn parameterize_arns_in(text: &str, account_id: Option<&str>) -> String {
+        let is_arn_field = |field: &str| {
+            field
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        };
+
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(pos) = rest.find("arn:") {
+            let preceded_by_word = rest[..pos]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_');
+            result.push_str(&rest[..pos]);
+            rest = &rest[pos..];
+
+            let fields: Vec<&str> = rest.splitn(6, ':').collect();
+            if preceded_by_word
+                || fields.len() < 6
+                || !fields[1].starts_with("aws")
+                || !fields[1..5].iter().all(|field| is_arn_field(field))
+            {
+                result.push_str("arn:");
+                rest = &rest["arn:".len()..];
+                continue;
+            }
+
+            let account = if account_id == Some(fields[4]) {
+                ACCOUNT_ID_PLACEHOLDER
+            } else {
+                fields[4]
+            };
+            result.push_str(&format!(
+                "arn:{}:{}:{}:{}:",
+                PARTITION_PLACEHOLDER, fields[2], fields[3], account
+            ));
+            rest = fields[5];
+        }
+        result.push_str(rest);
+        result
+    }
+
+    /// 所有者リソース（ユーザー/グループ/ロール）のTerraformリソース名をコンテキストに追加
+    ///
+    /// `{owner}_name`フィールドから`{owner}_resource_name`を生成し、
+    /// テンプレートから`aws_iam_{owner}.<name>`として参照できるようにします。
+    fn insert_owner_resource_name(
+        context: &mut serde_json::Map<String, Value>,
+        resource: &Value,
+        owner: &str,
+        config: &GenerationConfig,
+    ) {
+        if let Some(owner_name) = resource
+            .get(format!("{}_name", owner))
+            .and_then(|v| v.as_str())
+        {
+            context.insert(
+                format!("{}_resource_name", owner),
+                Value::String(NamingGenerator::apply_naming_convention(
+                    owner_name,
+                    &config.naming_convention,
+                )),
+            );
+        }
+    }
+
+    /// 参照先がTerraform管理下の場合、IDから生成したTerraformリソース名をコンテキストに追加
+    ///
+    /// ネットワーキングのリソースはIDをリソース名とするため、参照元が持つIDから
+    /// 参照先のリソース名を求められます。
+    fn insert_managed_reference(
+        context: &mut serde_json::Map<String, Value>,
+        resource: &Value,
+        id_field: &str,
+        managed_flag: &str,
+        key: &str,
+        config: &GenerationConfig,
+    ) {
+        if resource.get(managed_flag).and_then(|v| v.as_bool()) != Some(true) {
+            return;
+        }
+        if let Some(id) = resource.get(id_field).and_then(|v| v.as_str()) {
+            context.insert(
+                key.to_string(),
+                Value::String(NamingGenerator::apply_naming_convention(
+                    id,
+                    &config.naming_convention,
+                )),
+            );
+        }
+    }
+
+    /// 信頼ポリシーの`principal_providers`を、テンプレートで使用する
+    /// Terraformアドレス（`principal_references`）に変換したロールを返す
+    fn with_principal_references(role: &Value, config: &GenerationConfig) -> Value {
+        let mut role = role.clone();
+        for statement in role
+            .get_mut("assume_role_statements")
+            .and_then(|v| v.as_array_mut())
+            .into_iter()
+            .flatten()
+        {
+            let Some(providers) = statement
+                .get("principal_providers")
+                .and_then(|v| v.as_array())
+            else {
+                continue;
+            };
+            let references: Vec<Value> = providers
+                .iter()
+                .map(|provider| {
+                    match (
+                        provider.get("resource_type").and_then(|v| v.as_str()),
+                        provider.get("name").and_then(|v| v.as_str()),
+                    ) {
+                        (Some(resource_type), Some(name)) => Value::String(format!(
+                            "{}.{}.arn",
+                            resource_type,
+                            NamingGenerator::apply_naming_convention(
+                                name,
+                                &config.naming_convention
+                            )
+                        )),
+                        _ => Value::Null,
+                    }
+                })
+                .collect();
+            statement["principal_references"] = Value::Array(references);
+        }
+        role
+    }
+
+    /// アタッチメントの`policy_reference`を、テンプレートで使用する
+    /// Terraformアドレス（`policy_reference`）としてコンテキストに追加
+    fn insert_policy_reference(
+        context: &mut serde_json::Map<String, Value>,
+        attachment: &Value,
+        config: &GenerationConfig,
+    ) {
+        let Some(reference) = attachment.get("policy_reference") else {
+            return;
+        };
+        if let (Some(resource_type), Some(name)) = (
+            reference.get("resource_type").and_then(|v| v.as_str()),
+            reference.get("name").and_then(|v| v.as_str()),
+        ) {
+            context.insert(
+                "policy_reference".to_string(),
+                Value::String(format!(
+                    "{}.{}.arn",
+                    resource_type,
+                    NamingGenerator::apply_naming_convention(name, &config.naming_convention)
+                )),
+            );
+        }
+    }
+
+    /// ポリシードキュメントを`jsonencode()`に渡せるJSON文字列としてコンテキストに追加
+    ///
+    /// Terraformのテンプレート構文（`${...}`/`%{...}`）として解釈されないようにエスケープします。
+    fn insert_policy_json(
+        context: &mut serde_json::Map<String, Value>,
+        policy_document: Option<&Value>,
+    ) {
+        if let Some(document) = policy_document {
+            if let Ok(policy_json) = serde_json::to_string_pretty(document) {
+                context.insert(
+                    "policy_json".to_string(),
+                    Value::String(policy_json.replace("${", "$${").replace("%{", "%%{")),
+                );
+            }
+        }
+    }
+
+    /// リソースベースポリシーのステートメントを`aws_iam_policy_document`の
+    /// ブロック構造（`policy_statements`）に変換してコンテキストに追加
+    ///
+    /// 文字列・配列のどちらでも記述できる要素は配列に正規化し、
+    /// `Principal`/`Condition`は`principals`/`condition`ブロックの形式に展開します。
+    fn insert_policy_statements(
+        context: &mut serde_json::Map<String, Value>,
+        policy_document: Option<&Value>,
+    ) {
+        let Some(document) = policy_document else {
+            return;
+        };
+        if let Some(version) = document.get("Version").and_then(|v| v.as_str()) {
+            context.insert("policy_version".to_string(), serde_json::json!(version));
+        }
+        let statements = match document.get("Statement") {
+            Some(Value::Array(statements)) => statements.clone(),
+            Some(statement @ Value::Object(_)) => vec![statement.clone()],
+            _ => Vec::new(),
+        };
+
+        let statements: Vec<Value> = statements
+            .iter()
+            .map(|statement| {
+                let mut block = serde_json::json!({
+                    "effect": statement.get("Effect").and_then(|v| v.as_str()).unwrap_or("Allow"),
+                    "actions": Self::string_list(statement.get("Action")),
+                    "not_actions": Self::string_list(statement.get("NotAction")),
+                    "resources": Self::string_list(statement.get("Resource")),
+                    "not_resources": Self::string_list(statement.get("NotResource")),
+                    "principals": Self::principal_blocks(statement.get("Principal")),
+                    "not_principals": Self::principal_blocks(statement.get("NotPrincipal")),
+                    "conditions": Self::condition_blocks(statement.get("Condition")),
+                });
+                if let Some(sid) = statement.get("Sid").and_then(|v| v.as_str()) {
+                    block["sid"] = serde_json::json!(sid);
+                }
+                block
+            })
+            .collect();
+        context.insert("policy_statements".to_string(), Value::Array(statements));
+    }
+
+    /// 文字列または配列の要素を文字列の配列に正規化（真偽値・数値は文字列化）
+    fn string_list(value: Option<&Value>) -> Vec<String> {
+        let to_string = |value: &Value| match value {
+            Value::String(text) => Some(text.clone()),
+            Value::Bool(_) | Value::Number(_) => Some(value.to_string()),
+            _ => None,
+        };
+        match value {
+            Some(Value::Array(items)) => items.iter().filter_map(to_string).collect(),
+            Some(item) => to_string(item).into_iter().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// `Principal`を`principals`ブロック（`type`と`identifiers`）の配列に変換
+    ///
+    /// `"Principal": "*"`は`type = "*"`のブロックとして表現します。
+    fn principal_blocks(principal: Option<&Value>) -> Vec<Value> {
+        match principal {
+            Some(Value::String(text)) if text == "*" => {
+                vec![serde_json::json!({"type": "*", "identifiers": ["*"]})]
+            }
+            Some(Value::Object(principals)) => principals
+                .iter()
+                .map(|(principal_type, identifiers)| {
+                    serde_json::json!({
+                        "type": principal_type,
+                        "identifiers": Self::string_list(Some(identifiers)),
+                    })
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// `Condition`を`condition`ブロック（`test`・`variable`・`values`）の配列に変換
+    fn condition_blocks(condition: Option<&Value>) -> Vec<Value> {
+        let Some(Value::Object(operators)) = condition else {
+            return Vec::new();
+        };
+        operators
+            .iter()
+            .filter_map(|(test, entries)| Some((test, entries.as_object()?)))
+            .flat_map(|(test, entries)| {
+                entries.iter().map(move |(variable, values)| {
+                    serde_json::json!({
+                        "test": test,
+                        "variable": variable,
+                        "values": Self::string_list(Some(values)),
+                    })
+                })
+            })
+            .collect()
+    }
+
+    /// ポリシーARNからポリシー名（最後のパスセグメント）を取得
+    fn policy_name_from_arn(policy_arn: &str) -> &str {
+        policy_arn.rsplit('/').next().unwrap_or(policy_arn)
+    }
+
+    /// アタッチメントのリソース名に使うポリシーの識別子をARNから生成
+    ///
+    /// ポリシー名だけではAWS管理ポリシーと同名のカスタマー管理ポリシー等が衝突するため、
+    /// 所有者（`aws`またはアカウントID）・パス・ポリシー名を`_`で連結します。
+    fn policy_key_from_arn(policy_arn: &str) -> String {
+        // arn:<partition>:iam::<owner>:policy<path><name>
+        let resource = policy_arn.splitn(5, ':').nth(4).unwrap_or(policy_arn);
+        resource
+            .replacen(":policy", "", 1)
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+
+    /// 必須の文字列フィールドを取得
+    fn required_str<'a>(resource: &'a Value, field: &str) -> Result<&'a str> {
+        resource
+            .get(field)
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing {}", field))
+    }
+
+    fn get_resource_name(resource: &Value, resource_type: &str) -> Result<String> {
+        match resource_type {
+            "users" => Ok(resource
+                .get("user_name")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| anyhow::anyhow!("Missing user_name"))?
+                .to_string()),
+            "groups" => Ok(resource
+                .get("group_name")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| anyhow::anyhow!("Missing group_name"))?
+                .to_string()),
+            "roles" => Ok(resource
+                .get("role_name")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| anyhow::anyhow!("Missing role_name"))?
+                .to_string()),
+            "service_linked_roles" => Ok(Self::required_str(resource, "role_name")?.to_string()),
+            "aws_managed_policies" => Ok(Self::required_str(resource, "policy_name")?.to_string()),
+            // アカウント単位のシングルトンリソースは固定名とする
+            "account_password_policy" | "account_aliases" => Ok("this".to_string()),
+            "policies" => Ok(resource
+                .get("policy_name")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| anyhow::anyhow!("Missing policy_name"))?
+                .to_string()),
+            "instance_profiles" => {
+                Ok(Self::required_str(resource, "instance_profile_name")?.to_string())
+            }
+            // OIDCプロバイダーには名前が無いため、スキームを除いたURLから生成
+            "oidc_providers" => Ok(Self::required_str(resource, "url")?
+                .trim_start_matches("https://")
+                .replace('/', "_")),
+            "saml_providers" => Ok(Self::required_str(resource, "name")?.to_string()),
+            "user_inline_policies" => Ok(format!(
+                "{}_{}",
+                Self::required_str(resource, "user_name")?,
+                Self::required_str(resource, "policy_name")?
+            )),
+            "group_inline_policies" => Ok(format!(
+                "{}_{}",
+                Self::required_str(resource, "group_name")?,
+                Self::required_str(resource, "policy_name")?
+            )),
+            "role_inline_policies" => Ok(format!(
+                "{}_{}",
+                Self::required_str(resource, "role_name")?,
+                Self::required_str(resource, "policy_name")?
+            )),
+            "user_policy_attachments" => Ok(format!(
+                "{}_{}",
+                Self::required_str(resource, "user_name")?,
+                Self::policy_key_from_arn(Self::required_str(resource, "policy_arn")?)
+            )),
+            "group_policy_attachments" => Ok(format!(
+                "{}_{}",
+                Self::required_str(resource, "group_name")?,
+                Self::policy_key_from_arn(Self::required_str(resource, "policy_arn")?)
+            )),
+            "role_policy_attachments" => Ok(format!(
+                "{}_{}",
+                Self::required_str(resource, "role_name")?,
+                Self::policy_key_from_arn(Self::required_str(resource, "policy_arn")?)
+            )),
+            "user_group_memberships" => Ok(format!(
+                "{}_groups",
+                Self::required_str(resource, "user_name")?
+            )),
+            "sso_permission_sets" => Ok(Self::required_str(resource, "name")?.to_string()),
+            "sso_managed_policy_attachments" => Ok(format!(
+                "{}_{}",
+                Self::required_str(resource, "permission_set_name")?,
+                Self::policy_key_from_arn(Self::required_str(resource, "managed_policy_arn")?)
+            )),
+            "sso_inline_policies" => Ok(format!(
+                "{}_inline_policy",
+                Self::required_str(resource, "permission_set_name")?
+            )),
+            "sso_account_assignments" => Ok(format!(
+                "{}_{}_{}_{}",
+                Self::required_str(resource, "permission_set_name")?,
+                Self::required_str(resource, "target_id")?,
+                Self::required_str(resource, "principal_type")?,
+                Self::required_str(resource, "principal_id")?
+            )),
+            "service_control_policies" => Ok(Self::required_str(resource, "name")?.to_string()),
+            "service_control_policy_attachments" => Ok(format!(
+                "{}_{}",
+                Self::required_str(resource, "policy_name")?,
+                Self::required_str(resource, "target_id")?
+            )),
+            // 分割された設定リソースは`aws_s3_bucket`と同じ名前で参照できるようにする
+            "s3_bucket_policies"
+            | "s3_buckets"
+            | "s3_bucket_versionings"
+            | "s3_bucket_encryptions"
+            | "s3_bucket_lifecycle_configurations"
+            | "s3_bucket_public_access_blocks"
+            | "s3_bucket_ownership_controls" => {
+                Ok(Self::required_str(resource, "bucket")?.to_string())
+            }
+            // キーIDは数字で始まる場合があるため、Terraformの識別子として有効な接頭辞を付ける
+            "kms_key_policies" => Ok(format!("key_{}", Self::required_str(resource, "key_id")?)),
+            "sqs_queue_policies" => Ok(Self::required_str(resource, "queue_name")?.to_string()),
+            "sns_topic_policies" => Ok(Self::required_str(resource, "topic_name")?.to_string()),
+            // Nameタグは一意とは限らないため、ネットワーキングのリソースはIDを名前とする
+            "vpcs" => Ok(Self::required_str(resource, "vpc_id")?.to_string()),
+            "subnets" => Ok(Self::required_str(resource, "subnet_id")?.to_string()),
+            "security_groups" => Ok(Self::required_str(resource, "group_id")?.to_string()),
+            "security_group_ingress_rules" | "security_group_egress_rules" => {
+                Ok(Self::required_str(resource, "rule_id")?.to_string())
+            }
+            "cleanup_access_keys" => Ok(format!(
+                "{}_{}",
+                Self::required_str(resource, "user_name")?,
+                Self::required_str(resource, "access_key_id")?
+            )),
+            "cleanup_login_profiles" => Ok(format!(
+                "{}_login_profile",
+                Self::required_str(resource, "user_name")?
+            )),
+            "cleanup_mfa_devices" => Ok(format!(
+                "{}_{}",
+                Self::required_str(resource, "user_name")?,
+                Self::required_str(resource, "virtual_mfa_device_name")?
+            )),
+            _ => {
+                // Try common fields
+                if let Some(name) = resource.get("name").and_then(|v| v.as_str()) {
+                    Ok(name.to_string())
+                } else if let Some(name) = resource.get("display_name").and_then(|v| v.as_str()) {
+                    Ok(name.to_string())
+                } else {
+                    Err(anyhow::anyhow!("Cannot determine resource name"))
+                }
+            }
+        }
+    }
+
+    async fn generate_readme(
+        _config: &GenerationConfig,
+        output_path: &Path,
+        files: &[String],
+        warnings: &[ScanWarning],
+    ) -> Result<String> {
+        let mut readme = String::new();
+        readme.push_str("# Terraform Code Generation\n\n");
+        readme.push_str("This directory contains Terraform code generated by TFKosmos.\n\n");
+        readme.push_str("## Generated Files\n\n");
+
+        for file in files {
+            readme.push_str(&format!("- {}\n", file));
+        }
+
+        readme.push_str("\n## Usage\n\n");
+        readme.push_str("1. Review the generated Terraform files\n");
+        readme.push_str("2. Run `terraform init` to initialize the Terraform working directory\n");
+        readme.push_str("3. Run `terraform plan` to review the changes\n");
+        readme.push_str("4. Run `terraform apply` to apply the changes\n\n");
+        readme.push_str("## Import Script\n\n");
+        readme.push_str(
+            "Use the generated import script to import existing resources into Terraform state.\n",
+        );
+
+        if !warnings.is_empty() {
+            readme.push_str("\n## Scan Warnings\n\n");
+            readme.push_str(
+                "The following API calls failed during the scan. \
+                 The generated code for these resources may be incomplete \
+                 (e.g. missing policy attachments or policy documents), so review them carefully.\n\n",
+            );
+            readme.push_str("| Resource | Operation | Error | Message |\n");
+            readme.push_str("|---|---|---|---|\n");
+            for warning in warnings {
+                let resource = match &warning.account_id {
+                    Some(account_id) => format!(
+                        "{} `{}` ({})",
+                        warning.resource_type, warning.resource_name, account_id
+                    ),
+                    None => format!("{} `{}`", warning.resource_type, warning.resource_name),
+                };
+                readme.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    resource,
+                    warning.operation,
+                    warning.error_kind,
+                    warning.message.replace('|', "\\|").replace('\n', " ")
+                ));
+            }
+        }
+
+        let readme_path = output_path.join("README.md");
+        fs::write(&readme_path, readme)
+            .with_context(|| format!("Failed to write README: {:?}", readme_path))?;
+
+        Ok("README.md".to_string())
+    }
+
+    pub async fn generate_import_script(
+        scan_data: &Value,
+        config: &GenerationConfig,
+        selected_resources: &HashMap<String, Vec<Value>>,
+        output_path: &Path,
+    ) -> Result<Option<String>> {
+        let account_ids = Self::account_ids(scan_data);
+        let import_commands = if account_ids.is_empty() {
+            Self::collect_import_commands(scan_data, selected_resources)
+        } else {
+            // マルチアカウントスキャンは各アカウントのディレクトリに移動してインポート
+            let mut import_commands = Vec::new();
+            for account_id in &account_ids {
+                let account_data = Self::scan_data_for_account(scan_data, account_id);
+                let account_selection = Self::selection_for_account(selected_resources, account_id);
+                let commands = Self::collect_import_commands(&account_data, &account_selection);
+                if commands.is_empty() {
+                    continue;
+                }
+                import_commands.push(format!("# Account: {}", account_id));
+                import_commands.push(format!("cd {}", account_id));
+                import_commands.extend(commands);
+                import_commands.push("cd ..".to_string());
+            }
+            import_commands
+        };
+
+        println!(
+            "[GENERATE_IMPORT] Total import commands generated: {}",
+            import_commands.len()
+        );
+        if import_commands.is_empty() {
+            println!("[GENERATE_IMPORT] No import commands generated, returning None");
+            return Ok(None);
+        }
+
+        // Generate import script
+        let script_content = match config.import_script_format.as_str() {
+            "sh" => Self::generate_sh_import_script(&import_commands),
+            "ps1" => Self::generate_ps1_import_script(&import_commands),
+            _ => Self::generate_sh_import_script(&import_commands),
+        };
+
+        let script_name = match config.import_script_format.as_str() {
+            "ps1" => "import.ps1",
+            _ => "import.sh",
+        };
+
+        let script_path = output_path.join(script_name);
+        println!(
+            "[GENERATE_IMPORT] Writing import script: {:?} ({} bytes)",
+            script_path,
+            script_content.len()
+        );
+        fs::write(&script_path, script_content)
+            .with_context(|| format!("Failed to write import script: {:?}", script_path))?;
+
+        // Verify file was written
+        if !script_path.exists() {
+            return Err(anyhow::anyhow!(
+                "Import script was not created: {:?}",
+                script_path
+            ));
+        }
+        let metadata = fs::metadata(&script_path)?;
+        println!(
+            "[GENERATE_IMPORT] Import script written successfully: {:?} ({} bytes)",
+            script_path,
+            metadata.len()
+        );
+
+        // Make script executable on Unix systems
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mut perms = fs::metadata(&script_path)?.permissions();
+            perms.set_mode(0o755);
+            fs::set_permissions(&script_path, perms)?;
+            println!("[GENERATE_IMPORT] Import script made executable");
+        }
+
+        Ok(Some(script_name.to_string()))
+    }
+
+    /// スキャンデータからインポートコマンドを生成
+    fn collect_import_commands(
+        scan_data: &Value,
+        selected_resources: &HashMap<String, Vec<Value>>,
+    ) -> Vec<String> {
+        let provider = scan_data
+            .get("provider")
+            .and_then(|v| v.as_str())
+            .unwrap_or("aws");
+
+        let mut import_commands = Vec::new();
+
+        // Process each resource type
+        let templates = Self::get_templates_for_provider(provider);
+        for template_info in templates {
+            let resource_type = template_info.resource_type;
+
+            // データソースはインポート対象外
+            if Self::is_data_source(resource_type) {
+                continue;
+            }
+
+            let resources = Self::collect_resources(scan_data, resource_type);
+
+            if resources.is_empty() {
+                continue;
+            }
+
+            let resources_to_process =
+                Self::select_resources(resources, resource_type, selected_resources);
+
+            println!(
+                "[GENERATE_IMPORT] Processing {} resources for type '{}'",
+                resources_to_process.len(),
+                resource_type
+            );
+            for resource in resources_to_process {
+                match Self::generate_import_command(&resource, resource_type, provider) {
+                    Ok(import_cmd) => {
+                        println!("[GENERATE_IMPORT] Generated import command: {}", import_cmd);
+                        import_commands.push(import_cmd);
+                    }
+                    Err(e) => {
+                        eprintln!(
+                            "[GENERATE_IMPORT] Failed to generate import command for resource: {}",
+                            e
+                        );
+                    }
+                }
+            }
+        }
+
+        import_commands
+    }
+
+    fn generate_import_command(
+        resource: &Value,
+        resource_type: &str,
+        provider: &str,
+    ) -> Result<String> {
+        let resource_name = Self::get_resource_name(resource, resource_type)?;
+        let terraform_resource_name = NamingGenerator::to_snake_case(&resource_name);
+
+        match (provider, resource_type) {
+            ("aws", "users") => {
+                let arn = resource
+                    .get("arn")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing ARN"))?;
+                Ok(format!(
+                    "terraform import aws_iam_user.{} {}",
+                    terraform_resource_name, arn
+                ))
+            }
+            ("aws", "groups") => {
+                let arn = resource
+                    .get("arn")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing ARN"))?;
+                Ok(format!(
+                    "terraform import aws_iam_group.{} {}",
+                    terraform_resource_name, arn
+                ))
+            }
+            ("aws", "roles") => {
+                let arn = resource
+                    .get("arn")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing ARN"))?;
+                Ok(format!(
+                    "terraform import aws_iam_role.{} {}",
+                    terraform_resource_name, arn
+                ))
+            }
+            ("aws", "policies") => {
+                let arn = resource
+                    .get("arn")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing ARN"))?;
+                Ok(format!(
+                    "terraform import aws_iam_policy.{} {}",
+                    terraform_resource_name, arn
+                ))
+            }
+            ("aws", "account_password_policy") => Ok(format!(
+                "terraform import aws_iam_account_password_policy.{} iam-account-password-policy",
+                terraform_resource_name
+            )),
+            ("aws", "account_aliases") => Ok(format!(
+                "terraform import aws_iam_account_alias.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "account_alias")?
+            )),
+            ("aws", "service_linked_roles") => Ok(format!(
+                "terraform import aws_iam_service_linked_role.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "arn")?
+            )),
+            ("aws", "oidc_providers") => Ok(format!(
+                "terraform import aws_iam_openid_connect_provider.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "arn")?
+            )),
+            ("aws", "saml_providers") => Ok(format!(
+                "terraform import aws_iam_saml_provider.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "arn")?
+            )),
+            ("aws", "instance_profiles") => Ok(format!(
+                "terraform import aws_iam_instance_profile.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "instance_profile_name")?
+            )),
+            // IAM Identity Centerのリソースはカンマ区切りの複合IDでインポートする
+            ("aws", "sso_permission_sets") => Ok(format!(
+                "terraform import aws_ssoadmin_permission_set.{} {},{}",
+                terraform_resource_name,
+                Self::required_str(resource, "permission_set_arn")?,
+                Self::required_str(resource, "instance_arn")?
+            )),
+            ("aws", "sso_managed_policy_attachments") => Ok(format!(
+                "terraform import aws_ssoadmin_managed_policy_attachment.{} {},{},{}",
+                terraform_resource_name,
+                Self::required_str(resource, "managed_policy_arn")?,
+                Self::required_str(resource, "permission_set_arn")?,
+                Self::required_str(resource, "instance_arn")?
+            )),
+            ("aws", "sso_inline_policies") => Ok(format!(
+                "terraform import aws_ssoadmin_permission_set_inline_policy.{} {},{}",
+                terraform_resource_name,
+                Self::required_str(resource, "permission_set_arn")?,
+                Self::required_str(resource, "instance_arn")?
+            )),
+            ("aws", "sso_account_assignments") => Ok(format!(
+                "terraform import aws_ssoadmin_account_assignment.{} {},{},{},{},{},{}",
+                terraform_resource_name,
+                Self::required_str(resource, "principal_id")?,
+                Self::required_str(resource, "principal_type")?,
+                Self::required_str(resource, "target_id")?,
+                Self::required_str(resource, "target_type")?,
+                Self::required_str(resource, "permission_set_arn")?,
+                Self::required_str(resource, "instance_arn")?
+            )),
+            ("aws", "service_control_policies") => Ok(format!(
+                "terraform import aws_organizations_policy.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "policy_id")?
+            )),
+            ("aws", "service_control_policy_attachments") => Ok(format!(
+                "terraform import aws_organizations_policy_attachment.{} {}:{}",
+                terraform_resource_name,
+                Self::required_str(resource, "target_id")?,
+                Self::required_str(resource, "policy_id")?
+            )),
+            // S3バケットと分割された設定リソースはいずれもバケット名でインポートする
+            ("aws", "s3_buckets") => Ok(format!(
+                "terraform import aws_s3_bucket.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "bucket")?
+            )),
+            ("aws", "s3_bucket_versionings") => Ok(format!(
+                "terraform import aws_s3_bucket_versioning.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "bucket")?
+            )),
+            ("aws", "s3_bucket_encryptions") => Ok(format!(
+                "terraform import aws_s3_bucket_server_side_encryption_configuration.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "bucket")?
+            )),
+            ("aws", "s3_bucket_lifecycle_configurations") => Ok(format!(
+                "terraform import aws_s3_bucket_lifecycle_configuration.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "bucket")?
+            )),
+            ("aws", "s3_bucket_public_access_blocks") => Ok(format!(
+                "terraform import aws_s3_bucket_public_access_block.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "bucket")?
+            )),
+            ("aws", "s3_bucket_ownership_controls") => Ok(format!(
+                "terraform import aws_s3_bucket_ownership_controls.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "bucket")?
+            )),
+            ("aws", "s3_bucket_policies") => Ok(format!(
+                "terraform import aws_s3_bucket_policy.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "bucket")?
+            )),
+            ("aws", "kms_key_policies") => Ok(format!(
+                "terraform import aws_kms_key_policy.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "key_id")?
+            )),
+            ("aws", "sqs_queue_policies") => Ok(format!(
+                "terraform import aws_sqs_queue_policy.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "queue_url")?
+            )),
+            ("aws", "sns_topic_policies") => Ok(format!(
+                "terraform import aws_sns_topic_policy.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "topic_arn")?
+            )),
+            ("aws", "vpcs") => Ok(format!(
+                "terraform import aws_vpc.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "vpc_id")?
+            )),
+            ("aws", "subnets") => Ok(format!(
+                "terraform import aws_subnet.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "subnet_id")?
+            )),
+            ("aws", "security_groups") => Ok(format!(
+                "terraform import aws_security_group.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "group_id")?
+            )),
+            ("aws", "security_group_ingress_rules") => Ok(format!(
+                "terraform import aws_vpc_security_group_ingress_rule.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "rule_id")?
+            )),
+            ("aws", "security_group_egress_rules") => Ok(format!(
+                "terraform import aws_vpc_security_group_egress_rule.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "rule_id")?
+            )),
+            ("aws", "cleanup_access_keys") => Ok(format!(
+                "terraform import aws_iam_access_key.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "access_key_id")?
+            )),
+            ("aws", "cleanup_login_profiles") => Ok(format!(
+                "terraform import aws_iam_user_login_profile.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "user_name")?
+            )),
+            ("aws", "cleanup_mfa_devices") => Ok(format!(
+                "terraform import aws_iam_virtual_mfa_device.{} {}",
+                terraform_resource_name,
+                Self::required_str(resource, "serial_number")?
+            )),
+            ("aws", "user_inline_policies") => Ok(format!(
+                "terraform import aws_iam_user_policy.{} {}:{}",
+                terraform_resource_name,
+                Self::required_str(resource, "user_name")?,
+                Self::required_str(resource, "policy_name")?
+            )),
+            ("aws", "group_inline_policies") => Ok(format!(
+                "terraform import aws_iam_group_policy.{} {}:{}",
+                terraform_resource_name,
+                Self::required_str(resource, "group_name")?,
+                Self::required_str(resource, "policy_name")?
+            )),
+            ("aws", "role_inline_policies") => Ok(format!(
+                "terraform import aws_iam_role_policy.{} {}:{}",
+                terraform_resource_name,
+                Self::required_str(resource, "role_name")?,
+                Self::required_str(resource, "policy_name")?
+            )),
+            ("aws", "user_policy_attachments") => Ok(format!(
+                "terraform import aws_iam_user_policy_attachment.{} {}/{}",
+                terraform_resource_name,
+                Self::required_str(resource, "user_name")?,
+                Self::required_str(resource, "policy_arn")?
+            )),
+            ("aws", "group_policy_attachments") => Ok(format!(
+                "terraform import aws_iam_group_policy_attachment.{} {}/{}",
+                terraform_resource_name,
+                Self::required_str(resource, "group_name")?,
+                Self::required_str(resource, "policy_arn")?
+            )),
+            ("aws", "role_policy_attachments") => Ok(format!(
+                "terraform import aws_iam_role_policy_attachment.{} {}/{}",
+                terraform_resource_name,
+                Self::required_str(resource, "role_name")?,
+                Self::required_str(resource, "policy_arn")?
+            )),
+            ("aws", "user_group_memberships") => {
+                let group_names: Vec<&str> = resource
+                    .get("group_names")
+                    .and_then(|v| v.as_array())
+                    .map(|names| names.iter().filter_map(|n| n.as_str()).collect())
+                    .unwrap_or_default();
+                Ok(format!(
+                    "terraform import aws_iam_user_group_membership.{} {}/{}",
+                    terraform_resource_name,
+                    Self::required_str(resource, "user_name")?,
+                    group_names.join("/")
+                ))
+            }
+            _ => Err(anyhow::anyhow!(
+                "Unsupported provider/resource type combination"
+            )),
+        }
+    }
+
+    fn generate_sh_import_script(commands: &[String]) -> String {
+        let mut script = String::new();
+        script.push_str("#!/bin/bash\n");
+        script.push_str("# Terraform import script\n");
+        script.push_str("# Generated by TFKosmos\n\n");
+        script.push_str("set -e\n\n");
+
+        for cmd in commands {
+            script.push_str(&format!("{}\n", cmd));
+        }
+
+        script
+    }
+
+    fn generate_ps1_import_script(commands: &[String]) -> String {
+        let mut script = String::new();
+        script.push_str("# Terraform import script\n");
+        script.push_str("# Generated by TFKosmos\n\n");
+        script.push_str("$ErrorActionPreference = \"Stop\"\n\n");
+
+        for cmd in commands {
+            script.push_str(&format!("{}\n", cmd));
+        }
+
+        script
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    // ========================================
+    // get_templates_for_provider のテスト
+    // ========================================
+
+    #[test]
+    fn test_get_templates_for_aws() {
+        let templates = TerraformGenerator::get_templates_for_provider("aws");
+        assert_eq!(templates.len(), 42);
+
+        let template_types: Vec<&str> = templates.iter().map(|t| t.resource_type).collect();
+        assert!(template_types.contains(&"users"));
+        assert!(template_types.contains(&"groups"));
+        assert!(template_types.contains(&"roles"));
+        assert!(template_types.contains(&"policies"));
+        assert!(template_types.contains(&"aws_managed_policies"));
+        assert!(template_types.contains(&"account_password_policy"));
+        assert!(template_types.contains(&"account_aliases"));
+        assert!(template_types.contains(&"user_inline_policies"));
+        assert!(template_types.contains(&"group_inline_policies"));
+        assert!(template_types.contains(&"role_inline_policies"));
+        assert!(template_types.contains(&"user_policy_attachments"));
+        assert!(template_types.contains(&"group_policy_attachments"));
+        assert!(template_types.contains(&"role_policy_attachments"));
+        assert!(template_types.contains(&"user_group_memberships"));
+        assert!(template_types.contains(&"service_linked_roles"));
+        assert!(template_types.contains(&"instance_profiles"));
+        assert!(template_types.contains(&"oidc_providers"));
+        assert!(template_types.contains(&"saml_providers"));
+        assert!(template_types.contains(&"sso_permission_sets"));
+        assert!(template_types.contains(&"sso_managed_policy_attachments"));
+        assert!(template_types.contains(&"sso_inline_policies"));
+        assert!(template_types.contains(&"sso_account_assignments"));
+        assert!(template_types.contains(&"service_control_policies"));
+        assert!(template_types.contains(&"service_control_policy_attachments"));
+        assert!(template_types.contains(&"s3_buckets"));
+        assert!(template_types.contains(&"s3_bucket_versionings"));
+        assert!(template_types.contains(&"s3_bucket_encryptions"));
+        assert!(template_types.contains(&"s3_bucket_lifecycle_configurations"));
+        assert!(template_types.contains(&"s3_bucket_public_access_blocks"));
+        assert!(template_types.contains(&"s3_bucket_ownership_controls"));
+        assert!(template_types.contains(&"s3_bucket_policies"));
+        assert!(template_types.contains(&"kms_key_policies"));
+        assert!(template_types.contains(&"sqs_queue_policies"));
+        assert!(template_types.contains(&"sns_topic_policies"));
+        assert!(template_types.contains(&"vpcs"));
+        assert!(template_types.contains(&"subnets"));
+        assert!(template_types.contains(&"security_groups"));
+        assert!(template_types.contains(&"security_group_ingress_rules"));
+        assert!(template_types.contains(&"security_group_egress_rules"));
+        assert!(template_types.contains(&"cleanup_access_keys"));
+        assert!(template_types.contains(&"cleanup_login_profiles"));
+        assert!(template_types.contains(&"cleanup_mfa_devices"));
+    }
+
+    #[test]
+    fn test_get_templates_for_azure() {
+        let templates = TerraformGenerator::get_templates_for_provider("azure");
+        assert_eq!(templates.len(), 2);
+
+        let template_types: Vec<&str> = templates.iter().map(|t| t.resource_type).collect();
+        assert!(template_types.contains(&"role_definitions"));
+        assert!(template_types.contains(&"role_assignments"));
+    }
+
+    #[test]
+    fn test_get_templates_for_unknown_provider() {
+        let templates = TerraformGenerator::get_templates_for_provider("unknown");
+        assert_eq!(templates.len(), 0);
+    }
+
+    // ========================================
+    // get_resource_name のテスト
+    // ========================================
+
+    #[test]
+    fn test_get_resource_name_user() {
+        let resource = json!({
+            "user_name": "test-user",
+            "arn": "arn:aws:iam::123456789012:user/test-user"
+        });
+
+        let result = TerraformGenerator::get_resource_name(&resource, "users");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "test-user");
+    }
+
+    #[test]
+    fn test_get_resource_name_group() {
+        let resource = json!({
+            "group_name": "test-group",
+            "arn": "arn:aws:iam::123456789012:group/test-group"
+        });
+
+        let result = TerraformGenerator::get_resource_name(&resource, "groups");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "test-group");
+    }
+
+    #[test]
+    fn test_get_resource_name_role() {
+        let resource = json!({
+            "role_name": "test-role",
+            "arn": "arn:aws:iam::123456789012:role/test-role"
+        });
+
+        let result = TerraformGenerator::get_resource_name(&resource, "roles");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "test-role");
+    }
+
+    #[test]
+    fn test_get_resource_name_policy() {
+        let resource = json!({
+            "policy_name": "test-policy",
+            "arn": "arn:aws:iam::123456789012:policy/test-policy"
+        });
+
+        let result = TerraformGenerator::get_resource_name(&resource, "policies");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "test-policy");
+    }
+
+    #[test]
+    fn test_get_resource_name_missing_field() {
+        let resource = json!({
+            "arn": "arn:aws:iam::123456789012:user/test-user"
+        });
+
+        let result = TerraformGenerator::get_resource_name(&resource, "users");
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Missing user_name"));
+    }
+
+    #[test]
+    fn test_get_resource_name_generic() {
+        let resource = json!({
+            "name": "generic-resource"
+        });
+
+        let result = TerraformGenerator::get_resource_name(&resource, "unknown_type");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "generic-resource");
+    }
+
+    // ========================================
+    // generate_import_command のテスト
+    // ========================================
+
+    #[test]
+    fn test_generate_import_command_aws_user() {
+        let resource = json!({
+            "user_name": "test-user",
+            "arn": "arn:aws:iam::123456789012:user/test-user"
+        });
+
+        let result = TerraformGenerator::generate_import_command(&resource, "users", "aws");
+        assert!(result.is_ok());
+
+        let import_cmd = result.unwrap();
+        assert!(import_cmd.contains("terraform import"));
+        assert!(import_cmd.contains("aws_iam_user.test_user"));
+        assert!(import_cmd.contains("arn:aws:iam::123456789012:user/test-user"));
+    }
+
+    #[test]
+    fn test_generate_import_command_aws_group() {
+        let resource = json!({
+            "group_name": "test-group",
+            "arn": "arn:aws:iam::123456789012:group/test-group"
+        });
+
+        let result = TerraformGenerator::generate_import_command(&resource, "groups", "aws");
+        assert!(result.is_ok());
+
+        let import_cmd = result.unwrap();
+        assert!(import_cmd.contains("terraform import"));
+        assert!(import_cmd.contains("aws_iam_group.test_group"));
+        assert!(import_cmd.contains("arn:aws:iam::123456789012:group/test-group"));
+    }
+
+    #[test]
+    fn test_generate_import_command_aws_role() {
+        let resource = json!({
+            "role_name": "test-role",
+            "arn": "arn:aws:iam::123456789012:role/test-role"
+        });
+
+        let result = TerraformGenerator::generate_import_command(&resource, "roles", "aws");
+        assert!(result.is_ok());
+
+        let import_cmd = result.unwrap();
+        assert!(import_cmd.contains("terraform import"));
+        assert!(import_cmd.contains("aws_iam_role.test_role"));
+        assert!(import_cmd.contains("arn:aws:iam::123456789012:role/test-role"));
+    }
+
+    #[test]
+    fn test_generate_import_command_aws_policy() {
+        let resource = json!({
+            "policy_name": "test-policy",
+            "arn": "arn:aws:iam::123456789012:policy/test-policy"
+        });
+
+        let result = TerraformGenerator::generate_import_command(&resource, "policies", "aws");
+        assert!(result.is_ok());
+
+        let import_cmd = result.unwrap();
+        assert!(import_cmd.contains("terraform import"));
+        assert!(import_cmd.contains("aws_iam_policy.test_policy"));
+        assert!(import_cmd.contains("arn:aws:iam::123456789012:policy/test-policy"));
+    }
+
+    #[test]
+    fn test_generate_import_command_aws_user_inline_policy() {
+        let resource = json!({
+            "user_name": "test-user",
+            "policy_name": "inline-s3",
+            "policy_type": "inline"
+        });
+
+        let result =
+            TerraformGenerator::generate_import_command(&resource, "user_inline_policies", "aws");
+        assert_eq!(
+            result.unwrap(),
+            "terraform import aws_iam_user_policy.test_user_inline_s3 test-user:inline-s3"
+        );
+    }
+
+    #[test]
+    fn test_generate_import_command_aws_role_inline_policy() {
+        let resource = json!({
+            "role_name": "app-role",
+            "policy_name": "logs",
+            "policy_type": "inline"
+        });
+
+        let result =
+            TerraformGenerator::generate_import_command(&resource, "role_inline_policies", "aws");
+        assert_eq!(
+            result.unwrap(),
+            "terraform import aws_iam_role_policy.app_role_logs app-role:logs"
+        );
+    }
+
+    #[test]
+    fn test_generate_import_command_aws_role_policy_attachment() {
+        let resource = json!({
+            "role_name": "app-role",
+            "policy_arn": "arn:aws:iam::aws:policy/ReadOnlyAccess",
+            "policy_type": "managed"
+        });
+
+        let result = TerraformGenerator::generate_import_command(
+            &resource,
+            "role_policy_attachments",
+            "aws",
+        );
+        assert_eq!(
+            result.unwrap(),
+            "terraform import aws_iam_role_policy_attachment.app_role_aws_readonlyaccess app-role/arn:aws:iam::aws:policy/ReadOnlyAccess"
+        );
+    }
+
+    #[test]
+    fn test_generate_import_command_aws_instance_profile() {
+        let resource = json!({
+            "instance_profile_name": "web-profile",
+            "role_name": "web-role"
+        });
+
+        let result =
+            TerraformGenerator::generate_import_command(&resource, "instance_profiles", "aws");
+        assert_eq!(
+            result.unwrap(),
+            "terraform import aws_iam_instance_profile.web_profile web-profile"
+        );
+    }
+
+    #[test]
+    fn test_generate_import_command_aws_oidc_provider() {
+        let resource = json!({
+            "arn": "arn:aws:iam::123456789012:oidc-provider/token.actions.githubusercontent.com",
+            "url": "https://token.actions.githubusercontent.com"
+        });
+
+        let result =
+            TerraformGenerator::generate_import_command(&resource, "oidc_providers", "aws");
+        assert_eq!(
+            result.unwrap(),
+            "terraform import aws_iam_openid_connect_provider.token_actions_githubusercontent_com arn:aws:iam::123456789012:oidc-provider/token.actions.githubusercontent.com"
+        );
+    }
+
+    #[test]
+    fn test_generate_import_command_aws_saml_provider() {
+        let resource = json!({
+            "arn": "arn:aws:iam::123456789012:saml-provider/corp-okta",
+            "name": "corp-okta"
+        });
+
+        let result =
+            TerraformGenerator::generate_import_command(&resource, "saml_providers", "aws");
+        assert_eq!(
+            result.unwrap(),
+            "terraform import aws_iam_saml_provider.corp_okta arn:aws:iam::123456789012:saml-provider/corp-okta"
+        );
+    }
+
+    #[test]
+    fn test_generate_import_command_aws_user_group_membership() {
+        let resource = json!({
+            "user_name": "alice",
+            "group_names": ["developers", "admins"]
+        });
+
+        let result =
+            TerraformGenerator::generate_import_command(&resource, "user_group_memberships", "aws");
+        assert_eq!(
+            result.unwrap(),
+            "terraform import aws_iam_user_group_membership.alice_groups alice/developers/admins"
+        );
+    }
+
+    #[test]
+    fn test_generate_import_command_unsupported_provider() {
+        let resource = json!({
+            "user_name": "test-user",
+            "arn": "arn:aws:iam::123456789012:user/test-user"
+        });
+
+        let result = TerraformGenerator::generate_import_command(&resource, "users", "gcp");
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Unsupported provider/resource type combination"));
+    }
+
+    // ========================================
+    // generate_sh_import_script のテスト
+    // ========================================
+
+    #[test]
+    fn test_generate_sh_import_script() {
+        let commands = vec![
+            "terraform import aws_iam_user.test_user arn:aws:iam::123456789012:user/test-user"
+                .to_string(),
+            "terraform import aws_iam_group.test_group arn:aws:iam::123456789012:group/test-group"
+                .to_string(),
+        ];
+
+        let script = TerraformGenerator::generate_sh_import_script(&commands);
+
+        assert!(script.contains("#!/bin/bash"));
+        assert!(script.contains("set -e"));
+        assert!(script.contains("terraform import aws_iam_user.test_user"));
+        assert!(script.contains("terraform import aws_iam_group.test_group"));
+    }
+
+    // ========================================
+    // generate_ps1_import_script のテスト
+    // ========================================
+
+    #[test]
+    fn test_generate_ps1_import_script() {
+        let commands = vec![
+            "terraform import aws_iam_user.test_user arn:aws:iam::123456789012:user/test-user"
+                .to_string(),
+            "terraform import aws_iam_group.test_group arn:aws:iam::123456789012:group/test-group"
+                .to_string(),
+        ];
+
+        let script = TerraformGenerator::generate_ps1_import_script(&commands);
+
+        assert!(script.contains("$ErrorActionPreference"));
+        assert!(script.contains("terraform import aws_iam_user.test_user"));
+        assert!(script.contains("terraform import aws_iam_group.test_group"));
+    }
+
+    // ========================================
+    // generate_readme のテスト
+    // ========================================
+
+    #[tokio::test]
+    async fn test_generate_readme() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path();
+
+        let config = GenerationConfig {
+            output_path: output_path.to_str().unwrap().to_string(),
+            file_split_rule: "single".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: true,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
+
+        let files = vec!["users.tf".to_string(), "groups.tf".to_string()];
+
+        let result = TerraformGenerator::generate_readme(&config, output_path, &files, &[]).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "README.md");
+
+        let readme_path = output_path.join("README.md");
+        assert!(readme_path.exists());
+
+        let readme_content = std::fs::read_to_string(readme_path).unwrap();
+        assert!(readme_content.contains("# Terraform Code Generation"));
+        assert!(readme_content.contains("users.tf"));
+        assert!(readme_content.contains("groups.tf"));
+        assert!(readme_content.contains("terraform init"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_readme_lists_scan_warnings() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path();
+
+        let config = GenerationConfig {
+            output_path: output_path.to_str().unwrap().to_string(),
+            file_split_rule: "single".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: true,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
+        let warnings = vec![ScanWarning {
+            resource_type: "role".to_string(),
+            resource_name: "app".to_string(),
+            operation: "ListAttachedRolePolicies".to_string(),
+            error_kind: "access_denied".to_string(),
+            message: "AccessDenied | not authorized".to_string(),
+            account_id: Some("111111111111".to_string()),
+        }];
+
+        TerraformGenerator::generate_readme(&config, output_path, &[], &warnings)
+            .await
+            .unwrap();
+
+        let readme_content = std::fs::read_to_string(output_path.join("README.md")).unwrap();
+        assert!(readme_content.contains("## Scan Warnings"));
+        assert!(readme_content.contains(
+            "| role `app` (111111111111) | ListAttachedRolePolicies | access_denied | AccessDenied \\| not authorized |"
+        ));
+    }
+
+    // ========================================
+    // generate_import_script のテスト
+    // ========================================
+
+    #[tokio::test]
+    async fn test_generate_import_script_with_resources() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path();
+
+        let scan_data = json!({
+            "provider": "aws",
+            "users": [
+                {
+                    "user_name": "test-user",
+                    "arn": "arn:aws:iam::123456789012:user/test-user"
+                }
+            ]
+        });
+
+        let config = GenerationConfig {
+            output_path: output_path.to_str().unwrap().to_string(),
+            file_split_rule: "single".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: true,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
+
+        let selected_resources = HashMap::new();
+
+        let result = TerraformGenerator::generate_import_script(
+            &scan_data,
+            &config,
+            &selected_resources,
+            output_path,
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert!(result.as_ref().unwrap().is_some());
+
+        let script_name = result.unwrap().unwrap();
+        assert_eq!(script_name, "import.sh");
+
+        let script_path = output_path.join(&script_name);
+        assert!(script_path.exists());
+
+        let script_content = std::fs::read_to_string(script_path).unwrap();
+        assert!(script_content.contains("#!/bin/bash"));
+        assert!(script_content.contains("terraform import"));
+        assert!(script_content.contains("aws_iam_user.test_user"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_import_script_ps1_format() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path();
+
+        let scan_data = json!({
+            "provider": "aws",
+            "users": [
+                {
+                    "user_name": "test-user",
+                    "arn": "arn:aws:iam::123456789012:user/test-user"
+                }
+            ]
+        });
+
+        let config = GenerationConfig {
+            output_path: output_path.to_str().unwrap().to_string(),
+            file_split_rule: "single".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "ps1".to_string(),
+            generate_readme: true,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
+
+        let selected_resources = HashMap::new();
+
+        let result = TerraformGenerator::generate_import_script(
+            &scan_data,
+            &config,
+            &selected_resources,
+            output_path,
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert!(result.as_ref().unwrap().is_some());
+
+        let script_name = result.unwrap().unwrap();
+        assert_eq!(script_name, "import.ps1");
 
-        // Generate import script
-        let script_content = match config.import_script_format.as_str() {
-            "sh" => Self::generate_sh_import_script(&import_commands),
-            "ps1" => Self::generate_ps1_import_script(&import_commands),
-            _ => Self::generate_sh_import_script(&import_commands),
+        let script_path = output_path.join(&script_name);
+        assert!(script_path.exists());
+
+        let script_content = std::fs::read_to_string(script_path).unwrap();
+        assert!(script_content.contains("$ErrorActionPreference"));
+        assert!(script_content.contains("terraform import"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_import_script_no_resources() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path();
+
+        let scan_data = json!({
+            "provider": "aws",
+            "users": []
+        });
+
+        let config = GenerationConfig {
+            output_path: output_path.to_str().unwrap().to_string(),
+            file_split_rule: "single".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: true,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
         };
 
-        let script_name = match config.import_script_format.as_str() {
-            "ps1" => "import.ps1",
-            _ => "import.sh",
+        let selected_resources = HashMap::new();
+
+        let result = TerraformGenerator::generate_import_script(
+            &scan_data,
+            &config,
+            &selected_resources,
+            output_path,
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_none());
+    }
+
+    // ========================================
+    // インラインポリシーのテスト
+    // ========================================
+
+    fn inline_policy_scan_data() -> Value {
+        json!({
+            "provider": "aws",
+            "attachments": {
+                "user_policies": [],
+                "group_policies": [],
+                "role_policies": [
+                    {
+                        "role_name": "app-role",
+                        "policy_name": "s3-read",
+                        "policy_type": "inline",
+                        "policy_document": {
+                            "Version": "2012-10-17",
+                            "Statement": [{
+                                "Effect": "Allow",
+                                "Action": "s3:GetObject",
+                                "Resource": "arn:aws:s3:::bucket/${aws:username}/*"
+                            }]
+                        }
+                    },
+                    {
+                        "role_name": "app-role",
+                        "policy_arn": "arn:aws:iam::aws:policy/ReadOnlyAccess",
+                        "policy_type": "managed"
+                    },
+                    {
+                        "role_name": "other-role",
+                        "policy_name": "no-document",
+                        "policy_type": "inline"
+                    }
+                ],
+                "user_groups": []
+            }
+        })
+    }
+
+    #[test]
+    fn test_collect_resources_inline_policies() {
+        let scan_data = inline_policy_scan_data();
+
+        let resources = TerraformGenerator::collect_resources(&scan_data, "role_inline_policies");
+        assert_eq!(resources.len(), 1);
+        assert_eq!(resources[0]["policy_name"], "s3-read");
+
+        let resources = TerraformGenerator::collect_resources(&scan_data, "user_inline_policies");
+        assert!(resources.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_render_resource_role_inline_policy() {
+        let scan_data = inline_policy_scan_data();
+        let resource =
+            &TerraformGenerator::collect_resources(&scan_data, "role_inline_policies")[0];
+        let template_info = ResourceTemplate {
+            resource_type: "role_inline_policies",
+            template_path: "aws/iam_role_policy.tf.j2",
+            provider: "aws",
+        };
+        let config = GenerationConfig {
+            output_path: "unused".to_string(),
+            file_split_rule: "single".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
         };
 
-        let script_path = output_path.join(script_name);
-        println!(
-            "[GENERATE_IMPORT] Writing import script: {:?} ({} bytes)",
-            script_path,
-            script_content.len()
-        );
-        fs::write(&script_path, script_content)
-            .with_context(|| format!("Failed to write import script: {:?}", script_path))?;
+        let rendered = TerraformGenerator::render_resource(resource, &template_info, &config)
+            .await
+            .unwrap();
 
-        // Verify file was written
-        if !script_path.exists() {
-            return Err(anyhow::anyhow!(
-                "Import script was not created: {:?}",
-                script_path
-            ));
-        }
-        let metadata = fs::metadata(&script_path)?;
-        println!(
-            "[GENERATE_IMPORT] Import script written successfully: {:?} ({} bytes)",
-            script_path,
-            metadata.len()
-        );
+        assert!(rendered.contains(r#"resource "aws_iam_role_policy" "app_role_s3_read""#));
+        assert!(rendered.contains("role   = aws_iam_role.app_role.name"));
+        assert!(rendered.contains("s3:GetObject"));
+        // Terraformの補間と衝突しないようにエスケープされる
+        assert!(rendered.contains("$${aws:username}"));
+    }
 
-        // Make script executable on Unix systems
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let mut perms = fs::metadata(&script_path)?.permissions();
-            perms.set_mode(0o755);
-            fs::set_permissions(&script_path, perms)?;
-            println!("[GENERATE_IMPORT] Import script made executable");
-        }
+    #[tokio::test]
+    async fn test_render_resource_role_and_user_permissions_boundary() {
+        let boundary = "arn:aws:iam::123456789012:policy/guardrail-boundary";
+        let config = GenerationConfig {
+            output_path: "unused".to_string(),
+            file_split_rule: "single".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
 
-        Ok(Some(script_name.to_string()))
+        let role = json!({
+            "role_name": "guarded-role",
+            "path": "/",
+            "description": "Deploys \"app\" services",
+            "max_session_duration": 43200,
+            "permissions_boundary": boundary,
+            "assume_role_statements": [{
+                "effect": "Allow",
+                "principal_type": "Service",
+                "principal_identifiers": ["ec2.amazonaws.com"],
+                "actions": ["sts:AssumeRole"],
+                "conditions": []
+            }]
+        });
+        let template_info = ResourceTemplate {
+            resource_type: "roles",
+            template_path: "aws/iam_role.tf.j2",
+            provider: "aws",
+        };
+        let rendered = TerraformGenerator::render_resource(&role, &template_info, &config)
+            .await
+            .unwrap();
+        assert!(rendered.contains(&format!(r#"permissions_boundary = "{}""#, boundary)));
+        assert!(rendered.contains("max_session_duration = 43200"));
+        assert!(rendered.contains(r#"description          = "Deploys \"app\" services""#));
+        assert!(!rendered.contains("force_detach_policies"));
+
+        let user = json!({
+            "user_name": "guarded-user",
+            "path": "/",
+            "permissions_boundary": boundary
+        });
+        let template_info = ResourceTemplate {
+            resource_type: "users",
+            template_path: "aws/iam_user.tf.j2",
+            provider: "aws",
+        };
+        let rendered = TerraformGenerator::render_resource(&user, &template_info, &config)
+            .await
+            .unwrap();
+        assert!(rendered.contains(&format!(r#"permissions_boundary = "{}""#, boundary)));
     }
 
-    fn generate_import_command(
-        resource: &Value,
-        resource_type: &str,
-        provider: &str,
-    ) -> Result<String> {
-        let resource_name = Self::get_resource_name(resource, resource_type)?;
-        let terraform_resource_name = NamingGenerator::to_snake_case(&resource_name);
+    #[tokio::test]
+    async fn test_render_resource_instance_profile_references_role() {
+        let scan_data = json!({
+            "provider": "aws",
+            "roles": [{"role_name": "web-role"}],
+            "instance_profiles": [
+                {"instance_profile_name": "web-profile", "path": "/", "role_name": "web-role"},
+                {"instance_profile_name": "legacy-profile", "path": "/", "role_name": "unmanaged-role"}
+            ]
+        });
+        let resources = TerraformGenerator::collect_resources(&scan_data, "instance_profiles");
+        let template_info = ResourceTemplate {
+            resource_type: "instance_profiles",
+            template_path: "aws/iam_instance_profile.tf.j2",
+            provider: "aws",
+        };
+        let config = GenerationConfig {
+            output_path: "unused".to_string(),
+            file_split_rule: "single".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
 
-        match (provider, resource_type) {
-            ("aws", "users") => {
-                let arn = resource
-                    .get("arn")
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| anyhow::anyhow!("Missing ARN"))?;
-                Ok(format!(
-                    "terraform import aws_iam_user.{} {}",
-                    terraform_resource_name, arn
-                ))
-            }
-            ("aws", "groups") => {
-                let arn = resource
-                    .get("arn")
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| anyhow::anyhow!("Missing ARN"))?;
-                Ok(format!(
-                    "terraform import aws_iam_group.{} {}",
-                    terraform_resource_name, arn
-                ))
-            }
-            ("aws", "roles") => {
-                let arn = resource
-                    .get("arn")
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| anyhow::anyhow!("Missing ARN"))?;
-                Ok(format!(
-                    "terraform import aws_iam_role.{} {}",
-                    terraform_resource_name, arn
-                ))
-            }
-            ("aws", "policies") => {
-                let arn = resource
-                    .get("arn")
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| anyhow::anyhow!("Missing ARN"))?;
-                Ok(format!(
-                    "terraform import aws_iam_policy.{} {}",
-                    terraform_resource_name, arn
-                ))
+        let managed = TerraformGenerator::render_resource(&resources[0], &template_info, &config)
+            .await
+            .unwrap();
+        assert!(managed.contains(r#"resource "aws_iam_instance_profile" "web_profile""#));
+        assert!(managed.contains("role = aws_iam_role.web_role.name"));
+
+        // スキャン結果に含まれないロールは名前で参照する
+        let unmanaged = TerraformGenerator::render_resource(&resources[1], &template_info, &config)
+            .await
+            .unwrap();
+        assert!(unmanaged.contains(r#"role = "unmanaged-role""#));
+    }
+
+    fn service_linked_role_scan_data() -> Value {
+        json!({
+            "provider": "aws",
+            "roles": [
+                {"role_name": "app-role", "path": "/", "role_category": "standard"},
+                {
+                    "role_name": "AWSServiceRoleForAutoScaling_custom",
+                    "arn": "arn:aws:iam::123456789012:role/aws-service-role/autoscaling.amazonaws.com/AWSServiceRoleForAutoScaling_custom",
+                    "path": "/aws-service-role/autoscaling.amazonaws.com/",
+                    "role_category": "service_linked",
+                    "aws_service_name": "autoscaling.amazonaws.com",
+                    "custom_suffix": "custom"
+                }
+            ],
+            "attachments": {
+                "role_policies": [
+                    {"role_name": "app-role", "policy_type": "managed",
+                     "policy_arn": "arn:aws:iam::aws:policy/ReadOnlyAccess"},
+                    {"role_name": "AWSServiceRoleForAutoScaling_custom", "policy_type": "managed",
+                     "policy_arn": "arn:aws:iam::aws:policy/aws-service-role/AutoScalingServiceRolePolicy"}
+                ]
             }
-            _ => Err(anyhow::anyhow!(
-                "Unsupported provider/resource type combination"
-            )),
-        }
+        })
     }
 
-    fn generate_sh_import_script(commands: &[String]) -> String {
-        let mut script = String::new();
-        script.push_str("#!/bin/bash\n");
-        script.push_str("# Terraform import script\n");
-        script.push_str("# Generated by TFKosmos\n\n");
-        script.push_str("set -e\n\n");
+    #[test]
+    fn test_collect_resources_separates_service_linked_roles() {
+        let scan_data = service_linked_role_scan_data();
+
+        let roles = TerraformGenerator::collect_resources(&scan_data, "roles");
+        assert_eq!(roles.len(), 1);
+        assert_eq!(roles[0]["role_name"], "app-role");
+
+        let service_linked =
+            TerraformGenerator::collect_resources(&scan_data, "service_linked_roles");
+        assert_eq!(service_linked.len(), 1);
+        assert_eq!(
+            service_linked[0]["role_name"],
+            "AWSServiceRoleForAutoScaling_custom"
+        );
 
-        for cmd in commands {
-            script.push_str(&format!("{}\n", cmd));
-        }
+        // サービスリンクロールのポリシーアタッチメントは生成しない
+        let attachments =
+            TerraformGenerator::collect_resources(&scan_data, "role_policy_attachments");
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0]["role_name"], "app-role");
+    }
 
-        script
+    #[tokio::test]
+    async fn test_render_resource_service_linked_role() {
+        let scan_data = service_linked_role_scan_data();
+        let resources = TerraformGenerator::collect_resources(&scan_data, "service_linked_roles");
+        let template_info = ResourceTemplate {
+            resource_type: "service_linked_roles",
+            template_path: "aws/iam_service_linked_role.tf.j2",
+            provider: "aws",
+        };
+        let config = GenerationConfig {
+            output_path: "unused".to_string(),
+            file_split_rule: "single".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
+
+        let rendered = TerraformGenerator::render_resource(&resources[0], &template_info, &config)
+            .await
+            .unwrap();
+
+        assert!(rendered.contains(
+            r#"resource "aws_iam_service_linked_role" "awsserviceroleforautoscaling_custom""#
+        ));
+        assert!(rendered.contains(r#"aws_service_name = "autoscaling.amazonaws.com""#));
+        assert!(rendered.contains(r#"custom_suffix    = "custom""#));
+
+        let command = TerraformGenerator::generate_import_command(
+            &resources[0],
+            "service_linked_roles",
+            "aws",
+        )
+        .unwrap();
+        assert!(command.starts_with("terraform import aws_iam_service_linked_role."));
+        assert!(command.ends_with("/AWSServiceRoleForAutoScaling_custom"));
     }
 
-    fn generate_ps1_import_script(commands: &[String]) -> String {
-        let mut script = String::new();
-        script.push_str("# Terraform import script\n");
-        script.push_str("# Generated by TFKosmos\n\n");
-        script.push_str("$ErrorActionPreference = \"Stop\"\n\n");
+    fn cleanup_scan_data() -> Value {
+        json!({
+            "provider": "aws",
+            "users": [{"user_name": "alice", "arn": "arn:aws:iam::123456789012:user/alice"}],
+            "cleanup": [
+                {"type": "access_key", "resource": {
+                    "user_name": "alice", "access_key_id": "AKIAEXAMPLE", "status": "Inactive"
+                }},
+                {"type": "login_profile", "resource": {
+                    "user_name": "alice", "password_reset_required": false
+                }},
+                {"type": "mfa_device", "resource": {
+                    "user_name": "alice", "device_type": "virtual",
+                    "serial_number": "arn:aws:iam::123456789012:mfa/devices/alice-phone"
+                }},
+                {"type": "mfa_device", "resource": {
+                    "user_name": "alice", "device_type": "hardware",
+                    "serial_number": "GAHT12345678"
+                }}
+            ]
+        })
+    }
 
-        for cmd in commands {
-            script.push_str(&format!("{}\n", cmd));
+    #[test]
+    fn test_collect_resources_cleanup() {
+        let scan_data = cleanup_scan_data();
+
+        let access_keys = TerraformGenerator::collect_resources(&scan_data, "cleanup_access_keys");
+        assert_eq!(access_keys.len(), 1);
+        assert_eq!(
+            TerraformGenerator::selection_id(&access_keys[0], "cleanup_access_keys"),
+            Some("AKIAEXAMPLE")
+        );
+
+        let login_profiles =
+            TerraformGenerator::collect_resources(&scan_data, "cleanup_login_profiles");
+        assert_eq!(
+            TerraformGenerator::selection_id(&login_profiles[0], "cleanup_login_profiles"),
+            Some("login_profile_alice")
+        );
+
+        // ハードウェアMFAはTerraformで表現できないため除外される
+        let mfa_devices = TerraformGenerator::collect_resources(&scan_data, "cleanup_mfa_devices");
+        assert_eq!(mfa_devices.len(), 1);
+        assert_eq!(mfa_devices[0]["virtual_mfa_device_name"], "alice-phone");
+        assert_eq!(mfa_devices[0]["path"], "/devices/");
+        assert_eq!(
+            TerraformGenerator::selection_key("cleanup_mfa_devices"),
+            "cleanup"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_render_resource_cleanup_templates() {
+        let scan_data = cleanup_scan_data();
+        let config = GenerationConfig {
+            output_path: "unused".to_string(),
+            file_split_rule: "single".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
+        let templates = TerraformGenerator::get_templates_for_provider("aws");
+        let mut rendered = Vec::new();
+        for resource_type in [
+            "cleanup_access_keys",
+            "cleanup_login_profiles",
+            "cleanup_mfa_devices",
+        ] {
+            let template_info = templates
+                .iter()
+                .find(|t| t.resource_type == resource_type)
+                .unwrap();
+            let resources = TerraformGenerator::collect_resources(&scan_data, resource_type);
+            rendered.push(
+                TerraformGenerator::render_resource(&resources[0], template_info, &config)
+                    .await
+                    .unwrap(),
+            );
         }
 
-        script
+        assert!(rendered[0].contains(r#"resource "aws_iam_access_key" "alice_akiaexample""#));
+        assert!(rendered[0].contains("user   = aws_iam_user.alice.name"));
+        assert!(rendered[0].contains(r#"status = "Inactive""#));
+        assert!(
+            rendered[1].contains(r#"resource "aws_iam_user_login_profile" "alice_login_profile""#)
+        );
+        assert!(rendered[1].contains("password_reset_required = false"));
+        assert!(rendered[1].contains("ignore_changes = [password_length, pgp_key]"));
+        assert!(
+            rendered[2].contains(r#"resource "aws_iam_virtual_mfa_device" "alice_alice_phone""#)
+        );
+        assert!(rendered[2].contains(r#"virtual_mfa_device_name = "alice-phone""#));
+        assert!(rendered[2].contains(r#"path                    = "/devices/""#));
+    }
+
+    #[test]
+    fn test_collect_import_commands_cleanup_follows_selection() {
+        let mut selected_resources = HashMap::new();
+        selected_resources.insert("users".to_string(), vec![json!("alice")]);
+        selected_resources.insert(
+            "cleanup".to_string(),
+            vec![
+                json!("AKIAEXAMPLE"),
+                json!("arn:aws:iam::123456789012:mfa/devices/alice-phone"),
+            ],
+        );
+
+        let commands =
+            TerraformGenerator::collect_import_commands(&cleanup_scan_data(), &selected_resources);
+
+        assert!(commands.contains(
+            &"terraform import aws_iam_access_key.alice_akiaexample AKIAEXAMPLE".to_string()
+        ));
+        assert!(commands.contains(
+            &"terraform import aws_iam_virtual_mfa_device.alice_alice_phone arn:aws:iam::123456789012:mfa/devices/alice-phone"
+                .to_string()
+        ));
+        assert!(!commands
+            .iter()
+            .any(|c| c.contains("aws_iam_user_login_profile")));
+    }
+
+    #[tokio::test]
+    async fn test_render_resource_role_federated_principal_references_provider() {
+        let oidc_arn =
+            "arn:aws:iam::123456789012:oidc-provider/token.actions.githubusercontent.com";
+        let scan_data = json!({
+            "provider": "aws",
+            "oidc_providers": [
+                {"arn": oidc_arn, "url": "https://token.actions.githubusercontent.com"}
+            ],
+            "roles": [{
+                "role_name": "ci-deploy",
+                "path": "/",
+                "assume_role_statements": [{
+                    "effect": "Allow",
+                    "principal_type": "Federated",
+                    "principal_identifiers": [
+                        oidc_arn,
+                        "arn:aws:iam::123456789012:saml-provider/unmanaged"
+                    ],
+                    "actions": ["sts:AssumeRoleWithWebIdentity"],
+                    "conditions": []
+                }]
+            }]
+        });
+        let resources = TerraformGenerator::collect_resources(&scan_data, "roles");
+        let template_info = ResourceTemplate {
+            resource_type: "roles",
+            template_path: "aws/iam_role.tf.j2",
+            provider: "aws",
+        };
+        let config = GenerationConfig {
+            output_path: "unused".to_string(),
+            file_split_rule: "single".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
+
+        let rendered = TerraformGenerator::render_resource(&resources[0], &template_info, &config)
+            .await
+            .unwrap();
+        assert!(rendered
+            .contains("aws_iam_openid_connect_provider.token_actions_githubusercontent_com.arn,"));
+        assert!(!rendered.contains(&format!("\"{}\"", oidc_arn)));
+        // スキャン結果に含まれないプロバイダーはARNのまま
+        assert!(rendered.contains(r#""arn:aws:iam::123456789012:saml-provider/unmanaged","#));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use serde_json::json;
-    use std::collections::HashMap;
-    use tempfile::TempDir;
+    #[tokio::test]
+    async fn test_generate_import_script_inline_policies_follow_owner_selection() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path();
 
-    // ========================================
-    // get_templates_for_provider のテスト
-    // ========================================
+        let config = GenerationConfig {
+            output_path: output_path.to_str().unwrap().to_string(),
+            file_split_rule: "single".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
 
-    #[test]
-    fn test_get_templates_for_aws() {
-        let templates = TerraformGenerator::get_templates_for_provider("aws");
-        assert_eq!(templates.len(), 4);
+        let mut selected_resources = HashMap::new();
+        selected_resources.insert("roles".to_string(), vec![json!("app-role")]);
 
-        let template_types: Vec<&str> = templates.iter().map(|t| t.resource_type).collect();
-        assert!(template_types.contains(&"users"));
-        assert!(template_types.contains(&"groups"));
-        assert!(template_types.contains(&"roles"));
-        assert!(template_types.contains(&"policies"));
+        TerraformGenerator::generate_import_script(
+            &inline_policy_scan_data(),
+            &config,
+            &selected_resources,
+            output_path,
+        )
+        .await
+        .unwrap();
+
+        let script_content = std::fs::read_to_string(output_path.join("import.sh")).unwrap();
+        assert!(script_content
+            .contains("terraform import aws_iam_role_policy.app_role_s3_read app-role:s3-read"));
     }
 
-    #[test]
-    fn test_get_templates_for_azure() {
-        let templates = TerraformGenerator::get_templates_for_provider("azure");
-        assert_eq!(templates.len(), 2);
+    // ========================================
+    // ポリシーアタッチメント・グループメンバーシップのテスト
+    // ========================================
 
-        let template_types: Vec<&str> = templates.iter().map(|t| t.resource_type).collect();
-        assert!(template_types.contains(&"role_definitions"));
-        assert!(template_types.contains(&"role_assignments"));
+    fn attachment_scan_data() -> Value {
+        json!({
+            "provider": "aws",
+            "groups": [{"group_name": "developers"}, {"group_name": "admins"}],
+            "attachments": {
+                "user_policies": [
+                    {
+                        "user_name": "alice",
+                        "policy_arn": "arn:aws:iam::123456789012:policy/team/deploy",
+                        "policy_type": "managed"
+                    },
+                    {
+                        "user_name": "alice",
+                        "policy_name": "inline",
+                        "policy_type": "inline"
+                    }
+                ],
+                "group_policies": [],
+                "role_policies": [],
+                "user_groups": [
+                    {"user_name": "alice", "group_name": "developers"},
+                    {"user_name": "bob", "group_name": "developers"},
+                    {"user_name": "alice", "group_name": "admins"}
+                ]
+            }
+        })
     }
 
     #[test]
-    fn test_get_templates_for_unknown_provider() {
-        let templates = TerraformGenerator::get_templates_for_provider("unknown");
-        assert_eq!(templates.len(), 0);
+    fn test_collect_resources_managed_attachments() {
+        let resources = TerraformGenerator::collect_resources(
+            &attachment_scan_data(),
+            "user_policy_attachments",
+        );
+        assert_eq!(resources.len(), 1);
+        assert_eq!(
+            resources[0]["policy_arn"],
+            "arn:aws:iam::123456789012:policy/team/deploy"
+        );
     }
 
-    // ========================================
-    // get_resource_name のテスト
-    // ========================================
-
     #[test]
-    fn test_get_resource_name_user() {
-        let resource = json!({
-            "user_name": "test-user",
-            "arn": "arn:aws:iam::123456789012:user/test-user"
+    fn test_attachment_names_do_not_collide_for_same_policy_name() {
+        let aws_managed = json!({
+            "role_name": "app",
+            "policy_arn": "arn:aws:iam::aws:policy/ReadOnlyAccess"
+        });
+        let customer_managed = json!({
+            "role_name": "app",
+            "policy_arn": "arn:aws:iam::123456789012:policy/ReadOnlyAccess"
+        });
+        let with_path = json!({
+            "role_name": "app",
+            "policy_arn": "arn:aws:iam::123456789012:policy/team/ReadOnlyAccess"
         });
 
-        let result = TerraformGenerator::get_resource_name(&resource, "users");
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "test-user");
+        let names: Vec<String> = [aws_managed, customer_managed, with_path]
+            .iter()
+            .map(|r| TerraformGenerator::get_resource_name(r, "role_policy_attachments").unwrap())
+            .collect();
+
+        assert_eq!(
+            names,
+            vec![
+                "app_aws_ReadOnlyAccess",
+                "app_123456789012_ReadOnlyAccess",
+                "app_123456789012_team_ReadOnlyAccess",
+            ]
+        );
     }
 
     #[test]
-    fn test_get_resource_name_group() {
-        let resource = json!({
-            "group_name": "test-group",
-            "arn": "arn:aws:iam::123456789012:group/test-group"
-        });
-
-        let result = TerraformGenerator::get_resource_name(&resource, "groups");
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "test-group");
+    fn test_sso_attachment_names_do_not_collide_for_same_policy_name() {
+        let names: Vec<String> = [
+            "arn:aws:iam::aws:policy/ReadOnlyAccess",
+            "arn:aws:iam::123456789012:policy/ReadOnlyAccess",
+        ]
+        .iter()
+        .map(|arn| {
+            let resource = json!({
+                "permission_set_name": "auditor",
+                "managed_policy_arn": arn
+            });
+            TerraformGenerator::get_resource_name(&resource, "sso_managed_policy_attachments")
+                .unwrap()
+        })
+        .collect();
+
+        assert_eq!(
+            names,
+            vec![
+                "auditor_aws_ReadOnlyAccess",
+                "auditor_123456789012_ReadOnlyAccess"
+            ]
+        );
     }
 
     #[test]
-    fn test_get_resource_name_role() {
-        let resource = json!({
-            "role_name": "test-role",
-            "arn": "arn:aws:iam::123456789012:role/test-role"
-        });
-
-        let result = TerraformGenerator::get_resource_name(&resource, "roles");
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "test-role");
+    fn test_collect_resources_group_memberships() {
+        let resources = TerraformGenerator::collect_resources(
+            &attachment_scan_data(),
+            "user_group_memberships",
+        );
+        assert_eq!(resources.len(), 2);
+        assert_eq!(resources[0]["user_name"], "alice");
+        assert_eq!(resources[0]["group_names"], json!(["developers", "admins"]));
+        assert_eq!(resources[1]["user_name"], "bob");
+        assert_eq!(resources[1]["group_names"], json!(["developers"]));
     }
 
-    #[test]
-    fn test_get_resource_name_policy() {
-        let resource = json!({
-            "policy_name": "test-policy",
-            "arn": "arn:aws:iam::123456789012:policy/test-policy"
-        });
+    #[tokio::test]
+    async fn test_generate_renders_attachments_and_memberships() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().to_path_buf();
 
-        let result = TerraformGenerator::get_resource_name(&resource, "policies");
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "test-policy");
+        let config = GenerationConfig {
+            output_path: output_path.to_str().unwrap().to_string(),
+            file_split_rule: "by_resource_type".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
+
+        let files = TerraformGenerator::generate(
+            &attachment_scan_data(),
+            &config,
+            &HashMap::new(),
+            &output_path,
+        )
+        .await
+        .unwrap();
+
+        assert!(files.contains(&"user_policy_attachments.tf".to_string()));
+        assert!(files.contains(&"user_group_memberships.tf".to_string()));
+
+        let attachments =
+            std::fs::read_to_string(output_path.join("user_policy_attachments.tf")).unwrap();
+        assert!(attachments.contains(
+            r#"resource "aws_iam_user_policy_attachment" "alice_123456789012_team_deploy""#
+        ));
+        assert!(attachments.contains("user       = aws_iam_user.alice.name"));
+
+        let memberships =
+            std::fs::read_to_string(output_path.join("user_group_memberships.tf")).unwrap();
+        assert!(memberships.contains(r#"resource "aws_iam_user_group_membership" "alice_groups""#));
+        assert!(memberships.contains("aws_iam_group.developers.name,"));
+        assert!(memberships.contains("aws_iam_group.admins.name"));
     }
 
-    #[test]
-    fn test_get_resource_name_missing_field() {
-        let resource = json!({
-            "arn": "arn:aws:iam::123456789012:user/test-user"
+    #[tokio::test]
+    async fn test_generate_references_only_selected_resources() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().to_path_buf();
+        let config = GenerationConfig {
+            output_path: output_path.to_str().unwrap().to_string(),
+            file_split_rule: "by_resource_type".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
+        let scan_data = json!({
+            "provider": "aws",
+            "users": [{"user_name": "alice", "arn": "arn:aws:iam::123456789012:user/alice"}],
+            "groups": [{"group_name": "developers"}, {"group_name": "admins"}],
+            "roles": [{"role_name": "app", "arn": "arn:aws:iam::123456789012:role/app"}],
+            "policies": [
+                {"policy_name": "deploy", "arn": "arn:aws:iam::123456789012:policy/team/deploy"}
+            ],
+            "instance_profiles": [
+                {"instance_profile_name": "app-profile", "path": "/", "role_name": "app"}
+            ],
+            "attachments": {
+                "user_policies": [
+                    {"user_name": "alice", "policy_type": "managed",
+                     "policy_arn": "arn:aws:iam::123456789012:policy/team/deploy"}
+                ],
+                "user_groups": [
+                    {"user_name": "alice", "group_name": "developers"},
+                    {"user_name": "alice", "group_name": "admins"}
+                ]
+            }
         });
+        let mut selected = HashMap::new();
+        selected.insert("users".to_string(), vec![json!("alice")]);
+        selected.insert("groups".to_string(), vec![json!("developers")]);
+        selected.insert("roles".to_string(), Vec::new());
+        selected.insert("policies".to_string(), Vec::new());
+        selected.insert("instance_profiles".to_string(), vec![json!("app-profile")]);
+
+        let files = TerraformGenerator::generate(&scan_data, &config, &selected, &output_path)
+            .await
+            .unwrap();
+        let content: String = files
+            .iter()
+            .map(|file| std::fs::read_to_string(output_path.join(file)).unwrap())
+            .collect();
+
+        // 選択されていないポリシー・グループ・ロールはアドレスではなく名前やARNで指定する
+        assert!(!files.contains(&"policies.tf".to_string()));
+        assert!(!content.contains("aws_iam_policy.deploy"));
+        assert!(content.contains(r#"policy_arn = "arn:aws:iam::123456789012:policy/team/deploy""#));
+        assert!(content.contains("aws_iam_group.developers.name,"));
+        assert!(!content.contains("aws_iam_group.admins"));
+        assert!(content.contains(r#""admins","#));
+        assert!(!content.contains("aws_iam_role.app"));
+        assert!(content.contains(r#"role = "app""#));
+    }
 
-        let result = TerraformGenerator::get_resource_name(&resource, "users");
-        assert!(result.is_err());
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("Missing user_name"));
+    fn managed_policy_scan_data() -> Value {
+        json!({
+            "provider": "aws",
+            "roles": [{"role_name": "app", "arn": "arn:aws:iam::123456789012:role/app"}],
+            "policies": [
+                {"policy_name": "deploy", "arn": "arn:aws:iam::123456789012:policy/team/deploy"}
+            ],
+            "attachments": {
+                "role_policies": [
+                    {"role_name": "app", "policy_type": "managed",
+                     "policy_arn": "arn:aws:iam::aws:policy/ReadOnlyAccess"},
+                    {"role_name": "app", "policy_type": "managed",
+                     "policy_arn": "arn:aws:iam::aws:policy/service-role/AWSLambdaBasicExecutionRole"},
+                    {"role_name": "app", "policy_type": "managed",
+                     "policy_arn": "arn:aws:iam::123456789012:policy/team/deploy"},
+                    {"role_name": "app", "policy_type": "managed",
+                     "policy_arn": "arn:aws:iam::123456789012:policy/unscanned"}
+                ],
+                "user_policies": [
+                    {"user_name": "alice", "policy_type": "managed",
+                     "policy_arn": "arn:aws:iam::aws:policy/ReadOnlyAccess"}
+                ]
+            }
+        })
     }
 
     #[test]
-    fn test_get_resource_name_generic() {
-        let resource = json!({
-            "name": "generic-resource"
-        });
+    fn test_collect_resources_aws_managed_policies_are_distinct() {
+        let policies = TerraformGenerator::collect_resources(
+            &managed_policy_scan_data(),
+            "aws_managed_policies",
+        );
 
-        let result = TerraformGenerator::get_resource_name(&resource, "unknown_type");
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "generic-resource");
+        assert_eq!(policies.len(), 2);
+        assert_eq!(policies[0]["policy_name"], "ReadOnlyAccess");
+        assert_eq!(policies[0]["path"], "/");
+        assert_eq!(policies[1]["policy_name"], "AWSLambdaBasicExecutionRole");
+        assert_eq!(policies[1]["path"], "/service-role/");
+        assert!(TerraformGenerator::is_aws_managed_policy_arn(
+            "arn:aws-cn:iam::aws:policy/ReadOnlyAccess"
+        ));
+        assert!(!TerraformGenerator::is_aws_managed_policy_arn(
+            "arn:aws:iam::123456789012:policy/ReadOnlyAccess"
+        ));
     }
 
-    // ========================================
-    // generate_import_command のテスト
-    // ========================================
-
     #[test]
-    fn test_generate_import_command_aws_user() {
-        let resource = json!({
-            "user_name": "test-user",
-            "arn": "arn:aws:iam::123456789012:user/test-user"
+    fn test_aws_managed_policies_follow_owner_selection() {
+        let mut selected = HashMap::new();
+        selected.insert("users".to_string(), Vec::new());
+        selected.insert("roles".to_string(), vec![json!("app")]);
+        let scan_data = json!({
+            "provider": "aws",
+            "attachments": {
+                "role_policies": [
+                    {"role_name": "app", "policy_type": "managed",
+                     "policy_arn": "arn:aws:iam::aws:policy/ReadOnlyAccess"},
+                    {"role_name": "batch", "policy_type": "managed",
+                     "policy_arn": "arn:aws:iam::aws:policy/AmazonS3FullAccess"}
+                ],
+                "user_policies": [
+                    {"user_name": "alice", "policy_type": "managed",
+                     "policy_arn": "arn:aws:iam::aws:policy/AdministratorAccess"}
+                ]
+            }
         });
 
-        let result = TerraformGenerator::generate_import_command(&resource, "users", "aws");
-        assert!(result.is_ok());
+        let policies = TerraformGenerator::collect_resources(
+            &TerraformGenerator::selected_scan_data(&scan_data, &selected),
+            "aws_managed_policies",
+        );
 
-        let import_cmd = result.unwrap();
-        assert!(import_cmd.contains("terraform import"));
-        assert!(import_cmd.contains("aws_iam_user.test_user"));
-        assert!(import_cmd.contains("arn:aws:iam::123456789012:user/test-user"));
+        // 選択されていないロール・ユーザーにのみアタッチされたポリシーは生成しない
+        assert_eq!(policies.len(), 1);
+        assert_eq!(policies[0]["policy_name"], "ReadOnlyAccess");
     }
 
-    #[test]
-    fn test_generate_import_command_aws_group() {
-        let resource = json!({
-            "group_name": "test-group",
-            "arn": "arn:aws:iam::123456789012:group/test-group"
-        });
+    #[tokio::test]
+    async fn test_generate_attachments_reference_policy_resources() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().to_path_buf();
+        let config = GenerationConfig {
+            output_path: output_path.to_str().unwrap().to_string(),
+            file_split_rule: "by_resource_type".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
 
-        let result = TerraformGenerator::generate_import_command(&resource, "groups", "aws");
-        assert!(result.is_ok());
+        TerraformGenerator::generate(
+            &managed_policy_scan_data(),
+            &config,
+            &HashMap::new(),
+            &output_path,
+        )
+        .await
+        .unwrap();
+
+        let data_sources =
+            std::fs::read_to_string(output_path.join("aws_managed_policies.tf")).unwrap();
+        assert!(data_sources.contains(r#"data "aws_iam_policy" "readonlyaccess""#));
+        assert!(data_sources.contains(r#"path_prefix = "/service-role/""#));
+
+        let attachments =
+            std::fs::read_to_string(output_path.join("role_policy_attachments.tf")).unwrap();
+        assert!(attachments.contains("policy_arn = data.aws_iam_policy.readonlyaccess.arn"));
+        assert!(attachments
+            .contains("policy_arn = data.aws_iam_policy.awslambdabasicexecutionrole.arn"));
+        assert!(attachments.contains("policy_arn = aws_iam_policy.deploy.arn"));
+        // スキャン結果に含まれないカスタマー管理ポリシーはARNで指定する
+        assert!(
+            attachments.contains(r#"policy_arn = "arn:aws:iam::123456789012:policy/unscanned""#)
+        );
+        assert!(!attachments.contains("arn:aws:iam::aws:policy"));
 
-        let import_cmd = result.unwrap();
-        assert!(import_cmd.contains("terraform import"));
-        assert!(import_cmd.contains("aws_iam_group.test_group"));
-        assert!(import_cmd.contains("arn:aws:iam::123456789012:group/test-group"));
+        // データソースはインポート対象外
+        let commands = TerraformGenerator::collect_import_commands(
+            &managed_policy_scan_data(),
+            &HashMap::new(),
+        );
+        assert!(!commands
+            .iter()
+            .any(|c| c.contains("aws_iam_policy.readonlyaccess")));
     }
 
-    #[test]
-    fn test_generate_import_command_aws_role() {
-        let resource = json!({
-            "role_name": "test-role",
-            "arn": "arn:aws:iam::123456789012:role/test-role"
-        });
-
-        let result = TerraformGenerator::generate_import_command(&resource, "roles", "aws");
-        assert!(result.is_ok());
+    // ========================================
+    // アカウント設定のテスト
+    // ========================================
 
-        let import_cmd = result.unwrap();
-        assert!(import_cmd.contains("terraform import"));
-        assert!(import_cmd.contains("aws_iam_role.test_role"));
-        assert!(import_cmd.contains("arn:aws:iam::123456789012:role/test-role"));
+    fn account_settings_scan_data() -> Value {
+        json!({
+            "provider": "aws",
+            "account_password_policy": [{
+                "minimum_password_length": 14,
+                "require_symbols": true,
+                "require_numbers": true,
+                "require_uppercase_characters": false,
+                "require_lowercase_characters": true,
+                "allow_users_to_change_password": true,
+                "password_reuse_prevention": 24
+            }],
+            "account_aliases": [{"account_alias": "example-prod"}]
+        })
     }
 
-    #[test]
-    fn test_generate_import_command_aws_policy() {
-        let resource = json!({
-            "policy_name": "test-policy",
-            "arn": "arn:aws:iam::123456789012:policy/test-policy"
-        });
+    #[tokio::test]
+    async fn test_generate_account_settings_as_singletons() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().to_path_buf();
+        let config = GenerationConfig {
+            output_path: output_path.to_str().unwrap().to_string(),
+            file_split_rule: "by_resource_name".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
 
-        let result = TerraformGenerator::generate_import_command(&resource, "policies", "aws");
-        assert!(result.is_ok());
+        let files = TerraformGenerator::generate(
+            &account_settings_scan_data(),
+            &config,
+            &HashMap::new(),
+            &output_path,
+        )
+        .await
+        .unwrap();
+
+        // 分割ルールに関わらずリソースタイプ単位の1ファイルに出力される
+        assert_eq!(
+            files,
+            vec![
+                "account_password_policy.tf".to_string(),
+                "account_aliases.tf".to_string()
+            ]
+        );
 
-        let import_cmd = result.unwrap();
-        assert!(import_cmd.contains("terraform import"));
-        assert!(import_cmd.contains("aws_iam_policy.test_policy"));
-        assert!(import_cmd.contains("arn:aws:iam::123456789012:policy/test-policy"));
+        let password_policy =
+            std::fs::read_to_string(output_path.join("account_password_policy.tf")).unwrap();
+        assert!(password_policy.contains(r#"resource "aws_iam_account_password_policy" "this""#));
+        assert!(password_policy.contains("minimum_password_length        = 14"));
+        assert!(password_policy.contains("require_uppercase_characters   = false"));
+        assert!(password_policy.contains("password_reuse_prevention      = 24"));
+        assert!(!password_policy.contains("max_password_age"));
+        assert!(!password_policy.contains("hard_expiry"));
+
+        let alias = std::fs::read_to_string(output_path.join("account_aliases.tf")).unwrap();
+        assert!(alias.contains(r#"resource "aws_iam_account_alias" "this""#));
+        assert!(alias.contains(r#"account_alias = "example-prod""#));
     }
 
     #[test]
-    fn test_generate_import_command_unsupported_provider() {
-        let resource = json!({
-            "user_name": "test-user",
-            "arn": "arn:aws:iam::123456789012:user/test-user"
-        });
+    fn test_collect_import_commands_account_settings() {
+        let commands = TerraformGenerator::collect_import_commands(
+            &account_settings_scan_data(),
+            &HashMap::new(),
+        );
 
-        let result = TerraformGenerator::generate_import_command(&resource, "users", "gcp");
-        assert!(result.is_err());
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("Unsupported provider/resource type combination"));
+        assert_eq!(
+            commands,
+            vec![
+                "terraform import aws_iam_account_password_policy.this iam-account-password-policy"
+                    .to_string(),
+                "terraform import aws_iam_account_alias.this example-prod".to_string(),
+            ]
+        );
     }
 
-    // ========================================
-    // generate_sh_import_script のテスト
-    // ========================================
+    const SSO_INSTANCE_ARN: &str = "arn:aws:sso:::instance/ssoins-1111";
+    const SSO_PERMISSION_SET_ARN: &str = "arn:aws:sso:::permissionSet/ssoins-1111/ps-aaaa";
+
+    fn identity_center_scan_data() -> Value {
+        let owner = json!({
+            "instance_arn": SSO_INSTANCE_ARN,
+            "permission_set_arn": SSO_PERMISSION_SET_ARN,
+            "permission_set_name": "Admin"
+        });
+        let with_owner = |fields: Value| {
+            let mut resource = owner.clone();
+            resource
+                .as_object_mut()
+                .unwrap()
+                .extend(fields.as_object().unwrap().clone());
+            resource
+        };
+        json!({
+            "provider": "aws",
+            "sso_permission_sets": [{
+                "instance_arn": SSO_INSTANCE_ARN,
+                "permission_set_arn": SSO_PERMISSION_SET_ARN,
+                "name": "Admin",
+                "description": "Administrator access",
+                "session_duration": "PT4H"
+            }],
+            "sso_managed_policy_attachments": [with_owner(json!({
+                "managed_policy_arn": "arn:aws:iam::aws:policy/AdministratorAccess",
+                "managed_policy_name": "AdministratorAccess"
+            }))],
+            "sso_inline_policies": [with_owner(json!({
+                "policy_document": {
+                    "Version": "2012-10-17",
+                    "Statement": [{"Effect": "Deny", "Action": "iam:*", "Resource": "*"}]
+                }
+            }))],
+            "sso_account_assignments": [with_owner(json!({
+                "target_id": "111111111111",
+                "target_type": "AWS_ACCOUNT",
+                "principal_type": "GROUP",
+                "principal_id": "90676a1f-0000"
+            }))]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_generate_identity_center_resources() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().to_path_buf();
+        let config = GenerationConfig {
+            output_path: output_path.to_str().unwrap().to_string(),
+            file_split_rule: "by_resource_type".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
+
+        let files = TerraformGenerator::generate(
+            &identity_center_scan_data(),
+            &config,
+            &HashMap::new(),
+            &output_path,
+        )
+        .await
+        .unwrap();
+        let content: String = files
+            .iter()
+            .map(|file| std::fs::read_to_string(output_path.join(file)).unwrap())
+            .collect();
+
+        assert!(content.contains(r#"resource "aws_ssoadmin_permission_set" "admin""#));
+        assert!(content.contains(r#"session_duration = "PT4H""#));
+        assert!(!content.contains("relay_state"));
+        assert!(content.contains(
+            r#"resource "aws_ssoadmin_managed_policy_attachment" "admin_aws_administratoraccess""#
+        ));
+        assert!(content.contains("permission_set_arn = aws_ssoadmin_permission_set.admin.arn"));
+        assert!(content.contains(
+            r#"resource "aws_ssoadmin_permission_set_inline_policy" "admin_inline_policy""#
+        ));
+        assert!(content.contains("inline_policy      = jsonencode("));
+        assert!(content.contains(
+            r#"resource "aws_ssoadmin_account_assignment" "admin_111111111111_group_90676a1f_0000""#
+        ));
+        assert!(content.contains(r#"target_type        = "AWS_ACCOUNT""#));
+    }
 
     #[test]
-    fn test_generate_sh_import_script() {
-        let commands = vec![
-            "terraform import aws_iam_user.test_user arn:aws:iam::123456789012:user/test-user"
-                .to_string(),
-            "terraform import aws_iam_group.test_group arn:aws:iam::123456789012:group/test-group"
-                .to_string(),
-        ];
-
-        let script = TerraformGenerator::generate_sh_import_script(&commands);
+    fn test_collect_import_commands_identity_center() {
+        let commands = TerraformGenerator::collect_import_commands(
+            &identity_center_scan_data(),
+            &HashMap::new(),
+        );
 
-        assert!(script.contains("#!/bin/bash"));
-        assert!(script.contains("set -e"));
-        assert!(script.contains("terraform import aws_iam_user.test_user"));
-        assert!(script.contains("terraform import aws_iam_group.test_group"));
+        assert_eq!(
+            commands,
+            vec![
+                format!(
+                    "terraform import aws_ssoadmin_permission_set.admin {},{}",
+                    SSO_PERMISSION_SET_ARN, SSO_INSTANCE_ARN
+                ),
+                format!(
+                    "terraform import aws_ssoadmin_managed_policy_attachment.admin_aws_administratoraccess \
+                     arn:aws:iam::aws:policy/AdministratorAccess,{},{}",
+                    SSO_PERMISSION_SET_ARN, SSO_INSTANCE_ARN
+                ),
+                format!(
+                    "terraform import aws_ssoadmin_permission_set_inline_policy.admin_inline_policy {},{}",
+                    SSO_PERMISSION_SET_ARN, SSO_INSTANCE_ARN
+                ),
+                format!(
+                    "terraform import aws_ssoadmin_account_assignment.admin_111111111111_group_90676a1f_0000 \
+                     90676a1f-0000,GROUP,111111111111,AWS_ACCOUNT,{},{}",
+                    SSO_PERMISSION_SET_ARN, SSO_INSTANCE_ARN
+                ),
+            ]
+        );
     }
 
-    // ========================================
-    // generate_ps1_import_script のテスト
-    // ========================================
-
     #[test]
-    fn test_generate_ps1_import_script() {
-        let commands = vec![
-            "terraform import aws_iam_user.test_user arn:aws:iam::123456789012:user/test-user"
-                .to_string(),
-            "terraform import aws_iam_group.test_group arn:aws:iam::123456789012:group/test-group"
-                .to_string(),
-        ];
+    fn test_collect_import_commands_identity_center_follows_permission_set_selection() {
+        let mut selected = HashMap::new();
+        selected.insert("sso_permission_sets".to_string(), Vec::new());
 
-        let script = TerraformGenerator::generate_ps1_import_script(&commands);
+        let commands =
+            TerraformGenerator::collect_import_commands(&identity_center_scan_data(), &selected);
 
-        assert!(script.contains("$ErrorActionPreference"));
-        assert!(script.contains("terraform import aws_iam_user.test_user"));
-        assert!(script.contains("terraform import aws_iam_group.test_group"));
+        assert!(commands.is_empty());
     }
 
-    // ========================================
-    // generate_readme のテスト
-    // ========================================
+    fn networking_scan_data() -> Value {
+        json!({
+            "provider": "aws",
+            "vpcs": [{
+                "vpc_id": "vpc-0main",
+                "cidr_block": "10.0.0.0/16",
+                "instance_tenancy": "default",
+                "is_default": false,
+                "enable_dns_support": true,
+                "enable_dns_hostnames": false,
+                "name": "main",
+                "tags": {"Name": "main"}
+            }],
+            "subnets": [{
+                "subnet_id": "subnet-0app",
+                "vpc_id": "vpc-0main",
+                "cidr_block": "10.0.1.0/24",
+                "availability_zone": "ap-northeast-1a",
+                "map_public_ip_on_launch": false,
+                "tags": {}
+            }, {
+                "subnet_id": "subnet-0other",
+                "vpc_id": "vpc-0unscanned",
+                "cidr_block": "172.16.0.0/24",
+                "availability_zone": "ap-northeast-1c",
+                "map_public_ip_on_launch": true,
+                "tags": {}
+            }],
+            "security_groups": [{
+                "group_id": "sg-0web",
+                "group_name": "web",
+                "description": "Web \"frontend\" servers",
+                "vpc_id": "vpc-0main",
+                "tags": {}
+            }, {
+                "group_id": "sg-0db",
+                "group_name": "db",
+                "description": "Databases",
+                "vpc_id": "vpc-0main",
+                "tags": {}
+            }],
+            "security_group_ingress_rules": [{
+                "rule_id": "sgr-0fromweb",
+                "group_id": "sg-0db",
+                "group_name": "db",
+                "ip_protocol": "tcp",
+                "from_port": 5432,
+                "to_port": 5432,
+                "referenced_group_id": "sg-0web",
+                "description": "From web",
+                "tags": {}
+            }, {
+                "rule_id": "sgr-0peer",
+                "group_id": "sg-0web",
+                "group_name": "web",
+                "ip_protocol": "tcp",
+                "from_port": 443,
+                "to_port": 443,
+                "referenced_group_id": "sg-0peered",
+                "tags": {}
+            }],
+            "security_group_egress_rules": [{
+                "rule_id": "sgr-0allout",
+                "group_id": "sg-0db",
+                "group_name": "db",
+                "ip_protocol": "-1",
+                "from_port": -1,
+                "to_port": -1,
+                "cidr_ipv4": "0.0.0.0/0",
+                "tags": {}
+            }]
+        })
+    }
 
     #[tokio::test]
-    async fn test_generate_readme() {
+    async fn test_generate_networking_resources() {
         let temp_dir = TempDir::new().unwrap();
-        let output_path = temp_dir.path();
-
+        let output_path = temp_dir.path().to_path_buf();
         let config = GenerationConfig {
             output_path: output_path.to_str().unwrap().to_string(),
-            file_split_rule: "single".to_string(),
+            file_split_rule: "by_resource_type".to_string(),
             naming_convention: "snake_case".to_string(),
             import_script_format: "sh".to_string(),
-            generate_readme: true,
+            generate_readme: false,
             selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
         };
 
-        let files = vec!["users.tf".to_string(), "groups.tf".to_string()];
+        let files = TerraformGenerator::generate(
+            &networking_scan_data(),
+            &config,
+            &HashMap::new(),
+            &output_path,
+        )
+        .await
+        .unwrap();
+        let content: String = files
+            .iter()
+            .map(|file| std::fs::read_to_string(output_path.join(file)).unwrap())
+            .collect();
+
+        assert!(content.contains(r#"resource "aws_vpc" "vpc_0main""#));
+        assert!(content.contains("enable_dns_hostnames = false"));
+        assert!(content.contains(r#""Name" = "main""#));
+        // スキャン済みのVPCはアドレスで参照し、それ以外はIDを直接指定する
+        assert!(content.contains("vpc_id                  = aws_vpc.vpc_0main.id"));
+        assert!(content.contains(r#"vpc_id                  = "vpc-0unscanned""#));
+        assert!(content.contains(r#"resource "aws_security_group" "sg_0web""#));
+        assert!(content.contains(r#"description = "Web \"frontend\" servers""#));
+        assert!(
+            content.contains(r#"resource "aws_vpc_security_group_ingress_rule" "sgr_0fromweb""#)
+        );
+        assert!(content.contains("security_group_id = aws_security_group.sg_0db.id"));
+        assert!(content.contains("referenced_security_group_id = aws_security_group.sg_0web.id"));
+        assert!(content.contains(r#"referenced_security_group_id = "sg-0peered""#));
+        assert!(content.contains("from_port         = 5432"));
+        assert!(content.contains(r#"resource "aws_vpc_security_group_egress_rule" "sgr_0allout""#));
+        assert!(content.contains(r#"cidr_ipv4         = "0.0.0.0/0""#));
+        // 全プロトコルのルールにはポートを出力しない
+        assert!(!content.contains("from_port         = -1"));
+    }
 
-        let result = TerraformGenerator::generate_readme(&config, output_path, &files).await;
+    #[test]
+    fn test_collect_import_commands_networking_follows_group_selection() {
+        let mut selected = HashMap::new();
+        selected.insert("security_groups".to_string(), vec![json!("sg-0db")]);
+
+        let commands =
+            TerraformGenerator::collect_import_commands(&networking_scan_data(), &selected);
+
+        assert_eq!(
+            commands,
+            vec![
+                "terraform import aws_vpc.vpc_0main vpc-0main",
+                "terraform import aws_subnet.subnet_0app subnet-0app",
+                "terraform import aws_subnet.subnet_0other subnet-0other",
+                "terraform import aws_security_group.sg_0db sg-0db",
+                "terraform import aws_vpc_security_group_ingress_rule.sgr_0fromweb sgr-0fromweb",
+                "terraform import aws_vpc_security_group_egress_rule.sgr_0allout sgr-0allout",
+            ]
+        );
+    }
 
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "README.md");
+    fn s3_scan_data() -> Value {
+        json!({
+            "provider": "aws",
+            "s3_buckets": [{
+                "bucket": "app-assets",
+                "region": "us-east-1",
+                "tags": {"env": "prod"},
+                "versioning_status": "Enabled",
+                "encryption": {
+                    "sse_algorithm": "aws:kms",
+                    "kms_master_key_id": "alias/app",
+                    "bucket_key_enabled": true
+                },
+                "public_access_block": {
+                    "block_public_acls": true,
+                    "block_public_policy": true,
+                    "ignore_public_acls": true,
+                    "restrict_public_buckets": false
+                },
+                "object_ownership": "BucketOwnerEnforced"
+            }, {
+                "bucket": "eu-archive",
+                "region": "eu-west-1",
+                "provider_alias": "eu_west_1",
+                "tags": {},
+                "lifecycle_rules": [{
+                    "id": "archive",
+                    "status": "Enabled",
+                    "filter": {"prefix": "logs/"},
+                    "transitions": [{"days": 30, "storage_class": "GLACIER"}],
+                    "expiration": {"days": 365}
+                }]
+            }]
+        })
+    }
 
-        let readme_path = output_path.join("README.md");
-        assert!(readme_path.exists());
+    #[tokio::test]
+    async fn test_generate_s3_buckets_with_configuration_resources() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().to_path_buf();
+        let config = GenerationConfig {
+            output_path: output_path.to_str().unwrap().to_string(),
+            file_split_rule: "by_resource_type".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
 
-        let readme_content = std::fs::read_to_string(readme_path).unwrap();
-        assert!(readme_content.contains("# Terraform Code Generation"));
-        assert!(readme_content.contains("users.tf"));
-        assert!(readme_content.contains("groups.tf"));
-        assert!(readme_content.contains("terraform init"));
+        let files =
+            TerraformGenerator::generate(&s3_scan_data(), &config, &HashMap::new(), &output_path)
+                .await
+                .unwrap();
+        let content: String = files
+            .iter()
+            .map(|file| std::fs::read_to_string(output_path.join(file)).unwrap())
+            .collect();
+
+        assert!(content.contains(r#"resource "aws_s3_bucket" "app_assets""#));
+        assert!(content.contains(r#""env" = "prod""#));
+        assert!(content.contains(r#"resource "aws_s3_bucket_versioning" "app_assets""#));
+        assert!(content.contains("bucket = aws_s3_bucket.app_assets.id"));
+        assert!(content.contains(r#"kms_master_key_id = "alias/app""#));
+        assert!(content.contains("restrict_public_buckets = false"));
+        assert!(content.contains(r#"object_ownership = "BucketOwnerEnforced""#));
+        // 設定を持たないバケットの分割リソースは生成しない
+        assert!(!content.contains(r#"resource "aws_s3_bucket_versioning" "eu_archive""#));
+        assert!(
+            content.contains(r#"resource "aws_s3_bucket_lifecycle_configuration" "eu_archive""#)
+        );
+        assert!(content.contains(r#"prefix = "logs/""#));
+        assert!(content.contains(r#"storage_class = "GLACIER""#));
+        assert!(content.contains("days = 365"));
+        // 別リージョンのバケットはプロバイダーエイリアスを使用する
+        assert!(content.contains("provider = aws.eu_west_1"));
+        assert!(files.contains(&"provider_aliases.tf".to_string()));
+        let aliases = std::fs::read_to_string(output_path.join("provider_aliases.tf")).unwrap();
+        assert_eq!(
+            aliases,
+            "provider \"aws\" {\n  alias  = \"eu_west_1\"\n  region = \"eu-west-1\"\n}\n"
+        );
     }
 
-    // ========================================
-    // generate_import_script のテスト
-    // ========================================
+    #[test]
+    fn test_collect_import_commands_s3_buckets() {
+        let mut selected = HashMap::new();
+        selected.insert("s3_buckets".to_string(), vec![json!("app-assets")]);
+
+        let commands = TerraformGenerator::collect_import_commands(&s3_scan_data(), &selected);
+
+        assert_eq!(
+            commands,
+            vec![
+                "terraform import aws_s3_bucket.app_assets app-assets",
+                "terraform import aws_s3_bucket_versioning.app_assets app-assets",
+                "terraform import aws_s3_bucket_server_side_encryption_configuration.app_assets app-assets",
+                "terraform import aws_s3_bucket_public_access_block.app_assets app-assets",
+                "terraform import aws_s3_bucket_ownership_controls.app_assets app-assets",
+            ]
+        );
+    }
+
+    fn organizations_scan_data() -> Value {
+        json!({
+            "provider": "aws",
+            "service_control_policies": [{
+                "policy_id": "p-FullAWSAccess",
+                "name": "FullAWSAccess",
+                "aws_managed": true,
+                "policy_document": {
+                    "Version": "2012-10-17",
+                    "Statement": [{"Effect": "Allow", "Action": "*", "Resource": "*"}]
+                }
+            }, {
+                "policy_id": "p-denyleave",
+                "name": "DenyLeaveOrg",
+                "description": "Keep accounts in the org",
+                "aws_managed": false,
+                "policy_document": {
+                    "Version": "2012-10-17",
+                    "Statement": [{
+                        "Effect": "Deny",
+                        "Action": "organizations:LeaveOrganization",
+                        "Resource": "*"
+                    }]
+                }
+            }],
+            "service_control_policy_attachments": [{
+                "policy_id": "p-FullAWSAccess",
+                "policy_name": "FullAWSAccess",
+                "aws_managed": true,
+                "target_id": "r-abcd",
+                "target_type": "ROOT"
+            }, {
+                "policy_id": "p-denyleave",
+                "policy_name": "DenyLeaveOrg",
+                "aws_managed": false,
+                "target_id": "ou-abcd-workloads",
+                "target_type": "ORGANIZATIONAL_UNIT"
+            }]
+        })
+    }
 
     #[tokio::test]
-    async fn test_generate_import_script_with_resources() {
+    async fn test_generate_service_control_policies() {
         let temp_dir = TempDir::new().unwrap();
-        let output_path = temp_dir.path();
+        let output_path = temp_dir.path().to_path_buf();
+        let config = GenerationConfig {
+            output_path: output_path.to_str().unwrap().to_string(),
+            file_split_rule: "by_resource_type".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
+        };
 
-        let scan_data = json!({
+        let files = TerraformGenerator::generate(
+            &organizations_scan_data(),
+            &config,
+            &HashMap::new(),
+            &output_path,
+        )
+        .await
+        .unwrap();
+        let content: String = files
+            .iter()
+            .map(|file| std::fs::read_to_string(output_path.join(file)).unwrap())
+            .collect();
+
+        // AWS管理のSCPはリソースとして生成しない
+        assert!(!content.contains(r#"resource "aws_organizations_policy" "fullawsaccess""#));
+        assert!(content.contains(r#"resource "aws_organizations_policy" "denyleaveorg""#));
+        assert!(content.contains(r#"description = "Keep accounts in the org""#));
+        assert!(content.contains("content = jsonencode("));
+        assert!(content.contains("organizations:LeaveOrganization"));
+        assert!(content
+            .contains(r#"resource "aws_organizations_policy_attachment" "fullawsaccess_r_abcd""#));
+        assert!(content.contains(r#"policy_id = "p-FullAWSAccess""#));
+        assert!(content.contains("policy_id = aws_organizations_policy.denyleaveorg.id"));
+        assert!(content.contains(r#"target_id = "ou-abcd-workloads""#));
+    }
+
+    #[test]
+    fn test_collect_import_commands_service_control_policies() {
+        let commands = TerraformGenerator::collect_import_commands(
+            &organizations_scan_data(),
+            &HashMap::new(),
+        );
+
+        assert_eq!(
+            commands,
+            vec![
+                "terraform import aws_organizations_policy.denyleaveorg p-denyleave",
+                "terraform import aws_organizations_policy_attachment.fullawsaccess_r_abcd r-abcd:p-FullAWSAccess",
+                "terraform import aws_organizations_policy_attachment.denyleaveorg_ou_abcd_workloads ou-abcd-workloads:p-denyleave",
+            ]
+        );
+    }
+
+    fn resource_policy_scan_data() -> Value {
+        json!({
             "provider": "aws",
-            "users": [
-                {
-                    "user_name": "test-user",
-                    "arn": "arn:aws:iam::123456789012:user/test-user"
+            "s3_bucket_policies": [{
+                "bucket": "app-assets",
+                "policy_document": {
+                    "Version": "2012-10-17",
+                    "Statement": [{
+                        "Sid": "AllowAppRole",
+                        "Effect": "Allow",
+                        "Principal": {"AWS": ["arn:aws:iam::111111111111:role/app"]},
+                        "Action": "s3:GetObject",
+                        "Resource": "arn:aws:s3:::app-assets/${aws:username}/*",
+                        "Condition": {"Bool": {"aws:SecureTransport": false}}
+                    }, {
+                        "Effect": "Deny",
+                        "Principal": "*",
+                        "NotAction": ["s3:GetObject"],
+                        "Resource": "arn:aws:s3:::app-assets/*"
+                    }]
                 }
-            ]
-        });
+            }],
+            "kms_key_policies": [{
+                "key_id": "1234abcd-12ab",
+                "key_arn": "arn:aws:kms:us-east-1:111111111111:key/1234abcd-12ab",
+                "policy_document": {
+                    "Statement": {
+                        "Effect": "Allow",
+                        "Principal": {"AWS": "arn:aws:iam::111111111111:root"},
+                        "Action": "kms:*",
+                        "Resource": "*"
+                    }
+                }
+            }],
+            "sqs_queue_policies": [{
+                "queue_url": "https://sqs.us-east-1.amazonaws.com/111111111111/jobs",
+                "queue_arn": "arn:aws:sqs:us-east-1:111111111111:jobs",
+                "queue_name": "jobs",
+                "policy_document": {"Version": "2012-10-17", "Statement": []}
+            }],
+            "sns_topic_policies": [{
+                "topic_arn": "arn:aws:sns:us-east-1:111111111111:alerts",
+                "topic_name": "alerts",
+                "policy_document": {"Version": "2012-10-17", "Statement": []}
+            }]
+        })
+    }
 
+    #[tokio::test]
+    async fn test_generate_resource_policies() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().to_path_buf();
         let config = GenerationConfig {
             output_path: output_path.to_str().unwrap().to_string(),
-            file_split_rule: "single".to_string(),
+            file_split_rule: "by_resource_type".to_string(),
             naming_convention: "snake_case".to_string(),
             import_script_format: "sh".to_string(),
-            generate_readme: true,
+            generate_readme: false,
             selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
         };
 
-        let selected_resources = HashMap::new();
-
-        let result = TerraformGenerator::generate_import_script(
-            &scan_data,
+        let files = TerraformGenerator::generate(
+            &resource_policy_scan_data(),
             &config,
-            &selected_resources,
-            output_path,
+            &HashMap::new(),
+            &output_path,
         )
-        .await;
+        .await
+        .unwrap();
+        let content: String = files
+            .iter()
+            .map(|file| std::fs::read_to_string(output_path.join(file)).unwrap())
+            .collect();
+
+        assert!(content.contains(r#"data "aws_iam_policy_document" "app_assets_bucket_policy""#));
+        assert!(content.contains(r#"resource "aws_s3_bucket_policy" "app_assets""#));
+        assert!(
+            content.contains("policy = data.aws_iam_policy_document.app_assets_bucket_policy.json")
+        );
+        assert!(content.contains(r#"sid    = "AllowAppRole""#));
+        assert!(content.contains(r#"type        = "AWS""#));
+        assert!(content.contains(r#""arn:aws:iam::111111111111:role/app","#));
+        assert!(content.contains(r#""arn:aws:s3:::app-assets/$${aws:username}/*","#));
+        assert!(content.contains(r#"test     = "Bool""#));
+        assert!(content.contains(r#""false","#));
+        // "Principal": "*"はtype = "*"のprincipalsブロックになる
+        assert!(content.contains(r#"type        = "*""#));
+        assert!(content.contains("not_actions = ["));
+
+        assert!(content.contains(r#"resource "aws_kms_key_policy" "key_1234abcd_12ab""#));
+        assert!(content.contains(r#"key_id = "1234abcd-12ab""#));
+        assert!(content.contains(r#"resource "aws_sqs_queue_policy" "jobs""#));
+        assert!(content
+            .contains(r#"queue_url = "https://sqs.us-east-1.amazonaws.com/111111111111/jobs""#));
+        assert!(content.contains(r#"resource "aws_sns_topic_policy" "alerts""#));
+        assert!(content.contains(r#"arn    = "arn:aws:sns:us-east-1:111111111111:alerts""#));
+    }
 
-        assert!(result.is_ok());
-        assert!(result.as_ref().unwrap().is_some());
+    #[test]
+    fn test_collect_import_commands_resource_policies() {
+        let commands = TerraformGenerator::collect_import_commands(
+            &resource_policy_scan_data(),
+            &HashMap::new(),
+        );
 
-        let script_name = result.unwrap().unwrap();
-        assert_eq!(script_name, "import.sh");
+        assert_eq!(
+            commands,
+            vec![
+                "terraform import aws_s3_bucket_policy.app_assets app-assets",
+                "terraform import aws_kms_key_policy.key_1234abcd_12ab 1234abcd-12ab",
+                "terraform import aws_sqs_queue_policy.jobs https://sqs.us-east-1.amazonaws.com/111111111111/jobs",
+                "terraform import aws_sns_topic_policy.alerts arn:aws:sns:us-east-1:111111111111:alerts",
+            ]
+        );
+    }
 
-        let script_path = output_path.join(&script_name);
-        assert!(script_path.exists());
+    // ========================================
+    // ARNのパラメータ化のテスト
+    // ========================================
 
-        let script_content = std::fs::read_to_string(script_path).unwrap();
-        assert!(script_content.contains("#!/bin/bash"));
-        assert!(script_content.contains("terraform import"));
-        assert!(script_content.contains("aws_iam_user.test_user"));
+    #[test]
+    fn test_parameterize_arns_in() {
+        let rewrite =
+            |text: &str| TerraformGenerator::parameterize_arns_in(text, Some("123456789012"));
+
+        assert_eq!(
+            rewrite("arn:aws:iam::123456789012:role/app"),
+            format!(
+                "arn:{}:iam::{}:role/app",
+                PARTITION_PLACEHOLDER, ACCOUNT_ID_PLACEHOLDER
+            )
+        );
+        // 他アカウントのIDはそのまま残す
+        assert_eq!(
+            rewrite("arn:aws-us-gov:iam::999999999999:root"),
+            format!("arn:{}:iam::999999999999:root", PARTITION_PLACEHOLDER)
+        );
+        assert_eq!(
+            rewrite(r#"["arn:aws:s3:::bucket/*", "arn:aws:iam::aws:policy/ReadOnlyAccess"]"#),
+            format!(
+                r#"["arn:{p}:s3:::bucket/*", "arn:{p}:iam::aws:policy/ReadOnlyAccess"]"#,
+                p = PARTITION_PLACEHOLDER
+            )
+        );
+        assert_eq!(rewrite("yarn:aws:iam::x:y"), "yarn:aws:iam::x:y");
+        assert_eq!(rewrite("arn:incomplete"), "arn:incomplete");
+    }
+
+    #[test]
+    fn test_parameterize_arns_keeps_account_id_outside_arns_and_principals() {
+        let mut value = json!({
+            "tags": {"AccountId": "123456789012"},
+            "description": "123456789012",
+            "arn": "arn:aws:iam::123456789012:role/app",
+            "policy_document": {
+                "Statement": [{
+                    "Principal": {"AWS": ["123456789012", "999999999999"]},
+                    "Condition": {"StringEquals": {"aws:SourceAccount": "123456789012"}}
+                }]
+            }
+        });
+
+        TerraformGenerator::parameterize_arns(&mut value, Some("123456789012"));
+
+        // タグ・説明文のアカウントIDと同じ値はリテラルのまま
+        assert_eq!(value["tags"]["AccountId"], "123456789012");
+        assert_eq!(value["description"], "123456789012");
+        assert_eq!(
+            value["arn"],
+            format!(
+                "arn:{}:iam::{}:role/app",
+                PARTITION_PLACEHOLDER, ACCOUNT_ID_PLACEHOLDER
+            )
+        );
+        let statement = &value["policy_document"]["Statement"][0];
+        assert_eq!(
+            statement["Principal"]["AWS"],
+            json!([ACCOUNT_ID_PLACEHOLDER, "999999999999"])
+        );
+        assert_eq!(
+            statement["Condition"]["StringEquals"]["aws:SourceAccount"],
+            ACCOUNT_ID_PLACEHOLDER
+        );
+    }
+
+    fn parameterized_role_scan_data() -> Value {
+        json!({
+            "provider": "aws",
+            "roles": [{
+                "role_name": "app-role",
+                "arn": "arn:aws:iam::123456789012:role/app-role",
+                "path": "/",
+                "assume_role_statements": [{
+                    "effect": "Allow",
+                    "principal_type": "AWS",
+                    "principal_identifiers": [
+                        "arn:aws:iam::123456789012:root",
+                        "arn:aws:iam::999999999999:root"
+                    ],
+                    "actions": ["sts:AssumeRole"],
+                    "conditions": [{
+                        "test": "StringEquals",
+                        "variable": "aws:SourceAccount",
+                        "values": ["123456789012"]
+                    }]
+                }]
+            }],
+            "attachments": {
+                "role_policies": [{
+                    "role_name": "app-role",
+                    "policy_name": "queue",
+                    "policy_type": "inline",
+                    "policy_document": {
+                        "Version": "2012-10-17",
+                        "Statement": [{
+                            "Effect": "Allow",
+                            "Action": "sqs:SendMessage",
+                            "Resource": "arn:aws:sqs:us-east-1:123456789012:jobs"
+                        }]
+                    }
+                }]
+            }
+        })
     }
 
     #[tokio::test]
-    async fn test_generate_import_script_ps1_format() {
+    async fn test_generate_parameterizes_arns() {
         let temp_dir = TempDir::new().unwrap();
-        let output_path = temp_dir.path();
+        let output_path = temp_dir.path().to_path_buf();
+        let config = GenerationConfig {
+            output_path: output_path.to_str().unwrap().to_string(),
+            file_split_rule: "by_resource_type".to_string(),
+            naming_convention: "snake_case".to_string(),
+            import_script_format: "sh".to_string(),
+            generate_readme: false,
+            selected_resources: HashMap::new(),
+            parameterize_arns: true,
+            account_id: None,
+        };
 
-        let scan_data = json!({
+        let files = TerraformGenerator::generate(
+            &parameterized_role_scan_data(),
+            &config,
+            &HashMap::new(),
+            &output_path,
+        )
+        .await
+        .unwrap();
+
+        assert!(files.contains(&"arn_data_sources.tf".to_string()));
+        let data_sources =
+            std::fs::read_to_string(output_path.join("arn_data_sources.tf")).unwrap();
+        assert!(data_sources.contains(r#"data "aws_caller_identity" "current" {}"#));
+        assert!(data_sources.contains(r#"data "aws_partition" "current" {}"#));
+
+        let roles = std::fs::read_to_string(output_path.join("roles.tf")).unwrap();
+        assert!(roles.contains(
+            r#""arn:${data.aws_partition.current.partition}:iam::${data.aws_caller_identity.current.account_id}:root","#
+        ));
+        assert!(roles
+            .contains(r#""arn:${data.aws_partition.current.partition}:iam::999999999999:root","#));
+        assert!(roles.contains(r#""${data.aws_caller_identity.current.account_id}","#));
+        assert!(!roles.contains("123456789012"));
+
+        let policies =
+            std::fs::read_to_string(output_path.join("role_inline_policies.tf")).unwrap();
+        assert!(policies.contains(
+            "arn:${data.aws_partition.current.partition}:sqs:us-east-1:${data.aws_caller_identity.current.account_id}:jobs"
+        ));
+
+        // importコマンドのIDはリテラルのまま
+        let commands = TerraformGenerator::collect_import_commands(
+            &parameterized_role_scan_data(),
+            &HashMap::new(),
+        );
+        assert!(commands.contains(
+            &"terraform import aws_iam_role.app_role arn:aws:iam::123456789012:role/app-role"
+                .to_string()
+        ));
+    }
+
+    // ========================================
+    // マルチアカウント出力のテスト
+    // ========================================
+
+    fn multi_account_scan_data() -> Value {
+        json!({
             "provider": "aws",
             "users": [
-                {
-                    "user_name": "test-user",
-                    "arn": "arn:aws:iam::123456789012:user/test-user"
-                }
-            ]
-        });
+                {"user_name": "alice", "arn": "arn:aws:iam::111111111111:user/alice", "account_id": "111111111111"},
+                {"user_name": "bob", "arn": "arn:aws:iam::222222222222:user/bob", "account_id": "222222222222"}
+            ],
+            "groups": [],
+            "roles": [],
+            "policies": [],
+            "attachments": {
+                "user_policies": [],
+                "group_policies": [],
+                "role_policies": [],
+                "user_groups": []
+            },
+            "accounts": {
+                "111111111111": {"account_id": "111111111111", "status": "completed"},
+                "222222222222": {"account_id": "222222222222", "status": "completed"},
+                "333333333333": {"account_id": "333333333333", "status": "failed"}
+            }
+        })
+    }
+
+    #[test]
+    fn test_scan_data_for_account() {
+        let account_data =
+            TerraformGenerator::scan_data_for_account(&multi_account_scan_data(), "222222222222");
+
+        let users = account_data["users"].as_array().unwrap();
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0]["user_name"], "bob");
+        assert!(account_data["attachments"]["user_groups"].is_array());
+        assert!(account_data.get("accounts").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_generate_multi_account_outputs_per_account() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().to_path_buf();
 
         let config = GenerationConfig {
             output_path: output_path.to_str().unwrap().to_string(),
-            file_split_rule: "single".to_string(),
+            file_split_rule: "by_resource_type".to_string(),
             naming_convention: "snake_case".to_string(),
-            import_script_format: "ps1".to_string(),
+            import_script_format: "sh".to_string(),
             generate_readme: true,
             selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
         };
 
-        let selected_resources = HashMap::new();
+        let scan_data = multi_account_scan_data();
+        let files =
+            TerraformGenerator::generate(&scan_data, &config, &HashMap::new(), &output_path)
+                .await
+                .unwrap();
+
+        assert_eq!(
+            files,
+            vec![
+                "111111111111/users.tf".to_string(),
+                "222222222222/users.tf".to_string(),
+                "README.md".to_string(),
+            ]
+        );
+        let users = std::fs::read_to_string(output_path.join("111111111111/users.tf")).unwrap();
+        assert!(users.contains("alice"));
+        assert!(!users.contains("bob"));
+        assert!(!output_path.join("333333333333").exists());
 
-        let result = TerraformGenerator::generate_import_script(
+        let script_name = TerraformGenerator::generate_import_script(
             &scan_data,
             &config,
-            &selected_resources,
-            output_path,
+            &HashMap::new(),
+            &output_path,
         )
-        .await;
-
-        assert!(result.is_ok());
-        assert!(result.as_ref().unwrap().is_some());
-
-        let script_name = result.unwrap().unwrap();
-        assert_eq!(script_name, "import.ps1");
-
-        let script_path = output_path.join(&script_name);
-        assert!(script_path.exists());
-
-        let script_content = std::fs::read_to_string(script_path).unwrap();
-        assert!(script_content.contains("$ErrorActionPreference"));
-        assert!(script_content.contains("terraform import"));
+        .await
+        .unwrap()
+        .unwrap();
+        let script = std::fs::read_to_string(output_path.join(script_name)).unwrap();
+        assert!(script.contains(
+            "# Account: 111111111111\ncd 111111111111\nterraform import aws_iam_user.alice arn:aws:iam::111111111111:user/alice\ncd ..\n"
+        ));
+        assert!(script.contains("cd 222222222222\nterraform import aws_iam_user.bob arn:aws:iam::222222222222:user/bob\n"));
     }
 
     #[tokio::test]
-    async fn test_generate_import_script_no_resources() {
+    async fn test_generate_multi_account_selection_is_per_account() {
         let temp_dir = TempDir::new().unwrap();
-        let output_path = temp_dir.path();
-
-        let scan_data = json!({
-            "provider": "aws",
-            "users": []
-        });
+        let output_path = temp_dir.path().to_path_buf();
 
         let config = GenerationConfig {
             output_path: output_path.to_str().unwrap().to_string(),
-            file_split_rule: "single".to_string(),
+            file_split_rule: "by_resource_type".to_string(),
             naming_convention: "snake_case".to_string(),
             import_script_format: "sh".to_string(),
-            generate_readme: true,
+            generate_readme: false,
             selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
         };
 
-        let selected_resources = HashMap::new();
+        let scan_data = json!({
+            "provider": "aws",
+            "roles": [
+                {"role_name": "app", "arn": "arn:aws:iam::111111111111:role/app", "account_id": "111111111111"},
+                {"role_name": "app", "arn": "arn:aws:iam::222222222222:role/app", "account_id": "222222222222"}
+            ],
+            "accounts": {
+                "111111111111": {"account_id": "111111111111", "status": "completed"},
+                "222222222222": {"account_id": "222222222222", "status": "completed"}
+            }
+        });
+        let mut selected = HashMap::new();
+        selected.insert("roles".to_string(), vec![json!("111111111111:app")]);
 
-        let result = TerraformGenerator::generate_import_script(
+        let files = TerraformGenerator::generate(&scan_data, &config, &selected, &output_path)
+            .await
+            .unwrap();
+
+        // 同名のロールでも選択したアカウントのロールのみを生成する
+        assert_eq!(files, vec!["111111111111/roles.tf".to_string()]);
+        assert!(!output_path.join("222222222222").exists());
+
+        let script_name = TerraformGenerator::generate_import_script(
             &scan_data,
             &config,
-            &selected_resources,
-            output_path,
+            &selected,
+            &output_path,
         )
-        .await;
+        .await
+        .unwrap()
+        .unwrap();
+        let script = std::fs::read_to_string(output_path.join(script_name)).unwrap();
+        assert!(script.contains("arn:aws:iam::111111111111:role/app"));
+        assert!(!script.contains("222222222222"));
+    }
 
-        assert!(result.is_ok());
-        assert!(result.unwrap().is_none());
+    #[test]
+    fn test_selection_for_account() {
+        let mut selected = HashMap::new();
+        selected.insert(
+            "policies".to_string(),
+            vec![
+                json!("111111111111:arn:aws:iam::111111111111:policy/deploy"),
+                json!("222222222222:arn:aws:iam::222222222222:policy/deploy"),
+                json!("arn:aws:iam::aws:policy/ReadOnlyAccess"),
+            ],
+        );
+
+        let account_selection =
+            TerraformGenerator::selection_for_account(&selected, "111111111111");
+
+        assert_eq!(
+            account_selection["policies"],
+            vec![
+                json!("arn:aws:iam::111111111111:policy/deploy"),
+                json!("arn:aws:iam::aws:policy/ReadOnlyAccess"),
+            ]
+        );
     }
 }
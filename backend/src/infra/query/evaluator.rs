@@ -42,6 +42,10 @@ impl QueryEvaluator {
         match op {
             Operator::Eq => Self::compare_eq(field_value, expected),
             Operator::Ne => !Self::compare_eq(field_value, expected),
+            Operator::Gt => Self::compare_number(field_value, expected, |a, b| a > b),
+            Operator::Ge => Self::compare_number(field_value, expected, |a, b| a >= b),
+            Operator::Lt => Self::compare_number(field_value, expected, |a, b| a < b),
+            Operator::Le => Self::compare_number(field_value, expected, |a, b| a <= b),
             Operator::Like => Self::compare_like(field_value, expected),
             Operator::In => Self::compare_in(field_value, expected),
         }
@@ -58,6 +62,18 @@ impl QueryEvaluator {
         }
     }
 
+    /// 数値同士の大小比較（数値以外は常にfalse）
+    fn compare_number(
+        field_value: &JsonValue,
+        expected: &Value,
+        cmp: impl Fn(f64, f64) -> bool,
+    ) -> bool {
+        match (field_value.as_f64(), expected) {
+            (Some(a), Value::Number(b)) => cmp(a, *b),
+            _ => false,
+        }
+    }
+
     fn compare_like(field_value: &JsonValue, pattern: &Value) -> bool {
         if let (JsonValue::String(s), Value::String(pattern_str)) = (field_value, pattern) {
             Self::wildcard_match(s, pattern_str)
@@ -225,6 +241,35 @@ mod tests {
         assert!(!QueryEvaluator::evaluate(&expr, &resource));
     }
 
+    #[test]
+    fn test_evaluate_ordering_operators() {
+        let resource = json!({
+            "user_name": "ci-bot",
+            "last_used_days": 120
+        });
+
+        for (query, expected) in [
+            ("last_used_days > 90", true),
+            ("last_used_days >= 120", true),
+            ("last_used_days < 90", false),
+            ("last_used_days <= 120", true),
+            ("user_name > 1", false),
+            ("missing > 1", false),
+        ] {
+            let mut lexer = Lexer::new(query);
+            let tokens = lexer.tokenize().unwrap();
+            let mut parser = QueryParser::new(tokens);
+            let expr = parser.parse().unwrap();
+
+            assert_eq!(
+                QueryEvaluator::evaluate(&expr, &resource),
+                expected,
+                "{}",
+                query
+            );
+        }
+    }
+
     #[test]
     fn test_evaluate_and_expression() {
         let resource = json!({
@@ -19,6 +19,10 @@ pub enum Token {
 pub enum Operator {
     Eq,
     Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
     Like,
     In,
 }
@@ -107,6 +111,24 @@ impl<'a> Lexer<'a> {
                     Err("Unexpected character '=' (did you mean '=='?)".to_string())
                 }
             }
+            '>' => {
+                self.advance();
+                if self.current_char() == '=' {
+                    self.advance();
+                    Ok(Some(Token::Operator(Operator::Ge)))
+                } else {
+                    Ok(Some(Token::Operator(Operator::Gt)))
+                }
+            }
+            '<' => {
+                self.advance();
+                if self.current_char() == '=' {
+                    self.advance();
+                    Ok(Some(Token::Operator(Operator::Le)))
+                } else {
+                    Ok(Some(Token::Operator(Operator::Lt)))
+                }
+            }
             _ if ch.is_ascii_digit()
                 || (ch == '-' && self.peek_char().is_some_and(|c| c.is_ascii_digit())) =>
             {
@@ -306,6 +328,17 @@ mod tests {
         assert_eq!(tokens[2], Token::Number(42.0));
     }
 
+    #[test]
+    fn test_tokenize_ordering_operators() {
+        let mut lexer = Lexer::new("a > 1 AND b >= 2 AND c < 3 AND d <= 4");
+        let tokens = lexer.tokenize().unwrap();
+
+        assert_eq!(tokens[1], Token::Operator(Operator::Gt));
+        assert_eq!(tokens[5], Token::Operator(Operator::Ge));
+        assert_eq!(tokens[9], Token::Operator(Operator::Lt));
+        assert_eq!(tokens[13], Token::Operator(Operator::Le));
+    }
+
     #[test]
     fn test_tokenize_boolean() {
         let mut lexer = Lexer::new("enabled == true");
@@ -44,6 +44,11 @@ impl TemplateManager {
     }
 
     pub async fn load_template(template_name: &str) -> Result<String> {
+        Self::read_template(template_name)
+    }
+
+    /// テンプレートを読み込む（ユーザーテンプレートを優先）
+    fn read_template(template_name: &str) -> Result<String> {
         println!("[TEMPLATE] Loading template: {}", template_name);
 
         let base_paths = Self::get_template_base_paths();
@@ -104,6 +109,17 @@ impl TemplateManager {
         ))
     }
 
+    /// テンプレートの描画環境を作成
+    ///
+    /// `{% import %}`等で参照されたテンプレートも、ユーザーテンプレートを優先して読み込みます。
+    pub fn environment() -> minijinja::Environment<'static> {
+        let mut env = minijinja::Environment::new();
+        env.set_trim_blocks(true);
+        env.set_lstrip_blocks(true);
+        env.set_loader(|name| Ok(Self::read_template(name).ok()));
+        env
+    }
+
     pub async fn render_template(
         template_name: &str,
         context: &serde_json::Value,
@@ -112,10 +128,8 @@ impl TemplateManager {
         let template_content = Self::load_template(template_name).await?;
 
         // Use minijinja to render template
-        let mut env = minijinja::Environment::new();
-        env.set_trim_blocks(true);
-        env.set_lstrip_blocks(true);
-        env.add_template(template_name, &template_content)
+        let mut env = Self::environment();
+        env.add_template_owned(template_name.to_string(), template_content)
             .with_context(|| {
                 format!("Failed to add template '{}' to environment", template_name)
             })?;
@@ -357,4 +371,40 @@ mod tests {
         // 元のディレクトリに戻す
         std::env::set_current_dir(original_dir).unwrap();
     }
+
+    #[tokio::test]
+    async fn test_render_template_with_import() {
+        // Arrange: マクロを定義したテンプレートと、それをimportするテンプレートを作成
+        let temp_dir = TempDir::new().unwrap();
+        let user_template_dir = temp_dir.path().join("templates_user/terraform/aws");
+        fs::create_dir_all(&user_template_dir).unwrap();
+
+        fs::write(
+            user_template_dir.join("macros.tf.j2"),
+            r#"{% macro user_block(name) %}resource "aws_iam_user" "{{ name }}" {}{% endmacro %}"#,
+        )
+        .unwrap();
+        let template_name = "aws/test_template.tf.j2";
+        fs::write(
+            user_template_dir.join("test_template.tf.j2"),
+            r#"{% from "aws/macros.tf.j2" import user_block %}
+{{ user_block(resource_name) }}"#,
+        )
+        .unwrap();
+
+        let context = serde_json::json!({"resource_name": "test_user"});
+
+        // カレントディレクトリを一時ディレクトリに変更
+        let original_dir = std::env::current_dir().unwrap();
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+
+        // Act
+        let result = TemplateManager::render_template(template_name, &context).await;
+
+        // 元のディレクトリに戻す
+        std::env::set_current_dir(original_dir).unwrap();
+
+        // Assert
+        assert_eq!(result.unwrap(), r#"resource "aws_iam_user" "test_user" {}"#);
+    }
 }
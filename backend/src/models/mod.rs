@@ -1,5 +1,7 @@
+pub mod progress;
 pub mod request;
 pub mod response;
 
+pub use progress::*;
 pub use request::*;
 pub use response::*;
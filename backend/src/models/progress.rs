@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+/// スキャンの段階
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScanPhase {
+    /// スキャン開始（アカウント検出等の準備を含む）
+    Started,
+    /// リソース種別のスキャン中
+    Scanning,
+    /// リソース種別のスキャン完了
+    ResourceCompleted,
+    /// スキャン全体の完了
+    Completed,
+}
+
+/// スキャナーから通知される進捗イベント
+///
+/// SSEストリームとポーリングの両方で同じ形式を使用します。
+/// `message`は表示用であり、機械的な判定には他のフィールドを使用してください。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanProgress {
+    pub phase: ScanPhase,
+    /// スキャン全体の進捗率（0〜100）
+    pub progress: u32,
+    /// 対象のリソース種別（スキャン結果のキー。例: "users", "role_definitions"）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<String>,
+    /// 処理済み件数（`resource_completed`では取得件数）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed: Option<usize>,
+    /// 全体件数
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<usize>,
+    /// マルチアカウントスキャン時の対象アカウントID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    /// 表示用メッセージ
+    pub message: String,
+}
+
+/// 進捗コールバックの型
+pub type ProgressCallback = Box<dyn Fn(ScanProgress) + Send + Sync>;
+
+impl ScanProgress {
+    pub fn new(phase: ScanPhase, progress: u32, message: impl Into<String>) -> Self {
+        Self {
+            phase,
+            progress,
+            resource_type: None,
+            completed: None,
+            total: None,
+            account_id: None,
+            message: message.into(),
+        }
+    }
+
+    /// リソース種別のスキャン開始
+    pub fn scanning(progress: u32, resource_type: &str, message: impl Into<String>) -> Self {
+        Self::new(ScanPhase::Scanning, progress, message).with_resource_type(resource_type)
+    }
+
+    /// リソース種別のスキャン完了（取得件数付き）
+    pub fn resource_completed(
+        progress: u32,
+        resource_type: &str,
+        count: usize,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::new(ScanPhase::ResourceCompleted, progress, message)
+            .with_resource_type(resource_type)
+            .with_counts(count, count)
+    }
+
+    pub fn with_resource_type(mut self, resource_type: &str) -> Self {
+        self.resource_type = Some(resource_type.to_string());
+        self
+    }
+
+    pub fn with_counts(mut self, completed: usize, total: usize) -> Self {
+        self.completed = Some(completed);
+        self.total = Some(total);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resource_completed_serialization() {
+        let progress =
+            ScanProgress::resource_completed(40, "users", 3, "IAM Usersのスキャン完了: 3件");
+
+        let json = serde_json::to_value(&progress).unwrap();
+        assert_eq!(json["phase"], "resource_completed");
+        assert_eq!(json["resource_type"], "users");
+        assert_eq!(json["completed"], 3);
+        assert_eq!(json["total"], 3);
+        assert!(json.get("account_id").is_none());
+    }
+}
@@ -14,6 +14,34 @@ pub struct ScanConfig {
     pub assume_role_arn: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assume_role_session_name: Option<String>,
+    /// AssumeRole時に指定する外部ID（信頼ポリシーで`sts:ExternalId`を要求される場合）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assume_role_external_id: Option<String>,
+    /// AssumeRoleで取得するセッションの有効期間（秒）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assume_role_duration_seconds: Option<i32>,
+    /// AssumeRole時に使用するMFAデバイスのシリアル番号（ARN）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mfa_serial: Option<String>,
+    /// MFAデバイスのワンタイムコード
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mfa_token_code: Option<String>,
+    /// マルチアカウントスキャンの対象アカウントIDリスト
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub account_ids: Vec<String>,
+    /// AWS Organizationsから対象アカウントを検出するかどうか
+    #[serde(default)]
+    pub discover_accounts: bool,
+    /// マルチアカウントスキャンで各アカウントに引き受けるロール名
+    /// （未指定の場合は`OrganizationAccountAccessRole`）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_role_name: Option<String>,
+    /// 使用するリージョン（未指定の場合はプロファイル・環境変数の設定に従う）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// 全AWSサービス共通のエンドポイントURL（LocalStack・moto等のローカル環境用）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint_url: Option<String>,
 
     // Azure specific
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -21,7 +49,7 @@ pub struct ScanConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tenant_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auth_method: Option<String>, // "az_login", "service_principal"
+    pub auth_method: Option<String>, // "az_login", "service_principal", "workload_identity"
     #[serde(skip_serializing_if = "Option::is_none")]
     pub service_principal_config: Option<HashMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -40,6 +68,55 @@ pub struct ScanConfig {
     /// 大規模環境ではfalseにすることでスキャン速度が向上
     #[serde(default = "default_true")]
     pub include_tags: bool,
+    /// IAM API呼び出しの最大同時実行数（未指定の場合は`DEFAULT_MAX_CONCURRENCY`）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrency: Option<usize>,
+    /// AWS IAMの取得方式（"per_resource" または "authorization_details"）
+    ///
+    /// "authorization_details"の場合はGetAccountAuthorizationDetailsで一括取得します。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scan_strategy: Option<String>,
+    /// ユーザー/ロールごとのサービス最終アクセス情報を取得するかどうか
+    /// （レポート生成ジョブを待つためスキャン時間が増加する）
+    #[serde(default)]
+    pub include_service_last_accessed: bool,
+    /// 未使用と判定するまでの経過日数（未指定の場合は`DEFAULT_UNUSED_THRESHOLD_DAYS`）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unused_threshold_days: Option<u32>,
+}
+
+/// GetAccountAuthorizationDetailsで一括取得するスキャン方式
+pub const SCAN_STRATEGY_AUTHORIZATION_DETAILS: &str = "authorization_details";
+
+/// IAM API呼び出しの同時実行数のデフォルト値
+pub const DEFAULT_MAX_CONCURRENCY: usize = 8;
+
+/// 未使用IDと判定するまでの経過日数のデフォルト値
+pub const DEFAULT_UNUSED_THRESHOLD_DAYS: u32 = 90;
+
+impl ScanConfig {
+    /// 複数アカウントを対象とするスキャンかどうか
+    pub fn is_multi_account(&self) -> bool {
+        !self.account_ids.is_empty() || self.discover_accounts
+    }
+
+    /// GetAccountAuthorizationDetailsによる一括取得を使用するかどうか
+    pub fn uses_authorization_details(&self) -> bool {
+        self.scan_strategy.as_deref() == Some(SCAN_STRATEGY_AUTHORIZATION_DETAILS)
+    }
+
+    /// IAM API呼び出しの最大同時実行数（1以上）
+    pub fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+            .unwrap_or(DEFAULT_MAX_CONCURRENCY)
+            .max(1)
+    }
+
+    /// 未使用IDと判定するまでの経過日数
+    pub fn unused_threshold_days(&self) -> u32 {
+        self.unused_threshold_days
+            .unwrap_or(DEFAULT_UNUSED_THRESHOLD_DAYS)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,6 +132,13 @@ pub struct GenerationConfig {
     pub generate_readme: bool,
     #[serde(default)]
     pub selected_resources: HashMap<String, Vec<serde_json::Value>>,
+    /// ARN中のパーティションとアカウントIDを`data.aws_partition`/`data.aws_caller_identity`の
+    /// 参照に置き換えるかどうか（importコマンドのIDはリテラルのまま）
+    #[serde(default)]
+    pub parameterize_arns: bool,
+    /// ARNのパラメータ化で置き換えるアカウントID（未指定の場合はスキャン結果から推定）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
 }
 
 fn default_file_split_rule() -> String {
@@ -10,6 +10,32 @@ pub struct ScanResponse {
     pub message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub summary: Option<std::collections::HashMap<String, usize>>,
+    /// 直近の進捗イベント（段階・リソース種別・件数）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress_detail: Option<super::ScanProgress>,
+    /// スキャン中に発生した部分的な失敗
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<ScanWarning>,
+}
+
+/// スキャン中の部分的な失敗
+///
+/// 個別のAPI呼び出しが失敗してもスキャン全体は継続するため、
+/// 対象リソースの情報（ポリシー等）が欠落している可能性を示します。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanWarning {
+    /// 対象リソースの種類（"user" | "group" | "role" | "policy"）
+    pub resource_type: String,
+    /// 対象リソースの名前
+    pub resource_name: String,
+    /// 失敗したAPI操作（例: "ListAttachedRolePolicies"）
+    pub operation: String,
+    /// エラー種別（"access_denied" | "throttling" | "not_found" | "other"）
+    pub error_kind: String,
+    pub message: String,
+    /// マルチアカウントスキャン時の対象アカウントID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +68,9 @@ pub struct ConnectionTestResponse {
     pub account_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_arn: Option<String>,
+    /// AssumeRoleで引き受けたロールのARN（AWS用）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assumed_role_arn: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription_name: Option<String>, // Azure用
 }
@@ -2,6 +2,7 @@ use anyhow::Result;
 use std::collections::HashMap;
 
 use crate::infra::aws::client_factory::AwsClientFactory;
+pub use crate::infra::aws::client_factory::{AssumeRoleOptions, AwsEndpointOptions};
 use crate::infra::azure::client_factory::AzureClientFactory;
 use crate::models::{AzureResourceGroup, AzureSubscription, ConnectionTestResponse};
 
@@ -10,10 +11,10 @@ pub struct ConnectionService;
 impl ConnectionService {
     pub async fn test_aws_connection(
         profile: Option<String>,
-        assume_role_arn: Option<String>,
-        assume_role_session_name: Option<String>,
+        assume_role: Option<AssumeRoleOptions>,
+        endpoint: AwsEndpointOptions,
     ) -> Result<ConnectionTestResponse> {
-        AwsClientFactory::test_connection(profile, assume_role_arn, assume_role_session_name).await
+        AwsClientFactory::test_connection(profile, assume_role, &endpoint).await
     }
 
     pub async fn test_azure_connection(
@@ -67,7 +68,8 @@ mod tests {
     async fn test_aws_connection_accepts_optional_parameters() {
         // This test verifies the function signature accepts None values
         // Result may be Ok or Err depending on local AWS configuration
-        let _result = ConnectionService::test_aws_connection(None, None, None).await;
+        let _result =
+            ConnectionService::test_aws_connection(None, None, AwsEndpointOptions::default()).await;
 
         // Test passes if function completes without panicking
         // Actual result depends on local environment (Ok if AWS configured, Err if not)
@@ -1,6 +1,6 @@
 use anyhow::Result;
 use serde_json::Value;
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use crate::models::{DependencyEdge, DependencyGraph, DependencyNode};
 use crate::services::scan_service::ScanService;
@@ -30,6 +30,9 @@ impl DependencyService {
     }
 
     /// AWS IAMリソースの依存関係を抽出する
+    ///
+    /// マルチアカウントスキャンの結果では、同名リソースを区別するため
+    /// ノードIDにアカウントIDを含めます（例: `user:111111111111:alice`）。
     fn extract_aws_dependencies(
         scan_data: &Value,
         root_id: Option<&str>,
@@ -37,98 +40,197 @@ impl DependencyService {
         let mut nodes = Vec::new();
         let mut edges = Vec::new();
 
-        // ユーザーノードを追加
-        if let Some(users) = scan_data.get("users").and_then(|u| u.as_array()) {
-            for user in users {
-                if let Some(name) = user.get("user_name").and_then(|n| n.as_str()) {
-                    nodes.push(DependencyNode {
-                        id: format!("user:{}", name),
-                        node_type: "user".to_string(),
-                        name: name.to_string(),
-                        data: user.clone(),
-                    });
+        // ユーザー・グループ・ロールノードを追加
+        for (key, name_field, node_type) in [
+            ("users", "user_name", "user"),
+            ("groups", "group_name", "group"),
+            ("roles", "role_name", "role"),
+            (
+                "instance_profiles",
+                "instance_profile_name",
+                "instance_profile",
+            ),
+        ] {
+            if let Some(items) = scan_data.get(key).and_then(|v| v.as_array()) {
+                for item in items {
+                    if let Some(name) = item.get(name_field).and_then(|n| n.as_str()) {
+                        nodes.push(DependencyNode {
+                            id: Self::aws_node_id(node_type, item, name),
+                            node_type: node_type.to_string(),
+                            name: name.to_string(),
+                            data: item.clone(),
+                        });
+                    }
                 }
             }
         }
 
-        // グループノードを追加
-        if let Some(groups) = scan_data.get("groups").and_then(|g| g.as_array()) {
-            for group in groups {
-                if let Some(name) = group.get("group_name").and_then(|n| n.as_str()) {
+        // ポリシーノードを追加（ARNにアカウントIDが含まれるため、ARNをそのままIDに使用）
+        if let Some(policies) = scan_data.get("policies").and_then(|p| p.as_array()) {
+            for policy in policies {
+                if let Some(arn) = policy.get("arn").and_then(|a| a.as_str()) {
+                    let name = policy
+                        .get("policy_name")
+                        .and_then(|n| n.as_str())
+                        .unwrap_or(arn);
                     nodes.push(DependencyNode {
-                        id: format!("group:{}", name),
-                        node_type: "group".to_string(),
+                        id: format!("policy:{}", arn),
+                        node_type: "policy".to_string(),
                         name: name.to_string(),
-                        data: group.clone(),
+                        data: policy.clone(),
                     });
                 }
             }
         }
 
-        // ロールノードを追加
-        if let Some(roles) = scan_data.get("roles").and_then(|r| r.as_array()) {
-            for role in roles {
-                if let Some(name) = role.get("role_name").and_then(|n| n.as_str()) {
+        // IDプロバイダーノードを追加（ポリシーと同様にARNをIDに使用）
+        for (key, name_field, node_type) in [
+            ("oidc_providers", "url", "oidc_provider"),
+            ("saml_providers", "name", "saml_provider"),
+        ] {
+            let providers = scan_data.get(key).and_then(|v| v.as_array());
+            for provider in providers.into_iter().flatten() {
+                if let Some(arn) = provider.get("arn").and_then(|a| a.as_str()) {
+                    let name = provider
+                        .get(name_field)
+                        .and_then(|n| n.as_str())
+                        .unwrap_or(arn);
                     nodes.push(DependencyNode {
-                        id: format!("role:{}", name),
-                        node_type: "role".to_string(),
+                        id: format!("{}:{}", node_type, arn),
+                        node_type: node_type.to_string(),
                         name: name.to_string(),
-                        data: role.clone(),
+                        data: provider.clone(),
                     });
                 }
             }
         }
 
-        // ポリシーノードを追加
-        if let Some(policies) = scan_data.get("policies").and_then(|p| p.as_array()) {
-            for policy in policies {
-                if let Some(arn) = policy.get("arn").and_then(|a| a.as_str()) {
-                    let name = policy
-                        .get("policy_name")
-                        .and_then(|n| n.as_str())
-                        .unwrap_or(arn);
-                    nodes.push(DependencyNode {
-                        id: format!("policy:{}", arn),
-                        node_type: "policy".to_string(),
-                        name: name.to_string(),
-                        data: policy.clone(),
-                    });
+        match scan_data.get("attachments") {
+            // アタッチメント一覧（entity_type/entity_name形式）からエッジを作成
+            Some(Value::Array(attachments)) => {
+                for attachment in attachments {
+                    let entity_type = attachment
+                        .get("entity_type")
+                        .and_then(|e| e.as_str())
+                        .unwrap_or("");
+                    let entity_name = attachment
+                        .get("entity_name")
+                        .and_then(|e| e.as_str())
+                        .unwrap_or("");
+                    let policy_arn = attachment
+                        .get("policy_arn")
+                        .and_then(|p| p.as_str())
+                        .unwrap_or("");
+
+                    let node_type = match entity_type {
+                        "User" => "user",
+                        "Group" => "group",
+                        "Role" => "role",
+                        _ => continue,
+                    };
+
+                    edges.push(Self::policy_attachment_edge(
+                        Self::aws_node_id(node_type, attachment, entity_name),
+                        policy_arn,
+                    ));
                 }
             }
+            // スキャナー出力形式（user_policies/group_policies/role_policies/user_groups）からエッジを作成
+            Some(Value::Object(attachments)) => {
+                for (key, name_field, node_type) in [
+                    ("user_policies", "user_name", "user"),
+                    ("group_policies", "group_name", "group"),
+                    ("role_policies", "role_name", "role"),
+                ] {
+                    let entries = attachments.get(key).and_then(|v| v.as_array());
+                    for entry in entries.into_iter().flatten() {
+                        if let (Some(name), Some(policy_arn)) = (
+                            entry.get(name_field).and_then(|n| n.as_str()),
+                            entry.get("policy_arn").and_then(|p| p.as_str()),
+                        ) {
+                            edges.push(Self::policy_attachment_edge(
+                                Self::aws_node_id(node_type, entry, name),
+                                policy_arn,
+                            ));
+                        }
+                    }
+                }
+
+                let memberships = attachments.get("user_groups").and_then(|v| v.as_array());
+                for membership in memberships.into_iter().flatten() {
+                    if let (Some(user_name), Some(group_name)) = (
+                        membership.get("user_name").and_then(|n| n.as_str()),
+                        membership.get("group_name").and_then(|n| n.as_str()),
+                    ) {
+                        edges.push(Self::group_membership_edge(
+                            Self::aws_node_id("user", membership, user_name),
+                            Self::aws_node_id("group", membership, group_name),
+                        ));
+                    }
+                }
+            }
+            _ => {}
         }
 
-        // アタッチメントからエッジを作成
-        if let Some(attachments) = scan_data.get("attachments").and_then(|a| a.as_array()) {
-            for attachment in attachments {
-                let entity_type = attachment
-                    .get("entity_type")
-                    .and_then(|e| e.as_str())
-                    .unwrap_or("");
-                let entity_name = attachment
-                    .get("entity_name")
-                    .and_then(|e| e.as_str())
-                    .unwrap_or("");
-                let policy_arn = attachment
-                    .get("policy_arn")
-                    .and_then(|p| p.as_str())
-                    .unwrap_or("");
-
-                let source_id = match entity_type {
-                    "User" => format!("user:{}", entity_name),
-                    "Group" => format!("group:{}", entity_name),
-                    "Role" => format!("role:{}", entity_name),
-                    _ => continue,
-                };
+        // インスタンスプロファイルから関連付けられたロールへのエッジを作成
+        if let Some(profiles) = scan_data
+            .get("instance_profiles")
+            .and_then(|v| v.as_array())
+        {
+            for profile in profiles {
+                if let (Some(profile_name), Some(role_name)) = (
+                    profile
+                        .get("instance_profile_name")
+                        .and_then(|n| n.as_str()),
+                    profile.get("role_name").and_then(|n| n.as_str()),
+                ) {
+                    edges.push(DependencyEdge {
+                        source: Self::aws_node_id("instance_profile", profile, profile_name),
+                        target: Self::aws_node_id("role", profile, role_name),
+                        edge_type: "instance_profile_role".to_string(),
+                        label: Some("uses role".to_string()),
+                    });
+                }
+            }
+        }
 
-                edges.push(DependencyEdge {
-                    source: source_id,
-                    target: format!("policy:{}", policy_arn),
-                    edge_type: "policy_attachment".to_string(),
-                    label: Some("has policy".to_string()),
-                });
+        // ロールから信頼ポリシーのFederatedプリンシパル（IDプロバイダー）へのエッジを作成
+        let roles = scan_data.get("roles").and_then(|v| v.as_array());
+        for role in roles.into_iter().flatten() {
+            let Some(role_name) = role.get("role_name").and_then(|n| n.as_str()) else {
+                continue;
+            };
+            let statements = role
+                .get("assume_role_statements")
+                .and_then(|v| v.as_array());
+            for statement in statements.into_iter().flatten() {
+                if statement.get("principal_type").and_then(|v| v.as_str()) != Some("Federated") {
+                    continue;
+                }
+                let identifiers = statement
+                    .get("principal_identifiers")
+                    .and_then(|v| v.as_array());
+                for arn in identifiers.into_iter().flatten().filter_map(|v| v.as_str()) {
+                    let node_type = if arn.contains(":oidc-provider/") {
+                        "oidc_provider"
+                    } else if arn.contains(":saml-provider/") {
+                        "saml_provider"
+                    } else {
+                        continue;
+                    };
+                    edges.push(DependencyEdge {
+                        source: Self::aws_node_id("role", role, role_name),
+                        target: format!("{}:{}", node_type, arn),
+                        edge_type: "role_trust".to_string(),
+                        label: Some("trusts".to_string()),
+                    });
+                }
             }
         }
 
+        // リソースベースポリシーからAllowされたAWSプリンシパルへのエッジを作成
+        Self::extract_resource_policy_dependencies(scan_data, &mut nodes, &mut edges);
+
         // グループメンバーシップのエッジを作成
         if let Some(groups) = scan_data.get("groups").and_then(|g| g.as_array()) {
             for group in groups {
@@ -136,12 +238,10 @@ impl DependencyService {
                     if let Some(members) = group.get("members").and_then(|m| m.as_array()) {
                         for member in members {
                             if let Some(user_name) = member.as_str() {
-                                edges.push(DependencyEdge {
-                                    source: format!("user:{}", user_name),
-                                    target: format!("group:{}", group_name),
-                                    edge_type: "group_membership".to_string(),
-                                    label: Some("member of".to_string()),
-                                });
+                                edges.push(Self::group_membership_edge(
+                                    Self::aws_node_id("user", group, user_name),
+                                    Self::aws_node_id("group", group, group_name),
+                                ));
                             }
                         }
                     }
@@ -157,6 +257,140 @@ impl DependencyService {
         Ok(DependencyGraph { nodes, edges })
     }
 
+    /// リソースベースポリシー（S3・KMS・SQS・SNS）のノードとエッジを抽出する
+    ///
+    /// `Allow`ステートメントの`AWS`プリンシパルがスキャン済みのユーザー・ロールの場合は
+    /// そのノードへ、それ以外（他アカウント・ルート等）は`aws_principal`ノードへ接続します。
+    fn extract_resource_policy_dependencies(
+        scan_data: &Value,
+        nodes: &mut Vec<DependencyNode>,
+        edges: &mut Vec<DependencyEdge>,
+    ) {
+        // プリンシパルARNからスキャン済みノードのIDを引けるようにする
+        let mut principal_ids: HashMap<String, String> = HashMap::new();
+        for (key, name_field, node_type) in [
+            ("users", "user_name", "user"),
+            ("roles", "role_name", "role"),
+        ] {
+            let items = scan_data.get(key).and_then(|v| v.as_array());
+            for item in items.into_iter().flatten() {
+                if let (Some(arn), Some(name)) = (
+                    item.get("arn").and_then(|a| a.as_str()),
+                    item.get(name_field).and_then(|n| n.as_str()),
+                ) {
+                    principal_ids.insert(arn.to_string(), Self::aws_node_id(node_type, item, name));
+                }
+            }
+        }
+
+        let mut external_principals = HashSet::new();
+        for (key, id_field, name_field, node_type) in [
+            ("s3_bucket_policies", "bucket", "bucket", "s3_bucket"),
+            ("kms_key_policies", "key_arn", "key_id", "kms_key"),
+            ("sqs_queue_policies", "queue_arn", "queue_name", "sqs_queue"),
+            ("sns_topic_policies", "topic_arn", "topic_name", "sns_topic"),
+        ] {
+            let items = scan_data.get(key).and_then(|v| v.as_array());
+            for item in items.into_iter().flatten() {
+                let (Some(id), Some(name)) = (
+                    item.get(id_field).and_then(|v| v.as_str()),
+                    item.get(name_field).and_then(|v| v.as_str()),
+                ) else {
+                    continue;
+                };
+                let source = format!("{}:{}", node_type, id);
+                nodes.push(DependencyNode {
+                    id: source.clone(),
+                    node_type: node_type.to_string(),
+                    name: name.to_string(),
+                    data: item.clone(),
+                });
+
+                let mut targets = HashSet::new();
+                for principal in Self::allowed_aws_principals(item.get("policy_document")) {
+                    let target = match principal_ids.get(&principal) {
+                        Some(node_id) => node_id.clone(),
+                        None => {
+                            let node_id = format!("aws_principal:{}", principal);
+                            if external_principals.insert(node_id.clone()) {
+                                nodes.push(DependencyNode {
+                                    id: node_id.clone(),
+                                    node_type: "aws_principal".to_string(),
+                                    name: principal.clone(),
+                                    data: Value::Null,
+                                });
+                            }
+                            node_id
+                        }
+                    };
+                    // 複数ステートメントで同じプリンシパルを許可していても1本のエッジにまとめる
+                    if targets.insert(target.clone()) {
+                        edges.push(DependencyEdge {
+                            source: source.clone(),
+                            target,
+                            edge_type: "resource_policy".to_string(),
+                            label: Some("grants access to".to_string()),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// ポリシードキュメントの`Allow`ステートメントから`AWS`プリンシパルを列挙する
+    ///
+    /// 全員を表す`*`はIAMプリンシパルではないため除外します。
+    fn allowed_aws_principals(policy_document: Option<&Value>) -> Vec<String> {
+        let statements = match policy_document.and_then(|d| d.get("Statement")) {
+            Some(Value::Array(statements)) => statements.iter().collect(),
+            Some(statement @ Value::Object(_)) => vec![statement],
+            _ => Vec::new(),
+        };
+        statements
+            .into_iter()
+            .filter(|statement| statement.get("Effect").and_then(|e| e.as_str()) == Some("Allow"))
+            .filter_map(|statement| statement.get("Principal")?.get("AWS"))
+            .flat_map(|principals| match principals {
+                Value::String(principal) => vec![principal.clone()],
+                Value::Array(principals) => principals
+                    .iter()
+                    .filter_map(|p| p.as_str().map(|p| p.to_string()))
+                    .collect(),
+                _ => Vec::new(),
+            })
+            .filter(|principal| principal != "*")
+            .collect()
+    }
+
+    /// AWSリソースのノードIDを生成する
+    ///
+    /// リソースに`account_id`が付与されている場合（マルチアカウントスキャン）は
+    /// アカウントIDを含めたIDを返します。
+    fn aws_node_id(node_type: &str, resource: &Value, name: &str) -> String {
+        match resource.get("account_id").and_then(|a| a.as_str()) {
+            Some(account_id) => format!("{}:{}:{}", node_type, account_id, name),
+            None => format!("{}:{}", node_type, name),
+        }
+    }
+
+    fn policy_attachment_edge(source: String, policy_arn: &str) -> DependencyEdge {
+        DependencyEdge {
+            source,
+            target: format!("policy:{}", policy_arn),
+            edge_type: "policy_attachment".to_string(),
+            label: Some("has policy".to_string()),
+        }
+    }
+
+    fn group_membership_edge(source: String, target: String) -> DependencyEdge {
+        DependencyEdge {
+            source,
+            target,
+            edge_type: "group_membership".to_string(),
+            label: Some("member of".to_string()),
+        }
+    }
+
     /// Azure IAMリソースの依存関係を抽出する
     fn extract_azure_dependencies(
         scan_data: &Value,
@@ -293,6 +527,160 @@ mod tests {
         assert_eq!(result.edges.len(), 4); // 2 policy attachments + 2 group memberships
     }
 
+    #[test]
+    fn test_extract_aws_dependencies_multi_account() {
+        let scan_data = json!({
+            "provider": "aws",
+            "users": [
+                {"user_name": "alice", "account_id": "111111111111"},
+                {"user_name": "alice", "account_id": "222222222222"}
+            ],
+            "groups": [
+                {"group_name": "admins", "account_id": "111111111111"}
+            ],
+            "roles": [],
+            "policies": [
+                {"arn": "arn:aws:iam::111111111111:policy/Audit", "policy_name": "Audit", "account_id": "111111111111"}
+            ],
+            "attachments": {
+                "user_policies": [
+                    {"user_name": "alice", "policy_type": "managed", "policy_arn": "arn:aws:iam::111111111111:policy/Audit", "account_id": "111111111111"},
+                    {"user_name": "alice", "policy_type": "inline", "policy_name": "inline", "account_id": "222222222222"}
+                ],
+                "group_policies": [],
+                "role_policies": [],
+                "user_groups": [
+                    {"user_name": "alice", "group_name": "admins", "account_id": "111111111111"}
+                ]
+            },
+            "accounts": {
+                "111111111111": {"status": "completed"},
+                "222222222222": {"status": "completed"}
+            }
+        });
+
+        let result = DependencyService::extract_aws_dependencies(&scan_data, None).unwrap();
+
+        let node_ids: Vec<&str> = result.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert!(node_ids.contains(&"user:111111111111:alice"));
+        assert!(node_ids.contains(&"user:222222222222:alice"));
+        assert_eq!(result.edges.len(), 2); // 1 managed policy + 1 group membership
+
+        let graph = DependencyService::extract_aws_dependencies(
+            &scan_data,
+            Some("user:111111111111:alice"),
+        )
+        .unwrap();
+        assert_eq!(graph.nodes.len(), 3); // alice (111...) + admins + Audit
+        assert!(graph
+            .nodes
+            .iter()
+            .all(|n| n.id != "user:222222222222:alice"));
+    }
+
+    #[test]
+    fn test_extract_aws_dependencies_instance_profile() {
+        let scan_data = json!({
+            "provider": "aws",
+            "roles": [
+                {"role_name": "web-role"}
+            ],
+            "instance_profiles": [
+                {"instance_profile_name": "web-profile", "role_name": "web-role"},
+                {"instance_profile_name": "empty-profile"}
+            ]
+        });
+
+        let result = DependencyService::extract_aws_dependencies(&scan_data, None).unwrap();
+
+        assert_eq!(result.nodes.len(), 3); // 1 role + 2 instance profiles
+        assert_eq!(result.edges.len(), 1);
+        assert_eq!(result.edges[0].source, "instance_profile:web-profile");
+        assert_eq!(result.edges[0].target, "role:web-role");
+        assert_eq!(result.edges[0].edge_type, "instance_profile_role");
+    }
+
+    #[test]
+    fn test_extract_aws_dependencies_federated_trust() {
+        let oidc_arn =
+            "arn:aws:iam::123456789012:oidc-provider/token.actions.githubusercontent.com";
+        let scan_data = json!({
+            "provider": "aws",
+            "roles": [{
+                "role_name": "ci-deploy",
+                "assume_role_statements": [{
+                    "principal_type": "Federated",
+                    "principal_identifiers": [oidc_arn]
+                }]
+            }],
+            "oidc_providers": [
+                {"arn": oidc_arn, "url": "https://token.actions.githubusercontent.com"}
+            ]
+        });
+
+        let result = DependencyService::extract_aws_dependencies(&scan_data, None).unwrap();
+
+        assert_eq!(result.nodes.len(), 2);
+        assert_eq!(result.edges.len(), 1);
+        assert_eq!(result.edges[0].source, "role:ci-deploy");
+        assert_eq!(
+            result.edges[0].target,
+            format!("oidc_provider:{}", oidc_arn)
+        );
+        assert_eq!(result.edges[0].edge_type, "role_trust");
+    }
+
+    #[test]
+    fn test_extract_aws_dependencies_resource_policies() {
+        let role_arn = "arn:aws:iam::111111111111:role/app";
+        let scan_data = json!({
+            "provider": "aws",
+            "roles": [{"role_name": "app", "arn": role_arn}],
+            "s3_bucket_policies": [{
+                "bucket": "app-assets",
+                "policy_document": {
+                    "Statement": [
+                        {"Effect": "Allow", "Principal": {"AWS": role_arn}, "Action": "s3:GetObject"},
+                        {"Effect": "Allow", "Principal": {"AWS": [role_arn, "arn:aws:iam::222222222222:root"]}, "Action": "s3:ListBucket"},
+                        {"Effect": "Deny", "Principal": "*", "Action": "s3:*"}
+                    ]
+                }
+            }],
+            "kms_key_policies": [{
+                "key_id": "1234abcd",
+                "key_arn": "arn:aws:kms:us-east-1:111111111111:key/1234abcd",
+                "policy_document": {
+                    "Statement": {"Effect": "Allow", "Principal": {"AWS": "arn:aws:iam::222222222222:root"}, "Action": "kms:*"}
+                }
+            }]
+        });
+
+        let result = DependencyService::extract_aws_dependencies(&scan_data, None).unwrap();
+
+        // 1 role + 1 bucket + 1 key + 1 external principal（重複なし）
+        assert_eq!(result.nodes.len(), 4);
+        assert!(result
+            .nodes
+            .iter()
+            .any(|n| n.id == "aws_principal:arn:aws:iam::222222222222:root"
+                && n.node_type == "aws_principal"));
+        assert_eq!(result.edges.len(), 3);
+        assert!(result
+            .edges
+            .iter()
+            .all(|e| e.edge_type == "resource_policy"));
+        assert_eq!(result.edges[0].source, "s3_bucket:app-assets");
+        assert_eq!(result.edges[0].target, "role:app");
+        assert_eq!(
+            result.edges[1].target,
+            "aws_principal:arn:aws:iam::222222222222:root"
+        );
+        assert_eq!(
+            result.edges[2].source,
+            "kms_key:arn:aws:kms:us-east-1:111111111111:key/1234abcd"
+        );
+    }
+
     #[test]
     fn test_filter_by_root() {
         let mut nodes = vec![
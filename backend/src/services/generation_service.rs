@@ -444,6 +444,8 @@ mod tests {
             generate_readme: true,
             import_script_format: "sh".to_string(),
             selected_resources: HashMap::new(),
+            parameterize_arns: false,
+            account_id: None,
         }
     }
 
@@ -561,6 +563,15 @@ mod tests {
             profile: None,
             assume_role_arn: None,
             assume_role_session_name: None,
+            assume_role_external_id: None,
+            assume_role_duration_seconds: None,
+            mfa_serial: None,
+            mfa_token_code: None,
+            account_ids: Vec::new(),
+            discover_accounts: false,
+            member_role_name: None,
+            region: None,
+            endpoint_url: None,
             subscription_id: None,
             tenant_id: None,
             auth_method: None,
@@ -570,6 +581,10 @@ mod tests {
             scan_targets: HashMap::new(),
             filters: HashMap::new(),
             include_tags: true,
+            max_concurrency: None,
+            scan_strategy: None,
+            include_service_last_accessed: false,
+            unused_threshold_days: None,
         };
 
         // スキャン結果を登録
@@ -635,6 +650,15 @@ mod tests {
             profile: None,
             assume_role_arn: None,
             assume_role_session_name: None,
+            assume_role_external_id: None,
+            assume_role_duration_seconds: None,
+            mfa_serial: None,
+            mfa_token_code: None,
+            account_ids: Vec::new(),
+            discover_accounts: false,
+            member_role_name: None,
+            region: None,
+            endpoint_url: None,
             subscription_id: None,
             tenant_id: None,
             auth_method: None,
@@ -644,6 +668,10 @@ mod tests {
             scan_targets: HashMap::new(),
             filters: HashMap::new(),
             include_tags: true,
+            max_concurrency: None,
+            scan_strategy: None,
+            include_service_last_accessed: false,
+            unused_threshold_days: None,
         };
 
         ScanService::insert_test_scan_data(scan_id.to_string(), config, scan_data).await;
@@ -681,6 +709,15 @@ mod tests {
             profile: None,
             assume_role_arn: None,
             assume_role_session_name: None,
+            assume_role_external_id: None,
+            assume_role_duration_seconds: None,
+            mfa_serial: None,
+            mfa_token_code: None,
+            account_ids: Vec::new(),
+            discover_accounts: false,
+            member_role_name: None,
+            region: None,
+            endpoint_url: None,
             subscription_id: None,
             tenant_id: None,
             auth_method: None,
@@ -690,6 +727,10 @@ mod tests {
             scan_targets: HashMap::new(),
             filters: HashMap::new(),
             include_tags: true,
+            max_concurrency: None,
+            scan_strategy: None,
+            include_service_last_accessed: false,
+            unused_threshold_days: None,
         };
 
         ScanService::insert_test_scan_data(scan_id.to_string(), config, scan_data).await;
@@ -732,6 +773,15 @@ mod tests {
             profile: None,
             assume_role_arn: None,
             assume_role_session_name: None,
+            assume_role_external_id: None,
+            assume_role_duration_seconds: None,
+            mfa_serial: None,
+            mfa_token_code: None,
+            account_ids: Vec::new(),
+            discover_accounts: false,
+            member_role_name: None,
+            region: None,
+            endpoint_url: None,
             subscription_id: None,
             tenant_id: None,
             auth_method: None,
@@ -741,6 +791,10 @@ mod tests {
             scan_targets: HashMap::new(),
             filters: HashMap::new(),
             include_tags: true,
+            max_concurrency: None,
+            scan_strategy: None,
+            include_service_last_accessed: false,
+            unused_threshold_days: None,
         };
 
         ScanService::insert_test_scan_data(scan_id.to_string(), config, scan_data).await;
@@ -780,6 +834,15 @@ mod tests {
             profile: None,
             assume_role_arn: None,
             assume_role_session_name: None,
+            assume_role_external_id: None,
+            assume_role_duration_seconds: None,
+            mfa_serial: None,
+            mfa_token_code: None,
+            account_ids: Vec::new(),
+            discover_accounts: false,
+            member_role_name: None,
+            region: None,
+            endpoint_url: None,
             subscription_id: None,
             tenant_id: None,
             auth_method: None,
@@ -789,6 +852,10 @@ mod tests {
             scan_targets: HashMap::new(),
             filters: HashMap::new(),
             include_tags: true,
+            max_concurrency: None,
+            scan_strategy: None,
+            include_service_last_accessed: false,
+            unused_threshold_days: None,
         };
 
         ScanService::insert_test_scan_data(scan_id.to_string(), config, scan_data).await;
@@ -120,7 +120,11 @@ impl ResourceService {
             if let Some(obj) = scan_data.as_object() {
                 for (key, resources) in obj {
                     // Skip metadata fields
-                    if key == "provider" || key == "scan_id" || key == "timestamp" {
+                    if key == "provider"
+                        || key == "scan_id"
+                        || key == "timestamp"
+                        || key == "warnings"
+                    {
                         continue;
                     }
                     if let Some(arr) = resources.as_array() {
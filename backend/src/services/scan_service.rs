@@ -4,15 +4,18 @@ use std::sync::Arc;
 use tokio::sync::{mpsc, RwLock};
 use uuid::Uuid;
 
+use crate::infra::aws::multi_account::AwsMultiAccountScanner;
 use crate::infra::aws::scanner::AwsIamScanner;
 use crate::infra::azure::scanner::AzureIamScanner;
-use crate::models::{ScanConfig, ScanResponse};
+use crate::models::{
+    ProgressCallback, ScanConfig, ScanPhase, ScanProgress, ScanResponse, ScanWarning,
+};
 
 /// ストリーミングスキャンの進捗イベント
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScanProgressEvent {
     pub scan_id: String,
-    pub event_type: String, // "progress", "resource", "completed", "error"
+    pub event_type: String, // "progress", "resource", "warning", "completed", "error"
     pub progress: u32,
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -21,6 +24,35 @@ pub struct ScanProgressEvent {
     pub resource_count: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<serde_json::Value>,
+    /// スキャナーが通知した段階（progress / resourceイベントのみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<ScanPhase>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+}
+
+impl ScanProgressEvent {
+    /// スキャナーの進捗イベントからSSEイベントを作成
+    fn from_progress(scan_id: &str, event: ScanProgress) -> Self {
+        let is_resource = event.phase == ScanPhase::ResourceCompleted;
+        Self {
+            scan_id: scan_id.to_string(),
+            event_type: if is_resource { "resource" } else { "progress" }.to_string(),
+            progress: event.progress,
+            message: event.message,
+            resource_type: event.resource_type,
+            resource_count: if is_resource { event.completed } else { None },
+            data: None,
+            phase: Some(event.phase),
+            completed: event.completed,
+            total: event.total,
+            account_id: event.account_id,
+        }
+    }
 }
 
 // In-memory storage for scan results (in production, use Redis or database)
@@ -31,6 +63,8 @@ struct ScanResult {
     status: String,
     progress: Option<u32>,
     message: Option<String>,
+    /// 直近の進捗イベント
+    latest_progress: Option<ScanProgress>,
     _config: ScanConfig,
     data: Option<serde_json::Value>,
 }
@@ -43,14 +77,44 @@ pub struct ScanService;
 
 impl ScanService {
     /// 進捗状況を更新する
-    pub async fn update_progress(scan_id: &str, progress: u32, message: String) {
+    pub async fn update_progress(scan_id: &str, event: ScanProgress) {
         let mut results = SCAN_RESULTS.write().await;
         if let Some(scan_result) = results.get_mut(scan_id) {
-            scan_result.progress = Some(progress);
-            scan_result.message = Some(message);
+            scan_result.progress = Some(event.progress);
+            scan_result.message = Some(event.message.clone());
+            scan_result.latest_progress = Some(event);
         }
     }
 
+    /// 進捗をスキャン状態に反映するコールバック（ポーリング用）
+    fn polling_progress_callback(scan_id: &str) -> ProgressCallback {
+        let scan_id = scan_id.to_string();
+        Box::new(move |event: ScanProgress| {
+            let scan_id = scan_id.clone();
+            tokio::spawn(async move {
+                ScanService::update_progress(&scan_id, event).await;
+            });
+        })
+    }
+
+    /// 進捗をスキャン状態に反映し、SSEイベントとして送信するコールバック
+    fn stream_progress_callback(
+        scan_id: &str,
+        tx: mpsc::Sender<ScanProgressEvent>,
+    ) -> ProgressCallback {
+        let scan_id = scan_id.to_string();
+        Box::new(move |event: ScanProgress| {
+            let scan_id = scan_id.clone();
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                ScanService::update_progress(&scan_id, event.clone()).await;
+                let _ = tx
+                    .send(ScanProgressEvent::from_progress(&scan_id, event))
+                    .await;
+            });
+        })
+    }
+
     pub async fn start_scan(config: ScanConfig) -> Result<String> {
         let scan_id = Uuid::new_v4().to_string();
 
@@ -60,6 +124,7 @@ impl ScanService {
             status: "in_progress".to_string(),
             progress: Some(0),
             message: Some("スキャンを開始しています...".to_string()),
+            latest_progress: None,
             _config: config.clone(),
             data: None,
         };
@@ -73,31 +138,17 @@ impl ScanService {
         let scan_id_clone = scan_id.clone();
         tokio::spawn(async move {
             let result = match config.provider.as_str() {
-                "aws" => match AwsIamScanner::new(config.clone()).await {
-                    Ok(scanner) => {
-                        let scan_id_for_callback = scan_id_clone.clone();
-                        let progress_callback = Box::new(move |progress: u32, message: String| {
-                            let scan_id = scan_id_for_callback.clone();
-                            tokio::spawn(async move {
-                                ScanService::update_progress(&scan_id, progress, message).await;
-                            });
-                        });
-                        scanner.scan(progress_callback).await
-                    }
-                    Err(e) => {
-                        eprintln!("[SCAN ERROR] Failed to create AWS scanner: {}", e);
-                        Err(e)
+                "aws" => {
+                    let progress_callback = Self::polling_progress_callback(&scan_id_clone);
+                    let result = Self::run_aws_scan(&config, progress_callback).await;
+                    if let Err(e) = &result {
+                        eprintln!("[SCAN ERROR] AWS scan failed: {}", e);
                     }
-                },
+                    result
+                }
                 "azure" => match AzureIamScanner::new(config.clone()).await {
                     Ok(scanner) => {
-                        let scan_id_for_callback = scan_id_clone.clone();
-                        let progress_callback = Box::new(move |progress: u32, message: String| {
-                            let scan_id = scan_id_for_callback.clone();
-                            tokio::spawn(async move {
-                                ScanService::update_progress(&scan_id, progress, message).await;
-                            });
-                        });
+                        let progress_callback = Self::polling_progress_callback(&scan_id_clone);
                         scanner.scan(progress_callback).await
                     }
                     Err(e) => {
@@ -167,6 +218,7 @@ impl ScanService {
             status: "in_progress".to_string(),
             progress: Some(0),
             message: Some("スキャンを開始しています...".to_string()),
+            latest_progress: None,
             _config: config.clone(),
             data: None,
         };
@@ -186,6 +238,10 @@ impl ScanService {
                 resource_type: None,
                 resource_count: None,
                 data: None,
+                phase: None,
+                completed: None,
+                total: None,
+                account_id: None,
             })
             .await;
 
@@ -212,6 +268,13 @@ impl ScanService {
                             scan_result.data = Some(json_data.clone());
                         }
 
+                        // 部分的な失敗を警告イベントとして送信
+                        for warning in Self::scan_warnings(&json_data) {
+                            let _ = tx_clone
+                                .send(Self::warning_event(&scan_id_clone, warning))
+                                .await;
+                        }
+
                         // 完了イベントを送信
                         let _ = tx_clone
                             .send(ScanProgressEvent {
@@ -222,6 +285,10 @@ impl ScanService {
                                 resource_type: None,
                                 resource_count: None,
                                 data: Some(json_data),
+                                phase: None,
+                                completed: None,
+                                total: None,
+                                account_id: None,
                             })
                             .await;
                     }
@@ -242,6 +309,10 @@ impl ScanService {
                                 resource_type: None,
                                 resource_count: None,
                                 data: None,
+                                phase: None,
+                                completed: None,
+                                total: None,
+                                account_id: None,
                             })
                             .await;
                     }
@@ -262,6 +333,10 @@ impl ScanService {
                             resource_type: None,
                             resource_count: None,
                             data: None,
+                            phase: None,
+                            completed: None,
+                            total: None,
+                            account_id: None,
                         })
                         .await;
                 }
@@ -271,44 +346,32 @@ impl ScanService {
         Ok(rx)
     }
 
+    /// AWSスキャンを実行
+    ///
+    /// 複数アカウントが指定されている場合はマルチアカウントスキャンを実行します。
+    async fn run_aws_scan(
+        config: &ScanConfig,
+        progress_callback: ProgressCallback,
+    ) -> Result<serde_json::Value> {
+        if config.is_multi_account() {
+            AwsMultiAccountScanner::new(config.clone())
+                .scan(progress_callback)
+                .await
+        } else {
+            let scanner = AwsIamScanner::new(config.clone()).await?;
+            scanner.scan(progress_callback).await
+        }
+    }
+
     /// AWSスキャンをストリーミングモードで実行
     async fn run_aws_scan_stream(
         config: &ScanConfig,
         scan_id: &str,
         tx: mpsc::Sender<ScanProgressEvent>,
     ) -> Result<serde_json::Value> {
-        let scanner = AwsIamScanner::new(config.clone()).await?;
-
-        let scan_id_for_callback = scan_id.to_string();
-        let tx_for_callback = tx.clone();
-        let progress_callback = Box::new(move |progress: u32, message: String| {
-            let scan_id = scan_id_for_callback.clone();
-            let tx = tx_for_callback.clone();
-
-            // リソースタイプと件数をメッセージから抽出
-            let (resource_type, resource_count) = Self::parse_progress_message(&message);
-
-            tokio::spawn(async move {
-                ScanService::update_progress(&scan_id, progress, message.clone()).await;
-                let _ = tx
-                    .send(ScanProgressEvent {
-                        scan_id,
-                        event_type: if resource_count.is_some() {
-                            "resource".to_string()
-                        } else {
-                            "progress".to_string()
-                        },
-                        progress,
-                        message,
-                        resource_type,
-                        resource_count,
-                        data: None,
-                    })
-                    .await;
-            });
-        });
+        let progress_callback = Self::stream_progress_callback(scan_id, tx);
 
-        scanner.scan(progress_callback).await
+        Self::run_aws_scan(config, progress_callback).await
     }
 
     /// Azureスキャンをストリーミングモードで実行
@@ -319,61 +382,11 @@ impl ScanService {
     ) -> Result<serde_json::Value> {
         let scanner = AzureIamScanner::new(config.clone()).await?;
 
-        let scan_id_for_callback = scan_id.to_string();
-        let tx_for_callback = tx.clone();
-        let progress_callback = Box::new(move |progress: u32, message: String| {
-            let scan_id = scan_id_for_callback.clone();
-            let tx = tx_for_callback.clone();
-
-            // リソースタイプと件数をメッセージから抽出
-            let (resource_type, resource_count) = Self::parse_progress_message(&message);
-
-            tokio::spawn(async move {
-                ScanService::update_progress(&scan_id, progress, message.clone()).await;
-                let _ = tx
-                    .send(ScanProgressEvent {
-                        scan_id,
-                        event_type: if resource_count.is_some() {
-                            "resource".to_string()
-                        } else {
-                            "progress".to_string()
-                        },
-                        progress,
-                        message,
-                        resource_type,
-                        resource_count,
-                        data: None,
-                    })
-                    .await;
-            });
-        });
+        let progress_callback = Self::stream_progress_callback(scan_id, tx);
 
         scanner.scan(progress_callback).await
     }
 
-    /// 進捗メッセージからリソースタイプと件数を抽出
-    fn parse_progress_message(message: &str) -> (Option<String>, Option<usize>) {
-        // パターン: "XXXのスキャン完了: N件"
-        if message.contains("完了:") && message.contains("件") {
-            let parts: Vec<&str> = message.split("完了:").collect();
-            if parts.len() == 2 {
-                // リソースタイプを抽出
-                let resource_type = parts[0]
-                    .replace("のスキャン", "")
-                    .replace("IAM ", "")
-                    .trim()
-                    .to_lowercase();
-
-                // 件数を抽出
-                let count_str = parts[1].replace("件", "").trim().to_string();
-                if let Ok(count) = count_str.parse::<usize>() {
-                    return (Some(resource_type), Some(count));
-                }
-            }
-        }
-        (None, None)
-    }
-
     pub async fn get_scan_result(scan_id: &str) -> Option<ScanResponse> {
         let results = SCAN_RESULTS.read().await;
         results.get(scan_id).map(|result| {
@@ -391,6 +404,59 @@ impl ScanService {
                         if let Some(roles) = data.get("roles").and_then(|v| v.as_array()) {
                             summary.insert("roles".to_string(), roles.len());
                         }
+                        if let Some(instance_profiles) =
+                            data.get("instance_profiles").and_then(|v| v.as_array())
+                        {
+                            summary
+                                .insert("instance_profiles".to_string(), instance_profiles.len());
+                        }
+                        if let Some(oidc_providers) =
+                            data.get("oidc_providers").and_then(|v| v.as_array())
+                        {
+                            summary.insert("oidc_providers".to_string(), oidc_providers.len());
+                        }
+                        if let Some(saml_providers) =
+                            data.get("saml_providers").and_then(|v| v.as_array())
+                        {
+                            summary.insert("saml_providers".to_string(), saml_providers.len());
+                        }
+                        if let Some(password_policy) = data
+                            .get("account_password_policy")
+                            .and_then(|v| v.as_array())
+                        {
+                            summary.insert(
+                                "account_password_policy".to_string(),
+                                password_policy.len(),
+                            );
+                        }
+                        if let Some(account_aliases) =
+                            data.get("account_aliases").and_then(|v| v.as_array())
+                        {
+                            summary.insert("account_aliases".to_string(), account_aliases.len());
+                        }
+                        for key in [
+                            "sso_permission_sets",
+                            "sso_managed_policy_attachments",
+                            "sso_inline_policies",
+                            "sso_account_assignments",
+                            "s3_bucket_policies",
+                            "kms_key_policies",
+                            "sqs_queue_policies",
+                            "sns_topic_policies",
+                            "s3_buckets",
+                            "organizational_units",
+                            "service_control_policies",
+                            "service_control_policy_attachments",
+                            "vpcs",
+                            "subnets",
+                            "security_groups",
+                            "security_group_ingress_rules",
+                            "security_group_egress_rules",
+                        ] {
+                            if let Some(items) = data.get(key).and_then(|v| v.as_array()) {
+                                summary.insert(key.to_string(), items.len());
+                            }
+                        }
                         if let Some(policies) = data.get("policies").and_then(|v| v.as_array()) {
                             summary.insert("policies".to_string(), policies.len());
                         }
@@ -402,6 +468,15 @@ impl ScanService {
                         if let Some(cleanup) = data.get("cleanup").and_then(|v| v.as_array()) {
                             summary.insert("cleanup".to_string(), cleanup.len());
                         }
+                        if let Some(unused_identities) =
+                            data.get("unused_identities").and_then(|v| v.as_array())
+                        {
+                            summary
+                                .insert("unused_identities".to_string(), unused_identities.len());
+                        }
+                        if let Some(accounts) = data.get("accounts").and_then(|v| v.as_object()) {
+                            summary.insert("accounts".to_string(), accounts.len());
+                        }
                     } else if provider == "azure" {
                         if let Some(role_definitions) =
                             data.get("role_definitions").and_then(|v| v.as_array())
@@ -424,10 +499,50 @@ impl ScanService {
                 progress: result.progress,
                 message: result.message.clone(),
                 summary,
+                progress_detail: result.latest_progress.clone(),
+                warnings: result
+                    .data
+                    .as_ref()
+                    .map(Self::scan_warnings)
+                    .unwrap_or_default(),
             }
         })
     }
 
+    /// スキャンデータから部分的な失敗の一覧を取得
+    fn scan_warnings(data: &serde_json::Value) -> Vec<ScanWarning> {
+        data.get("warnings")
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    /// スキャン警告をSSEイベントに変換
+    fn warning_event(scan_id: &str, warning: ScanWarning) -> ScanProgressEvent {
+        let target = match &warning.account_id {
+            Some(account_id) => format!(
+                "[{}] {} {}",
+                account_id, warning.resource_type, warning.resource_name
+            ),
+            None => format!("{} {}", warning.resource_type, warning.resource_name),
+        };
+        ScanProgressEvent {
+            scan_id: scan_id.to_string(),
+            event_type: "warning".to_string(),
+            progress: 100,
+            message: format!(
+                "{}の{}に失敗しました（{}）",
+                target, warning.operation, warning.error_kind
+            ),
+            resource_type: Some(warning.resource_type.clone()),
+            resource_count: None,
+            data: serde_json::to_value(&warning).ok(),
+            phase: None,
+            completed: None,
+            total: None,
+            account_id: None,
+        }
+    }
+
     pub async fn get_scan_data(scan_id: &str) -> Option<serde_json::Value> {
         let results = SCAN_RESULTS.read().await;
         results.get(scan_id).and_then(|result| result.data.clone())
@@ -445,6 +560,7 @@ impl ScanService {
             status: "completed".to_string(),
             progress: Some(100),
             message: Some("Test scan completed".to_string()),
+            latest_progress: None,
             _config: config,
             data: Some(data),
         };
@@ -485,13 +601,67 @@ mod tests {
     async fn test_update_progress_for_nonexistent_scan() {
         // Test updating progress for a non-existent scan (should not panic)
         let non_existent_id = "non-existent-scan-id";
-        ScanService::update_progress(non_existent_id, 50, "Test message".to_string()).await;
+        ScanService::update_progress(
+            non_existent_id,
+            ScanProgress::new(ScanPhase::Scanning, 50, "Test message"),
+        )
+        .await;
 
         // Verify the scan was not created
         let result = ScanService::get_scan_result(non_existent_id).await;
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_progress_event_from_scan_progress() {
+        let mut progress =
+            ScanProgress::resource_completed(40, "users", 3, "IAM Usersのスキャン完了: 3件");
+        progress.account_id = Some("111111111111".to_string());
+
+        let event = ScanProgressEvent::from_progress("scan-1", progress);
+        assert_eq!(event.event_type, "resource");
+        assert_eq!(event.resource_type.as_deref(), Some("users"));
+        assert_eq!(event.resource_count, Some(3));
+        assert_eq!(event.account_id.as_deref(), Some("111111111111"));
+
+        let event = ScanProgressEvent::from_progress(
+            "scan-1",
+            ScanProgress::scanning(60, "attachments", "アタッチメントのスキャン中: 2/5")
+                .with_counts(2, 5),
+        );
+        assert_eq!(event.event_type, "progress");
+        assert_eq!(event.phase, Some(ScanPhase::Scanning));
+        assert_eq!(event.resource_count, None);
+        assert_eq!((event.completed, event.total), (Some(2), Some(5)));
+    }
+
+    #[test]
+    fn test_warning_event_from_scan_data() {
+        let data = serde_json::json!({
+            "provider": "aws",
+            "warnings": [{
+                "resource_type": "role",
+                "resource_name": "app",
+                "operation": "ListAttachedRolePolicies",
+                "error_kind": "access_denied",
+                "message": "AccessDenied",
+                "account_id": "111111111111"
+            }]
+        });
+
+        let warnings = ScanService::scan_warnings(&data);
+        assert_eq!(warnings.len(), 1);
+
+        let event = ScanService::warning_event("scan-1", warnings[0].clone());
+        assert_eq!(event.event_type, "warning");
+        assert_eq!(event.resource_type.as_deref(), Some("role"));
+        assert!(event.message.contains("[111111111111] role app"));
+        assert!(event.message.contains("ListAttachedRolePolicies"));
+        assert_eq!(event.data.unwrap()["error_kind"], "access_denied");
+
+        assert!(ScanService::scan_warnings(&serde_json::json!({})).is_empty());
+    }
+
     // Note: Full integration tests for start_scan would require:
     // - Mocked AWS/Azure scanners
     // - Test credentials
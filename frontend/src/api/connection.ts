@@ -4,6 +4,12 @@ export interface AwsConnectionConfig {
   profile?: string
   assume_role_arn?: string
   assume_role_session_name?: string
+  assume_role_external_id?: string
+  assume_role_duration_seconds?: number
+  mfa_serial?: string
+  mfa_token_code?: string
+  region?: string
+  endpoint_url?: string
 }
 
 export interface AzureConnectionConfig {
@@ -6,6 +6,8 @@ export interface GenerationConfig {
   naming_convention?: string;
   import_script_format?: string;
   generate_readme?: boolean;
+  parameterize_arns?: boolean;
+  account_id?: string;
   selected_resources?: Record<string, string[]>;
 }
 
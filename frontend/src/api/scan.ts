@@ -9,15 +9,50 @@ export interface ScanConfig {
   profile?: string
   assume_role_arn?: string
   assume_role_session_name?: string
+  assume_role_external_id?: string
+  assume_role_duration_seconds?: number
+  mfa_serial?: string
+  mfa_token_code?: string
+  /** マルチアカウントスキャンの対象アカウントIDリスト */
+  account_ids?: string[]
+  /** AWS Organizationsから対象アカウントを検出するかどうか */
+  discover_accounts?: boolean
+  /** 各アカウントで引き受けるロール名（デフォルト: OrganizationAccountAccessRole） */
+  member_role_name?: string
+  /** 使用するリージョン（未指定の場合はプロファイルの設定） */
+  region?: string
+  /** 全AWSサービス共通のエンドポイントURL（LocalStack等） */
+  endpoint_url?: string
   subscription_id?: string
+  /** Azureの認証方式（'az_login' | 'service_principal' | 'workload_identity'） */
   auth_method?: string
   tenant_id?: string
+  /** サービスプリンシパル/ワークロードIDの設定（client_id、client_secret等） */
+  service_principal_config?: Record<string, string>
   scope_type?: string
   scope_value?: string
   scan_targets: Record<string, boolean>
   filters?: Record<string, string>
   /** タグ情報を取得するかどうか（デフォルト: true） */
   include_tags?: boolean
+  /** IAM API呼び出しの最大同時実行数（デフォルト: 8） */
+  max_concurrency?: number
+  /** IAMスキャン方式（デフォルト: 'per_resource'） */
+  scan_strategy?: 'per_resource' | 'authorization_details'
+  /** サービス最終アクセス情報を取得するかどうか（デフォルト: false） */
+  include_service_last_accessed?: boolean
+  /** 未使用と判定するまでの経過日数（デフォルト: 90） */
+  unused_threshold_days?: number
+}
+
+/** スキャン中の部分的な失敗（該当リソースの情報が不完全な可能性がある） */
+export interface ScanWarning {
+  resource_type: string
+  resource_name: string
+  operation: string
+  error_kind: 'access_denied' | 'throttling' | 'not_found' | 'other'
+  message: string
+  account_id?: string
 }
 
 export interface ScanResponse {
@@ -25,6 +60,21 @@ export interface ScanResponse {
   status: string
   summary?: Record<string, number>
   resources?: Record<string, unknown[]>
+  warnings?: ScanWarning[]
+}
+
+/** スキャナーが通知する進捗の段階 */
+export type ScanPhase = 'started' | 'scanning' | 'resource_completed' | 'completed'
+
+/** スキャナーが通知する進捗（SSE・ポーリング共通） */
+export interface ScanProgressDetail {
+  phase: ScanPhase
+  progress: number
+  resource_type?: string
+  completed?: number
+  total?: number
+  account_id?: string
+  message: string
 }
 
 export interface ScanStatus {
@@ -33,23 +83,30 @@ export interface ScanStatus {
   progress: number
   message: string
   summary?: Record<string, number>
+  progress_detail?: ScanProgressDetail
+  warnings?: ScanWarning[]
 }
 
 /** SSEストリーミングスキャンの進捗イベント */
 export interface ScanProgressEvent {
   scan_id: string
-  event_type: 'progress' | 'resource' | 'completed' | 'error'
+  event_type: 'progress' | 'resource' | 'warning' | 'completed' | 'error'
   progress: number
   message: string
   resource_type?: string
   resource_count?: number
   data?: unknown
+  phase?: ScanPhase
+  completed?: number
+  total?: number
+  account_id?: string
 }
 
 /** ストリーミングスキャンのコールバック関数 */
 export interface ScanStreamCallbacks {
   onProgress?: (event: ScanProgressEvent) => void
   onResource?: (event: ScanProgressEvent) => void
+  onWarning?: (event: ScanProgressEvent) => void
   onCompleted?: (event: ScanProgressEvent) => void
   onError?: (error: Error | ScanProgressEvent) => void
 }
@@ -134,6 +191,9 @@ async function fetchSSEStream(
               case 'resource':
                 callbacks.onResource?.(event)
                 break
+              case 'warning':
+                callbacks.onWarning?.(event)
+                break
               case 'completed':
                 callbacks.onCompleted?.(event)
                 break
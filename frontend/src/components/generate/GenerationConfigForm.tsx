@@ -133,6 +133,19 @@ export default function GenerationConfigForm({
           READMEを生成
         </label>
       </div>
+
+      <div style={{ marginBottom: "1rem" }}>
+        <label style={{ display: "flex", alignItems: "center", gap: "0.5rem" }}>
+          <input
+            type="checkbox"
+            checked={config.parameterize_arns === true}
+            onChange={(e) =>
+              updateConfig({ parameterize_arns: e.target.checked })
+            }
+          />
+          ARNのパーティション・アカウントIDをデータソース参照に置き換える
+        </label>
+      </div>
     </div>
   );
 }
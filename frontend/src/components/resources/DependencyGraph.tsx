@@ -22,7 +22,15 @@ const nodeColors: Record<string, string> = {
   user: '#4CAF50',
   group: '#2196F3',
   role: '#FF9800',
+  instance_profile: '#FFC107',
+  oidc_provider: '#00BCD4',
+  saml_provider: '#009688',
   policy: '#9C27B0',
+  s3_bucket: '#8BC34A',
+  kms_key: '#E91E63',
+  sqs_queue: '#FF5722',
+  sns_topic: '#795548',
+  aws_principal: '#607D8B',
   role_definition: '#9C27B0',
   principal: '#4CAF50',
 }
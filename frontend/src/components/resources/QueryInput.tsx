@@ -99,6 +99,7 @@ export default function QueryInput({ onQuery, onClear, isLoading, error }: Props
           <ul style={{ margin: '0.5rem 0', paddingLeft: '1.5rem' }}>
             <li><code>field == "value"</code> - 等価</li>
             <li><code>field != "value"</code> - 不等価</li>
+            <li><code>field &gt; 90</code> / <code>&gt;=</code> / <code>&lt;</code> / <code>&lt;=</code> - 数値の大小比較</li>
             <li><code>field LIKE "pattern*"</code> - パターンマッチ</li>
             <li><code>field IN ["a", "b"]</code> - 配列に含まれる</li>
             <li><code>expr AND expr</code> - 論理積</li>
@@ -112,6 +113,7 @@ export default function QueryInput({ onQuery, onClear, isLoading, error }: Props
             <li><code>tags.env == "production"</code></li>
             <li><code>path LIKE "/admin/*"</code></li>
             <li><code>role IN ["admin", "moderator"]</code></li>
+            <li><code>last_used_days &gt; 90</code></li>
             <li><code>tags.env == "production" AND user_name LIKE "app-*"</code></li>
             <li><code>(path == "/" OR path LIKE "/admin/*") AND NOT tags.temporary == "true"</code></li>
           </ul>
@@ -9,11 +9,23 @@ interface ScanTargetSelectorProps {
 }
 
 const AWS_TARGETS = [
-  { key: "users", label: "Users (Access Keys, Login Profiles, MFA)" },
+  { key: "users", label: "Users" },
   { key: "groups", label: "Groups" },
   { key: "roles", label: "Roles" },
+  { key: "instance_profiles", label: "Instance Profiles" },
+  { key: "oidc_providers", label: "OIDC Providers" },
+  { key: "saml_providers", label: "SAML Providers" },
+  { key: "account_settings", label: "Account Settings (Password Policy, Alias)" },
+  { key: "identity_center", label: "IAM Identity Center (Permission Sets, Assignments)" },
+  { key: "resource_policies", label: "Resource Policies (S3, KMS, SQS, SNS)" },
+  { key: "organizations", label: "Organizations (OUs, Service Control Policies)" },
+  { key: "s3_buckets", label: "S3 Buckets (with Configuration)" },
+  { key: "vpcs", label: "VPCs" },
+  { key: "subnets", label: "Subnets" },
+  { key: "security_groups", label: "Security Groups (with Rules)" },
   { key: "policies", label: "Policies" },
   { key: "attachments", label: "Attachments" },
+  { key: "cleanup", label: "Cleanup (Access Keys, Login Profiles, MFA)" },
 ];
 
 const AZURE_TARGETS = [
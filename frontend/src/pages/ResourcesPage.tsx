@@ -32,9 +32,30 @@ export default function ResourcesPage() {
     { id: "users", label: "Users" },
     { id: "groups", label: "Groups" },
     { id: "roles", label: "Roles" },
+    { id: "instance_profiles", label: "Instance Profiles" },
+    { id: "oidc_providers", label: "OIDC Providers" },
+    { id: "saml_providers", label: "SAML Providers" },
+    { id: "account_password_policy", label: "Password Policy" },
+    { id: "account_aliases", label: "Account Alias" },
+    { id: "sso_permission_sets", label: "Permission Sets" },
+    { id: "sso_account_assignments", label: "Account Assignments" },
+    { id: "s3_buckets", label: "S3 Buckets" },
+    { id: "s3_bucket_policies", label: "Bucket Policies" },
+    { id: "kms_key_policies", label: "Key Policies" },
+    { id: "sqs_queue_policies", label: "Queue Policies" },
+    { id: "sns_topic_policies", label: "Topic Policies" },
+    { id: "organizational_units", label: "Organizational Units" },
+    { id: "service_control_policies", label: "SCPs" },
+    { id: "service_control_policy_attachments", label: "SCP Attachments" },
+    { id: "vpcs", label: "VPCs" },
+    { id: "subnets", label: "Subnets" },
+    { id: "security_groups", label: "Security Groups" },
+    { id: "security_group_ingress_rules", label: "Ingress Rules" },
+    { id: "security_group_egress_rules", label: "Egress Rules" },
     { id: "policies", label: "Policies" },
     { id: "attachments", label: "Attachments" },
     { id: "cleanup", label: "Cleanup" },
+    { id: "unused_identities", label: "Unused" },
     { id: "dependencies", label: "Dependencies" },
   ];
 
@@ -217,11 +238,49 @@ export default function ResourcesPage() {
     }
   };
 
+  // マルチアカウントスキャンでは同名のリソースを区別するため、IDにアカウントIDを付与
   const getResourceId = (resource: any): string => {
+    const id = getAccountLocalResourceId(resource);
+    return resource.account_id ? `${resource.account_id}:${id}` : id;
+  };
+
+  const getAccountLocalResourceId = (resource: any): string => {
     if (activeTab === "users") return resource.user_name || resource.id;
     if (activeTab === "groups") return resource.group_name || resource.id;
     if (activeTab === "roles") return resource.role_name || resource.id;
+    if (activeTab === "instance_profiles")
+      return resource.instance_profile_name || resource.id;
+    if (activeTab === "oidc_providers" || activeTab === "saml_providers")
+      return resource.arn || resource.id;
+    if (activeTab === "account_password_policy") return "account_password_policy";
+    if (activeTab === "account_aliases") return resource.account_alias || resource.id;
+    if (activeTab === "sso_permission_sets")
+      return resource.permission_set_arn || resource.id;
+    if (activeTab === "sso_account_assignments")
+      return resource.permission_set_arn && resource.principal_id
+        ? `${resource.permission_set_arn}_${resource.target_id}_${resource.principal_type}_${resource.principal_id}`
+        : resource.id;
+    if (activeTab === "s3_buckets" || activeTab === "s3_bucket_policies")
+      return resource.bucket || resource.id;
+    if (activeTab === "kms_key_policies") return resource.key_arn || resource.id;
+    if (activeTab === "sqs_queue_policies") return resource.queue_arn || resource.id;
+    if (activeTab === "sns_topic_policies") return resource.topic_arn || resource.id;
+    if (activeTab === "organizational_units") return resource.ou_id || resource.id;
+    if (activeTab === "service_control_policies") return resource.policy_id || resource.id;
+    if (activeTab === "service_control_policy_attachments")
+      return resource.policy_id && resource.target_id
+        ? `${resource.policy_id}_${resource.target_id}`
+        : resource.id;
+    if (activeTab === "vpcs") return resource.vpc_id || resource.id;
+    if (activeTab === "subnets") return resource.subnet_id || resource.id;
+    if (activeTab === "security_groups") return resource.group_id || resource.id;
+    if (
+      activeTab === "security_group_ingress_rules" ||
+      activeTab === "security_group_egress_rules"
+    )
+      return resource.rule_id || resource.id;
     if (activeTab === "policies") return resource.arn || resource.id;
+    if (activeTab === "unused_identities") return resource.arn || resource.id;
     if (activeTab === "attachments") {
       // Create unique ID from entity type, name, policy type, and policy name/ARN
       const entityType = resource.entity_type || resource.target_type || "";
@@ -248,12 +307,19 @@ export default function ResourcesPage() {
     return resource.id || JSON.stringify(resource);
   };
 
+  // マルチアカウントスキャンの結果にはアカウント列を追加
+  const withAccountColumn = (columns: any[]) => {
+    if (!resources.some((resource) => resource.account_id)) return columns;
+    return [{ key: "account_id", label: "Account" }, ...columns];
+  };
+
   const getColumns = () => {
     if (activeTab === "users") {
       return [
         { key: "user_name", label: "User Name" },
         { key: "arn", label: "ARN" },
         { key: "path", label: "Path" },
+        { key: "last_used_days", label: "Last Used (days)" },
       ];
     }
     if (activeTab === "groups") {
@@ -266,8 +332,161 @@ export default function ResourcesPage() {
     if (activeTab === "roles") {
       return [
         { key: "role_name", label: "Role Name" },
+        { key: "role_category", label: "Category" },
+        { key: "arn", label: "ARN" },
+        { key: "path", label: "Path" },
+        { key: "last_used_days", label: "Last Used (days)" },
+      ];
+    }
+    if (activeTab === "instance_profiles") {
+      return [
+        { key: "instance_profile_name", label: "Instance Profile Name" },
+        { key: "role_name", label: "Role" },
+        { key: "arn", label: "ARN" },
+      ];
+    }
+    if (activeTab === "oidc_providers") {
+      return [
+        { key: "url", label: "URL" },
+        {
+          key: "client_id_list",
+          label: "Client IDs",
+          render: (resource: any) =>
+            (resource.client_id_list || []).join(", ") || "-",
+        },
+        { key: "arn", label: "ARN" },
+      ];
+    }
+    if (activeTab === "saml_providers") {
+      return [
+        { key: "name", label: "Name" },
+        { key: "valid_until", label: "Valid Until" },
         { key: "arn", label: "ARN" },
+      ];
+    }
+    if (activeTab === "account_password_policy") {
+      return [
+        { key: "minimum_password_length", label: "Minimum Length" },
+        { key: "max_password_age", label: "Max Age (days)" },
+        { key: "password_reuse_prevention", label: "Reuse Prevention" },
+        {
+          key: "require_symbols",
+          label: "Require Symbols",
+          render: (resource: any) => (resource.require_symbols ? "Yes" : "No"),
+        },
+      ];
+    }
+    if (activeTab === "account_aliases") {
+      return [{ key: "account_alias", label: "Account Alias" }];
+    }
+    if (activeTab === "sso_permission_sets") {
+      return [
+        { key: "name", label: "Name" },
+        { key: "description", label: "Description" },
+        { key: "session_duration", label: "Session Duration" },
+        { key: "permission_set_arn", label: "ARN" },
+      ];
+    }
+    if (activeTab === "sso_account_assignments") {
+      return [
+        { key: "permission_set_name", label: "Permission Set" },
+        { key: "target_id", label: "Account ID" },
+        { key: "principal_type", label: "Principal Type" },
+        { key: "principal_id", label: "Principal ID" },
+      ];
+    }
+    if (activeTab === "s3_bucket_policies") {
+      return [{ key: "bucket", label: "Bucket" }];
+    }
+    if (activeTab === "kms_key_policies") {
+      return [
+        { key: "key_id", label: "Key ID" },
+        { key: "description", label: "Description" },
+        { key: "key_arn", label: "ARN" },
+      ];
+    }
+    if (activeTab === "sqs_queue_policies") {
+      return [
+        { key: "queue_name", label: "Queue Name" },
+        { key: "queue_url", label: "URL" },
+        { key: "queue_arn", label: "ARN" },
+      ];
+    }
+    if (activeTab === "sns_topic_policies") {
+      return [
+        { key: "topic_name", label: "Topic Name" },
+        { key: "topic_arn", label: "ARN" },
+      ];
+    }
+    if (activeTab === "organizational_units") {
+      return [
+        { key: "name", label: "Name" },
         { key: "path", label: "Path" },
+        { key: "ou_id", label: "OU ID" },
+        { key: "parent_id", label: "Parent ID" },
+      ];
+    }
+    if (activeTab === "s3_buckets") {
+      return [
+        { key: "bucket", label: "Bucket" },
+        { key: "region", label: "Region" },
+        { key: "versioning_status", label: "Versioning" },
+        { key: "object_ownership", label: "Object Ownership" },
+      ];
+    }
+    if (activeTab === "service_control_policies") {
+      return [
+        { key: "name", label: "Policy Name" },
+        { key: "policy_id", label: "Policy ID" },
+        { key: "aws_managed", label: "AWS Managed" },
+        { key: "description", label: "Description" },
+      ];
+    }
+    if (activeTab === "service_control_policy_attachments") {
+      return [
+        { key: "policy_name", label: "Policy Name" },
+        { key: "target_name", label: "Target Name" },
+        { key: "target_type", label: "Target Type" },
+        { key: "target_id", label: "Target ID" },
+      ];
+    }
+    if (activeTab === "vpcs") {
+      return [
+        { key: "vpc_id", label: "VPC ID" },
+        { key: "name", label: "Name" },
+        { key: "cidr_block", label: "CIDR" },
+        { key: "is_default", label: "Default" },
+      ];
+    }
+    if (activeTab === "subnets") {
+      return [
+        { key: "subnet_id", label: "Subnet ID" },
+        { key: "name", label: "Name" },
+        { key: "vpc_id", label: "VPC ID" },
+        { key: "cidr_block", label: "CIDR" },
+        { key: "availability_zone", label: "AZ" },
+      ];
+    }
+    if (activeTab === "security_groups") {
+      return [
+        { key: "group_id", label: "Group ID" },
+        { key: "group_name", label: "Group Name" },
+        { key: "vpc_id", label: "VPC ID" },
+        { key: "description", label: "Description" },
+      ];
+    }
+    if (
+      activeTab === "security_group_ingress_rules" ||
+      activeTab === "security_group_egress_rules"
+    ) {
+      return [
+        { key: "rule_id", label: "Rule ID" },
+        { key: "group_name", label: "Group Name" },
+        { key: "ip_protocol", label: "Protocol" },
+        { key: "from_port", label: "From" },
+        { key: "to_port", label: "To" },
+        { key: "cidr_ipv4", label: "CIDR" },
+        { key: "referenced_group_id", label: "Source Group" },
       ];
     }
     if (activeTab === "policies") {
@@ -277,6 +496,19 @@ export default function ResourcesPage() {
         { key: "path", label: "Path" },
       ];
     }
+    if (activeTab === "unused_identities") {
+      return [
+        { key: "principal_type", label: "Type" },
+        { key: "name", label: "Name" },
+        { key: "last_used_days", label: "Idle (days)" },
+        {
+          key: "never_used",
+          label: "Never Used",
+          render: (resource: any) => (resource.never_used ? "Yes" : "No"),
+        },
+        { key: "arn", label: "ARN" },
+      ];
+    }
     if (activeTab === "attachments") {
       return [
         {
@@ -501,7 +733,7 @@ export default function ResourcesPage() {
               onSelectionChange={handleSelectionChange}
               onSelectAll={handleSelectAll}
               getResourceId={getResourceId}
-              columns={getColumns()}
+              columns={withAccountColumn(getColumns())}
               resourceType={activeTab}
             />
             {totalPages > 0 && (